@@ -0,0 +1,188 @@
+//! `util/dev bench`: times Clippy's lint passes on a pinned corpus of crates
+//! and compares the times against a saved baseline.
+
+use crate::corpus;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Passes which took less nanoseconds than this in the baseline are too noisy
+/// to compare.
+const NOISE_FLOOR: u64 = 10_000_000;
+
+/// The nanoseconds each pass took, keyed by `(crate, pass)`.
+pub type Times = BTreeMap<(String, String), u64>;
+
+pub struct Options {
+    /// How often each crate is linted, the fastest run counts.
+    pub runs: usize,
+    /// The slowdown in percent which counts as a regression.
+    pub threshold: f64,
+    pub baseline: PathBuf,
+    /// Whether to save the times as the baseline instead of comparing them.
+    pub save: bool,
+}
+
+/// Returns whether the times are within the threshold of the baseline.
+///
+/// # Errors
+///
+/// Fails if the crates can't be linted or the baseline can't be read or
+/// written.
+pub fn run(options: &Options) -> Result<bool, String> {
+    let bench_dir = Path::new("../target/bench");
+    fs::create_dir_all(bench_dir).map_err(|e| format!("couldn't create `{}`: {}", bench_dir.display(), e))?;
+    let bench_dir = bench_dir.canonicalize().map_err(|e| e.to_string())?;
+
+    let mut times = Times::new();
+    for (name, version) in corpus::crates() {
+        let krate = format!("{}-{}", name, version);
+        println!("linting {}", krate);
+        let dir = corpus::fetch(name, version)?;
+        for (pass, nanos) in time_crate(&bench_dir, &dir, name, options.runs)? {
+            times.insert((krate.clone(), pass), nanos);
+        }
+    }
+
+    if options.save {
+        fs::write(&options.baseline, format_times(&times))
+            .map_err(|e| format!("couldn't write `{}`: {}", options.baseline.display(), e))?;
+        println!("saved the baseline to `{}`", options.baseline.display());
+        return Ok(true);
+    }
+    let baseline = fs::read_to_string(&options.baseline).map_err(|e| {
+        format!(
+            "couldn't read the baseline `{}`, create it with `--save`: {}",
+            options.baseline.display(),
+            e
+        )
+    })?;
+    let regressions = regressions(&parse_times(&baseline), &times, options.threshold);
+    for ((krate, pass), base, nanos) in &regressions {
+        println!(
+            "{} {}: {:.1}ms -> {:.1}ms (+{:.0}%)",
+            krate,
+            pass,
+            *base as f64 / 1e6,
+            *nanos as f64 / 1e6,
+            (*nanos as f64 / *base as f64 - 1.0) * 100.0
+        );
+    }
+    println!("{} regressions", regressions.len());
+    Ok(regressions.is_empty())
+}
+
+/// Lints the crate in `dir` `runs` times and returns the fastest time of each
+/// pass.
+fn time_crate(bench_dir: &Path, dir: &Path, name: &str, runs: usize) -> Result<BTreeMap<String, u64>, String> {
+    let manifest = dir.join("Cargo.toml");
+    let target_dir = bench_dir.join("target");
+    let times_file = bench_dir.join("times.txt");
+    let mut fastest = BTreeMap::new();
+    for _ in 0..runs {
+        corpus::clean(dir, name, &target_dir)?;
+        let _ = fs::remove_file(&times_file);
+
+        // the crate may well trigger deny-by-default lints, so the exit status
+        // is ignored
+        Command::new("cargo")
+            .current_dir("..")
+            .args(&[
+                "run",
+                "--release",
+                "--bin",
+                "cargo-clippy",
+                "--",
+                "clippy",
+                "--manifest-path",
+            ])
+            .arg(&manifest)
+            .arg("--target-dir")
+            .arg(&target_dir)
+            .env("CLIPPY_TIME_PASSES", &times_file)
+            .status()
+            .map_err(|e| format!("couldn't run cargo: {}", e))?;
+
+        let times = fs::read_to_string(&times_file).map_err(|_| format!("Clippy didn't lint {}", dir.display()))?;
+        // each target of the crate has its own times
+        let mut run = BTreeMap::new();
+        for line in times.lines() {
+            let mut parts = line.split_whitespace();
+            if let (Some(pass), Some(Ok(nanos))) = (parts.next(), parts.next().map(str::parse::<u64>)) {
+                *run.entry(pass.to_owned()).or_insert(0) += nanos;
+            }
+        }
+        for (pass, nanos) in run {
+            let best = fastest.entry(pass).or_insert(nanos);
+            *best = (*best).min(nanos);
+        }
+    }
+    Ok(fastest)
+}
+
+fn format_times(times: &Times) -> String {
+    times
+        .iter()
+        .map(|((krate, pass), nanos)| format!("{} {} {}\n", krate, pass, nanos))
+        .collect()
+}
+
+fn parse_times(text: &str) -> Times {
+    text.lines()
+        .filter_map(|line| {
+            let mut parts = line.split_whitespace();
+            let key = (parts.next()?.to_owned(), parts.next()?.to_owned());
+            Some((key, parts.next()?.parse().ok()?))
+        })
+        .collect()
+}
+
+/// Returns the passes which got more than `threshold` percent slower than in
+/// the baseline, with their time in the baseline and now.
+fn regressions(baseline: &Times, times: &Times, threshold: f64) -> Vec<((String, String), u64, u64)> {
+    times
+        .iter()
+        .filter_map(|(key, &nanos)| {
+            let base = *baseline.get(key)?;
+            if base >= NOISE_FLOOR && nanos as f64 > base as f64 * (1.0 + threshold / 100.0) {
+                Some((key.clone(), base, nanos))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn test_parse_times() {
+    let mut times = Times::new();
+    times.insert(("log-0.4.6".to_owned(), "EqOp".to_owned()), 42);
+    times.insert(("regex-1.1.0".to_owned(), "Types".to_owned()), 1_000_000);
+    assert_eq!(parse_times(&format_times(&times)), times);
+    assert_eq!(parse_times("log-0.4.6 EqOp\n\n"), Times::new());
+}
+
+#[test]
+fn test_regressions() {
+    let key = |pass: &str| ("regex-1.1.0".to_owned(), pass.to_owned());
+    let baseline: Times = vec![
+        (key("Fast"), 1_000),
+        (key("Slow"), 100_000_000),
+        (key("Slower"), 100_000_000),
+    ]
+    .into_iter()
+    .collect();
+    let times: Times = vec![
+        (key("Fast"), 1_000_000),
+        (key("Slow"), 110_000_000),
+        (key("Slower"), 130_000_000),
+        (key("New"), 200_000_000),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(
+        regressions(&baseline, &times, 20.0),
+        vec![(key("Slower"), 100_000_000, 130_000_000)]
+    );
+}
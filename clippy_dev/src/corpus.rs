@@ -0,0 +1,112 @@
+//! The crates `util/dev bench` and `util/dev lintcheck` lint. They are
+//! downloaded through cargo once and kept in `target/corpus`.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use walkdir::WalkDir;
+
+/// The crates to lint, one `<name> <version>` per line.
+const CORPUS: &str = include_str!("../corpus.txt");
+
+/// Returns the name and version of each crate in the corpus.
+pub fn crates() -> impl Iterator<Item = (&'static str, &'static str)> {
+    CORPUS
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            let mut parts = line.split_whitespace();
+            Some((parts.next()?, parts.next()?))
+        })
+}
+
+/// Returns the directory with the copy of the crate, after downloading it
+/// through cargo and copying it into its own workspace if that wasn't done
+/// before.
+///
+/// # Errors
+///
+/// Fails if cargo can't download the crate or the copy can't be written.
+pub fn fetch(name: &str, version: &str) -> Result<PathBuf, String> {
+    let corpus_dir = Path::new("../target/corpus");
+    fs::create_dir_all(corpus_dir).map_err(|e| format!("couldn't create `{}`: {}", corpus_dir.display(), e))?;
+    let corpus_dir = corpus_dir.canonicalize().map_err(|e| e.to_string())?;
+    let krate = format!("{}-{}", name, version);
+    let dir = corpus_dir.join(&krate);
+    if dir.exists() {
+        return Ok(dir);
+    }
+
+    // a crate depending on exactly this version, to have cargo download it
+    let fetch_dir = corpus_dir.join("fetch").join(&krate);
+    fs::create_dir_all(fetch_dir.join("src")).map_err(|e| e.to_string())?;
+    fs::write(fetch_dir.join("src").join("lib.rs"), "").map_err(|e| e.to_string())?;
+    fs::write(
+        fetch_dir.join("Cargo.toml"),
+        format!(
+            "[package]\nname = \"fetch\"\nversion = \"0.0.0\"\n\n[dependencies]\n{} = \"={}\"\n\n[workspace]\n",
+            name, version
+        ),
+    )
+    .map_err(|e| e.to_string())?;
+    let output = Command::new("cargo")
+        .args(&["metadata", "--format-version", "1", "--manifest-path"])
+        .arg(fetch_dir.join("Cargo.toml"))
+        .output()
+        .map_err(|e| format!("couldn't run cargo: {}", e))?;
+    if !output.status.success() {
+        return Err(format!(
+            "couldn't fetch {}:\n{}",
+            krate,
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+    let metadata: serde_json::Value = serde_json::from_slice(&output.stdout).map_err(|e| e.to_string())?;
+    let source = source_dir(&metadata, name, version).ok_or_else(|| format!("cargo didn't fetch {}", krate))?;
+
+    for entry in WalkDir::new(&source).into_iter().filter_map(Result::ok) {
+        let target = dir.join(entry.path().strip_prefix(&source).map_err(|e| e.to_string())?);
+        if entry.file_type().is_dir() {
+            fs::create_dir_all(&target).map_err(|e| e.to_string())?;
+        } else {
+            fs::copy(entry.path(), &target).map_err(|e| e.to_string())?;
+        }
+    }
+    // otherwise cargo would consider the copy part of Clippy's workspace
+    let manifest = fs::read_to_string(dir.join("Cargo.toml")).map_err(|e| e.to_string())?;
+    fs::write(dir.join("Cargo.toml"), manifest + "\n[workspace]\n").map_err(|e| e.to_string())?;
+    Ok(dir)
+}
+
+/// Returns the directory cargo downloaded the crate to, from the output of
+/// `cargo metadata`.
+fn source_dir(metadata: &serde_json::Value, name: &str, version: &str) -> Option<PathBuf> {
+    metadata["packages"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .find(|package| package["name"] == name && package["version"] == version)
+        .and_then(|package| {
+            Path::new(package["manifest_path"].as_str()?)
+                .parent()
+                .map(Path::to_path_buf)
+        })
+}
+
+/// Removes the build results of the crate in `dir`, otherwise cargo wouldn't
+/// run Clippy on it again.
+///
+/// # Errors
+///
+/// Fails if `cargo clean` can't be run.
+pub fn clean(dir: &Path, name: &str, target_dir: &Path) -> Result<(), String> {
+    Command::new("cargo")
+        .args(&["clean", "-p", name, "--manifest-path"])
+        .arg(dir.join("Cargo.toml"))
+        .arg("--target-dir")
+        .arg(target_dir)
+        .status()
+        .map_err(|e| format!("couldn't run cargo: {}", e))?;
+    Ok(())
+}
@@ -9,6 +9,10 @@ use std::fs;
 use std::io::prelude::*;
 use walkdir::WalkDir;
 
+pub mod bench;
+pub mod corpus;
+pub mod lintcheck;
+
 lazy_static! {
     static ref DEC_CLIPPY_LINT_RE: Regex = Regex::new(
         r#"(?x)
@@ -47,7 +51,7 @@ impl Lint {
             name: name.to_lowercase(),
             group: group.to_string(),
             desc: NL_ESCAPE_RE.replace(&desc.replace("\\\"", "\""), "").to_string(),
-            deprecation: deprecation.map(|d| d.to_string()),
+            deprecation: deprecation.map(ToString::to_string),
             module: module.to_string(),
         }
     }
@@ -61,7 +65,7 @@ impl Lint {
     pub fn by_lint_group(lints: &[Self]) -> HashMap<String, Vec<Self>> {
         lints
             .iter()
-            .map(|lint| (lint.group.to_string(), lint.clone()))
+            .map(|lint| (lint.group.clone(), lint.clone()))
             .into_group_map()
     }
 
@@ -85,6 +89,21 @@ pub fn gen_lint_group_list(lints: Vec<Lint>) -> Vec<String> {
         .collect::<Vec<String>>()
 }
 
+/// Generates the table of the lint groups in `src/sarif.rs`.
+pub fn gen_lint_groups(lints: Vec<Lint>) -> Vec<String> {
+    lints
+        .into_iter()
+        .filter_map(|l| {
+            if l.is_internal() || l.deprecation.is_some() {
+                None
+            } else {
+                Some(format!("    (\"{}\", \"{}\"),", l.name, l.group))
+            }
+        })
+        .sorted()
+        .collect::<Vec<String>>()
+}
+
 /// Generates the `pub mod module_name` list in `clippy_lints/src/lib.rs`.
 pub fn gen_modules_list(lints: Vec<Lint>) -> Vec<String> {
     lints
@@ -178,7 +197,7 @@ fn lint_files() -> impl Iterator<Item = walkdir::DirEntry> {
     // Otherwise we would not collect all the lints, for example in `clippy_lints/src/methods/`.
     WalkDir::new("../clippy_lints/src")
         .into_iter()
-        .filter_map(|f| f.ok())
+        .filter_map(Result::ok)
         .filter(|f| f.path().extension() == Some(OsStr::new("rs")))
 }
 
@@ -194,7 +213,12 @@ pub struct FileChange {
 /// `path` is the relative path to the file on which you want to perform the replacement.
 ///
 /// See `replace_region_in_text` for documentation of the other options.
-#[allow(clippy::expect_fun_call)]
+///
+/// # Panics
+///
+/// Panics if the file can't be read or written, or if `start` or `end` isn't
+/// a valid regex.
+#[allow(clippy::expect_fun_call, clippy::adjacent_same_type_arguments)]
 pub fn replace_region_in_file<F>(
     path: &str,
     start: &str,
@@ -237,6 +261,10 @@ where
 /// If you want to perform the replacement on files instead of already parsed text,
 /// use `replace_region_in_file`.
 ///
+/// # Panics
+///
+/// Panics if `start` or `end` isn't a valid regex.
+///
 /// # Example
 ///
 /// ```
@@ -421,6 +449,27 @@ fn test_gen_changelog_lint_list() {
     assert_eq!(expected, gen_changelog_lint_list(lints));
 }
 
+#[test]
+fn test_gen_lint_groups() {
+    let lints = vec![
+        Lint::new("should_assert_eq", "group1", "abc", None, "module_name"),
+        Lint::new("abc", "group2", "abc", None, "module_name"),
+        Lint::new(
+            "should_assert_eq2",
+            "group2",
+            "abc",
+            Some("will be removed"),
+            "module_name",
+        ),
+        Lint::new("incorrect_internal", "internal_style", "abc", None, "module_name"),
+    ];
+    let expected = vec![
+        "    (\"abc\", \"group2\"),".to_string(),
+        "    (\"should_assert_eq\", \"group1\"),".to_string(),
+    ];
+    assert_eq!(expected, gen_lint_groups(lints));
+}
+
 #[test]
 fn test_gen_deprecated() {
     let lints = vec![
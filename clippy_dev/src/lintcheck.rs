@@ -0,0 +1,265 @@
+//! `util/dev lintcheck`: lints the corpus with the working copy and with a
+//! reference revision of Clippy and reports the warnings which were added or
+//! removed, per lint.
+
+use crate::corpus;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+/// The lints which are enabled in addition to the default ones.
+const LINT_ARGS: &[&str] = &["-W", "clippy::pedantic", "-W", "clippy::nursery"];
+
+/// A warning emitted by Clippy. Saved as a line with the tab separated fields.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Warning {
+    pub krate: String,
+    pub lint: String,
+    /// `<file>:<line>:<column>`, relative to the crate.
+    pub location: String,
+    pub message: String,
+}
+
+pub struct Options {
+    /// The revision of Clippy to compare against, e.g. `master`.
+    pub reference: String,
+}
+
+pub fn run(options: &Options) -> Result<(), String> {
+    let lintcheck_dir = Path::new("../target/lintcheck");
+    fs::create_dir_all(lintcheck_dir.join("results"))
+        .map_err(|e| format!("couldn't create `{}`: {}", lintcheck_dir.display(), e))?;
+    let lintcheck_dir = lintcheck_dir.canonicalize().map_err(|e| e.to_string())?;
+
+    // the warnings of a commit don't change, so they are only collected once
+    let commit = rev_parse(&options.reference)?;
+    let cached = lintcheck_dir.join("results").join(format!("{}.txt", commit));
+    let reference = match fs::read_to_string(&cached) {
+        Ok(text) => parse_warnings(&text),
+        Err(_) => {
+            let clippy_dir = lintcheck_dir.join("reference");
+            checkout(&clippy_dir, &commit)?;
+            println!("linting the corpus with {} ({})", options.reference, commit);
+            let warnings = lint_corpus(&clippy_dir, &lintcheck_dir.join("reference-target"))?;
+            fs::write(&cached, format_warnings(&warnings))
+                .map_err(|e| format!("couldn't write `{}`: {}", cached.display(), e))?;
+            warnings
+        },
+    };
+    println!("linting the corpus with the working copy");
+    let current = lint_corpus(Path::new(".."), &lintcheck_dir.join("target"))?;
+    let current_file = lintcheck_dir.join("results").join("current.txt");
+    fs::write(&current_file, format_warnings(&current))
+        .map_err(|e| format!("couldn't write `{}`: {}", current_file.display(), e))?;
+
+    let (added, removed) = diff(&reference, &current);
+    let mut per_lint = BTreeMap::new();
+    for warning in &added {
+        per_lint.entry(&warning.lint).or_insert((0, 0)).0 += 1;
+    }
+    for warning in &removed {
+        per_lint.entry(&warning.lint).or_insert((0, 0)).1 += 1;
+    }
+    for (lint, (added, removed)) in &per_lint {
+        println!("{}: +{} -{}", lint, added, removed);
+    }
+    for (title, warnings) in &[("added", &added), ("removed", &removed)] {
+        if !warnings.is_empty() {
+            println!("\n{}:", title);
+        }
+        for warning in warnings.iter() {
+            println!(
+                "{} {} {}: {}",
+                warning.krate, warning.location, warning.lint, warning.message
+            );
+        }
+    }
+    println!(
+        "\n{} warnings added, {} removed compared to {}",
+        added.len(),
+        removed.len(),
+        options.reference
+    );
+    Ok(())
+}
+
+fn rev_parse(rev: &str) -> Result<String, String> {
+    let output = Command::new("git")
+        .args(&["rev-parse", "--verify"])
+        .arg(format!("{}^{{commit}}", rev))
+        .output()
+        .map_err(|e| format!("couldn't run git: {}", e))?;
+    if !output.status.success() {
+        return Err(format!("`{}` isn't a revision of Clippy", rev));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_owned())
+}
+
+/// Checks `commit` out into a git worktree in `dir`.
+fn checkout(dir: &Path, commit: &str) -> Result<(), String> {
+    let mut git = Command::new("git");
+    if dir.exists() {
+        git.arg("-C").arg(dir).args(&["checkout", "--detach", commit]);
+    } else {
+        git.args(&["worktree", "add", "--detach"]).arg(dir).arg(commit);
+    }
+    let status = git.status().map_err(|e| format!("couldn't run git: {}", e))?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(format!("couldn't check out {} into `{}`", commit, dir.display()))
+    }
+}
+
+/// Lints each crate of the corpus with the Clippy in `clippy_dir`.
+fn lint_corpus(clippy_dir: &Path, target_dir: &Path) -> Result<Vec<Warning>, String> {
+    let mut warnings = Vec::new();
+    for (name, version) in corpus::crates() {
+        let krate = format!("{}-{}", name, version);
+        println!("linting {}", krate);
+        let dir = corpus::fetch(name, version)?;
+        corpus::clean(&dir, name, target_dir)?;
+
+        // the crate may well trigger deny-by-default lints, so the exit status
+        // is ignored
+        let output = Command::new("cargo")
+            .current_dir(clippy_dir)
+            .args(&[
+                "run",
+                "--release",
+                "--bin",
+                "cargo-clippy",
+                "--",
+                "clippy",
+                "--message-format=json",
+                "--manifest-path",
+            ])
+            .arg(dir.join("Cargo.toml"))
+            .arg("--target-dir")
+            .arg(target_dir)
+            .arg("--")
+            .args(LINT_ARGS)
+            .output()
+            .map_err(|e| format!("couldn't run cargo: {}", e))?;
+        warnings.extend(parse_messages(&krate, &String::from_utf8_lossy(&output.stdout)));
+    }
+    warnings.sort();
+    Ok(warnings)
+}
+
+/// Collects Clippy's warnings from the output of `cargo clippy --message-format=json`.
+fn parse_messages(krate: &str, output: &str) -> Vec<Warning> {
+    output
+        .lines()
+        .filter_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+        .filter(|message| message["reason"] == "compiler-message")
+        .filter_map(|message| {
+            let message = &message["message"];
+            let lint = message["code"]["code"]
+                .as_str()
+                .filter(|code| code.starts_with("clippy::"))?;
+            let span = message["spans"]
+                .as_array()?
+                .iter()
+                .find(|span| span["is_primary"] == true)?;
+            Some(Warning {
+                krate: krate.to_owned(),
+                lint: lint.to_owned(),
+                location: format!(
+                    "{}:{}:{}",
+                    span["file_name"].as_str()?,
+                    span["line_start"],
+                    span["column_start"]
+                ),
+                message: message["message"].as_str()?.replace(&['\t', '\n'][..], " "),
+            })
+        })
+        .collect()
+}
+
+fn format_warnings(warnings: &[Warning]) -> String {
+    warnings
+        .iter()
+        .map(|w| format!("{}\t{}\t{}\t{}\n", w.krate, w.lint, w.location, w.message))
+        .collect()
+}
+
+fn parse_warnings(text: &str) -> Vec<Warning> {
+    text.lines()
+        .filter_map(|line| {
+            let mut parts = line.splitn(4, '\t');
+            Some(Warning {
+                krate: parts.next()?.to_owned(),
+                lint: parts.next()?.to_owned(),
+                location: parts.next()?.to_owned(),
+                message: parts.next()?.to_owned(),
+            })
+        })
+        .collect()
+}
+
+/// Returns the warnings only in `current` and the ones only in `reference`.
+/// A warning emitted twice at the same place counts twice.
+fn diff<'a>(reference: &'a [Warning], current: &'a [Warning]) -> (Vec<&'a Warning>, Vec<&'a Warning>) {
+    let mut counts = BTreeMap::new();
+    for warning in current {
+        *counts.entry(warning).or_insert(0) += 1;
+    }
+    for warning in reference {
+        *counts.entry(warning).or_insert(0) -= 1;
+    }
+    let mut added = Vec::new();
+    let mut removed = Vec::new();
+    for (warning, count) in counts {
+        for _ in 0..count {
+            added.push(warning);
+        }
+        for _ in count..0 {
+            removed.push(warning);
+        }
+    }
+    (added, removed)
+}
+
+#[cfg(test)]
+fn warning(lint: &str, location: &str) -> Warning {
+    Warning {
+        krate: "log-0.4.6".to_owned(),
+        lint: lint.to_owned(),
+        location: location.to_owned(),
+        message: "this is a message".to_owned(),
+    }
+}
+
+#[test]
+fn test_parse_messages() {
+    let output = r#"{"reason":"compiler-artifact","target":{"name":"log"}}
+{"reason":"compiler-message","message":{"code":{"code":"clippy::new_ret_no_self","explanation":null},"message":"this is a message","spans":[{"file_name":"src/lib.rs","line_start":1,"column_start":2,"is_primary":false},{"file_name":"src/lib.rs","line_start":10,"column_start":5,"is_primary":true}]}}
+{"reason":"compiler-message","message":{"code":{"code":"unused_variables","explanation":null},"message":"unused variable","spans":[]}}
+not json"#;
+    assert_eq!(
+        parse_messages("log-0.4.6", output),
+        vec![warning("clippy::new_ret_no_self", "src/lib.rs:10:5")]
+    );
+}
+
+#[test]
+fn test_parse_warnings() {
+    let warnings = vec![
+        warning("clippy::new_ret_no_self", "src/lib.rs:10:5"),
+        warning("clippy::unreadable_literal", "src/macros.rs:1:1"),
+    ];
+    assert_eq!(parse_warnings(&format_warnings(&warnings)), warnings);
+    assert_eq!(parse_warnings("log-0.4.6\tclippy::new_ret_no_self\n\n"), Vec::new());
+}
+
+#[test]
+fn test_diff() {
+    let kept = warning("clippy::new_ret_no_self", "src/lib.rs:10:5");
+    let fixed = warning("clippy::unreadable_literal", "src/lib.rs:1:1");
+    let new = warning("clippy::unreadable_literal", "src/lib.rs:2:1");
+    let reference = vec![kept.clone(), fixed.clone(), new.clone()];
+    let current = vec![kept, new.clone(), new.clone()];
+    assert_eq!(diff(&reference, &current), (vec![&new], vec![&fixed]));
+}
@@ -2,7 +2,7 @@ extern crate clap;
 extern crate clippy_dev;
 extern crate regex;
 
-use clap::{App, Arg, SubCommand};
+use clap::{value_t, App, Arg, SubCommand};
 use clippy_dev::*;
 
 #[derive(PartialEq)]
@@ -21,6 +21,7 @@ fn main() {
                      * the changelog contains markdown link references at the bottom\n \
                      * all lint groups include the correct lints\n \
                      * lint modules in `clippy_lints/*` are visible in `src/lib.rs` via `pub mod`\n \
+                     * the SARIF output knows the group of each lint\n \
                      * all lints are registered in the lint store",
                 )
                 .arg(Arg::with_name("print-only").long("print-only").help(
@@ -34,6 +35,53 @@ fn main() {
                         .help("Checks that util/dev update_lints has been run. Used on CI."),
                 ),
         )
+        .subcommand(
+            SubCommand::with_name("bench")
+                .about(
+                    "Times Clippy's lint passes on a pinned corpus of crates and compares the times to a baseline. \
+                     Fails if a pass got slower than the threshold.",
+                )
+                .arg(
+                    Arg::with_name("save")
+                        .long("save")
+                        .help("Saves the times as the baseline instead of comparing them"),
+                )
+                .arg(
+                    Arg::with_name("baseline")
+                        .long("baseline")
+                        .takes_value(true)
+                        .default_value("../target/bench/baseline.txt")
+                        .help("The file with the baseline"),
+                )
+                .arg(
+                    Arg::with_name("threshold")
+                        .long("threshold")
+                        .takes_value(true)
+                        .default_value("20")
+                        .help("The slowdown in percent which counts as a regression"),
+                )
+                .arg(
+                    Arg::with_name("runs")
+                        .long("runs")
+                        .takes_value(true)
+                        .default_value("3")
+                        .help("How often to lint each crate, the fastest run counts"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("lintcheck")
+                .about(
+                    "Lints the corpus of crates with the working copy and with a reference revision of Clippy \
+                     and lists the warnings which were added or removed, per lint.",
+                )
+                .arg(
+                    Arg::with_name("reference")
+                        .long("reference")
+                        .takes_value(true)
+                        .default_value("master")
+                        .help("The revision of Clippy to compare against"),
+                ),
+        )
         .get_matches();
 
     if let Some(matches) = matches.subcommand_matches("update_lints") {
@@ -45,6 +93,33 @@ fn main() {
             update_lints(&UpdateMode::Change);
         }
     }
+
+    if let Some(matches) = matches.subcommand_matches("bench") {
+        let options = bench::Options {
+            runs: value_t!(matches, "runs", usize).unwrap_or_else(|e| e.exit()),
+            threshold: value_t!(matches, "threshold", f64).unwrap_or_else(|e| e.exit()),
+            baseline: matches.value_of("baseline").unwrap_or_default().into(),
+            save: matches.is_present("save"),
+        };
+        match bench::run(&options) {
+            Ok(true) => {},
+            Ok(false) => std::process::exit(1),
+            Err(error) => {
+                eprintln!("error: {}", error);
+                std::process::exit(1);
+            },
+        }
+    }
+
+    if let Some(matches) = matches.subcommand_matches("lintcheck") {
+        let options = lintcheck::Options {
+            reference: matches.value_of("reference").unwrap_or_default().to_owned(),
+        };
+        if let Err(error) = lintcheck::run(&options) {
+            eprintln!("error: {}", error);
+            std::process::exit(1);
+        }
+    }
 }
 
 fn print_lints() {
@@ -123,6 +198,16 @@ fn update_lints(update_mode: &UpdateMode) {
     )
     .changed;
 
+    file_change |= replace_region_in_file(
+        "../src/sarif.rs",
+        "begin lint groups",
+        "end lint groups",
+        false,
+        update_mode == &UpdateMode::Change,
+        || gen_lint_groups(usable_lints.clone()),
+    )
+    .changed;
+
     // Generate lists of lints in the clippy::all lint group
     file_change |= replace_region_in_file(
         "../clippy_lints/src/lib.rs",
@@ -0,0 +1,21 @@
+use std::env;
+use std::process::Command;
+
+fn main() {
+    // Only re-run the build script when it changes
+    println!("cargo:rerun-if-changed=build.rs");
+    // forward the version of the compiler the lints run in, which is the MSRV if none is set
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| String::from("rustc"));
+    let version = Command::new(rustc)
+        .arg("-V")
+        .output()
+        .ok()
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .and_then(|version| {
+            // `rustc 1.34.0-nightly (f66e4697a 2019-02-20)`
+            let release = version.split_whitespace().nth(1)?;
+            Some(release.split('-').next()?.to_string())
+        })
+        .unwrap_or_default();
+    println!("cargo:rustc-env=RUSTC_RELEASE={}", version);
+}
@@ -0,0 +1,90 @@
+//! lint on async code holding a lock guard across a suspension point
+
+use crate::utils::{in_macro_not_desugaring, lock_guard_name, span_help_and_lint};
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::ty;
+use rustc::{declare_tool_lint, lint_array};
+
+/// **What it does:** Checks for async functions and blocks, and generators,
+/// which hold a `MutexGuard`, a `RwLockReadGuard` or a `RwLockWriteGuard`
+/// of `std::sync` across an `await!` or a `yield`.
+///
+/// **Why is this bad?** The lock stays locked while the future is suspended,
+/// and other tasks which want to lock it block the thread, which may be the
+/// one that would resume the future, so it deadlocks. The guards aren't
+/// `Send` either, so the future can't be spawned on most executors.
+///
+/// **Known problems:** The lint looks at the types the compiler keeps alive
+/// across the suspension points, so a guard which is dropped with `drop`
+/// before them is still linted. Moving the guard into a block which ends
+/// before them avoids that. The lint points to the whole function or block,
+/// not to the guard.
+///
+/// **Example:**
+/// ```rust
+/// async fn increment(counter: &Mutex<u32>) {
+///     let mut guard = counter.lock().unwrap();
+///     *guard += await!(fetch_increment());
+/// }
+/// ```
+/// Could be written as:
+/// ```rust
+/// async fn increment(counter: &Mutex<u32>) {
+///     let increment = await!(fetch_increment());
+///     *counter.lock().unwrap() += increment;
+/// }
+/// ```
+declare_clippy_lint! {
+    pub AWAIT_HOLDING_LOCK,
+    pedantic,
+    "async code holding a lock guard of `std::sync` across an `await!`"
+}
+
+#[derive(Copy, Clone)]
+pub struct Pass;
+
+impl LintPass for Pass {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(AWAIT_HOLDING_LOCK)
+    }
+
+    fn name(&self) -> &'static str {
+        "AwaitHoldingLock"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if let ExprKind::Closure(.., Some(_)) = expr.node {
+            if in_macro_not_desugaring(expr.span) {
+                return;
+            }
+            let (def_id, substs) = match cx.tables.expr_ty(expr).sty {
+                ty::Generator(def_id, substs, _) => (def_id, substs),
+                _ => return,
+            };
+            // the types which are alive across a suspension point
+            let interior = match substs.witness(def_id, cx.tcx).sty {
+                ty::GeneratorWitness(interior) => interior,
+                _ => return,
+            };
+            let mut names: Vec<_> = interior
+                .skip_binder()
+                .iter()
+                .filter_map(|&ty| lock_guard_name(cx, ty))
+                .collect();
+            names.sort();
+            names.dedup();
+            for name in names {
+                span_help_and_lint(
+                    cx,
+                    AWAIT_HOLDING_LOCK,
+                    expr.span,
+                    &format!("this holds a `{}` across an `await!` or `yield`", name),
+                    "drop the guard before it, e.g. by ending the block it's in",
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,161 @@
+//! lint on calls to blocking functions in async code
+
+use crate::utils::{get_def_path, in_macro, opt_def_id, path_to_def, resolve_fn_path, span_help_and_lint};
+use rustc::hir::def::Def;
+use rustc::hir::def_id::DefId;
+use rustc::hir::*;
+use rustc::lint::{in_external_macro, LateContext, LateLintPass, LintArray, LintContext, LintPass};
+use rustc::ty::DefIdTree;
+use rustc::{declare_tool_lint, lint_array};
+use rustc_data_structures::fx::FxHashSet;
+use syntax::ast::NodeId;
+
+/// **What it does:** Checks for calls to blocking functions and methods in
+/// `async fn`s and `async` blocks, like `std::thread::sleep`, the functions
+/// of `std::fs` and `Stdin::read_line`. More of them can be configured with
+/// `blocking-fns` in `clippy.toml`, by path, where `path::*` stands for
+/// everything in a module. Closures inside the async code aren't linted,
+/// since they are usually run somewhere else, e.g. by `spawn_blocking`.
+///
+/// **Why is this bad?** Executors run many futures on few threads. A blocking
+/// call stalls its thread and every future on it until it returns, which
+/// shows up as hard to explain latencies.
+///
+/// **Known problems:** Trait methods, e.g. `Read::read` on a `File`, aren't
+/// linted.
+///
+/// **Example:**
+/// ```rust
+/// async fn load(path: &Path) -> io::Result<String> {
+///     std::fs::read_to_string(path)
+/// }
+/// ```
+declare_clippy_lint! {
+    pub BLOCKING_IN_ASYNC,
+    pedantic,
+    "calls to blocking functions in async code"
+}
+
+/// The blocking functions which are always linted.
+const BLOCKING_FNS: [&str; 8] = [
+    "std::fs::*",
+    "std::io::Stdin::read_line",
+    "std::net::TcpStream::connect",
+    "std::process::Child::wait",
+    "std::process::Command::output",
+    "std::process::Command::status",
+    "std::thread::JoinHandle::join",
+    "std::thread::sleep",
+];
+
+#[derive(Clone, Debug)]
+pub struct BlockingInAsync {
+    blocking: Vec<String>,
+    /// the functions of `blocking` which could be resolved
+    fns: FxHashSet<DefId>,
+    /// the modules of `blocking` given as `path::*`
+    modules: Vec<DefId>,
+}
+
+impl BlockingInAsync {
+    pub fn new(blocking: &[String]) -> Self {
+        Self {
+            blocking: BLOCKING_FNS
+                .iter()
+                .map(|path| (*path).to_string())
+                .chain(blocking.iter().cloned())
+                .collect(),
+            fns: FxHashSet::default(),
+            modules: Vec::new(),
+        }
+    }
+
+    /// Checks whether `def_id` is a blocking function.
+    fn is_blocking(&self, cx: &LateContext<'_, '_>, def_id: DefId) -> bool {
+        if self.fns.contains(&def_id) {
+            return true;
+        }
+        let mut parent = cx.tcx.parent(def_id);
+        while let Some(module) = parent {
+            if self.modules.contains(&module) {
+                return true;
+            }
+            parent = cx.tcx.parent(module);
+        }
+        // local functions can't be resolved by `path_to_def`
+        let def_path = get_def_path(cx.tcx, def_id).join("::");
+        self.blocking.iter().any(|blocking| *blocking == def_path)
+    }
+}
+
+impl LintPass for BlockingInAsync {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(BLOCKING_IN_ASYNC)
+    }
+
+    fn name(&self) -> &'static str {
+        "BlockingInAsync"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for BlockingInAsync {
+    fn check_crate(&mut self, cx: &LateContext<'a, 'tcx>, _: &'tcx Crate) {
+        for blocking in &self.blocking {
+            let path: Vec<_> = blocking.split("::").collect();
+            if let Some((&"*", module)) = path.split_last() {
+                if let Some(Def::Mod(def_id)) = path_to_def(cx, module) {
+                    self.modules.push(def_id);
+                }
+            } else if let Some(def_id) = resolve_fn_path(cx, &path) {
+                self.fns.insert(def_id);
+            }
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        let def_id = match expr.node {
+            ExprKind::Call(ref callee, _) => match callee.node {
+                ExprKind::Path(ref qpath) => opt_def_id(cx.tables.qpath_def(qpath, callee.hir_id)),
+                _ => None,
+            },
+            ExprKind::MethodCall(..) => cx.tables.type_dependent_defs().get(expr.hir_id).map(Def::def_id),
+            _ => None,
+        };
+        let def_id = match def_id {
+            Some(def_id) => def_id,
+            None => return,
+        };
+        if in_macro(expr.span) || in_external_macro(cx.sess(), expr.span) || !in_async(cx, expr.id) {
+            return;
+        }
+        if self.is_blocking(cx, def_id) {
+            span_help_and_lint(
+                cx,
+                BLOCKING_IN_ASYNC,
+                expr.span,
+                &format!("`{}` blocks the thread in async code", cx.tcx.item_name(def_id)),
+                "use a non-blocking version, or run it where blocking is fine, e.g. on a thread pool",
+            );
+        }
+    }
+}
+
+/// Checks whether `node` is in an `async fn` or an `async` block, and not in
+/// a closure inside it.
+fn in_async(cx: &LateContext<'_, '_>, mut node: NodeId) -> bool {
+    let map = cx.tcx.hir();
+    loop {
+        let parent = map.get_parent_node(node);
+        if parent == node {
+            return false;
+        }
+        match map.find(parent) {
+            Some(Node::Expr(&Expr {
+                node: ExprKind::Closure(.., movability),
+                ..
+            })) => return movability.is_some(),
+            Some(Node::Item(_)) | Some(Node::ImplItem(_)) | Some(Node::TraitItem(_)) | None => return false,
+            _ => node = parent,
+        }
+    }
+}
@@ -0,0 +1,194 @@
+//! lint on functions returning `Box<dyn Trait>` that always box the same type
+
+use crate::utils::{in_macro, meets_msrv, msrvs, snippet, span_help_and_lint, RustcVersion};
+use rustc::hir;
+use rustc::hir::intravisit::{walk_expr, FnKind, NestedVisitorMap, Visitor};
+use rustc::hir::*;
+use rustc::lint::{in_external_macro, LateContext, LateLintPass, LintArray, LintContext, LintPass};
+use rustc::ty::{self, Ty};
+use rustc::{declare_tool_lint, lint_array};
+use syntax::ast::NodeId;
+use syntax::source_map::Span;
+
+/// **What it does:** Checks for functions returning `Box<dyn Trait>` where
+/// every return path boxes the same concrete type.
+///
+/// **Why is this bad?** Returning `impl Trait` avoids both the allocation and
+/// the dynamic dispatch.
+///
+/// **Known problems:** Changing the return type is a breaking change for
+/// public functions, and callers that need a trait object have to box the
+/// value themselves. Trait methods are never linted, since they can't return
+/// `impl Trait`, and neither is code whose MSRV is older than Rust 1.26.
+///
+/// **Example:**
+/// ```rust
+/// fn numbers() -> Box<dyn Iterator<Item = u32>> {
+///     Box::new(0..10)
+/// }
+/// ```
+/// Could be written as:
+/// ```rust
+/// fn numbers() -> impl Iterator<Item = u32> {
+///     0..10
+/// }
+/// ```
+declare_clippy_lint! {
+    pub BOXED_TRAIT_OBJECT_RETURN,
+    pedantic,
+    "functions returning `Box<dyn Trait>` that always return the same type"
+}
+
+#[derive(Copy, Clone)]
+pub struct Pass {
+    msrv: Option<RustcVersion>,
+}
+
+impl Pass {
+    pub fn new(msrv: Option<RustcVersion>) -> Self {
+        Self { msrv }
+    }
+}
+
+impl LintPass for Pass {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(BOXED_TRAIT_OBJECT_RETURN)
+    }
+
+    fn name(&self) -> &'static str {
+        "BoxedTraitObjectReturn"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'a, 'tcx>,
+        kind: FnKind<'tcx>,
+        decl: &'tcx FnDecl,
+        body: &'tcx Body,
+        span: Span,
+        node_id: NodeId,
+    ) {
+        if in_macro(span) || in_external_macro(cx.sess(), span) {
+            return;
+        }
+        if let FnKind::Closure(..) = kind {
+            return;
+        }
+        // trait methods can't return `impl Trait`, and impls have to match the trait
+        if let Some(Node::Item(item)) = cx.tcx.hir().find(cx.tcx.hir().get_parent_node(node_id)) {
+            match item.node {
+                ItemKind::Impl(_, _, _, _, Some(_), _, _) | ItemKind::Trait(..) => return,
+                _ => {},
+            }
+        }
+        let ret_ty = match decl.output {
+            FunctionRetTy::Return(ref ty) => ty,
+            FunctionRetTy::DefaultReturn(_) => return,
+        };
+        let fn_def_id = cx.tcx.hir().local_def_id(node_id);
+        let output = cx.tcx.fn_sig(fn_def_id).output().skip_binder();
+        if !output.is_box() || !output.boxed_ty().is_trait() {
+            return;
+        }
+        let trait_snippet = match boxed_trait_snippet(cx, ret_ty) {
+            Some(snippet) => snippet,
+            None => return,
+        };
+
+        let mut visitor = ReturnVisitor { returns: Vec::new() };
+        visitor.visit_expr(&body.value);
+        let mut returns = visitor.returns;
+        returns.push(&body.value);
+
+        let mut concrete: Option<Ty<'tcx>> = None;
+        for expr in returns {
+            let mut leaves = Vec::new();
+            collect_leaves(expr, &mut leaves);
+            for leaf in leaves {
+                let ty = cx.tables.expr_ty(leaf);
+                match ty.sty {
+                    ty::Never => continue,
+                    _ if ty.is_box() && !ty.boxed_ty().is_trait() => {},
+                    _ => return,
+                }
+                match concrete {
+                    Some(other) if other != ty.boxed_ty() => return,
+                    _ => concrete = Some(ty.boxed_ty()),
+                }
+            }
+        }
+        if concrete.is_none() || !meets_msrv(cx.tcx, node_id, self.msrv, msrvs::IMPL_TRAIT) {
+            return;
+        }
+
+        span_help_and_lint(
+            cx,
+            BOXED_TRAIT_OBJECT_RETURN,
+            ret_ty.span,
+            "this function returns a boxed trait object, but always returns the same type",
+            &format!(
+                "consider returning `impl {}` and removing the `Box::new` calls",
+                trait_snippet
+            ),
+        );
+    }
+}
+
+/// Returns the snippet of `Trait` in a `Box<dyn Trait>` type.
+fn boxed_trait_snippet(cx: &LateContext<'_, '_>, ty: &hir::Ty) -> Option<String> {
+    if let TyKind::Path(QPath::Resolved(None, ref path)) = ty.node {
+        if let Some(ref args) = path.segments.last().and_then(|segment| segment.args.as_ref()) {
+            if let Some(GenericArg::Type(ref boxed)) = args.args.first() {
+                let boxed = snippet(cx, boxed.span, "..");
+                return Some(boxed.trim_start_matches("dyn ").to_string());
+            }
+        }
+    }
+    None
+}
+
+/// Collects the expressions whose value ends up as the value of `expr`,
+/// looking through blocks, `if` and `match`.
+fn collect_leaves<'a>(expr: &'a Expr, leaves: &mut Vec<&'a Expr>) {
+    match expr.node {
+        ExprKind::Block(ref block, _) => {
+            if let Some(ref expr) = block.expr {
+                collect_leaves(expr, leaves);
+            }
+        },
+        ExprKind::If(_, ref then, ref els) => {
+            collect_leaves(then, leaves);
+            if let Some(ref els) = *els {
+                collect_leaves(els, leaves);
+            }
+        },
+        ExprKind::Match(_, ref arms, _) => {
+            for arm in arms {
+                collect_leaves(&arm.body, leaves);
+            }
+        },
+        _ => leaves.push(expr),
+    }
+}
+
+/// Collects the values of all `return` expressions outside of closures.
+struct ReturnVisitor<'tcx> {
+    returns: Vec<&'tcx Expr>,
+}
+
+impl<'tcx> Visitor<'tcx> for ReturnVisitor<'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        match expr.node {
+            ExprKind::Ret(Some(ref value)) => self.returns.push(value),
+            ExprKind::Closure(..) => return,
+            _ => {},
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
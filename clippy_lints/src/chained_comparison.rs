@@ -0,0 +1,120 @@
+//! lint on comparing the result of a comparison, like `(a < b) < c`
+
+use crate::utils::{in_macro, snippet_with_applicability, span_lint_and_then};
+use if_chain::if_chain;
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::{declare_tool_lint, lint_array};
+use rustc_errors::Applicability;
+
+/// **What it does:** Checks for ordering comparisons of the result of another
+/// ordering comparison, like `(a < b) < c` or `((a < b) as i32) < c`.
+///
+/// **Why is this bad?** Rustc rejects chained comparisons like `a < b < c`,
+/// but adding parentheses or a cast to make it compile compares a `bool` or
+/// its integer value, which is virtually always a typo for `a < b && b < c`.
+///
+/// **Known problems:** The suggestion evaluates the operand in the middle
+/// twice.
+///
+/// **Example:**
+/// ```rust
+/// if ((0 <= x) as i32) < 10 {}
+/// ```
+/// Could be written as:
+/// ```rust
+/// if 0 <= x && x < 10 {}
+/// ```
+declare_clippy_lint! {
+    pub CHAINED_COMPARISONS,
+    correctness,
+    "ordering comparisons of the result of another comparison, like `(a < b) < c`"
+}
+
+#[derive(Copy, Clone)]
+pub struct Pass;
+
+impl LintPass for Pass {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(CHAINED_COMPARISONS)
+    }
+
+    fn name(&self) -> &'static str {
+        "ChainedComparisons"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if_chain! {
+            if let ExprKind::Binary(op, ref lhs, ref rhs) = expr.node;
+            if is_ordering(op.node);
+            if !in_macro(expr.span);
+            then {
+                if let Some((a, inner, b)) = comparison(cx, lhs) {
+                    // `(a < b) < rhs`
+                    check_chain(cx, expr, (a, inner, b), (b, op.node, rhs));
+                } else if let Some((b, inner, c)) = comparison(cx, rhs) {
+                    // `lhs < (b < c)`
+                    check_chain(cx, expr, (lhs, op.node, b), (b, inner, c));
+                }
+            }
+        }
+    }
+}
+
+/// Lints `expr`, suggesting to chain the comparisons `first && second` if
+/// both of them compare operands of the same type.
+fn check_chain<'a, 'tcx>(
+    cx: &LateContext<'a, 'tcx>,
+    expr: &Expr,
+    first: (&Expr, BinOpKind, &Expr),
+    second: (&Expr, BinOpKind, &Expr),
+) {
+    span_lint_and_then(
+        cx,
+        CHAINED_COMPARISONS,
+        expr.span,
+        "comparing the result of a comparison",
+        |db| {
+            let same_ty =
+                |(left, _, right): (&Expr, BinOpKind, &Expr)| cx.tables.expr_ty(left) == cx.tables.expr_ty(right);
+            if same_ty(first) && same_ty(second) {
+                // the operand in the middle is evaluated twice
+                let mut applicability = Applicability::MaybeIncorrect;
+                let mut cmp = |(left, op, right): (&Expr, BinOpKind, &Expr)| {
+                    format!(
+                        "{} {} {}",
+                        snippet_with_applicability(cx, left.span, "..", &mut applicability),
+                        op.as_str(),
+                        snippet_with_applicability(cx, right.span, "..", &mut applicability)
+                    )
+                };
+                let sugg = format!("{} && {}", cmp(first), cmp(second));
+                db.span_suggestion(expr.span, "did you mean to chain the comparisons", sugg, applicability);
+            } else {
+                db.help("to chain the comparisons, use `&&`");
+            }
+        },
+    );
+}
+
+fn is_ordering(op: BinOpKind) -> bool {
+    match op {
+        BinOpKind::Lt | BinOpKind::Le | BinOpKind::Gt | BinOpKind::Ge => true,
+        _ => false,
+    }
+}
+
+/// Returns the operands and the operator of `expr` if it's an ordering
+/// comparison, possibly cast to an integer.
+fn comparison<'e>(cx: &LateContext<'_, '_>, expr: &'e Expr) -> Option<(&'e Expr, BinOpKind, &'e Expr)> {
+    let expr = match expr.node {
+        ExprKind::Cast(ref inner, _) if cx.tables.expr_ty(expr).is_integral() => inner,
+        _ => expr,
+    };
+    match expr.node {
+        ExprKind::Binary(op, ref left, ref right) if is_ordering(op.node) => Some((left, op.node, right)),
+        _ => None,
+    }
+}
@@ -0,0 +1,253 @@
+//! calculate cognitive complexity and warn about overly complex functions
+
+use rustc::hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintContext, LintPass};
+use rustc::{declare_tool_lint, lint_array};
+use syntax::ast::{Attribute, NodeId};
+use syntax::source_map::Span;
+
+use crate::utils::{attr_limit, check_limit_attrs, higher, in_macro, in_macro_not_desugaring, span_help_and_lint};
+
+/// **What it does:** Checks for functions with a high cognitive complexity,
+/// which can be configured with `cognitive-complexity-threshold` in
+/// `clippy.toml` or with `#[clippy::cognitive_complexity = "N"]`.
+///
+/// The complexity is computed like SonarSource's: `if`s, `match`es, loops,
+/// sequences of `&&` or `||` and `break`s or `continue`s to labels add one to
+/// it, and the control flow constructs add one more for each level they are
+/// nested in. `else` and `else if` add one regardless of nesting. Closures
+/// nest, and count towards the function they are in. Early exits like
+/// `return` and `?`, and code from macros, don't add anything.
+///
+/// **Why is this bad?** Deeply nested logic is hard to follow. Unlike
+/// `cyclomatic_complexity`, a flat `match` with many arms counts once.
+///
+/// **Known problems:** Sometimes it's hard to find a way to reduce the
+/// complexity.
+///
+/// **Example:** No. You'll see it when you get the warning.
+declare_clippy_lint! {
+    pub COGNITIVE_COMPLEXITY,
+    nursery,
+    "functions that should be split up into multiple functions"
+}
+
+pub struct CognitiveComplexity {
+    limit: u64,
+}
+
+impl CognitiveComplexity {
+    pub fn new(limit: u64) -> Self {
+        Self { limit }
+    }
+}
+
+impl LintPass for CognitiveComplexity {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(COGNITIVE_COMPLEXITY)
+    }
+
+    fn name(&self) -> &'static str {
+        "CognitiveComplexity"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for CognitiveComplexity {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'a, 'tcx>,
+        kind: intravisit::FnKind<'tcx>,
+        _: &'tcx FnDecl,
+        body: &'tcx Body,
+        span: Span,
+        node_id: NodeId,
+    ) {
+        // closures count towards the function they're in
+        if let intravisit::FnKind::Closure(_) = kind {
+            return;
+        }
+        let def_id = cx.tcx.hir().local_def_id(node_id);
+        if in_macro(span) || cx.tcx.has_attr(def_id, "test") {
+            return;
+        }
+        let limit = attr_limit(cx.tcx, node_id, "cognitive_complexity", self.limit);
+        let mut helper = CognitiveHelper {
+            complexity: 0,
+            nesting: 0,
+            cx,
+        };
+        helper.visit_expr(&body.value);
+        if helper.complexity > limit {
+            span_help_and_lint(
+                cx,
+                COGNITIVE_COMPLEXITY,
+                span,
+                &format!("the function has a cognitive complexity of {}", helper.complexity),
+                "you could split it up into multiple smaller functions",
+            );
+        }
+    }
+
+    fn enter_lint_attrs(&mut self, cx: &LateContext<'a, 'tcx>, attrs: &'tcx [Attribute]) {
+        check_limit_attrs(cx.sess(), attrs, "cognitive_complexity");
+    }
+}
+
+struct CognitiveHelper<'a, 'tcx: 'a> {
+    complexity: u64,
+    nesting: u64,
+    cx: &'a LateContext<'a, 'tcx>,
+}
+
+impl<'a, 'tcx> CognitiveHelper<'a, 'tcx> {
+    /// Adds one for a control flow construct, and one for each level it's
+    /// nested in.
+    fn add_nested(&mut self) {
+        self.complexity += 1 + self.nesting;
+    }
+
+    fn visit_nested(&mut self, expr: &'tcx Expr) {
+        self.nesting += 1;
+        self.visit_expr(expr);
+        self.nesting -= 1;
+    }
+
+    /// Visits an `if` or `if let` whose complexity has been added already.
+    fn visit_if(&mut self, cond: &'tcx Expr, then: &'tcx Expr, els: Option<&'tcx Expr>) {
+        self.visit_expr(cond);
+        self.visit_nested(then);
+        if let Some(els) = els {
+            // `else` and `else if` don't add for the nesting
+            self.complexity += 1;
+            match if_parts(else_if(els)) {
+                Some((cond, then, els)) => self.visit_if(cond, then, els),
+                None => self.visit_nested(els),
+            }
+        }
+    }
+
+    /// Visits the operands of a sequence of `op`s.
+    fn visit_logical(&mut self, op: BinOpKind, expr: &'tcx Expr) {
+        match expr.node {
+            ExprKind::Binary(other, ref left, ref right) if other.node == op && !in_macro_not_desugaring(expr.span) => {
+                self.visit_logical(op, left);
+                self.visit_logical(op, right);
+            },
+            _ => self.visit_expr(expr),
+        }
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for CognitiveHelper<'a, 'tcx> {
+    fn visit_expr(&mut self, e: &'tcx Expr) {
+        if in_macro_not_desugaring(e.span) {
+            walk_expr(self, e);
+            return;
+        }
+        if let Some((_, arg, body)) = higher::for_loop(e) {
+            self.add_nested();
+            self.visit_expr(arg);
+            self.visit_nested(body);
+            return;
+        }
+        if let Some((cond, then, els)) = if_parts(e) {
+            self.add_nested();
+            self.visit_if(cond, then, els);
+            return;
+        }
+        match e.node {
+            ExprKind::Match(ref scrutinee, ref arms, MatchSource::Normal) => {
+                self.add_nested();
+                self.visit_expr(scrutinee);
+                self.nesting += 1;
+                for arm in arms {
+                    self.visit_arm(arm);
+                }
+                self.nesting -= 1;
+            },
+            ExprKind::While(ref cond, ref body, _) => {
+                self.add_nested();
+                self.visit_expr(cond);
+                self.nesting += 1;
+                self.visit_block(body);
+                self.nesting -= 1;
+            },
+            ExprKind::Loop(ref block, _, source) => {
+                self.add_nested();
+                if let Some((scrutinee, body)) = while_let_parts(block, source) {
+                    self.visit_expr(scrutinee);
+                    self.visit_nested(body);
+                } else {
+                    self.nesting += 1;
+                    self.visit_block(block);
+                    self.nesting -= 1;
+                }
+            },
+            ExprKind::Binary(op, ref left, ref right) if op.node == BinOpKind::And || op.node == BinOpKind::Or => {
+                self.complexity += 1;
+                self.visit_logical(op.node, left);
+                self.visit_logical(op.node, right);
+            },
+            ExprKind::Break(Destination { label: Some(_), .. }, _)
+            | ExprKind::Continue(Destination { label: Some(_), .. }) => {
+                self.complexity += 1;
+                walk_expr(self, e);
+            },
+            ExprKind::Closure(..) => {
+                self.nesting += 1;
+                walk_expr(self, e);
+                self.nesting -= 1;
+            },
+            _ => walk_expr(self, e),
+        }
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::OnlyBodies(&self.cx.tcx.hir())
+    }
+}
+
+/// Returns the condition, or the scrutinee of an `if let`, and the branches
+/// of `expr`, if it's an `if` or an `if let`.
+fn if_parts(expr: &Expr) -> Option<(&Expr, &Expr, Option<&Expr>)> {
+    if in_macro_not_desugaring(expr.span) {
+        return None;
+    }
+    match expr.node {
+        ExprKind::If(ref cond, ref then, ref els) => Some((&**cond, &**then, els.as_ref().map(|els| &**els))),
+        ExprKind::Match(ref scrutinee, ref arms, MatchSource::IfLetDesugar { contains_else_clause }) => {
+            let els = if contains_else_clause {
+                Some(&*arms[1].body)
+            } else {
+                None
+            };
+            Some((&**scrutinee, &*arms[0].body, els))
+        },
+        _ => None,
+    }
+}
+
+/// Returns the scrutinee and the body of a `while let` loop.
+fn while_let_parts(block: &Block, source: LoopSource) -> Option<(&Expr, &Expr)> {
+    if source != LoopSource::WhileLet {
+        return None;
+    }
+    match block.expr.as_ref()?.node {
+        ExprKind::Match(ref scrutinee, ref arms, MatchSource::WhileLetDesugar) => Some((&**scrutinee, &*arms[0].body)),
+        _ => None,
+    }
+}
+
+/// Returns the `if` or `if let` of an `else if`, which is wrapped in a block
+/// if it's an `else if let` after an `if`.
+fn else_if(els: &Expr) -> &Expr {
+    if let ExprKind::Block(ref block, None) = els.node {
+        if let Some(ref expr) = block.expr {
+            if block.stmts.is_empty() && expr.span == els.span {
+                return expr;
+            }
+        }
+    }
+    els
+}
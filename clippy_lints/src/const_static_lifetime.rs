@@ -1,4 +1,5 @@
-use crate::utils::{in_macro, snippet, span_lint_and_then};
+use crate::utils::msrvs::{self, MsrvStack};
+use crate::utils::{in_macro, snippet, span_lint_and_then, RustcVersion};
 use rustc::lint::{EarlyContext, EarlyLintPass, LintArray, LintPass};
 use rustc::{declare_tool_lint, lint_array};
 use rustc_errors::Applicability;
@@ -26,7 +27,9 @@ declare_clippy_lint! {
     "Using explicit `'static` lifetime for constants when elision rules would allow omitting them."
 }
 
-pub struct StaticConst;
+pub struct StaticConst {
+    msrv: MsrvStack,
+}
 
 impl LintPass for StaticConst {
     fn get_lints(&self) -> LintArray {
@@ -39,6 +42,12 @@ impl LintPass for StaticConst {
 }
 
 impl StaticConst {
+    pub fn new(msrv: Option<RustcVersion>) -> Self {
+        Self {
+            msrv: MsrvStack::new(msrv),
+        }
+    }
+
     // Recursively visit types
     fn visit_type(&mut self, ty: &Ty, cx: &EarlyContext<'_>) {
         match ty.node {
@@ -91,7 +100,7 @@ impl StaticConst {
 
 impl EarlyLintPass for StaticConst {
     fn check_item(&mut self, cx: &EarlyContext<'_>, item: &Item) {
-        if !in_macro(item.span) {
+        if !in_macro(item.span) && self.msrv.meets_msrv(msrvs::STATIC_IN_CONST) {
             // Match only constants...
             if let ItemKind::Const(ref var_type, _) = item.node {
                 self.visit_type(var_type, cx);
@@ -99,5 +108,13 @@ impl EarlyLintPass for StaticConst {
         }
     }
 
+    fn enter_lint_attrs(&mut self, _: &EarlyContext<'_>, attrs: &[Attribute]) {
+        self.msrv.enter_lint_attrs(attrs);
+    }
+
+    fn exit_lint_attrs(&mut self, _: &EarlyContext<'_>, attrs: &[Attribute]) {
+        self.msrv.exit_lint_attrs(attrs);
+    }
+
     // Don't check associated consts because `'static` cannot be elided on those (issue #2438)
 }
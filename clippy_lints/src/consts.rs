@@ -1,14 +1,16 @@
 #![allow(clippy::float_cmp)]
 
-use crate::utils::{clip, get_def_path, sext, unsext};
-use if_chain::if_chain;
-use rustc::hir::def::Def;
+use crate::utils::{clip, get_def_path, int_bits, sext, uint_bits, unsext};
+use matches::matches;
+use rustc::hir::def::{CtorKind, Def};
 use rustc::hir::*;
 use rustc::lint::LateContext;
 use rustc::ty::subst::{Subst, Substs};
-use rustc::ty::{self, Instance, Ty, TyCtxt};
+use rustc::ty::{self, Instance, Ty, TyCtxt, TypeFoldable};
 use rustc::{bug, span_bug};
+use rustc_data_structures::fx::FxHashMap;
 use rustc_data_structures::sync::Lrc;
+use std::cell::RefCell;
 use std::cmp::Ordering::{self, Equal};
 use std::cmp::PartialOrd;
 use std::convert::TryFrom;
@@ -167,11 +169,33 @@ pub fn lit_to_constant<'tcx>(lit: &LitKind, ty: Ty<'tcx>) -> Constant {
     }
 }
 
+thread_local! {
+    /// The results of `constant`, for the `TypeckTables` (i.e. the body) they
+    /// were evaluated with. Lints often fold the same expressions, so this is
+    /// reset only when the tables change. With rustc's parallel front-end,
+    /// each thread has its own cache.
+    static CACHE: RefCell<(usize, FxHashMap<HirId, Option<(Constant, bool)>>)> =
+        RefCell::new((0, FxHashMap::default()));
+}
+
 pub fn constant<'c, 'cc>(
     lcx: &LateContext<'c, 'cc>,
     tables: &'c ty::TypeckTables<'cc>,
     e: &Expr,
 ) -> Option<(Constant, bool)> {
+    let tables_id = tables as *const _ as usize;
+    let cached = CACHE.with(|cache| {
+        let cache = cache.borrow();
+        if cache.0 == tables_id {
+            cache.1.get(&e.hir_id).cloned()
+        } else {
+            None
+        }
+    });
+    if let Some(result) = cached {
+        return result;
+    }
+
     let mut cx = ConstEvalLateContext {
         tcx: lcx.tcx,
         tables,
@@ -179,7 +203,15 @@ pub fn constant<'c, 'cc>(
         needed_resolution: false,
         substs: lcx.tcx.intern_substs(&[]),
     };
-    cx.expr(e).map(|cst| (cst, cx.needed_resolution))
+    let result = cx.expr(e).map(|cst| (cst, cx.needed_resolution));
+    CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+        if cache.0 != tables_id {
+            *cache = (tables_id, FxHashMap::default());
+        }
+        cache.1.insert(e.hir_id, result.clone());
+    });
+    result
 }
 
 pub fn constant_simple<'c, 'cc>(
@@ -235,31 +267,9 @@ impl<'c, 'cc> ConstEvalLateContext<'c, 'cc> {
                 UnDeref => Some(o),
             }),
             ExprKind::Binary(op, ref left, ref right) => self.binop(op, left, right),
-            ExprKind::Call(ref callee, ref args) => {
-                // We only handle a few const functions for now
-                if_chain! {
-                    if args.is_empty();
-                    if let ExprKind::Path(qpath) = &callee.node;
-                    let def = self.tables.qpath_def(qpath, callee.hir_id);
-                    if let Some(def_id) = def.opt_def_id();
-                    let def_path = get_def_path(self.tcx, def_id);
-                    if let &["core", "num", impl_ty, "max_value"] = &def_path[..];
-                    then {
-                       let value = match impl_ty {
-                           "<impl i8>" => i8::max_value() as u128,
-                           "<impl i16>" => i16::max_value() as u128,
-                           "<impl i32>" => i32::max_value() as u128,
-                           "<impl i64>" => i64::max_value() as u128,
-                           "<impl i128>" => i128::max_value() as u128,
-                           _ => return None,
-                       };
-                       Some(Constant::Int(value))
-                    }
-                    else {
-                        None
-                    }
-                }
-            },
+            ExprKind::Call(ref callee, ref args) => self.call(e, callee, args),
+            ExprKind::MethodCall(ref path, _, ref args) => self.method_call(e, path, args),
+            ExprKind::Cast(ref operand, _) => self.cast(operand, self.tables.expr_ty(e)),
             // TODO: add other expressions
             _ => None,
         }
@@ -302,6 +312,285 @@ impl<'c, 'cc> ConstEvalLateContext<'c, 'cc> {
         }
     }
 
+    /// We only handle a few const functions: `max_value` and `min_value` of
+    /// the integer types and `mem::size_of`.
+    fn call(&mut self, e: &Expr, callee: &Expr, args: &[Expr]) -> Option<Constant> {
+        if !args.is_empty() {
+            return None;
+        }
+        let def_id = match callee.node {
+            ExprKind::Path(ref qpath) => self.tables.qpath_def(qpath, callee.hir_id).opt_def_id()?,
+            _ => return None,
+        };
+        let def_path = get_def_path(self.tcx, def_id);
+        match (&def_path[..], &self.tables.expr_ty(e).sty) {
+            (&["core", "num", _, "max_value"], &ty::Int(ity)) => {
+                let max = i128::max_value() >> (128 - int_bits(self.tcx, ity));
+                Some(Constant::Int(unsext(self.tcx, max, ity)))
+            },
+            (&["core", "num", _, "min_value"], &ty::Int(ity)) => {
+                let min = i128::min_value() >> (128 - int_bits(self.tcx, ity));
+                Some(Constant::Int(unsext(self.tcx, min, ity)))
+            },
+            (&["core", "num", _, "max_value"], &ty::Uint(ity)) => {
+                Some(Constant::Int(u128::max_value() >> (128 - uint_bits(self.tcx, ity))))
+            },
+            (&["core", "num", _, "min_value"], &ty::Uint(_)) => Some(Constant::Int(0)),
+            (&["core", "mem", "size_of"], _) => {
+                let substs = self.tables.node_substs(callee.hir_id);
+                if substs.is_empty() {
+                    return None;
+                }
+                let ty = if self.substs.is_empty() {
+                    substs.type_at(0)
+                } else {
+                    substs.type_at(0).subst(self.tcx, self.substs)
+                };
+                // the size of a type parameter isn't known before monomorphization
+                if ty.has_param_types() {
+                    return None;
+                }
+                let layout = self.tcx.layout_of(self.param_env.and(ty)).ok()?;
+                // the size depends on the target
+                self.needed_resolution = true;
+                Some(Constant::Int(u128::from(layout.size.bytes())))
+            },
+            _ => None,
+        }
+    }
+
+    /// Folds calls of the integer methods without side effects, like `pow` or
+    /// `saturating_add`, and of `unwrap`, `expect` and `unwrap_or` on the
+    /// results of the `checked_*` methods.
+    fn method_call(&mut self, e: &Expr, path: &PathSegment, args: &[Expr]) -> Option<Constant> {
+        let def_id = self.tables.type_dependent_defs().get(e.hir_id)?.def_id();
+        match &get_def_path(self.tcx, def_id)[..] {
+            &["core", "option", _, name] => return self.option_method_call(name, args),
+            &["core", "num", _, _] => {},
+            _ => return None,
+        }
+        let (value, arg) = self.int_method_args(args)?;
+        match (&self.tables.expr_ty(&args[0]).sty, &*path.ident.as_str(), arg) {
+            (&ty::Int(ity), name, Some(arg)) => {
+                let bits = int_bits(self.tcx, ity);
+                let (min, max) = (i128::min_value() >> (128 - bits), i128::max_value() >> (128 - bits));
+                let l = sext(self.tcx, value, ity);
+                let r = sext(self.tcx, arg, ity);
+                let result = match name {
+                    "saturating_add" => l.saturating_add(r).max(min).min(max),
+                    "saturating_sub" => l.saturating_sub(r).max(min).min(max),
+                    "saturating_mul" => l.saturating_mul(r).max(min).min(max),
+                    "wrapping_add" => l.wrapping_add(r),
+                    "wrapping_sub" => l.wrapping_sub(r),
+                    "wrapping_mul" => l.wrapping_mul(r),
+                    "pow" => checked_pow(l, u32::try_from(arg).ok()?).filter(|n| min <= *n && *n <= max)?,
+                    _ => return None,
+                };
+                Some(Constant::Int(unsext(self.tcx, result, ity)))
+            },
+            (&ty::Uint(ity), name, Some(r)) => {
+                let max = u128::max_value() >> (128 - uint_bits(self.tcx, ity));
+                let result = match name {
+                    "saturating_add" => value.saturating_add(r).min(max),
+                    "saturating_sub" => value.saturating_sub(r),
+                    "saturating_mul" => value.saturating_mul(r).min(max),
+                    "wrapping_add" => value.wrapping_add(r),
+                    "wrapping_sub" => value.wrapping_sub(r),
+                    "wrapping_mul" => value.wrapping_mul(r),
+                    "pow" => checked_pow_unsigned(value, u32::try_from(r).ok()?).filter(|n| *n <= max)?,
+                    _ => return None,
+                };
+                Some(Constant::Int(clip(self.tcx, result, ity)))
+            },
+            (ty, name, None) => {
+                let bits = match *ty {
+                    ty::Int(ity) => int_bits(self.tcx, ity),
+                    ty::Uint(ity) => uint_bits(self.tcx, ity),
+                    _ => return None,
+                };
+                // `value` is the bit representation, so the unused high bits are zero
+                let result = match name {
+                    "count_ones" => u64::from(value.count_ones()),
+                    "count_zeros" => bits - u64::from(value.count_ones()),
+                    "leading_zeros" => u64::from(value.leading_zeros()) - (128 - bits),
+                    "trailing_zeros" if value == 0 => bits,
+                    "trailing_zeros" => u64::from(value.trailing_zeros()),
+                    _ => return None,
+                };
+                Some(Constant::Int(u128::from(result)))
+            },
+        }
+    }
+
+    /// Folds casts between the numeric types, and of `bool`, `char` and unit
+    /// enum variants to integers.
+    fn cast(&mut self, e: &Expr, to: Ty<'cc>) -> Option<Constant> {
+        let from = self.tables.expr_ty(e);
+        let (value, from) = match from.sty {
+            ty::Adt(adt, _) if adt.is_enum() => self.discriminant(e, adt)?,
+            _ => (self.expr(e)?, from),
+        };
+        match (value, &from.sty) {
+            (Constant::Int(value), &ty::Int(ity)) => self.cast_int(sext(self.tcx, value, ity), to),
+            (Constant::Int(value), _) => self.cast_uint(value, to),
+            (Constant::Bool(b), _) => self.cast_uint(u128::from(b), to),
+            (Constant::Char(c), _) => self.cast_uint(u128::from(u32::from(c)), to),
+            (Constant::F32(f), _) => self.cast_float(f64::from(f), to),
+            (Constant::F64(f), _) => self.cast_float(f, to),
+            _ => None,
+        }
+    }
+
+    /// The discriminant of a unit variant, and its type.
+    fn discriminant(&self, e: &Expr, adt: &'cc ty::AdtDef) -> Option<(Constant, Ty<'cc>)> {
+        if let ExprKind::Path(ref qpath) = e.node {
+            if let Def::VariantCtor(variant_id, CtorKind::Const) = self.tables.qpath_def(qpath, e.hir_id) {
+                let discr = adt.discriminant_for_variant(self.tcx, adt.variant_index_with_id(variant_id));
+                return Some((Constant::Int(discr.val), discr.ty));
+            }
+        }
+        None
+    }
+
+    #[allow(clippy::cast_sign_loss, clippy::cast_precision_loss)]
+    fn cast_int(&self, value: i128, to: Ty<'_>) -> Option<Constant> {
+        match to.sty {
+            ty::Int(ity) => Some(Constant::Int(unsext(self.tcx, value, ity))),
+            ty::Uint(ity) => Some(Constant::Int(clip(self.tcx, value as u128, ity))),
+            ty::Float(FloatTy::F32) => Some(Constant::F32(value as f32)),
+            ty::Float(FloatTy::F64) => Some(Constant::F64(value as f64)),
+            _ => None,
+        }
+    }
+
+    #[allow(clippy::cast_possible_wrap, clippy::cast_precision_loss)]
+    fn cast_uint(&self, value: u128, to: Ty<'_>) -> Option<Constant> {
+        match to.sty {
+            ty::Int(ity) => Some(Constant::Int(unsext(self.tcx, value as i128, ity))),
+            ty::Uint(ity) => Some(Constant::Int(clip(self.tcx, value, ity))),
+            ty::Float(FloatTy::F32) => Some(Constant::F32(value as f32)),
+            ty::Float(FloatTy::F64) => Some(Constant::F64(value as f64)),
+            _ => None,
+        }
+    }
+
+    /// Casts of floats to integers truncate towards zero. Casts of values out
+    /// of the range of the integer type aren't folded.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+    fn cast_float(&self, value: f64, to: Ty<'_>) -> Option<Constant> {
+        // the bounds are powers of two, so they are represented exactly
+        match to.sty {
+            ty::Float(FloatTy::F32) => Some(Constant::F32(value as f32)),
+            ty::Float(FloatTy::F64) => Some(Constant::F64(value)),
+            ty::Int(ity) => {
+                let value = value.trunc();
+                let bound = 2f64.powi(int_bits(self.tcx, ity) as i32 - 1);
+                if -bound <= value && value < bound {
+                    Some(Constant::Int(unsext(self.tcx, value as i128, ity)))
+                } else {
+                    None
+                }
+            },
+            ty::Uint(ity) => {
+                let value = value.trunc();
+                let bound = 2f64.powi(uint_bits(self.tcx, ity) as i32);
+                if 0.0 <= value && value < bound {
+                    Some(Constant::Int(value as u128))
+                } else {
+                    None
+                }
+            },
+            _ => None,
+        }
+    }
+
+    /// Folds `unwrap`, `expect`, `unwrap_or`, `is_some` and `is_none` calls on
+    /// the results of the `checked_*` integer methods. `unwrap` and `expect`
+    /// on `None` panic, so they aren't constant.
+    fn option_method_call(&mut self, name: &str, args: &[Expr]) -> Option<Constant> {
+        let result = match args[0].node {
+            ExprKind::MethodCall(ref path, _, ref checked_args) => {
+                self.checked_method_call(&args[0], path, checked_args)?
+            },
+            _ => return None,
+        };
+        match (name, result) {
+            ("unwrap", Some(value)) | ("expect", Some(value)) => Some(value),
+            ("unwrap_or", result) => {
+                // the default is evaluated either way
+                let default = self.expr(&args[1])?;
+                Some(result.unwrap_or(default))
+            },
+            ("is_some", result) => Some(Constant::Bool(result.is_some())),
+            ("is_none", result) => Some(Constant::Bool(result.is_none())),
+            _ => None,
+        }
+    }
+
+    /// Folds calls of the `checked_*` integer methods into the `Option` they
+    /// return.
+    fn checked_method_call(&mut self, e: &Expr, path: &PathSegment, args: &[Expr]) -> Option<Option<Constant>> {
+        let def_id = self.tables.type_dependent_defs().get(e.hir_id)?.def_id();
+        if !matches!(&get_def_path(self.tcx, def_id)[..], &["core", "num", _, _]) {
+            return None;
+        }
+        let (value, arg) = self.int_method_args(args)?;
+        match (&self.tables.expr_ty(&args[0]).sty, &*path.ident.as_str(), arg) {
+            (&ty::Int(ity), name, arg) => {
+                let bits = int_bits(self.tcx, ity);
+                let (min, max) = (i128::min_value() >> (128 - bits), i128::max_value() >> (128 - bits));
+                let fits = |n: &i128| min <= *n && *n <= max;
+                let l = sext(self.tcx, value, ity);
+                let result = match (name, arg) {
+                    ("checked_neg", None) => l.checked_neg(),
+                    ("checked_add", Some(r)) => l.checked_add(sext(self.tcx, r, ity)),
+                    ("checked_sub", Some(r)) => l.checked_sub(sext(self.tcx, r, ity)),
+                    ("checked_mul", Some(r)) => l.checked_mul(sext(self.tcx, r, ity)),
+                    ("checked_div", Some(r)) => l.checked_div(sext(self.tcx, r, ity)),
+                    // `MIN % -1` overflows like `MIN / -1`
+                    ("checked_rem", Some(r)) => {
+                        let r = sext(self.tcx, r, ity);
+                        l.checked_div(r).filter(fits).and_then(|_| l.checked_rem(r))
+                    },
+                    ("checked_pow", Some(exp)) => checked_pow(l, u32::try_from(exp).ok()?),
+                    _ => return None,
+                };
+                Some(result.filter(fits).map(|n| Constant::Int(unsext(self.tcx, n, ity))))
+            },
+            (&ty::Uint(ity), name, arg) => {
+                let max = u128::max_value() >> (128 - uint_bits(self.tcx, ity));
+                let result = match (name, arg) {
+                    ("checked_neg", None) => Some(value).filter(|n| *n == 0),
+                    ("checked_add", Some(r)) => value.checked_add(r),
+                    ("checked_sub", Some(r)) => value.checked_sub(r),
+                    ("checked_mul", Some(r)) => value.checked_mul(r),
+                    ("checked_div", Some(r)) => value.checked_div(r),
+                    ("checked_rem", Some(r)) => value.checked_rem(r),
+                    ("checked_pow", Some(exp)) => checked_pow_unsigned(value, u32::try_from(exp).ok()?),
+                    _ => return None,
+                };
+                Some(result.filter(|n| *n <= max).map(Constant::Int))
+            },
+            _ => None,
+        }
+    }
+
+    /// Evaluates the receiver and the optional argument of an integer method.
+    fn int_method_args(&mut self, args: &[Expr]) -> Option<(u128, Option<u128>)> {
+        let value = match self.expr(&args[0])? {
+            Constant::Int(value) => value,
+            _ => return None,
+        };
+        let arg = match args.get(1) {
+            Some(arg) => match self.expr(arg)? {
+                Constant::Int(arg) => Some(arg),
+                _ => return None,
+            },
+            None => None,
+        };
+        Some((value, arg))
+    }
+
     /// create `Some(Vec![..])` of all constants, unless there is any
     /// non-constant part
     fn multi(&mut self, vec: &[Expr]) -> Option<Vec<Constant>> {
@@ -366,17 +655,20 @@ impl<'c, 'cc> ConstEvalLateContext<'c, 'cc> {
         match (l, r) {
             (Constant::Int(l), Some(Constant::Int(r))) => match self.tables.expr_ty(left).sty {
                 ty::Int(ity) => {
+                    let bits = i128::from(int_bits(self.tcx, ity));
                     let l = sext(self.tcx, l, ity);
                     let r = sext(self.tcx, r, ity);
                     let zext = |n: i128| Constant::Int(unsext(self.tcx, n, ity));
+                    // results which don't fit into `ity` overflow
+                    let fits = |n: &i128| sext(self.tcx, unsext(self.tcx, *n, ity), ity) == *n;
                     match op.node {
-                        BinOpKind::Add => l.checked_add(r).map(zext),
-                        BinOpKind::Sub => l.checked_sub(r).map(zext),
-                        BinOpKind::Mul => l.checked_mul(r).map(zext),
-                        BinOpKind::Div if r != 0 => l.checked_div(r).map(zext),
+                        BinOpKind::Add => l.checked_add(r).filter(fits).map(zext),
+                        BinOpKind::Sub => l.checked_sub(r).filter(fits).map(zext),
+                        BinOpKind::Mul => l.checked_mul(r).filter(fits).map(zext),
+                        BinOpKind::Div if r != 0 => l.checked_div(r).filter(fits).map(zext),
                         BinOpKind::Rem if r != 0 => l.checked_rem(r).map(zext),
-                        BinOpKind::Shr => l.checked_shr(r.try_into().expect("invalid shift")).map(zext),
-                        BinOpKind::Shl => l.checked_shl(r.try_into().expect("invalid shift")).map(zext),
+                        BinOpKind::Shr if 0 <= r && r < bits => Some(zext(l >> r)),
+                        BinOpKind::Shl if 0 <= r && r < bits => Some(zext(l << r)),
                         BinOpKind::BitXor => Some(zext(l ^ r)),
                         BinOpKind::BitOr => Some(zext(l | r)),
                         BinOpKind::BitAnd => Some(zext(l & r)),
@@ -389,24 +681,28 @@ impl<'c, 'cc> ConstEvalLateContext<'c, 'cc> {
                         _ => None,
                     }
                 },
-                ty::Uint(_) => match op.node {
-                    BinOpKind::Add => l.checked_add(r).map(Constant::Int),
-                    BinOpKind::Sub => l.checked_sub(r).map(Constant::Int),
-                    BinOpKind::Mul => l.checked_mul(r).map(Constant::Int),
-                    BinOpKind::Div => l.checked_div(r).map(Constant::Int),
-                    BinOpKind::Rem => l.checked_rem(r).map(Constant::Int),
-                    BinOpKind::Shr => l.checked_shr(r.try_into().expect("shift too large")).map(Constant::Int),
-                    BinOpKind::Shl => l.checked_shl(r.try_into().expect("shift too large")).map(Constant::Int),
-                    BinOpKind::BitXor => Some(Constant::Int(l ^ r)),
-                    BinOpKind::BitOr => Some(Constant::Int(l | r)),
-                    BinOpKind::BitAnd => Some(Constant::Int(l & r)),
-                    BinOpKind::Eq => Some(Constant::Bool(l == r)),
-                    BinOpKind::Ne => Some(Constant::Bool(l != r)),
-                    BinOpKind::Lt => Some(Constant::Bool(l < r)),
-                    BinOpKind::Le => Some(Constant::Bool(l <= r)),
-                    BinOpKind::Ge => Some(Constant::Bool(l >= r)),
-                    BinOpKind::Gt => Some(Constant::Bool(l > r)),
-                    _ => None,
+                ty::Uint(ity) => {
+                    let bits = u128::from(uint_bits(self.tcx, ity));
+                    let fits = |n: &u128| clip(self.tcx, *n, ity) == *n;
+                    match op.node {
+                        BinOpKind::Add => l.checked_add(r).filter(fits).map(Constant::Int),
+                        BinOpKind::Sub => l.checked_sub(r).map(Constant::Int),
+                        BinOpKind::Mul => l.checked_mul(r).filter(fits).map(Constant::Int),
+                        BinOpKind::Div => l.checked_div(r).map(Constant::Int),
+                        BinOpKind::Rem => l.checked_rem(r).map(Constant::Int),
+                        BinOpKind::Shr if r < bits => Some(Constant::Int(l >> r)),
+                        BinOpKind::Shl if r < bits => Some(Constant::Int(clip(self.tcx, l << r, ity))),
+                        BinOpKind::BitXor => Some(Constant::Int(l ^ r)),
+                        BinOpKind::BitOr => Some(Constant::Int(l | r)),
+                        BinOpKind::BitAnd => Some(Constant::Int(l & r)),
+                        BinOpKind::Eq => Some(Constant::Bool(l == r)),
+                        BinOpKind::Ne => Some(Constant::Bool(l != r)),
+                        BinOpKind::Lt => Some(Constant::Bool(l < r)),
+                        BinOpKind::Le => Some(Constant::Bool(l <= r)),
+                        BinOpKind::Ge => Some(Constant::Bool(l >= r)),
+                        BinOpKind::Gt => Some(Constant::Bool(l > r)),
+                        _ => None,
+                    }
                 },
                 _ => None,
             },
@@ -453,6 +749,35 @@ impl<'c, 'cc> ConstEvalLateContext<'c, 'cc> {
     }
 }
 
+/// `base.pow(exp)`, by squaring.
+fn checked_pow(mut base: i128, mut exp: u32) -> Option<i128> {
+    let mut acc: i128 = 1;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            acc = acc.checked_mul(base)?;
+        }
+        exp >>= 1;
+        if exp > 0 {
+            base = base.checked_mul(base)?;
+        }
+    }
+    Some(acc)
+}
+
+fn checked_pow_unsigned(mut base: u128, mut exp: u32) -> Option<u128> {
+    let mut acc: u128 = 1;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            acc = acc.checked_mul(base)?;
+        }
+        exp >>= 1;
+        if exp > 0 {
+            base = base.checked_mul(base)?;
+        }
+    }
+    Some(acc)
+}
+
 pub fn miri_to_const<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>, result: &ty::Const<'tcx>) -> Option<Constant> {
     use rustc::mir::interpret::{ConstValue, Scalar};
     match result.val {
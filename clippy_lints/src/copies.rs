@@ -142,7 +142,7 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for CopyAndPaste {
 fn lint_same_then_else(cx: &LateContext<'_, '_>, blocks: &[&Block]) {
     let eq: &dyn Fn(&&Block, &&Block) -> bool = &|&lhs, &rhs| -> bool { SpanlessEq::new(cx).eq_block(lhs, rhs) };
 
-    if let Some((i, j)) = search_same_sequenced(blocks, eq) {
+    for (i, j) in search_same_sequenced(blocks, eq) {
         span_note_and_lint(
             cx,
             IF_SAME_THEN_ELSE,
@@ -165,7 +165,7 @@ fn lint_same_cond(cx: &LateContext<'_, '_>, conds: &[&Expr]) {
     let eq: &dyn Fn(&&Expr, &&Expr) -> bool =
         &|&lhs, &rhs| -> bool { SpanlessEq::new(cx).ignore_fn().eq_expr(lhs, rhs) };
 
-    if let Some((i, j)) = search_same(conds, hash, eq) {
+    for (i, j) in search_same(conds, hash, eq) {
         span_note_and_lint(
             cx,
             IFS_SAME_COND,
@@ -198,7 +198,7 @@ fn lint_match_arms(cx: &LateContext<'_, '_>, expr: &Expr) {
         };
 
         let indexed_arms: Vec<(usize, &Arm)> = arms.iter().enumerate().collect();
-        if let Some((&(_, i), &(_, j))) = search_same(&indexed_arms, hash, eq) {
+        if let Some(&(&(_, i), &(_, j))) = search_same(&indexed_arms, hash, eq).first() {
             span_lint_and_then(
                 cx,
                 MATCH_SAME_ARMS,
@@ -324,46 +324,45 @@ fn bindings<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, pat: &Pat) -> FxHashMap<LocalI
     result
 }
 
-fn search_same_sequenced<T, Eq>(exprs: &[T], eq: Eq) -> Option<(&T, &T)>
+/// Returns the pairs of consecutive elements of `exprs` which are equal.
+fn search_same_sequenced<T, Eq>(exprs: &[T], eq: Eq) -> Vec<(&T, &T)>
 where
     Eq: Fn(&T, &T) -> bool,
 {
-    for win in exprs.windows(2) {
-        if eq(&win[0], &win[1]) {
-            return Some((&win[0], &win[1]));
-        }
-    }
-    None
+    exprs
+        .windows(2)
+        .filter(|win| eq(&win[0], &win[1]))
+        .map(|win| (&win[0], &win[1]))
+        .collect()
 }
 
-fn search_same<T, Hash, Eq>(exprs: &[T], hash: Hash, eq: Eq) -> Option<(&T, &T)>
+/// Returns the elements of `exprs` which are equal to a previous one, each
+/// with the first element it is equal to.
+fn search_same<T, Hash, Eq>(exprs: &[T], hash: Hash, eq: Eq) -> Vec<(&T, &T)>
 where
     Hash: Fn(&T) -> u64,
     Eq: Fn(&T, &T) -> bool,
 {
     // common cases
     if exprs.len() < 2 {
-        return None;
+        return vec![];
     } else if exprs.len() == 2 {
         return if eq(&exprs[0], &exprs[1]) {
-            Some((&exprs[0], &exprs[1]))
+            vec![(&exprs[0], &exprs[1])]
         } else {
-            None
+            vec![]
         };
     }
 
+    let mut same = vec![];
     let mut map: FxHashMap<_, Vec<&_>> =
         FxHashMap::with_capacity_and_hasher(exprs.len(), BuildHasherDefault::default());
 
     for expr in exprs {
         match map.entry(hash(expr)) {
-            Entry::Occupied(mut o) => {
-                for o in o.get() {
-                    if eq(o, expr) {
-                        return Some((o, expr));
-                    }
-                }
-                o.get_mut().push(expr);
+            Entry::Occupied(mut o) => match o.get().iter().find(|o| eq(o, expr)).cloned() {
+                Some(first) => same.push((first, expr)),
+                None => o.get_mut().push(expr),
             },
             Entry::Vacant(v) => {
                 v.insert(vec![expr]);
@@ -371,5 +370,5 @@ where
         }
     }
 
-    None
+    same
 }
@@ -9,7 +9,7 @@ use rustc::{declare_tool_lint, lint_array};
 use syntax::ast::{Attribute, NodeId};
 use syntax::source_map::Span;
 
-use crate::utils::{in_macro, is_allowed, match_type, paths, span_help_and_lint, LimitStack};
+use crate::utils::{attr_limit, check_limit_attrs, in_macro, is_allowed, match_type, paths, span_help_and_lint};
 
 /// **What it does:** Checks for methods with high cyclomatic complexity.
 ///
@@ -27,14 +27,12 @@ declare_clippy_lint! {
 }
 
 pub struct CyclomaticComplexity {
-    limit: LimitStack,
+    limit: u64,
 }
 
 impl CyclomaticComplexity {
     pub fn new(limit: u64) -> Self {
-        Self {
-            limit: LimitStack::new(limit),
-        }
+        Self { limit }
     }
 }
 
@@ -49,7 +47,7 @@ impl LintPass for CyclomaticComplexity {
 }
 
 impl CyclomaticComplexity {
-    fn check<'a, 'tcx: 'a>(&mut self, cx: &'a LateContext<'a, 'tcx>, body: &'tcx Body, span: Span) {
+    fn check<'a, 'tcx: 'a>(&mut self, cx: &'a LateContext<'a, 'tcx>, body: &'tcx Body, span: Span, limit: u64) {
         if in_macro(span) {
             return;
         }
@@ -102,7 +100,7 @@ impl CyclomaticComplexity {
             if rust_cc >= ret_adjust {
                 rust_cc -= ret_adjust;
             }
-            if rust_cc > self.limit.limit() {
+            if rust_cc > limit {
                 span_help_and_lint(
                     cx,
                     CYCLOMATIC_COMPLEXITY,
@@ -127,15 +125,13 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for CyclomaticComplexity {
     ) {
         let def_id = cx.tcx.hir().local_def_id(node_id);
         if !cx.tcx.has_attr(def_id, "test") {
-            self.check(cx, body, span);
+            let limit = attr_limit(cx.tcx, node_id, "cyclomatic_complexity", self.limit);
+            self.check(cx, body, span, limit);
         }
     }
 
     fn enter_lint_attrs(&mut self, cx: &LateContext<'a, 'tcx>, attrs: &'tcx [Attribute]) {
-        self.limit.push_attrs(cx.sess(), attrs, "cyclomatic_complexity");
-    }
-    fn exit_lint_attrs(&mut self, cx: &LateContext<'a, 'tcx>, attrs: &'tcx [Attribute]) {
-        self.limit.pop_attrs(cx.sess(), attrs, "cyclomatic_complexity");
+        check_limit_attrs(cx.sess(), attrs, "cyclomatic_complexity");
     }
 }
 
@@ -1,9 +1,11 @@
+use crate::utils::sugg::Sugg;
 use crate::utils::{snippet_opt, span_help_and_lint, span_lint_and_sugg};
 use rustc::lint::{EarlyContext, EarlyLintPass, LintArray, LintPass};
 use rustc::{declare_tool_lint, lint_array};
 use rustc_errors::Applicability;
 use syntax::ast;
-use syntax::source_map::Span;
+use syntax::parse::{parser, token};
+use syntax::ptr::P;
 use syntax::tokenstream::TokenStream;
 
 /// **What it does:** Checks for usage of dbg!() macro.
@@ -43,7 +45,22 @@ impl LintPass for Pass {
 impl EarlyLintPass for Pass {
     fn check_mac(&mut self, cx: &EarlyContext<'_>, mac: &ast::Mac) {
         if mac.node.path == "dbg" {
-            if let Some(sugg) = tts_span(mac.node.tts.clone()).and_then(|span| snippet_opt(cx, span)) {
+            let sugg = parse_args(cx, mac.node.tts.clone())
+                .filter(|args| args.iter().all(|arg| arg.span.ctxt() == mac.span.ctxt()))
+                .and_then(|args| match args[..] {
+                    [] => None,
+                    // the parentheses keep the precedence, e.g. of `dbg!(a + b) * 2`
+                    [ref arg] => Some((
+                        Sugg::ast(cx, arg, "..").maybe_par().to_string(),
+                        Applicability::MachineApplicable,
+                    )),
+                    // `dbg!(a, b)` returns a tuple
+                    [ref first, .., ref last] => Some((
+                        format!("({})", snippet_opt(cx, first.span.to(last.span))?),
+                        Applicability::MaybeIncorrect,
+                    )),
+                });
+            if let Some((sugg, applicability)) = sugg {
                 span_lint_and_sugg(
                     cx,
                     DBG_MACRO,
@@ -51,7 +68,7 @@ impl EarlyLintPass for Pass {
                     "`dbg!` macro is intended as a debugging tool",
                     "ensure to avoid having uses of it in version control",
                     sugg,
-                    Applicability::MaybeIncorrect,
+                    applicability,
                 );
             } else {
                 span_help_and_lint(
@@ -66,13 +83,15 @@ impl EarlyLintPass for Pass {
     }
 }
 
-// Get span enclosing entire the token stream.
-fn tts_span(tts: TokenStream) -> Option<Span> {
-    let mut cursor = tts.into_trees();
-    let first = cursor.next()?.span();
-    let span = match cursor.last() {
-        Some(tree) => first.to(tree.span()),
-        None => first,
-    };
-    Some(span)
+/// Parses the comma separated expressions `dbg!` is called with.
+fn parse_args(cx: &EarlyContext<'_>, tts: TokenStream) -> Option<Vec<P<ast::Expr>>> {
+    let mut parser = parser::Parser::new(&cx.sess.parse_sess, tts, None, false, false);
+    let mut args = Vec::new();
+    while parser.token != token::Eof {
+        args.push(parser.parse_expr().map_err(|mut err| err.cancel()).ok()?);
+        if !parser.eat(&token::Comma) && parser.token != token::Eof {
+            return None;
+        }
+    }
+    Some(args)
 }
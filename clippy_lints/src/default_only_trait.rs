@@ -0,0 +1,122 @@
+//! lint on private traits that only have provided methods and barely any implementors
+
+use crate::utils::{in_macro, span_lint_node_and_then};
+use rustc::hir::def::Def;
+use rustc::hir::def_id::DefId;
+use rustc::hir::*;
+use rustc::lint::{in_external_macro, LateContext, LateLintPass, LintArray, LintContext, LintPass};
+use rustc::{declare_tool_lint, lint_array};
+use rustc_data_structures::fx::FxHashMap;
+use syntax::ast::NodeId;
+use syntax::source_map::Span;
+
+/// **What it does:** Checks for traits that aren't exported, whose methods all
+/// have a default implementation, that have no associated types or constants,
+/// and that are implemented at most once in the crate.
+///
+/// **Why is this bad?** Such a trait doesn't abstract over anything: the only
+/// implementor uses the provided methods as they are. Free functions or
+/// inherent methods are simpler to read and navigate.
+///
+/// **Known problems:** Traits with a blanket implementation are never linted,
+/// since they are usually extension traits. Traits used as trait objects or
+/// bounds may still be worth keeping.
+///
+/// **Example:**
+/// ```rust
+/// trait Greet {
+///     fn greet(&self) {
+///         println!("hello");
+///     }
+/// }
+///
+/// impl Greet for Person {}
+/// ```
+declare_clippy_lint! {
+    pub DEFAULT_ONLY_TRAIT,
+    pedantic,
+    "private traits whose methods are all provided and that have at most one implementor"
+}
+
+#[derive(Default)]
+pub struct Pass {
+    /// candidate traits, their nodes and their spans
+    traits: Vec<(DefId, NodeId, Span)>,
+    /// number of implementations per trait, `usize::max_value()` for blanket implementations
+    impls: FxHashMap<DefId, usize>,
+}
+
+impl LintPass for Pass {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(DEFAULT_ONLY_TRAIT)
+    }
+
+    fn name(&self) -> &'static str {
+        "DefaultOnlyTrait"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
+        match item.node {
+            ItemKind::Trait(_, _, _, _, ref trait_items) => {
+                if in_macro(item.span)
+                    || in_external_macro(cx.sess(), item.span)
+                    || cx.access_levels.is_exported(item.id)
+                    || trait_items.is_empty()
+                {
+                    return;
+                }
+                let all_provided = trait_items.iter().all(|trait_item| match trait_item.kind {
+                    AssociatedItemKind::Method { .. } => trait_item.defaultness.has_value(),
+                    _ => false,
+                });
+                if all_provided {
+                    self.traits.push((
+                        cx.tcx.hir().local_def_id(item.id),
+                        item.id,
+                        cx.sess().source_map().def_span(item.span),
+                    ));
+                }
+            },
+            ItemKind::Impl(_, _, _, _, Some(ref trait_ref), ref self_ty, _) => {
+                let count = self.impls.entry(trait_ref.trait_def_id()).or_insert(0);
+                *count = if is_ty_param(self_ty) {
+                    usize::max_value()
+                } else {
+                    count.saturating_add(1)
+                };
+            },
+            _ => {},
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'a, 'tcx>, _: &'tcx Crate) {
+        for &(def_id, node, span) in &self.traits {
+            let help = match self.impls.get(&def_id).cloned().unwrap_or(0) {
+                0 => "consider replacing the trait with free functions",
+                1 => "consider moving the methods into an inherent impl of the implementing type",
+                _ => continue,
+            };
+            span_lint_node_and_then(
+                cx,
+                DEFAULT_ONLY_TRAIT,
+                node,
+                span,
+                "all methods of this trait have a default implementation and it has at most one implementor",
+                |db| {
+                    db.help(help);
+                },
+            );
+        }
+    }
+}
+
+fn is_ty_param(ty: &Ty) -> bool {
+    if let TyKind::Path(QPath::Resolved(None, ref path)) = ty.node {
+        if let Def::TyParam(_) = path.def {
+            return true;
+        }
+    }
+    false
+}
@@ -0,0 +1,193 @@
+//! lint on manual `Default` impls which `#[derive(Default)]` would generate
+
+use crate::utils::{
+    in_macro, is_automatically_derived, match_def_path, meets_msrv, msrvs, opt_def_id, paths, remove_blocks,
+    span_help_and_lint, RustcVersion,
+};
+use rustc::hir::def::{CtorKind, Def};
+use rustc::hir::def_id::DefId;
+use rustc::hir::*;
+use rustc::lint::{in_external_macro, LateContext, LateLintPass, LintArray, LintContext, LintPass};
+use rustc::ty;
+use rustc::{declare_tool_lint, lint_array};
+use syntax::ast::LitKind;
+
+/// **What it does:** Checks for manual `impl Default` blocks which do the
+/// same as `#[derive(Default)]`: the struct is built with
+/// `Default::default()`, `None` or a zero literal like `0`, `false` or `""`
+/// for every field, or the enum's default is a unit variant, which can be
+/// marked with `#[default]`.
+///
+/// **Why is this bad?** The derive is shorter, and it's obvious that every
+/// field has its default.
+///
+/// **Known problems:** Types with type parameters aren't linted, since the
+/// derive requires them to implement `Default` as well. `#[default]` on enum
+/// variants needs Rust 1.62, so enums are only linted if the MSRV is set to
+/// it or later, or the compiler clippy runs in has it.
+///
+/// **Example:**
+/// ```rust
+/// struct Config {
+///     verbose: bool,
+///     level: u32,
+/// }
+///
+/// impl Default for Config {
+///     fn default() -> Self {
+///         Self { verbose: false, level: 0 }
+///     }
+/// }
+/// ```
+/// Could be written as:
+/// ```rust
+/// #[derive(Default)]
+/// struct Config {
+///     verbose: bool,
+///     level: u32,
+/// }
+/// ```
+declare_clippy_lint! {
+    pub DERIVABLE_IMPLS,
+    complexity,
+    "manual `Default` impls which `#[derive(Default)]` would generate"
+}
+
+#[derive(Copy, Clone)]
+pub struct Pass {
+    msrv: Option<RustcVersion>,
+}
+
+impl Pass {
+    pub fn new(msrv: Option<RustcVersion>) -> Self {
+        Self { msrv }
+    }
+}
+
+impl LintPass for Pass {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(DERIVABLE_IMPLS)
+    }
+
+    fn name(&self) -> &'static str {
+        "DerivableImpls"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
+        let impl_items = match item.node {
+            ItemKind::Impl(_, _, _, _, Some(ref trait_ref), _, ref impl_items) => {
+                if !match_def_path(cx.tcx, trait_ref.trait_def_id(), &paths::DEFAULT_TRAIT) {
+                    return;
+                }
+                impl_items
+            },
+            _ => return,
+        };
+        if impl_items.len() != 1
+            || is_automatically_derived(&item.attrs)
+            || in_macro(item.span)
+            || in_external_macro(cx.sess(), item.span)
+        {
+            return;
+        }
+        let (adt, substs) = match cx.tcx.type_of(cx.tcx.hir().local_def_id(item.id)).sty {
+            ty::Adt(adt, substs) => (adt, substs),
+            _ => return,
+        };
+        // the derive adds `T: Default` bounds for the type parameters
+        if substs.types().next().is_some() {
+            return;
+        }
+        // the derive has to be added where the type is declared
+        match cx.tcx.hir().as_local_node_id(adt.did) {
+            Some(node_id) if !in_macro(cx.tcx.hir().span(node_id)) => {},
+            _ => return,
+        }
+        let body = match cx.tcx.hir().impl_item(impl_items[0].id).node {
+            ImplItemKind::Method(_, body_id) => remove_blocks(&cx.tcx.hir().body(body_id).value),
+            _ => return,
+        };
+        let name = cx.tcx.item_name(adt.did);
+        if adt.is_struct() && is_default_struct(cx, body) {
+            span_help_and_lint(
+                cx,
+                DERIVABLE_IMPLS,
+                item.span,
+                "this `impl` can be derived",
+                &format!("remove it, and annotate `{}` with `#[derive(Default)]`", name),
+            );
+        } else if let Some(variant) = unit_variant(cx, body) {
+            if meets_msrv(cx.tcx, item.id, self.msrv, msrvs::DEFAULT_ENUM_VARIANT) {
+                span_help_and_lint(
+                    cx,
+                    DERIVABLE_IMPLS,
+                    item.span,
+                    "this `impl` can be derived",
+                    &format!(
+                        "remove it, annotate `{}` with `#[derive(Default)]` and `{}` with `#[default]`",
+                        name,
+                        cx.tcx.item_name(variant)
+                    ),
+                );
+            }
+        }
+    }
+}
+
+/// Checks whether `expr` builds the struct with the default of every field.
+fn is_default_struct(cx: &LateContext<'_, '_>, expr: &Expr) -> bool {
+    match expr.node {
+        // `..base` would call `default` again
+        ExprKind::Struct(_, ref fields, None) => fields.iter().all(|field| is_default_value(cx, &field.expr)),
+        ExprKind::Call(ref callee, ref args) => match callee.node {
+            ExprKind::Path(ref qpath) => match cx.tables.qpath_def(qpath, callee.hir_id) {
+                Def::StructCtor(_, CtorKind::Fn) | Def::SelfCtor(_) => args.iter().all(|arg| is_default_value(cx, arg)),
+                _ => false,
+            },
+            _ => false,
+        },
+        ExprKind::Path(ref qpath) => match cx.tables.qpath_def(qpath, expr.hir_id) {
+            Def::StructCtor(_, CtorKind::Const) | Def::SelfCtor(_) => true,
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Returns the unit variant `expr` is, if it's one.
+fn unit_variant(cx: &LateContext<'_, '_>, expr: &Expr) -> Option<DefId> {
+    if let ExprKind::Path(ref qpath) = expr.node {
+        if let Def::VariantCtor(variant, CtorKind::Const) = cx.tables.qpath_def(qpath, expr.hir_id) {
+            return Some(variant);
+        }
+    }
+    None
+}
+
+/// Checks whether `expr` is what `Default::default()` returns for its type.
+fn is_default_value(cx: &LateContext<'_, '_>, expr: &Expr) -> bool {
+    match expr.node {
+        ExprKind::Lit(ref lit) => match lit.node {
+            LitKind::Int(value, _) => value == 0,
+            LitKind::Float(value, _) | LitKind::FloatUnsuffixed(value) => {
+                value.as_str().parse::<f64>().ok() == Some(0.0)
+            },
+            LitKind::Bool(value) => !value,
+            LitKind::Char(value) => value == '\0',
+            LitKind::Str(value, _) => value.as_str().is_empty(),
+            _ => false,
+        },
+        ExprKind::Call(ref callee, ref args) if args.is_empty() => match callee.node {
+            ExprKind::Path(ref qpath) => opt_def_id(cx.tables.qpath_def(qpath, callee.hir_id))
+                .map_or(false, |def_id| {
+                    match_def_path(cx.tcx, def_id, &paths::DEFAULT_TRAIT_METHOD)
+                }),
+            _ => false,
+        },
+        ExprKind::Path(ref qpath) => opt_def_id(cx.tables.qpath_def(qpath, expr.hir_id))
+            .map_or(false, |def_id| match_def_path(cx.tcx, def_id, &paths::OPTION_NONE)),
+        _ => false,
+    }
+}
@@ -1,10 +1,14 @@
 use crate::utils::paths;
 use crate::utils::{is_automatically_derived, is_copy, match_path, span_lint_and_then};
 use if_chain::if_chain;
+use rustc::hir::def::Def;
+use rustc::hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
 use rustc::hir::*;
 use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
 use rustc::ty::{self, Ty};
 use rustc::{declare_tool_lint, lint_array};
+use std::collections::BTreeSet;
+use syntax::ast::NodeId;
 use syntax::source_map::Span;
 
 /// **What it does:** Checks for deriving `Hash` but implementing `PartialEq`
@@ -62,11 +66,43 @@ declare_clippy_lint! {
     "implementing `Clone` explicitly on `Copy` types"
 }
 
+/// **What it does:** Checks for `Ord` and `PartialOrd` implementations of
+/// structs which compare other fields than the `PartialEq` implementation,
+/// where at least one of them is written by hand.
+///
+/// **Why is this bad?** The implementations must agree: `a == b` must hold
+/// exactly if `a.cmp(&b)` is `Ordering::Equal`. Otherwise sorted containers
+/// like `BTreeMap` and functions like `binary_search` misbehave.
+///
+/// **Known problems:** Only the fields accessed directly on `self` and the
+/// other value are known. Implementations passing the values as a whole to
+/// other functions are not checked.
+///
+/// **Example:**
+/// ```rust
+/// #[derive(PartialEq, Eq, PartialOrd)]
+/// struct Version {
+///     number: u32,
+///     name: String,
+/// }
+///
+/// impl Ord for Version {
+///     fn cmp(&self, other: &Self) -> Ordering {
+///         self.number.cmp(&other.number)
+///     }
+/// }
+/// ```
+declare_clippy_lint! {
+    pub INCONSISTENT_CMP_FIELDS,
+    correctness,
+    "implementing `Ord` or `PartialOrd` comparing other fields than `PartialEq`"
+}
+
 pub struct Derive;
 
 impl LintPass for Derive {
     fn get_lints(&self) -> LintArray {
-        lint_array!(EXPL_IMPL_CLONE_ON_COPY, DERIVE_HASH_XOR_EQ)
+        lint_array!(EXPL_IMPL_CLONE_ON_COPY, DERIVE_HASH_XOR_EQ, INCONSISTENT_CMP_FIELDS)
     }
 
     fn name(&self) -> &'static str {
@@ -76,11 +112,12 @@ impl LintPass for Derive {
 
 impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Derive {
     fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
-        if let ItemKind::Impl(_, _, _, _, Some(ref trait_ref), _, _) = item.node {
+        if let ItemKind::Impl(_, _, _, _, Some(ref trait_ref), _, ref impl_items) = item.node {
             let ty = cx.tcx.type_of(cx.tcx.hir().local_def_id(item.id));
             let is_automatically_derived = is_automatically_derived(&*item.attrs);
 
             check_hash_peq(cx, item.span, trait_ref, ty, is_automatically_derived);
+            check_cmp_fields(cx, item, trait_ref, impl_items, ty, is_automatically_derived);
 
             if !is_automatically_derived {
                 check_copy_clone(cx, item, trait_ref, ty);
@@ -137,6 +174,177 @@ fn check_hash_peq<'a, 'tcx>(
     }
 }
 
+/// Implementation of the `INCONSISTENT_CMP_FIELDS` lint.
+fn check_cmp_fields<'a, 'tcx>(
+    cx: &LateContext<'a, 'tcx>,
+    item: &Item,
+    trait_ref: &TraitRef,
+    impl_items: &[ImplItemRef],
+    ty: Ty<'tcx>,
+    cmp_is_automatically_derived: bool,
+) {
+    let (trait_name, method) = if match_path(&trait_ref.path, &paths::ORD) {
+        ("Ord", "cmp")
+    } else if match_path(&trait_ref.path, &paths::PARTIAL_ORD) {
+        ("PartialOrd", "partial_cmp")
+    } else {
+        return;
+    };
+    let all_fields: BTreeSet<_> = match ty.sty {
+        ty::Adt(def, _) if def.is_struct() => def
+            .non_enum_variant()
+            .fields
+            .iter()
+            .map(|field| field.ident.to_string())
+            .collect(),
+        _ => return,
+    };
+    // Only care about `impl PartialOrd<Foo> for Foo`
+    if trait_name == "PartialOrd" {
+        let impl_trait_ref = cx.tcx.impl_trait_ref(cx.tcx.hir().local_def_id(item.id));
+        if impl_trait_ref.map_or(true, |trait_ref| trait_ref.substs.type_at(1) != ty) {
+            return;
+        }
+    }
+    let cmp_fields = if cmp_is_automatically_derived {
+        all_fields.clone()
+    } else {
+        match compared_fields(cx, impl_items, method) {
+            Some(fields) => fields,
+            None => return,
+        }
+    };
+    let peq_trait_def_id = match cx.tcx.lang_items().eq_trait() {
+        Some(def_id) => def_id,
+        None => return,
+    };
+
+    cx.tcx.for_each_relevant_impl(peq_trait_def_id, ty, |impl_id| {
+        let peq_is_automatically_derived = is_automatically_derived(&cx.tcx.get_attrs(impl_id));
+        if peq_is_automatically_derived && cmp_is_automatically_derived {
+            return;
+        }
+        let trait_ref = cx.tcx.impl_trait_ref(impl_id).expect("must be a trait implementation");
+        if trait_ref.substs.type_at(1) != ty {
+            return;
+        }
+        let node_id = match cx.tcx.hir().as_local_node_id(impl_id) {
+            Some(node_id) => node_id,
+            None => return,
+        };
+        let peq_fields = if peq_is_automatically_derived {
+            all_fields.clone()
+        } else if let ItemKind::Impl(.., ref impl_items) = cx.tcx.hir().expect_item(node_id).node {
+            match compared_fields(cx, impl_items, "eq") {
+                Some(fields) => fields,
+                None => return,
+            }
+        } else {
+            return;
+        };
+
+        if cmp_fields != peq_fields {
+            span_lint_and_then(
+                cx,
+                INCONSISTENT_CMP_FIELDS,
+                item.span,
+                &format!(
+                    "this `{}` implementation compares other fields than `PartialEq`",
+                    trait_name
+                ),
+                |db| {
+                    db.span_note(cx.tcx.hir().span(node_id), "`PartialEq` implemented here");
+                    let only = |fields: &BTreeSet<String>, other: &BTreeSet<String>| {
+                        let only: Vec<_> = fields.difference(other).map(|field| format!("`{}`", field)).collect();
+                        only.join(", ")
+                    };
+                    let only_cmp = only(&cmp_fields, &peq_fields);
+                    if !only_cmp.is_empty() {
+                        db.note(&format!("only `{}` compares {}", trait_name, only_cmp));
+                    }
+                    let only_peq = only(&peq_fields, &cmp_fields);
+                    if !only_peq.is_empty() {
+                        db.note(&format!("only `PartialEq` compares {}", only_peq));
+                    }
+                },
+            );
+        }
+    });
+}
+
+/// Returns the fields of `self` and the other value the method `name` of an
+/// implementation compares. Returns `None` if there is no such method or it
+/// uses the values as a whole, so that the fields it looks at are unknown.
+fn compared_fields<'a, 'tcx>(
+    cx: &'a LateContext<'a, 'tcx>,
+    impl_items: &[ImplItemRef],
+    name: &str,
+) -> Option<BTreeSet<String>> {
+    let impl_item = impl_items.iter().find(|impl_item| impl_item.ident.name == name)?;
+    let body_id = match cx.tcx.hir().impl_item(impl_item.id).node {
+        ImplItemKind::Method(_, body_id) => body_id,
+        _ => return None,
+    };
+    let body = cx.tcx.hir().body(body_id);
+    let mut params = Vec::new();
+    for arg in &body.arguments {
+        match arg.pat.node {
+            PatKind::Binding(_, id, _, _, None) => params.push(id),
+            PatKind::Wild => {},
+            // the fields are taken apart by the pattern
+            _ => return None,
+        }
+    }
+    let mut visitor = FieldVisitor {
+        cx,
+        params,
+        fields: BTreeSet::new(),
+        whole: false,
+    };
+    visitor.visit_expr(&body.value);
+    if visitor.whole {
+        None
+    } else {
+        Some(visitor.fields)
+    }
+}
+
+/// Collects the fields accessed on the parameters of a method.
+struct FieldVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    params: Vec<NodeId>,
+    fields: BTreeSet<String>,
+    /// Whether a parameter is used other than to access a field.
+    whole: bool,
+}
+
+impl<'a, 'tcx> FieldVisitor<'a, 'tcx> {
+    fn is_param(&self, expr: &Expr) -> bool {
+        if let ExprKind::Path(QPath::Resolved(None, ref path)) = expr.node {
+            if let Def::Local(id) = path.def {
+                return self.params.contains(&id);
+            }
+        }
+        false
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for FieldVisitor<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        match expr.node {
+            ExprKind::Field(ref base, ident) if self.is_param(base) => {
+                self.fields.insert(ident.to_string());
+            },
+            _ if self.is_param(expr) => self.whole = true,
+            _ => walk_expr(self, expr),
+        }
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::All(&self.cx.tcx.hir())
+    }
+}
+
 /// Implementation of the `EXPL_IMPL_CLONE_ON_COPY` lint.
 fn check_copy_clone<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, item: &Item, trait_ref: &TraitRef, ty: Ty<'tcx>) {
     if match_path(&trait_ref.path, &paths::CLONE_TRAIT) {
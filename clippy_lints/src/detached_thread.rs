@@ -0,0 +1,95 @@
+//! lint on dropping the `JoinHandle` of a spawned thread right away
+
+use crate::utils::{in_macro, match_type, paths, span_help_and_lint};
+use rustc::hir::*;
+use rustc::lint::{in_external_macro, LateContext, LateLintPass, LintArray, LintContext, LintPass};
+use rustc::{declare_tool_lint, lint_array};
+use rustc_data_structures::fx::FxHashSet;
+
+/// **What it does:** Checks for statements spawning a thread and dropping its
+/// `JoinHandle` right away, like `thread::spawn(f);` or
+/// `let _ = thread::spawn(f);`, which detaches the thread.
+///
+/// Functions which are meant to start daemon threads can be allowed with
+/// `daemon-thread-fns` in `clippy.toml`, by name or path.
+///
+/// **Why is this bad?** Nobody waits for a detached thread to finish or
+/// learns about it panicking, and it is killed when the main thread exits.
+/// Some codebases require all threads to be joined or tracked.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// std::thread::spawn(|| println!("hello"));
+/// ```
+/// Could be written as:
+/// ```rust
+/// let handle = std::thread::spawn(|| println!("hello"));
+/// handle.join().unwrap();
+/// ```
+declare_clippy_lint! {
+    pub DETACHED_THREAD,
+    restriction,
+    "dropping the `JoinHandle` of a spawned thread right away"
+}
+
+#[derive(Clone, Debug)]
+pub struct DetachedThread {
+    daemon_fns: FxHashSet<String>,
+}
+
+impl DetachedThread {
+    pub fn new(daemon_fns: FxHashSet<String>) -> Self {
+        Self { daemon_fns }
+    }
+
+    /// Checks whether the function `node` is in is allowed to detach threads.
+    fn is_daemon_fn(&self, cx: &LateContext<'_, '_>, node: NodeId) -> bool {
+        if self.daemon_fns.is_empty() {
+            return false;
+        }
+        let map = cx.tcx.hir();
+        let item = map.get_parent(node);
+        let path = cx.tcx.item_path_str(map.local_def_id(item));
+        let name = map.name(item);
+        self.daemon_fns
+            .iter()
+            .any(|daemon_fn| name == &**daemon_fn || path == *daemon_fn || path.ends_with(&format!("::{}", daemon_fn)))
+    }
+}
+
+impl LintPass for DetachedThread {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(DETACHED_THREAD)
+    }
+
+    fn name(&self) -> &'static str {
+        "DetachedThread"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for DetachedThread {
+    fn check_stmt(&mut self, cx: &LateContext<'a, 'tcx>, stmt: &'tcx Stmt) {
+        let expr = match stmt.node {
+            StmtKind::Semi(ref expr) => expr,
+            StmtKind::Local(ref local) => match (&local.pat.node, &local.init) {
+                (PatKind::Wild, Some(init)) => init,
+                _ => return,
+            },
+            _ => return,
+        };
+        if in_macro(stmt.span) || in_external_macro(cx.sess(), stmt.span) {
+            return;
+        }
+        if match_type(cx, cx.tables.expr_ty(expr), &paths::JOIN_HANDLE) && !self.is_daemon_fn(cx, stmt.id) {
+            span_help_and_lint(
+                cx,
+                DETACHED_THREAD,
+                stmt.span,
+                "the `JoinHandle` of this thread is dropped right away, which detaches the thread",
+                "consider keeping the `JoinHandle` and joining the thread",
+            );
+        }
+    }
+}
@@ -0,0 +1,114 @@
+//! lint on calls to the functions and methods disallowed in `clippy.toml`
+
+use crate::utils::conf::DisallowedPath;
+use crate::utils::{get_def_path, is_local_def_path, opt_def_id, resolve_fn_path, span_lint};
+use rustc::hir::def::Def;
+use rustc::hir::def_id::DefId;
+use rustc::hir::*;
+use rustc::lint::{in_external_macro, LateContext, LateLintPass, LintArray, LintContext, LintPass};
+use rustc::{declare_tool_lint, lint_array};
+use rustc_data_structures::fx::FxHashMap;
+
+/// **What it does:** Checks for calls to the functions and methods configured
+/// with `disallowed-methods` in `clippy.toml`. Does nothing if none are
+/// configured.
+///
+/// The functions are given by their paths, which can go through re-exports.
+/// Methods are given by the path of their type or trait, like
+/// `regex::Regex::new` or `std::io::Write::flush`. Entries can be tables
+/// with the `path` and a `reason`, which is shown in the lint message. Paths
+/// which don't name a function or method are configuration errors.
+///
+/// **Why is this bad?** Projects often have functions which shouldn't be
+/// used, e.g. because they aren't thread safe or because there is a wrapper
+/// which should be used instead.
+///
+/// **Known problems:** Functions which are only referred to, but not called
+/// directly, aren't linted.
+///
+/// **Example:**
+/// ```toml
+/// disallowed-methods = [
+///     "std::env::set_var",
+///     { path = "regex::Regex::new", reason = "use the regexes in `crate::patterns`" },
+/// ]
+/// ```
+declare_clippy_lint! {
+    pub DISALLOWED_METHODS,
+    style,
+    "calls to the functions and methods disallowed in `clippy.toml`"
+}
+
+#[derive(Clone, Debug)]
+pub struct DisallowedMethods {
+    disallowed: Vec<DisallowedPath>,
+    /// the functions of `disallowed` which could be resolved, with their index
+    resolved: FxHashMap<DefId, usize>,
+}
+
+impl DisallowedMethods {
+    pub fn new(disallowed: Vec<DisallowedPath>) -> Self {
+        Self {
+            disallowed,
+            resolved: FxHashMap::default(),
+        }
+    }
+
+    /// Returns the configured entry for `def_id`, if it's disallowed.
+    fn find(&self, cx: &LateContext<'_, '_>, def_id: DefId) -> Option<&DisallowedPath> {
+        if let Some(&index) = self.resolved.get(&def_id) {
+            return Some(&self.disallowed[index]);
+        }
+        // local functions can't be resolved by `path_to_def`
+        let def_path = get_def_path(cx.tcx, def_id).join("::");
+        self.disallowed.iter().find(|disallowed| disallowed.path() == def_path)
+    }
+}
+
+impl LintPass for DisallowedMethods {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(DISALLOWED_METHODS)
+    }
+
+    fn name(&self) -> &'static str {
+        "DisallowedMethods"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for DisallowedMethods {
+    fn check_crate(&mut self, cx: &LateContext<'a, 'tcx>, _: &'tcx Crate) {
+        for (index, disallowed) in self.disallowed.iter().enumerate() {
+            let path: Vec<_> = disallowed.path().split("::").collect();
+            if let Some(def_id) = resolve_fn_path(cx, &path) {
+                self.resolved.insert(def_id, index);
+            } else if !is_local_def_path(cx, disallowed.path()) {
+                cx.sess().err(&format!(
+                    "error reading Clippy's configuration file: `disallowed-methods` has `{}`, which isn't a function \
+                     or method",
+                    disallowed.path()
+                ));
+            }
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if self.disallowed.is_empty() || in_external_macro(cx.sess(), expr.span) {
+            return;
+        }
+        let def_id = match expr.node {
+            ExprKind::Call(ref callee, _) => match callee.node {
+                ExprKind::Path(ref qpath) => opt_def_id(cx.tables.qpath_def(qpath, callee.hir_id)),
+                _ => None,
+            },
+            ExprKind::MethodCall(..) => cx.tables.type_dependent_defs().get(expr.hir_id).map(Def::def_id),
+            _ => None,
+        };
+        if let Some(disallowed) = def_id.and_then(|def_id| self.find(cx, def_id)) {
+            let msg = match disallowed.reason() {
+                Some(reason) => format!("use of a disallowed method `{}`: {}", disallowed.path(), reason),
+                None => format!("use of a disallowed method `{}`", disallowed.path()),
+            };
+            span_lint(cx, DISALLOWED_METHODS, expr.span, &msg);
+        }
+    }
+}
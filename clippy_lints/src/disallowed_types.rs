@@ -0,0 +1,132 @@
+//! lint on uses of the types and traits disallowed in `clippy.toml`
+
+use crate::utils::conf::DisallowedPath;
+use crate::utils::{get_def_path, in_macro, is_local_def_path, path_to_def, span_lint};
+use rustc::hir::def::Def;
+use rustc::hir::def_id::DefId;
+use rustc::hir::*;
+use rustc::lint::{in_external_macro, LateContext, LateLintPass, LintArray, LintContext, LintPass};
+use rustc::ty::{self, DefIdTree};
+use rustc::{declare_tool_lint, lint_array};
+use rustc_data_structures::fx::FxHashMap;
+use syntax::ast::NodeId;
+
+/// **What it does:** Checks for uses of the types and traits configured with
+/// `disallowed-types` in `clippy.toml`, in `use` items, fields, signatures,
+/// bounds, local variables, generic arguments and expressions like
+/// `HashMap::new()`. Does nothing if none are configured.
+///
+/// The types are given by their paths, which can go through re-exports. Type
+/// aliases of disallowed types are linted as well. Entries can be tables with
+/// the `path` and a `reason`, which is shown in the lint message. Paths which
+/// don't name a type or trait are configuration errors.
+///
+/// **Why is this bad?** Projects often have types which shouldn't be used,
+/// e.g. `HashMap`, whose iteration order isn't deterministic, when the output
+/// has to be reproducible.
+///
+/// **Known problems:** Types which are only inferred, e.g. the type of
+/// `let map = make_map();`, aren't linted.
+///
+/// **Example:**
+/// ```toml
+/// disallowed-types = [
+///     "std::sync::Mutex",
+///     { path = "std::collections::HashMap", reason = "use `BTreeMap` for a deterministic order" },
+/// ]
+/// ```
+declare_clippy_lint! {
+    pub DISALLOWED_TYPES,
+    style,
+    "uses of the types and traits disallowed in `clippy.toml`"
+}
+
+#[derive(Clone, Debug)]
+pub struct DisallowedTypes {
+    disallowed: Vec<DisallowedPath>,
+    /// the types of `disallowed` which could be resolved, with their index
+    resolved: FxHashMap<DefId, usize>,
+}
+
+impl DisallowedTypes {
+    pub fn new(disallowed: Vec<DisallowedPath>) -> Self {
+        Self {
+            disallowed,
+            resolved: FxHashMap::default(),
+        }
+    }
+
+    /// Returns the configured entry for `def_id`, if it's disallowed.
+    fn find(&self, cx: &LateContext<'_, '_>, def_id: DefId) -> Option<&DisallowedPath> {
+        if let Some(&index) = self.resolved.get(&def_id) {
+            return Some(&self.disallowed[index]);
+        }
+        // local types can't be resolved by `path_to_def`
+        let def_path = get_def_path(cx.tcx, def_id).join("::");
+        self.disallowed.iter().find(|disallowed| disallowed.path() == def_path)
+    }
+}
+
+impl LintPass for DisallowedTypes {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(DISALLOWED_TYPES)
+    }
+
+    fn name(&self) -> &'static str {
+        "DisallowedTypes"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for DisallowedTypes {
+    fn check_crate(&mut self, cx: &LateContext<'a, 'tcx>, _: &'tcx Crate) {
+        for (index, disallowed) in self.disallowed.iter().enumerate() {
+            let path: Vec<_> = disallowed.path().split("::").collect();
+            match path_to_def(cx, &path) {
+                Some(Def::Struct(def_id))
+                | Some(Def::Enum(def_id))
+                | Some(Def::Union(def_id))
+                | Some(Def::Trait(def_id))
+                | Some(Def::TyAlias(def_id))
+                | Some(Def::ForeignTy(def_id)) => {
+                    self.resolved.insert(def_id, index);
+                },
+                _ => {
+                    if !is_local_def_path(cx, disallowed.path()) {
+                        cx.sess().err(&format!(
+                            "error reading Clippy's configuration file: `disallowed-types` has `{}`, which isn't a \
+                             type or trait",
+                            disallowed.path()
+                        ));
+                    }
+                },
+            }
+        }
+    }
+
+    fn check_path(&mut self, cx: &LateContext<'a, 'tcx>, path: &'tcx Path, _: NodeId) {
+        if self.disallowed.is_empty() || in_macro(path.span) || in_external_macro(cx.sess(), path.span) {
+            return;
+        }
+        let disallowed = match path.def {
+            Def::Struct(def_id)
+            | Def::Enum(def_id)
+            | Def::Union(def_id)
+            | Def::Trait(def_id)
+            | Def::ForeignTy(def_id) => self.find(cx, def_id),
+            Def::StructCtor(ctor_id, _) => cx.tcx.parent(ctor_id).and_then(|def_id| self.find(cx, def_id)),
+            // the alias itself, or the type it stands for
+            Def::TyAlias(def_id) => self.find(cx, def_id).or_else(|| match cx.tcx.type_of(def_id).sty {
+                ty::Adt(adt, _) => self.find(cx, adt.did),
+                _ => None,
+            }),
+            _ => None,
+        };
+        if let Some(disallowed) = disallowed {
+            let msg = match disallowed.reason() {
+                Some(reason) => format!("use of a disallowed type `{}`: {}", disallowed.path(), reason),
+                None => format!("use of a disallowed type `{}`", disallowed.path()),
+            };
+            span_lint(cx, DISALLOWED_TYPES, path.span, &msg);
+        }
+    }
+}
@@ -1,10 +1,16 @@
-use crate::utils::span_lint;
+use crate::utils::{
+    in_macro, is_cfg_test, is_expn_of, is_in_test, match_type, paths, return_ty, span_lint, span_lint_and_then,
+};
 use itertools::Itertools;
 use pulldown_cmark;
-use rustc::lint::{EarlyContext, EarlyLintPass, LintArray, LintPass};
+use regex::Regex;
+use rustc::hir;
+use rustc::hir::intravisit::{self, NestedVisitorMap, Visitor};
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::ty;
 use rustc::{declare_tool_lint, lint_array};
 use rustc_data_structures::fx::FxHashSet;
-use syntax::ast;
+use syntax::ast::{self, NodeId};
 use syntax::source_map::{BytePos, Span};
 use syntax_pos::Pos;
 use url::Url;
@@ -33,20 +39,144 @@ declare_clippy_lint! {
     "presence of `_`, `::` or camel-case outside backticks in documentation"
 }
 
+/// **What it does:** Checks for the doc comments of public `unsafe` functions
+/// and traits which don't have a `# Safety` section.
+///
+/// **Why is this bad?** Callers of unsafe functions and implementors of unsafe
+/// traits have to know what they need to uphold to not cause undefined
+/// behavior.
+///
+/// **Known problems:** None.
+///
+/// **Examples:**
+/// ```rust
+/// /// Forgets the vector without freeing it.
+/// pub unsafe fn leak(v: Vec<u8>) -> *mut u8 { .. }
+/// ```
+/// Could be written as:
+/// ```rust
+/// /// Forgets the vector without freeing it.
+/// ///
+/// /// # Safety
+/// ///
+/// /// The returned pointer must be freed with `Vec::from_raw_parts`.
+/// pub unsafe fn leak(v: Vec<u8>) -> *mut u8 { .. }
+/// ```
+declare_clippy_lint! {
+    pub MISSING_SAFETY_DOC,
+    style,
+    "public `unsafe` function or trait documented without a `# Safety` section"
+}
+
+/// **What it does:** Checks for the doc comments of public functions which
+/// return a `Result` and don't have an `# Errors` section. Trait impls are
+/// skipped, their methods are documented by the trait.
+///
+/// **Why is this bad?** Callers have to know when the function fails to
+/// handle the errors.
+///
+/// **Known problems:** None.
+///
+/// **Examples:**
+/// ```rust
+/// /// Reads the config.
+/// pub fn read_config(path: &Path) -> io::Result<String> { .. }
+/// ```
+/// Could be written as:
+/// ```rust
+/// /// Reads the config.
+/// ///
+/// /// # Errors
+/// ///
+/// /// Fails if the file at `path` can't be read.
+/// pub fn read_config(path: &Path) -> io::Result<String> { .. }
+/// ```
+declare_clippy_lint! {
+    pub MISSING_ERRORS_DOC,
+    pedantic,
+    "public function returning `Result` documented without an `# Errors` section"
+}
+
+/// **What it does:** Checks for the doc comments of public functions which
+/// may panic and don't have a `# Panics` section. Functions may panic if they
+/// call `panic!`, `assert!`, `unwrap` or `expect`, or index into something.
+/// `debug_assert!`s and code with `#[cfg(test)]` are ignored.
+///
+/// **Why is this bad?** Callers have to know what to avoid to not panic.
+///
+/// **Known problems:** Indexing is linted even if the index is checked
+/// beforehand.
+///
+/// **Examples:**
+/// ```rust
+/// /// Returns the first byte.
+/// pub fn first(bytes: &[u8]) -> u8 {
+///     bytes[0]
+/// }
+/// ```
+/// Could be written as:
+/// ```rust
+/// /// Returns the first byte.
+/// ///
+/// /// # Panics
+/// ///
+/// /// Panics if `bytes` is empty.
+/// pub fn first(bytes: &[u8]) -> u8 {
+///     bytes[0]
+/// }
+/// ```
+declare_clippy_lint! {
+    pub MISSING_PANICS_DOC,
+    pedantic,
+    "public function which may panic documented without a `# Panics` section"
+}
+
 #[derive(Clone)]
 pub struct Doc {
-    valid_idents: FxHashSet<String>,
+    valid_idents: ValidIdents,
 }
 
 impl Doc {
     pub fn new(valid_idents: FxHashSet<String>) -> Self {
-        Self { valid_idents }
+        Self {
+            valid_idents: ValidIdents::new(valid_idents),
+        }
+    }
+}
+
+/// The words configured with `doc-valid-idents`. Entries containing anything
+/// but letters, digits and `_` are regular expressions matching whole words.
+#[derive(Clone)]
+pub struct ValidIdents {
+    words: FxHashSet<String>,
+    patterns: Vec<Regex>,
+}
+
+impl ValidIdents {
+    fn new(idents: FxHashSet<String>) -> Self {
+        let mut words = FxHashSet::default();
+        let mut patterns = Vec::new();
+        for ident in idents {
+            if !ident.chars().all(|c| c.is_alphanumeric() || c == '_') {
+                // entries that aren't valid regular expressions are matched literally
+                if let Ok(pattern) = Regex::new(&format!("^(?:{})$", ident)) {
+                    patterns.push(pattern);
+                    continue;
+                }
+            }
+            words.insert(ident);
+        }
+        Self { words, patterns }
+    }
+
+    fn contains(&self, word: &str) -> bool {
+        self.words.contains(word) || self.patterns.iter().any(|pattern| pattern.is_match(word))
     }
 }
 
 impl LintPass for Doc {
     fn get_lints(&self) -> LintArray {
-        lint_array![DOC_MARKDOWN]
+        lint_array![DOC_MARKDOWN, MISSING_SAFETY_DOC, MISSING_ERRORS_DOC, MISSING_PANICS_DOC]
     }
 
     fn name(&self) -> &'static str {
@@ -54,16 +184,169 @@ impl LintPass for Doc {
     }
 }
 
-impl EarlyLintPass for Doc {
-    fn check_crate(&mut self, cx: &EarlyContext<'_>, krate: &ast::Crate) {
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Doc {
+    fn check_crate(&mut self, cx: &LateContext<'a, 'tcx>, krate: &'tcx hir::Crate) {
         check_attrs(cx, &self.valid_idents, &krate.attrs);
     }
 
-    fn check_item(&mut self, cx: &EarlyContext<'_>, item: &ast::Item) {
-        check_attrs(cx, &self.valid_idents, &item.attrs);
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx hir::Item) {
+        let headers = check_attrs(cx, &self.valid_idents, &item.attrs);
+        match item.node {
+            hir::ItemKind::Fn(_, ref header, _, body_id) => {
+                lint_missing_headers(cx, item.id, item.span, header.unsafety, Some(body_id), &headers);
+            },
+            hir::ItemKind::Trait(_, hir::Unsafety::Unsafe, ..) => {
+                if !headers.safety && cx.access_levels.is_exported(item.id) && !in_macro(item.span) {
+                    span_lint(
+                        cx,
+                        MISSING_SAFETY_DOC,
+                        item.span,
+                        "docs for unsafe trait missing `# Safety` section",
+                    );
+                }
+            },
+            _ => (),
+        }
+    }
+
+    fn check_trait_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx hir::TraitItem) {
+        let headers = check_attrs(cx, &self.valid_idents, &item.attrs);
+        if let hir::TraitItemKind::Method(ref sig, ref method) = item.node {
+            let body_id = match *method {
+                hir::TraitMethod::Provided(body_id) => Some(body_id),
+                hir::TraitMethod::Required(_) => None,
+            };
+            lint_missing_headers(cx, item.id, item.span, sig.header.unsafety, body_id, &headers);
+        }
+    }
+
+    fn check_impl_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx hir::ImplItem) {
+        let headers = check_attrs(cx, &self.valid_idents, &item.attrs);
+        // like in `missing_doc`, the methods of trait impls are documented by the trait
+        let def_id = cx.tcx.hir().local_def_id(item.id);
+        if let ty::ImplContainer(cid) = cx.tcx.associated_item(def_id).container {
+            if cx.tcx.impl_trait_ref(cid).is_some() {
+                return;
+            }
+        }
+        if let hir::ImplItemKind::Method(ref sig, body_id) = item.node {
+            lint_missing_headers(cx, item.id, item.span, sig.header.unsafety, Some(body_id), &headers);
+        }
+    }
+}
+
+/// Lints the public function `id` if its doc comment is missing the sections
+/// for its unsafety, its errors or its panics.
+fn lint_missing_headers<'a, 'tcx>(
+    cx: &LateContext<'a, 'tcx>,
+    id: NodeId,
+    span: Span,
+    unsafety: hir::Unsafety,
+    body_id: Option<hir::BodyId>,
+    headers: &DocHeaders,
+) {
+    if !cx.access_levels.is_exported(id) || in_macro(span) || is_in_test(cx.tcx, id) {
+        return;
+    }
+    if unsafety == hir::Unsafety::Unsafe && !headers.safety {
+        span_lint(
+            cx,
+            MISSING_SAFETY_DOC,
+            span,
+            "docs for unsafe function missing `# Safety` section",
+        );
+    }
+    if !headers.errors && match_type(cx, return_ty(cx, id), &paths::RESULT) {
+        span_lint(
+            cx,
+            MISSING_ERRORS_DOC,
+            span,
+            "docs for function returning `Result` missing `# Errors` section",
+        );
+    }
+    if let Some(body_id) = body_id {
+        if headers.panics {
+            return;
+        }
+        let mut visitor = FindPanic {
+            cx,
+            tables: cx.tcx.body_tables(body_id),
+            panic_span: None,
+        };
+        visitor.visit_expr(&cx.tcx.hir().body(body_id).value);
+        if let Some(panic_span) = visitor.panic_span {
+            span_lint_and_then(
+                cx,
+                MISSING_PANICS_DOC,
+                span,
+                "docs for function which may panic missing `# Panics` section",
+                |db| {
+                    db.span_note(panic_span, "first possible panic found here");
+                },
+            );
+        }
+    }
+}
+
+/// Finds the first expression in a function body which may panic.
+struct FindPanic<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    tables: &'tcx ty::TypeckTables<'tcx>,
+    panic_span: Option<Span>,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for FindPanic<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx hir::Expr) {
+        if self.panic_span.is_some() || expr.attrs.iter().any(is_cfg_test) {
+            return;
+        }
+        // `debug_assert!`s only panic in debug builds, and `unreachable!` is no documented panic
+        if ["debug_assert", "debug_assert_eq", "debug_assert_ne", "unreachable"]
+            .iter()
+            .any(|name| is_expn_of(expr.span, name).is_some())
+        {
+            return;
+        }
+        self.panic_span = match expr.node {
+            hir::ExprKind::MethodCall(ref path, _, ref args)
+                if path.ident.name == "unwrap" || path.ident.name == "expect" =>
+            {
+                let ty = self.tables.expr_ty(&args[0]);
+                if match_type(self.cx, ty, &paths::OPTION) || match_type(self.cx, ty, &paths::RESULT) {
+                    Some(expr.span)
+                } else {
+                    None
+                }
+            },
+            hir::ExprKind::Index(..) => Some(expr.span),
+            _ => ["panic", "assert", "assert_eq", "assert_ne"]
+                .iter()
+                .filter_map(|name| is_expn_of(expr.span, name))
+                .next(),
+        };
+        if self.panic_span.is_none() {
+            intravisit::walk_expr(self, expr);
+        }
+    }
+
+    fn visit_local(&mut self, local: &'tcx hir::Local) {
+        if !local.attrs.iter().any(is_cfg_test) {
+            intravisit::walk_local(self, local);
+        }
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::OnlyBodies(&self.cx.tcx.hir())
     }
 }
 
+/// The sections of a doc comment lints look for.
+struct DocHeaders {
+    safety: bool,
+    errors: bool,
+    panics: bool,
+}
+
 struct Parser<'a> {
     parser: pulldown_cmark::Parser<'a>,
 }
@@ -89,6 +372,10 @@ impl<'a> Iterator for Parser<'a> {
 /// `syntax::parse::lexer::comments::strip_doc_comment_decoration` because we
 /// need to keep track of
 /// the spans but this function is inspired from the later.
+///
+/// # Panics
+///
+/// Panics if `comment` isn't a doc comment.
 #[allow(clippy::cast_possible_truncation)]
 pub fn strip_doc_comment_decoration(comment: &str, span: Span) -> (String, Vec<(usize, Span)>) {
     // one-line comments lose their prefix
@@ -99,7 +386,7 @@ pub fn strip_doc_comment_decoration(comment: &str, span: Span) -> (String, Vec<(
             let mut doc = doc.to_owned();
             doc.push('\n');
             return (
-                doc.to_owned(),
+                doc.clone(),
                 vec![(doc.len(), span.with_lo(span.lo() + BytePos(prefix.len() as u32)))],
             );
         }
@@ -140,7 +427,10 @@ pub fn strip_doc_comment_decoration(comment: &str, span: Span) -> (String, Vec<(
     panic!("not a doc-comment: {}", comment);
 }
 
-pub fn check_attrs<'a>(cx: &EarlyContext<'_>, valid_idents: &FxHashSet<String>, attrs: &'a [ast::Attribute]) {
+/// Checks the doc comment in `attrs`, and returns which sections it has. Items
+/// without a doc comment are treated as if they had all sections, they are
+/// `missing_docs`'s concern.
+fn check_attrs<'a>(cx: &LateContext<'_, '_>, valid_idents: &ValidIdents, attrs: &'a [ast::Attribute]) -> DocHeaders {
     let mut doc = String::new();
     let mut spans = vec![];
 
@@ -154,7 +444,11 @@ pub fn check_attrs<'a>(cx: &EarlyContext<'_>, valid_idents: &FxHashSet<String>,
             }
         } else if attr.name() == "doc" {
             // ignore mix of sugared and non-sugared doc
-            return;
+            return DocHeaders {
+                safety: true,
+                errors: true,
+                panics: true,
+            };
         }
     }
 
@@ -165,38 +459,50 @@ pub fn check_attrs<'a>(cx: &EarlyContext<'_>, valid_idents: &FxHashSet<String>,
         current += offset_copy;
     }
 
-    if !doc.is_empty() {
-        let parser = Parser::new(pulldown_cmark::Parser::new(&doc));
-        let parser = parser.coalesce(|x, y| {
-            use pulldown_cmark::Event::*;
+    if doc.is_empty() {
+        return DocHeaders {
+            safety: true,
+            errors: true,
+            panics: true,
+        };
+    }
 
-            let x_offset = x.0;
-            let y_offset = y.0;
+    let parser = Parser::new(pulldown_cmark::Parser::new(&doc));
+    let parser = parser.coalesce(|x, y| {
+        use pulldown_cmark::Event::*;
 
-            match (x.1, y.1) {
-                (Text(x), Text(y)) => {
-                    let mut x = x.into_owned();
-                    x.push_str(&y);
-                    Ok((x_offset, Text(x.into())))
-                },
-                (x, y) => Err(((x_offset, x), (y_offset, y))),
-            }
-        });
-        check_doc(cx, valid_idents, parser, &spans);
-    }
+        let x_offset = x.0;
+        let y_offset = y.0;
+
+        match (x.1, y.1) {
+            (Text(x), Text(y)) => {
+                let mut x = x.into_owned();
+                x.push_str(&y);
+                Ok((x_offset, Text(x.into())))
+            },
+            (x, y) => Err(((x_offset, x), (y_offset, y))),
+        }
+    });
+    check_doc(cx, valid_idents, parser, &spans)
 }
 
 fn check_doc<'a, Events: Iterator<Item = (usize, pulldown_cmark::Event<'a>)>>(
-    cx: &EarlyContext<'_>,
-    valid_idents: &FxHashSet<String>,
+    cx: &LateContext<'_, '_>,
+    valid_idents: &ValidIdents,
     docs: Events,
     spans: &[(usize, Span)],
-) {
+) -> DocHeaders {
     use pulldown_cmark::Event::*;
     use pulldown_cmark::Tag::*;
 
+    let mut headers = DocHeaders {
+        safety: false,
+        errors: false,
+        panics: false,
+    };
     let mut in_code = false;
     let mut in_link = None;
+    let mut in_heading = false;
 
     for (offset, event) in docs {
         match event {
@@ -204,6 +510,8 @@ fn check_doc<'a, Events: Iterator<Item = (usize, pulldown_cmark::Event<'a>)>>(
             End(CodeBlock(_)) | End(Code) => in_code = false,
             Start(Link(link, _)) => in_link = Some(link),
             End(Link(_, _)) => in_link = None,
+            Start(Header(_)) => in_heading = true,
+            End(Header(_)) => in_heading = false,
             Start(_tag) | End(_tag) => (),         // We don't care about other tags
             Html(_html) | InlineHtml(_html) => (), // HTML is weird, just ignore it
             SoftBreak | HardBreak => (),
@@ -215,6 +523,11 @@ fn check_doc<'a, Events: Iterator<Item = (usize, pulldown_cmark::Event<'a>)>>(
                     continue;
                 }
 
+                if in_heading {
+                    headers.safety |= text.trim() == "Safety";
+                    headers.errors |= text.trim() == "Errors";
+                    headers.panics |= text.trim() == "Panics";
+                }
                 if !in_code {
                     let index = match spans.binary_search_by(|c| c.0.cmp(&offset)) {
                         Ok(o) => o,
@@ -231,9 +544,10 @@ fn check_doc<'a, Events: Iterator<Item = (usize, pulldown_cmark::Event<'a>)>>(
             },
         }
     }
+    headers
 }
 
-fn check_text(cx: &EarlyContext<'_>, valid_idents: &FxHashSet<String>, text: &str, span: Span) {
+fn check_text(cx: &LateContext<'_, '_>, valid_idents: &ValidIdents, text: &str, span: Span) {
     for word in text.split(|c: char| c.is_whitespace() || c == '\'') {
         // Trim punctuation as in `some comment (see foo::bar).`
         //                                                   ^^
@@ -256,7 +570,7 @@ fn check_text(cx: &EarlyContext<'_>, valid_idents: &FxHashSet<String>, text: &st
     }
 }
 
-fn check_word(cx: &EarlyContext<'_>, word: &str, span: Span) {
+fn check_word(cx: &LateContext<'_, '_>, word: &str, span: Span) {
     /// Checks if a string is camel-case, ie. contains at least two uppercase
     /// letter (`Clippy` is
     /// ok) and one lower-case letter (`NASA` is ok). Plural are also excluded
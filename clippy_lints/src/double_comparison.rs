@@ -6,7 +6,7 @@ use rustc::{declare_tool_lint, lint_array};
 use rustc_errors::Applicability;
 use syntax::source_map::Span;
 
-use crate::utils::{snippet_with_applicability, span_lint_and_sugg, SpanlessEq};
+use crate::utils::{snippet_with_context, span_lint_and_sugg, SpanlessEq};
 
 /// **What it does:** Checks for double comparions that could be simplified to a single expression.
 ///
@@ -59,8 +59,8 @@ impl<'a, 'tcx> Pass {
         macro_rules! lint_double_comparison {
             ($op:tt) => {{
                 let mut applicability = Applicability::MachineApplicable;
-                let lhs_str = snippet_with_applicability(cx, llhs.span, "", &mut applicability);
-                let rhs_str = snippet_with_applicability(cx, lrhs.span, "", &mut applicability);
+                let (lhs_str, _) = snippet_with_context(cx, llhs.span, span.ctxt(), "", &mut applicability);
+                let (rhs_str, _) = snippet_with_context(cx, lrhs.span, span.ctxt(), "", &mut applicability);
                 let sugg = format!("{} {} {}", lhs_str, stringify!($op), rhs_str);
                 span_lint_and_sugg(
                     cx,
@@ -7,7 +7,9 @@ use syntax::source_map::Spanned;
 
 use crate::consts::{constant, Constant};
 use crate::utils::paths;
-use crate::utils::{match_type, snippet_with_applicability, span_lint_and_sugg, walk_ptrs_ty};
+use crate::utils::{
+    match_type, meets_msrv, msrvs, snippet_with_applicability, span_lint_and_sugg, walk_ptrs_ty, RustcVersion,
+};
 
 /// **What it does:** Checks for calculation of subsecond microseconds or milliseconds
 /// from other `Duration` methods.
@@ -30,7 +32,15 @@ declare_clippy_lint! {
 }
 
 #[derive(Copy, Clone)]
-pub struct DurationSubsec;
+pub struct DurationSubsec {
+    msrv: Option<RustcVersion>,
+}
+
+impl DurationSubsec {
+    pub fn new(msrv: Option<RustcVersion>) -> Self {
+        Self { msrv }
+    }
+}
 
 impl LintPass for DurationSubsec {
     fn get_lints(&self) -> LintArray {
@@ -55,6 +65,9 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for DurationSubsec {
                     ("subsec_nanos", 1_000) => "subsec_micros",
                     _ => return,
                 };
+                if !meets_msrv(cx.tcx, expr.id, self.msrv, msrvs::DURATION_SUBSEC_MILLIS) {
+                    return;
+                }
                 let mut applicability = Applicability::MachineApplicable;
                 span_lint_and_sugg(
                     cx,
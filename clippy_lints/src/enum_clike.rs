@@ -1,14 +1,14 @@
 //! lint on C-like enums that are `repr(isize/usize)` and have values that
-//! don't fit into an `i32`
+//! don't fit into an `i32`, or whose discriminants are casts which overflow
 
-use crate::consts::{miri_to_const, Constant};
-use crate::utils::span_lint;
+use crate::consts::{constant, miri_to_const, Constant};
+use crate::utils::{clip, int_bits, sext, span_help_and_lint, span_lint, uint_bits};
 use rustc::hir::*;
 use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
 use rustc::mir::interpret::GlobalId;
-use rustc::ty;
 use rustc::ty::subst::Substs;
 use rustc::ty::util::IntTypeExt;
+use rustc::ty::{self, Ty};
 use rustc::{declare_tool_lint, lint_array};
 use syntax::ast::{IntTy, UintTy};
 
@@ -34,11 +34,40 @@ declare_clippy_lint! {
     "C-like enums that are `repr(isize/usize)` and have values that don't fit into an `i32`"
 }
 
+/// **What it does:** Checks for explicit discriminants of C-like enums which
+/// cast a constant to a narrower integer type that can't represent it, like
+/// `X = 0x100u32 as u8` in a `#[repr(u8)]` enum. Casts between types of
+/// the same width, like `-1i8 as u8`, keep the bits, which is usually the
+/// intent, so they aren't linted.
+///
+/// **Why is this bad?** The cast silently truncates or wraps the value, so the
+/// variant's discriminant isn't the one written down. Duplicated values and out
+/// of range literals are errors already, but casts of constants defined
+/// elsewhere aren't checked.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// const FLAG: u32 = 0x100;
+///
+/// #[repr(u8)]
+/// enum Flags {
+///     Low = 1,
+///     High = FLAG as u8,
+/// }
+/// ```
+declare_clippy_lint! {
+    pub OVERFLOWING_ENUM_DISCRIMINANT,
+    correctness,
+    "C-like enum discriminants casting a value which doesn't fit into the `repr` type"
+}
+
 pub struct UnportableVariant;
 
 impl LintPass for UnportableVariant {
     fn get_lints(&self) -> LintArray {
-        lint_array!(ENUM_CLIKE_UNPORTABLE_VARIANT)
+        lint_array!(ENUM_CLIKE_UNPORTABLE_VARIANT, OVERFLOWING_ENUM_DISCRIMINANT)
     }
 
     fn name(&self) -> &'static str {
@@ -49,13 +78,15 @@ impl LintPass for UnportableVariant {
 impl<'a, 'tcx> LateLintPass<'a, 'tcx> for UnportableVariant {
     #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap, clippy::cast_sign_loss)]
     fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
-        if cx.tcx.data_layout.pointer_size.bits() != 64 {
-            return;
-        }
+        let is_64_bit = cx.tcx.data_layout.pointer_size.bits() == 64;
         if let ItemKind::Enum(ref def, _) = item.node {
             for var in &def.variants {
                 let variant = &var.node;
                 if let Some(ref anon_const) = variant.disr_expr {
+                    check_cast_discriminant(cx, var, anon_const);
+                    if !is_64_bit {
+                        continue;
+                    }
                     let param_env = ty::ParamEnv::empty();
                     let def_id = cx.tcx.hir().body_owner_def_id(anon_const.body);
                     let substs = Substs::identity_for_item(cx.tcx.global_tcx(), def_id);
@@ -94,3 +125,53 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for UnportableVariant {
         }
     }
 }
+
+/// Lints the discriminant `anon_const` of `var` if it's a cast of a constant
+/// to a narrower discriminant type which can't represent it.
+#[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+fn check_cast_discriminant(cx: &LateContext<'_, '_>, var: &Variant, anon_const: &AnonConst) {
+    let body = cx.tcx.hir().body(anon_const.body);
+    let tables = cx.tcx.body_tables(anon_const.body);
+    let (inner, discr_ty) = match body.value.node {
+        ExprKind::Cast(ref inner, _) => (inner, tables.expr_ty(&body.value)),
+        _ => return,
+    };
+    match (int_width(cx, tables.expr_ty(inner)), int_width(cx, discr_ty)) {
+        (Some(from), Some(to)) if to < from => {},
+        _ => return,
+    }
+    let value = match constant(cx, tables, inner) {
+        Some((Constant::Int(bits), _)) => match tables.expr_ty(inner).sty {
+            ty::Int(ity) => sext(cx.tcx, bits, ity),
+            ty::Uint(_) if bits <= i128::max_value() as u128 => bits as i128,
+            _ => return,
+        },
+        _ => return,
+    };
+    let discr = match discr_ty.sty {
+        ty::Int(ity) => sext(cx.tcx, value as u128, ity),
+        ty::Uint(uty) => clip(cx.tcx, value as u128, uty) as i128,
+        _ => return,
+    };
+    if discr != value {
+        span_help_and_lint(
+            cx,
+            OVERFLOWING_ENUM_DISCRIMINANT,
+            var.span,
+            &format!(
+                "`{}` doesn't fit into `{}`, so this discriminant is `{}`",
+                value, discr_ty, discr
+            ),
+            "use a value in the range of the `repr` type, or a larger `repr`",
+        );
+    }
+}
+
+/// Returns the width of the integer type `ty` in bits.
+fn int_width(cx: &LateContext<'_, '_>, ty: Ty<'_>) -> Option<u64> {
+    match ty.sty {
+        ty::Int(ity) => Some(int_bits(cx.tcx, ity)),
+        ty::Uint(uty) => Some(uint_bits(cx.tcx, uty)),
+        _ => None,
+    }
+}
@@ -4,6 +4,7 @@ use crate::utils::{camel_case, in_macro};
 use crate::utils::{span_help_and_lint, span_lint};
 use rustc::lint::{EarlyContext, EarlyLintPass, Lint, LintArray, LintPass};
 use rustc::{declare_tool_lint, lint_array};
+use rustc_data_structures::fx::FxHashSet;
 use syntax::ast::*;
 use syntax::source_map::Span;
 use syntax::symbol::{InternedString, LocalInternedString};
@@ -14,6 +15,11 @@ use syntax::symbol::{InternedString, LocalInternedString};
 /// **Why is this bad?** Enumeration variant names should specify their variant,
 /// not repeat the enumeration name.
 ///
+/// Prefixes that are fine to share, like `Http` in a protocol crate, can be
+/// listed in `enum-variant-allowed-prefixes`. Setting
+/// `check-variant-enum-name-prefix = false` stops linting variants starting
+/// with the enum's name.
+///
 /// **Known problems:** None.
 ///
 /// **Example:**
@@ -104,13 +110,17 @@ declare_clippy_lint! {
 pub struct EnumVariantNames {
     modules: Vec<(InternedString, String)>,
     threshold: u64,
+    allowed_prefixes: FxHashSet<String>,
+    check_enum_name_prefix: bool,
 }
 
 impl EnumVariantNames {
-    pub fn new(threshold: u64) -> Self {
+    pub fn new(threshold: u64, allowed_prefixes: FxHashSet<String>, check_enum_name_prefix: bool) -> Self {
         Self {
             modules: Vec::new(),
             threshold,
+            allowed_prefixes,
+            check_enum_name_prefix,
         }
     }
 }
@@ -154,19 +164,20 @@ fn partial_rmatch(post: &str, name: &str) -> usize {
 
 fn check_variant(
     cx: &EarlyContext<'_>,
-    threshold: u64,
+    evn: &EnumVariantNames,
     def: &EnumDef,
     item_name: &str,
     item_name_chars: usize,
     span: Span,
     lint: &'static Lint,
 ) {
-    if (def.variants.len() as u64) < threshold {
+    if (def.variants.len() as u64) < evn.threshold {
         return;
     }
     for var in &def.variants {
         let name = var2str(var);
-        if partial_match(item_name, &name) == item_name_chars
+        if evn.check_enum_name_prefix
+            && partial_match(item_name, &name) == item_name_chars
             && name.chars().nth(item_name_chars).map_or(false, |c| !c.is_lowercase())
         {
             span_lint(cx, lint, var.span, "Variant name starts with the enum's name");
@@ -201,6 +212,9 @@ fn check_variant(
         let post_camel = camel_case::from(post);
         post = &post[post_camel..];
     }
+    if evn.allowed_prefixes.contains(pre) {
+        pre = "";
+    }
     let (what, value) = match (pre.is_empty(), post.is_empty()) {
         (true, true) => return,
         (false, _) => ("pre", pre),
@@ -301,7 +315,7 @@ impl EarlyLintPass for EnumVariantNames {
                 VisibilityKind::Public => PUB_ENUM_VARIANT_NAMES,
                 _ => ENUM_VARIANT_NAMES,
             };
-            check_variant(cx, self.threshold, def, &item_name, item_name_chars, item.span, lint);
+            check_variant(cx, self, def, &item_name, item_name_chars, item.span, lint);
         }
         self.modules.push((item_name.as_interned_str(), item_camel));
     }
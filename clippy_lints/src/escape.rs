@@ -1,4 +1,5 @@
-use crate::utils::span_lint;
+use crate::utils::{meets_msrv, msrvs, snippet, span_help_and_lint, span_lint, RustcVersion};
+use rustc::hir;
 use rustc::hir::intravisit as visit;
 use rustc::hir::*;
 use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
@@ -13,6 +14,7 @@ use syntax::source_map::Span;
 
 pub struct Pass {
     pub too_large_for_stack: u64,
+    pub msrv: Option<RustcVersion>,
 }
 
 /// **What it does:** Checks for usage of `Box<T>` where an unboxed `T` would
@@ -38,19 +40,58 @@ declare_clippy_lint! {
     "using `Box<T>` where unnecessary"
 }
 
+/// **What it does:** Checks for arguments of functions and methods of type
+/// `Box<dyn Trait>` that are only ever dereferenced, i.e. never moved,
+/// borrowed, stored, returned or captured by a closure.
+///
+/// **Why is this bad?** Callers are forced to allocate a box just to call the
+/// function. Taking `&dyn Trait` or a generic `impl Trait` argument works just
+/// as well.
+///
+/// Exported functions and provided trait methods aren't linted, since changing
+/// their signature breaks other crates or makes the trait not object safe.
+///
+/// **Known problems:** Uses that only need the box itself, like formatting
+/// `value` instead of `*value`, make the lint give up, though the argument
+/// could still be a reference.
+///
+/// **Example:**
+/// ```rust
+/// fn area(shape: Box<dyn Shape>) -> f64 {
+///     shape.area()
+/// }
+/// ```
+/// Could be written as:
+/// ```rust
+/// fn area(shape: &dyn Shape) -> f64 {
+///     shape.area()
+/// }
+/// ```
+declare_clippy_lint! {
+    pub BOXED_TRAIT_OBJECT_ARG,
+    perf,
+    "`Box<dyn Trait>` arguments that are only dereferenced"
+}
+
 fn is_non_trait_box(ty: Ty<'_>) -> bool {
     ty.is_box() && !ty.boxed_ty().is_trait()
 }
 
+fn is_trait_box(ty: Ty<'_>) -> bool {
+    ty.is_box() && ty.boxed_ty().is_trait()
+}
+
 struct EscapeDelegate<'a, 'tcx: 'a> {
     cx: &'a LateContext<'a, 'tcx>,
     set: NodeSet,
+    /// `Box<dyn Trait>` arguments that didn't escape (yet)
+    trait_args: NodeSet,
     too_large_for_stack: u64,
 }
 
 impl LintPass for Pass {
     fn get_lints(&self) -> LintArray {
-        lint_array!(BOXED_LOCAL)
+        lint_array!(BOXED_LOCAL, BOXED_TRAIT_OBJECT_ARG)
     }
 
     fn name(&self) -> &'static str {
@@ -62,8 +103,8 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
     fn check_fn(
         &mut self,
         cx: &LateContext<'a, 'tcx>,
-        _: visit::FnKind<'tcx>,
-        _: &'tcx FnDecl,
+        kind: visit::FnKind<'tcx>,
+        decl: &'tcx FnDecl,
         body: &'tcx Body,
         _: Span,
         node_id: NodeId,
@@ -72,15 +113,19 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
         let parent_id = cx.tcx.hir().get_parent(node_id);
         let parent_node = cx.tcx.hir().find(parent_id);
 
+        let mut provided = false;
         if let Some(Node::Item(item)) = parent_node {
-            if let ItemKind::Impl(_, _, _, _, Some(..), _, _) = item.node {
-                return;
+            match item.node {
+                ItemKind::Impl(_, _, _, _, Some(..), _, _) => return,
+                ItemKind::Trait(..) => provided = true,
+                _ => {},
             }
         }
 
         let mut v = EscapeDelegate {
             cx,
             set: NodeSet::default(),
+            trait_args: NodeSet::default(),
             too_large_for_stack: self.too_large_for_stack,
         };
 
@@ -96,15 +141,66 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
                 "local variable doesn't need to be boxed here",
             );
         }
+
+        // the arguments of closures are given by the functions they're passed to
+        if let visit::FnKind::Closure(_) = kind {
+            return;
+        }
+        if provided || cx.access_levels.is_exported(node_id) {
+            return;
+        }
+        let impl_trait = meets_msrv(cx.tcx, node_id, self.msrv, msrvs::IMPL_TRAIT);
+        for (arg, input) in body.arguments.iter().zip(&decl.inputs) {
+            if v.trait_args.contains(&arg.pat.id) {
+                let boxed = boxed_ty_snippet(cx, input);
+                let help = if impl_trait {
+                    format!(
+                        "consider taking `&{}` or a generic `impl Trait` argument instead",
+                        boxed
+                    )
+                } else {
+                    format!("consider taking `&{}` instead", boxed)
+                };
+                span_help_and_lint(
+                    cx,
+                    BOXED_TRAIT_OBJECT_ARG,
+                    input.span,
+                    "this argument doesn't need to be a boxed trait object",
+                    &help,
+                );
+            }
+        }
     }
 }
 
+/// Returns the snippet of `T` in a `Box<T>` type, or `..` if it can't be found.
+fn boxed_ty_snippet(cx: &LateContext<'_, '_>, ty: &hir::Ty) -> String {
+    if let TyKind::Path(QPath::Resolved(None, ref path)) = ty.node {
+        if let Some(ref args) = path.segments.last().and_then(|segment| segment.args.as_ref()) {
+            if let Some(GenericArg::Type(ref boxed)) = args.args.first() {
+                let boxed = snippet(cx, boxed.span, "..");
+                // `&dyn A + B` doesn't parse
+                return if boxed.contains('+') {
+                    format!("({})", boxed)
+                } else {
+                    boxed.to_string()
+                };
+            }
+        }
+    }
+    "..".to_string()
+}
+
 impl<'a, 'tcx> Delegate<'tcx> for EscapeDelegate<'a, 'tcx> {
     fn consume(&mut self, _: NodeId, _: Span, cmt: &cmt_<'tcx>, mode: ConsumeMode) {
         if let Categorization::Local(lid) = cmt.cat {
-            if let Move(DirectRefMove) = mode {
+            if let Move(move_reason) = mode {
                 // moved out or in. clearly can't be localized
-                self.set.remove(&lid);
+                if let DirectRefMove = move_reason {
+                    self.set.remove(&lid);
+                }
+                // including into a `move` closure
+                self.trait_args.remove(&lid);
             }
         }
     }
@@ -118,6 +214,8 @@ impl<'a, 'tcx> Delegate<'tcx> for EscapeDelegate<'a, 'tcx> {
             }
             if is_non_trait_box(cmt.ty) && !self.is_large_box(cmt.ty) {
                 self.set.insert(consume_pat.id);
+            } else if is_trait_box(cmt.ty) {
+                self.trait_args.insert(consume_pat.id);
             }
             return;
         }
@@ -140,6 +238,8 @@ impl<'a, 'tcx> Delegate<'tcx> for EscapeDelegate<'a, 'tcx> {
             }
         }
         if let Categorization::Local(lid) = cmt.cat {
+            // let y = x where x is a boxed trait object argument
+            self.trait_args.remove(&lid);
             if self.set.contains(&lid) {
                 // let y = x where x is known
                 // remove x, insert y
@@ -158,6 +258,8 @@ impl<'a, 'tcx> Delegate<'tcx> for EscapeDelegate<'a, 'tcx> {
         loan_cause: LoanCause,
     ) {
         if let Categorization::Local(lid) = cmt.cat {
+            // uses through a deref borrow `*x` instead, so the box itself is needed
+            self.trait_args.remove(&lid);
             match loan_cause {
                 // x.foo()
                 // Used without autodereffing (i.e. x.clone())
@@ -1,15 +1,20 @@
 use crate::utils::{is_adjusted, iter_input_pats, snippet_opt, span_lint_and_then};
+use rustc::hir::def::Def;
+use rustc::hir::def_id::DefId;
 use rustc::hir::*;
 use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
-use rustc::ty;
+use rustc::ty::item_path::with_crate_prefix;
+use rustc::ty::subst::Subst;
+use rustc::ty::{self, Ty};
 use rustc::{declare_tool_lint, lint_array};
 use rustc_errors::Applicability;
 
 pub struct EtaPass;
 
 /// **What it does:** Checks for closures which just call another function where
-/// the function can be called directly. `unsafe` functions or calls where types
-/// get adjusted are ignored.
+/// the function can be called directly. `unsafe` functions are ignored, as are
+/// calls where the arguments get adjusted, unless the function takes the
+/// arguments with the types of the closure's parameters.
 ///
 /// **Why is this bad?** Needlessly creating a closure adds code for no benefit
 /// and gives the optimizer more work.
@@ -32,9 +37,32 @@ declare_clippy_lint! {
     "redundant closures, i.e. `|a| foo(a)` (which can be written as just `foo`)"
 }
 
+/// **What it does:** Checks for closures which just call a method on their
+/// first parameter, with the other parameters as the arguments, where the
+/// method can be passed by its path instead.
+///
+/// **Why is this bad?** It's more concise to pass the method directly.
+///
+/// **Known problems:** Closures calling a method on a captured receiver, like
+/// `|x| set.contains(x)`, can't be replaced by a path and aren't linted.
+///
+/// **Example:**
+/// ```rust
+/// names.iter().map(|n| n.to_uppercase())
+/// ```
+/// Could be written as:
+/// ```rust
+/// names.iter().map(str::to_uppercase)
+/// ```
+declare_clippy_lint! {
+    pub REDUNDANT_CLOSURE_FOR_METHOD_CALLS,
+    pedantic,
+    "redundant closures for method calls, i.e. `|a| a.foo()` (which can be written as just `Type::foo`)"
+}
+
 impl LintPass for EtaPass {
     fn get_lints(&self) -> LintArray {
-        lint_array!(REDUNDANT_CLOSURE)
+        lint_array!(REDUNDANT_CLOSURE, REDUNDANT_CLOSURE_FOR_METHOD_CALLS)
     }
 
     fn name(&self) -> &'static str {
@@ -59,56 +87,140 @@ fn check_closure(cx: &LateContext<'_, '_>, expr: &Expr) {
     if let ExprKind::Closure(_, ref decl, eid, _, _) = expr.node {
         let body = cx.tcx.hir().body(eid);
         let ex = &body.value;
-        if let ExprKind::Call(ref caller, ref args) = ex.node {
-            if args.len() != decl.inputs.len() {
-                // Not the same number of arguments, there
-                // is no way the closure is the same as the function
-                return;
-            }
-            if is_adjusted(cx, ex) || args.iter().any(|arg| is_adjusted(cx, arg)) {
-                // Are the expression or the arguments type-adjusted? Then we need the closure
-                return;
-            }
-            let fn_ty = cx.tables.expr_ty(caller);
-            match fn_ty.sty {
-                // Is it an unsafe function? They don't implement the closure traits
-                ty::FnDef(..) | ty::FnPtr(_) => {
-                    let sig = fn_ty.fn_sig(cx.tcx);
-                    if sig.skip_binder().unsafety == Unsafety::Unsafe || sig.skip_binder().output().sty == ty::Never {
-                        return;
-                    }
-                },
-                _ => (),
-            }
-            for (a1, a2) in iter_input_pats(decl, body).zip(args) {
-                if let PatKind::Binding(.., ident, _) = a1.pat.node {
-                    // XXXManishearth Should I be checking the binding mode here?
-                    if let ExprKind::Path(QPath::Resolved(None, ref p)) = a2.node {
-                        if p.segments.len() != 1 {
-                            // If it's a proper path, it can't be a local variable
+        if is_adjusted(cx, ex) {
+            // Is the result type-adjusted? Then we need the closure
+            return;
+        }
+        match ex.node {
+            ExprKind::Call(ref caller, ref args) => {
+                let fn_ty = cx.tables.expr_ty(caller);
+                let inputs = match fn_ty.sty {
+                    ty::FnDef(..) | ty::FnPtr(_) => {
+                        let sig = fn_ty.fn_sig(cx.tcx);
+                        // Is it an unsafe function? They don't implement the closure traits
+                        if sig.skip_binder().unsafety == Unsafety::Unsafe || sig.skip_binder().output().sty == ty::Never
+                        {
                             return;
                         }
-                        if p.segments[0].ident.name != ident.name {
-                            // The two idents should be the same
+                        cx.tcx.erase_late_bound_regions(&sig).inputs().to_vec()
+                    },
+                    _ => {
+                        if args.iter().any(|arg| is_adjusted(cx, arg)) {
+                            // Are the arguments type-adjusted? Then we need the closure
                             return;
                         }
-                    } else {
-                        return;
+                        vec![]
+                    },
+                };
+                if !is_forwarded(cx, decl, body, args, &inputs) {
+                    return;
+                }
+                span_lint_and_then(cx, REDUNDANT_CLOSURE, expr.span, "redundant closure found", |db| {
+                    if let Some(snippet) = snippet_opt(cx, caller.span) {
+                        db.span_suggestion(
+                            expr.span,
+                            "remove closure as shown",
+                            snippet,
+                            Applicability::MachineApplicable,
+                        );
                     }
-                } else {
+                });
+            },
+            ExprKind::MethodCall(ref path, _, ref args) => {
+                if path.args.is_some() {
+                    // The generic arguments can't be given for the path of the method
                     return;
                 }
-            }
-            span_lint_and_then(cx, REDUNDANT_CLOSURE, expr.span, "redundant closure found", |db| {
-                if let Some(snippet) = snippet_opt(cx, caller.span) {
-                    db.span_suggestion(
+                let def_id = match cx.tables.type_dependent_defs().get(ex.hir_id) {
+                    Some(&Def::Method(def_id)) => def_id,
+                    _ => return,
+                };
+                let sig = cx.tcx.fn_sig(def_id).subst(cx.tcx, cx.tables.node_substs(ex.hir_id));
+                if sig.skip_binder().unsafety == Unsafety::Unsafe || sig.skip_binder().output().sty == ty::Never {
+                    return;
+                }
+                let inputs = cx.tcx.erase_late_bound_regions(&sig).inputs().to_vec();
+                if !is_forwarded(cx, decl, body, args, &inputs) {
+                    return;
+                }
+                if let Some(type_path) = method_parent_path(cx, def_id) {
+                    span_lint_and_then(
+                        cx,
+                        REDUNDANT_CLOSURE_FOR_METHOD_CALLS,
                         expr.span,
-                        "remove closure as shown",
-                        snippet,
-                        Applicability::MachineApplicable,
+                        "redundant closure found",
+                        |db| {
+                            db.span_suggestion(
+                                expr.span,
+                                "remove closure as shown",
+                                format!("{}::{}", type_path, path.ident),
+                                Applicability::MachineApplicable,
+                            );
+                        },
                     );
                 }
-            });
+            },
+            _ => (),
+        }
+    }
+}
+
+/// Checks whether the closure passes its parameters, in order, as `args`. If
+/// the types of the parameters the called function takes are given as
+/// `inputs`, the arguments may be adjusted as long as the function takes them
+/// with the types of the closure's parameters, otherwise they may not be
+/// adjusted at all.
+fn is_forwarded<'tcx>(
+    cx: &LateContext<'_, 'tcx>,
+    decl: &FnDecl,
+    body: &Body,
+    args: &[Expr],
+    inputs: &[Ty<'tcx>],
+) -> bool {
+    if args.len() != decl.inputs.len() {
+        // Not the same number of arguments, there
+        // is no way the closure is the same as the function
+        return false;
+    }
+    for (index, (a1, a2)) in iter_input_pats(decl, body).zip(args).enumerate() {
+        if let PatKind::Binding(.., ident, _) = a1.pat.node {
+            // XXXManishearth Should I be checking the binding mode here?
+            if let ExprKind::Path(QPath::Resolved(None, ref p)) = a2.node {
+                if p.segments.len() != 1 {
+                    // If it's a proper path, it can't be a local variable
+                    return false;
+                }
+                if p.segments[0].ident.name != ident.name {
+                    // The two idents should be the same
+                    return false;
+                }
+            } else {
+                return false;
+            }
+        } else {
+            return false;
+        }
+        if is_adjusted(cx, a2) {
+            match inputs.get(index) {
+                Some(&input) if cx.tcx.erase_regions(&input) == cx.tcx.erase_regions(&cx.tables.expr_ty(a2)) => {},
+                _ => return false,
+            }
         }
     }
+    true
+}
+
+/// Returns the path of the trait or type the method `def_id` belongs to, if
+/// it can be written in a path expression.
+fn method_parent_path(cx: &LateContext<'_, '_>, def_id: DefId) -> Option<String> {
+    let item_path = |def_id| with_crate_prefix(|| cx.tcx.item_path_str(def_id));
+    if let Some(trait_id) = cx.tcx.trait_of_item(def_id) {
+        return Some(item_path(trait_id));
+    }
+    let self_ty = cx.tcx.type_of(cx.tcx.impl_of_method(def_id)?);
+    match self_ty.sty {
+        ty::Adt(adt, _) => Some(item_path(adt.did)),
+        ty::Bool | ty::Char | ty::Int(_) | ty::Uint(_) | ty::Float(_) | ty::Str => Some(self_ty.to_string()),
+        _ => None,
+    }
 }
@@ -0,0 +1,156 @@
+//! lint on functions with deeply nested control flow
+
+use crate::utils::{in_macro, span_help_and_lint};
+use rustc::lint::{in_external_macro, EarlyContext, EarlyLintPass, LintArray, LintContext, LintPass};
+use rustc::{declare_tool_lint, lint_array};
+use syntax::ast::*;
+use syntax::ptr::P;
+use syntax::source_map::Span;
+use syntax::visit::{walk_expr, FnKind, Visitor};
+
+/// **What it does:** Checks for functions whose control flow (`if`, loops,
+/// `match` and closures) is nested deeper than the configured
+/// `nesting-depth-threshold`.
+///
+/// **Why is this bad?** Deeply nested code is hard to follow, since the reader
+/// has to keep every enclosing condition in mind. Early returns or extracting
+/// the inner parts into functions usually flatten it.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// for x in xs {
+///     if x.is_valid() {
+///         match x.kind() {
+///             Kind::A => loop {
+///                 if done() {
+///                     // ...
+///                 }
+///             },
+///             _ => {},
+///         }
+///     }
+/// }
+/// ```
+declare_clippy_lint! {
+    pub EXCESSIVE_NESTING,
+    pedantic,
+    "functions with deeply nested control flow"
+}
+
+#[derive(Copy, Clone)]
+pub struct ExcessiveNesting {
+    max_depth: u64,
+}
+
+impl ExcessiveNesting {
+    pub fn new(max_depth: u64) -> Self {
+        Self { max_depth }
+    }
+}
+
+impl LintPass for ExcessiveNesting {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(EXCESSIVE_NESTING)
+    }
+
+    fn name(&self) -> &'static str {
+        "ExcessiveNesting"
+    }
+}
+
+impl EarlyLintPass for ExcessiveNesting {
+    fn check_fn(&mut self, cx: &EarlyContext<'_>, kind: FnKind<'_>, _: &FnDecl, span: Span, _: NodeId) {
+        if in_macro(span) || in_external_macro(cx.sess(), span) {
+            return;
+        }
+        let block = match kind {
+            FnKind::ItemFn(.., block) | FnKind::Method(.., block) => block,
+            // closures are checked as part of the enclosing function
+            FnKind::Closure(_) => return,
+        };
+
+        let mut visitor = NestingVisitor {
+            depth: 0,
+            deepest: None,
+        };
+        visitor.visit_block(block);
+
+        if let Some((depth, span)) = visitor.deepest {
+            if depth > self.max_depth {
+                span_help_and_lint(
+                    cx,
+                    EXCESSIVE_NESTING,
+                    span,
+                    &format!(
+                        "this code is nested {} levels deep, which exceeds the maximum of {}",
+                        depth, self.max_depth
+                    ),
+                    "consider using early returns or moving the nested code into a separate function",
+                );
+            }
+        }
+    }
+}
+
+/// Finds the most deeply nested control flow expression.
+struct NestingVisitor {
+    depth: u64,
+    deepest: Option<(u64, Span)>,
+}
+
+impl NestingVisitor {
+    fn nested(&mut self, span: Span, f: impl FnOnce(&mut Self)) {
+        self.depth += 1;
+        if self.deepest.map_or(true, |(depth, _)| self.depth > depth) {
+            self.deepest = Some((self.depth, span));
+        }
+        f(self);
+        self.depth -= 1;
+    }
+
+    /// `else if` chains don't add a nesting level.
+    fn visit_else<'ast>(&mut self, els: &'ast Option<P<Expr>>) {
+        if let Some(ref els) = *els {
+            match els.node {
+                ExprKind::If(..) | ExprKind::IfLet(..) => self.visit_expr(els),
+                _ => self.nested(els.span, |v| v.visit_expr(els)),
+            }
+        }
+    }
+}
+
+impl<'ast> Visitor<'ast> for NestingVisitor {
+    fn visit_expr(&mut self, expr: &'ast Expr) {
+        if in_macro(expr.span) {
+            return;
+        }
+        match expr.node {
+            ExprKind::If(ref cond, ref then, ref els) => {
+                self.nested(expr.span, |v| {
+                    v.visit_expr(cond);
+                    v.visit_block(then);
+                });
+                self.visit_else(els);
+            },
+            ExprKind::IfLet(_, ref cond, ref then, ref els) => {
+                self.nested(expr.span, |v| {
+                    v.visit_expr(cond);
+                    v.visit_block(then);
+                });
+                self.visit_else(els);
+            },
+            ExprKind::While(..)
+            | ExprKind::WhileLet(..)
+            | ExprKind::ForLoop(..)
+            | ExprKind::Loop(..)
+            | ExprKind::Match(..)
+            | ExprKind::Closure(..) => self.nested(expr.span, |v| walk_expr(v, expr)),
+            _ => walk_expr(self, expr),
+        }
+    }
+
+    // nested functions are checked on their own
+    fn visit_item(&mut self, _: &'ast Item) {}
+}
@@ -166,7 +166,7 @@ impl FloatFormat {
         match self {
             FloatFormat::LowerExp => format!("{:e}", f),
             FloatFormat::UpperExp => format!("{:E}", f),
-            FloatFormat::Normal => format!("{}", f),
+            FloatFormat::Normal => f.to_string(),
         }
     }
 }
@@ -0,0 +1,137 @@
+//! lint on exported enums and structs which aren't `#[non_exhaustive]`
+
+use crate::utils::sugg::DiagnosticBuilderExt;
+use crate::utils::{in_macro, meets_msrv, msrvs, span_lint_and_then, RustcVersion};
+use rustc::hir::*;
+use rustc::lint::{in_external_macro, LateContext, LateLintPass, LintArray, LintContext, LintPass};
+use rustc::session::config::CrateType;
+use rustc::{declare_tool_lint, lint_array};
+use rustc_errors::Applicability;
+use syntax::attr;
+
+/// **What it does:** Checks for exported enums in library crates which aren't
+/// `#[non_exhaustive]`.
+///
+/// **Why is this bad?** Adding a variant to an exhaustive enum breaks the
+/// crates matching on it, so it needs a major release. Libraries which want
+/// to stay free to add variants have to mark their enums up front.
+///
+/// **Known problems:** Many enums are complete by design, e.g. the days of the
+/// week, and marking them only burdens their users with a wildcard arm.
+///
+/// **Example:**
+/// ```rust
+/// pub enum Shape {
+///     Circle,
+///     Square,
+/// }
+/// ```
+/// Could be written as:
+/// ```rust
+/// #[non_exhaustive]
+/// pub enum Shape {
+///     Circle,
+///     Square,
+/// }
+/// ```
+declare_clippy_lint! {
+    pub EXHAUSTIVE_ENUMS,
+    restriction,
+    "exported enums in libraries which aren't `#[non_exhaustive]`"
+}
+
+/// **What it does:** Checks for exported structs in library crates whose
+/// fields are all public and which aren't `#[non_exhaustive]`. Structs with
+/// private fields can't be constructed or destructured outside the crate
+/// anyway, so they aren't linted.
+///
+/// **Why is this bad?** Adding a field to a struct whose fields are all
+/// public breaks the crates constructing it or destructuring it without
+/// `..`, so it needs a major release.
+///
+/// **Known problems:** `#[non_exhaustive]` structs can't be constructed with
+/// struct expressions outside the crate, which may be what the users need.
+///
+/// **Example:**
+/// ```rust
+/// pub struct Point {
+///     pub x: i32,
+///     pub y: i32,
+/// }
+/// ```
+/// Could be written as:
+/// ```rust
+/// #[non_exhaustive]
+/// pub struct Point {
+///     pub x: i32,
+///     pub y: i32,
+/// }
+/// ```
+declare_clippy_lint! {
+    pub EXHAUSTIVE_STRUCTS,
+    restriction,
+    "exported structs in libraries with only public fields which aren't `#[non_exhaustive]`"
+}
+
+#[derive(Copy, Clone)]
+pub struct Pass {
+    msrv: Option<RustcVersion>,
+}
+
+impl Pass {
+    pub fn new(msrv: Option<RustcVersion>) -> Self {
+        Self { msrv }
+    }
+}
+
+impl LintPass for Pass {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(EXHAUSTIVE_ENUMS, EXHAUSTIVE_STRUCTS)
+    }
+
+    fn name(&self) -> &'static str {
+        "ExhaustiveItems"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
+        let (lint, msg) = match item.node {
+            ItemKind::Enum(..) => (EXHAUSTIVE_ENUMS, "exported enums should not be exhaustive"),
+            ItemKind::Struct(ref data, _) => {
+                if data.fields().iter().any(|field| !field.vis.node.is_pub()) {
+                    return;
+                }
+                (EXHAUSTIVE_STRUCTS, "exported structs should not be exhaustive")
+            },
+            _ => return,
+        };
+        if !is_library(cx)
+            || !cx.access_levels.is_exported(item.id)
+            || attr::contains_name(&item.attrs, "non_exhaustive")
+            || in_macro(item.span)
+            || in_external_macro(cx.sess(), item.span)
+            || !meets_msrv(cx.tcx, item.id, self.msrv, msrvs::NON_EXHAUSTIVE)
+        {
+            return;
+        }
+        span_lint_and_then(cx, lint, item.span.with_hi(item.ident.span.hi()), msg, |db| {
+            db.suggest_item_with_attr(
+                cx,
+                item.span,
+                "try adding `#[non_exhaustive]`",
+                "#[non_exhaustive]",
+                Applicability::MaybeIncorrect,
+            );
+        });
+    }
+}
+
+/// Checks whether the crate is compiled as a Rust library, whose types other
+/// crates can use.
+fn is_library(cx: &LateContext<'_, '_>) -> bool {
+    cx.tcx.sess.crate_types.get().iter().any(|t| match t {
+        CrateType::Rlib | CrateType::Dylib => true,
+        _ => false,
+    })
+}
@@ -0,0 +1,146 @@
+//! combines the lint passes which only implement `check_expr` into one pass
+
+use crate::utils::conf::Conf;
+use crate::{
+    approx_const, assertions_on_constants, assign_ops, await_holding_lock, bit_mask, block_in_if_condition, bytecount,
+    chained_comparison, default_trait_access, double_comparison, drop_forget_ref, duration_subsec, entry, eq_op,
+    erasing_op, eta_reduction, excessive_precision, explicit_write, identity_op, indexing_slicing, invalid_ref,
+    large_futures, manual_digit_conversion, manual_filter, map_clone, map_for_side_effects, matches, mem_discriminant,
+    mem_forget, mem_replace, mut_reference, needless_bool, needless_update, neg_cmp_op_on_partial_ord, neg_multiply,
+    ok_if_let, open_options, option_literal_cmp, overflow_check_conditional, panic_in_result_fn, panic_unimplemented,
+    path_join, question_mark, redundant_pattern_matching, replace_consts, self_assignment, strings,
+    suspicious_trait_impl, swapped_arguments, system_time, temporary_assignment, time_subtraction, transmute, types,
+    undocumented_unsafe_blocks, unicode, zero_div_zero,
+};
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LateLintPassObject, LintArray, LintPass};
+
+/// Declares `ExprPasses`, which calls the `check_expr` of each pass, in the
+/// given order, for the `ExprKind`s the pass lints. Registering the passes
+/// separately makes the lint store call each of them for every expression.
+///
+/// Only passes which implement nothing but `check_expr` can be combined, the
+/// other methods aren't forwarded. The `ExprKind`s just save the calls, the
+/// passes still check everything else of the expression themselves, e.g. the
+/// macro it was expanded from.
+macro_rules! declare_expr_passes {
+    ($conf:ident; $($field:ident: $pass:ty = $init:expr => [$($kind:ident),+],)+) => {
+        pub struct ExprPasses {
+            $($field: $pass,)+
+        }
+
+        impl ExprPasses {
+            pub fn new($conf: &Conf) -> Self {
+                Self {
+                    $($field: $init,)+
+                }
+            }
+
+            /// Returns the passes, to register them separately.
+            pub fn into_passes(self) -> Vec<LateLintPassObject> {
+                vec![$(box self.$field as LateLintPassObject,)+]
+            }
+        }
+
+        impl LintPass for ExprPasses {
+            fn get_lints(&self) -> LintArray {
+                let mut lints = LintArray::new();
+                $(lints.extend(self.$field.get_lints());)+
+                lints
+            }
+
+            fn name(&self) -> &'static str {
+                "ExprPasses"
+            }
+        }
+
+        impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ExprPasses {
+            fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+                $(
+                    match expr.node {
+                        $(ExprKind::$kind(..))|+ => self.$field.check_expr(cx, expr),
+                        _ => {},
+                    }
+                )+
+            }
+        }
+    };
+}
+
+declare_expr_passes! { conf;
+    eq_op: eq_op::EqOp = eq_op::EqOp => [Binary],
+    excessive_precision: excessive_precision::ExcessivePrecision = excessive_precision::ExcessivePrecision => [Lit],
+    bit_mask: bit_mask::BitMask = bit_mask::BitMask::new(conf.verbose_bit_mask_threshold) => [Binary],
+    needless_bool: needless_bool::NeedlessBool = needless_bool::NeedlessBool => [If, Block],
+    bool_comparison: needless_bool::BoolComparison = needless_bool::BoolComparison => [Binary],
+    approx_const: approx_const::Pass = approx_const::Pass => [Lit],
+    eta_reduction: eta_reduction::EtaPass = eta_reduction::EtaPass => [Call, MethodCall],
+    identity_op: identity_op::IdentityOp = identity_op::IdentityOp => [Binary],
+    erasing_op: erasing_op::ErasingOp = erasing_op::ErasingOp => [Binary],
+    mut_reference: mut_reference::UnnecessaryMutPassed = mut_reference::UnnecessaryMutPassed => [Call, MethodCall],
+    block_in_if_condition: block_in_if_condition::BlockInIfCondition =
+        block_in_if_condition::BlockInIfCondition => [If],
+    unicode: unicode::Unicode = unicode::Unicode => [Lit],
+    string_add: strings::StringAdd = strings::StringAdd => [Binary, Assign],
+    map_clone: map_clone::Pass = map_clone::Pass => [MethodCall],
+    unit_cmp: types::UnitCmp = types::UnitCmp => [Binary],
+    entry: entry::HashMapLint = entry::HashMapLint => [If],
+    cast: types::CastPass = types::CastPass => [Cast],
+    matches: matches::MatchPass = matches::MatchPass::new(conf.msrv) => [Match],
+    open_options: open_options::NonSensical = open_options::NonSensical => [MethodCall],
+    zero_div_zero: zero_div_zero::Pass = zero_div_zero::Pass => [Binary],
+    needless_update: needless_update::Pass =
+        needless_update::Pass::new(conf.required_fields.iter().cloned().collect()) => [Struct],
+    temporary_assignment: temporary_assignment::Pass = temporary_assignment::Pass => [Assign],
+    transmute: transmute::Transmute = transmute::Transmute::new(conf.msrv) => [Call],
+    panic_unimplemented: panic_unimplemented::Pass =
+        panic_unimplemented::Pass::new(conf.allow_unwrap_in.clone()) => [Block],
+    string_lit_as_bytes: strings::StringLitAsBytes = strings::StringLitAsBytes => [MethodCall],
+    char_lit_as_u8: types::CharLitAsU8 = types::CharLitAsU8 => [Cast],
+    drop_forget_ref: drop_forget_ref::Pass = drop_forget_ref::Pass => [Call],
+    absurd_extreme_comparisons: types::AbsurdExtremeComparisons = types::AbsurdExtremeComparisons => [Binary],
+    invalid_upcast_comparisons: types::InvalidUpcastComparisons = types::InvalidUpcastComparisons => [Binary],
+    overflow_check_conditional: overflow_check_conditional::OverflowCheckConditional =
+        overflow_check_conditional::OverflowCheckConditional => [Binary],
+    neg_multiply: neg_multiply::NegMultiply = neg_multiply::NegMultiply => [Binary],
+    mem_discriminant: mem_discriminant::MemDiscriminant = mem_discriminant::MemDiscriminant => [Call],
+    mem_forget: mem_forget::MemForget = mem_forget::MemForget => [Call],
+    mem_replace: mem_replace::MemReplace = mem_replace::MemReplace => [Call],
+    assign_ops: assign_ops::AssignOps = assign_ops::AssignOps => [AssignOp, Assign],
+    ok_if_let: ok_if_let::Pass = ok_if_let::Pass => [Match],
+    redundant_pattern_matching: redundant_pattern_matching::Pass = redundant_pattern_matching::Pass => [Match],
+    explicit_write: explicit_write::Pass = explicit_write::Pass => [MethodCall],
+    bytecount: bytecount::ByteCount = bytecount::ByteCount => [MethodCall],
+    invalid_ref: invalid_ref::InvalidRef = invalid_ref::InvalidRef => [Call],
+    replace_consts: replace_consts::ReplaceConsts = replace_consts::ReplaceConsts => [Path],
+    double_comparison: double_comparison::Pass = double_comparison::Pass => [Binary],
+    question_mark: question_mark::Pass = question_mark::Pass::new(conf.msrv) => [If],
+    suspicious_trait_impl: suspicious_trait_impl::SuspiciousImpl = suspicious_trait_impl::SuspiciousImpl => [Binary],
+    neg_cmp_op_on_partial_ord: neg_cmp_op_on_partial_ord::NoNegCompOpForPartialOrd =
+        neg_cmp_op_on_partial_ord::NoNegCompOpForPartialOrd => [Unary],
+    duration_subsec: duration_subsec::DurationSubsec = duration_subsec::DurationSubsec::new(conf.msrv) => [Binary],
+    default_trait_access: default_trait_access::DefaultTraitAccess = default_trait_access::DefaultTraitAccess => [Call],
+    indexing_slicing: indexing_slicing::IndexingSlicing =
+        indexing_slicing::IndexingSlicing::new(conf.allow_unwrap_in.clone()) => [Index],
+    ref_to_mut: types::RefToMut = types::RefToMut => [Unary],
+    assertions_on_constants: assertions_on_constants::AssertionsOnConstants =
+        assertions_on_constants::AssertionsOnConstants => [Unary],
+    time_subtraction: time_subtraction::TimeSubtraction =
+        time_subtraction::TimeSubtraction::new(conf.msrv) => [Binary, AssignOp],
+    system_time: system_time::Pass = system_time::Pass => [MethodCall, Match],
+    self_assignment: self_assignment::Pass = self_assignment::Pass => [Assign],
+    path_join: path_join::Pass = path_join::Pass => [MethodCall],
+    option_literal_cmp: option_literal_cmp::Pass = option_literal_cmp::Pass => [Binary],
+    chained_comparison: chained_comparison::Pass = chained_comparison::Pass => [Binary],
+    map_for_side_effects: map_for_side_effects::Pass = map_for_side_effects::Pass::new(conf.msrv) => [MethodCall],
+    manual_filter: manual_filter::Pass = manual_filter::Pass::new(conf.msrv) => [Match, MethodCall],
+    undocumented_unsafe_blocks: undocumented_unsafe_blocks::Pass =
+        undocumented_unsafe_blocks::Pass::new(conf.unsafe_comment_prefix.clone()) => [Block],
+    manual_digit_conversion: manual_digit_conversion::Pass = manual_digit_conversion::Pass => [Cast, Binary],
+    await_holding_lock: await_holding_lock::Pass = await_holding_lock::Pass => [Closure],
+    large_futures: large_futures::LargeFutures =
+        large_futures::LargeFutures::new(conf.future_size_threshold) => [Closure],
+    swapped_arguments: swapped_arguments::Pass = swapped_arguments::Pass => [Call, MethodCall],
+    panic_in_result_fn: panic_in_result_fn::Pass =
+        panic_in_result_fn::Pass::new(conf.allow_unwrap_in.clone()) => [MethodCall, Block, Index],
+}
@@ -1,15 +1,18 @@
 use crate::utils::paths;
+use crate::utils::sugg::Sugg;
 use crate::utils::{
-    in_macro, is_expn_of, last_path_segment, match_def_path, match_type, opt_def_id, resolve_node, snippet,
-    span_lint_and_then, walk_ptrs_ty,
+    get_trait_def_id, implements_trait, in_macro, is_expn_of, last_path_segment, match_def_path, match_type,
+    opt_def_id, resolve_node, snippet, span_lint_and_sugg, span_lint_and_then, walk_ptrs_ty,
 };
 use if_chain::if_chain;
 use rustc::hir::*;
 use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
-use rustc::ty;
+use rustc::ty::{self, Ty};
 use rustc::{declare_tool_lint, lint_array};
 use rustc_errors::Applicability;
 use syntax::ast::LitKind;
+use syntax::source_map::Span;
+use syntax::symbol::LocalInternedString;
 
 /// **What it does:** Checks for the use of `format!("string literal with no
 /// argument")` and `format!("{}", foo)` where `foo` is a string.
@@ -33,12 +36,62 @@ declare_clippy_lint! {
     "useless use of `format!`"
 }
 
+/// **What it does:** Checks for `format!("{}", x)`, where `x` isn't a string.
+/// `format!("{:?}", x)` is linted as well if `x` implements `Display` and
+/// isn't a string, with a note, since `Debug` has no method like `to_string`.
+///
+/// **Why is this bad?** `x.to_string()` does the same, and states that only
+/// `x` is converted.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// let s = format!("{}", 42);
+/// ```
+/// Could be written as:
+/// ```rust
+/// let s = 42.to_string();
+/// ```
+declare_clippy_lint! {
+    pub SINGLE_VALUE_FORMAT,
+    pedantic,
+    "`format!(\"{}\", x)`, which can be written as `x.to_string()`"
+}
+
+/// **What it does:** Checks for unwrapping the result of `write!` or
+/// `writeln!` into a `String`, like `write!(s, "{}", x).unwrap()`.
+///
+/// **Why is this bad?** Formatting into a `String` can't fail, the unwrap
+/// only adds noise and spreads the habit of unwrapping I/O results. Simple
+/// cases can use `push_str` instead, which doesn't need the
+/// `std::fmt::Write` import.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// use std::fmt::Write;
+/// let mut s = String::new();
+/// write!(s, "foo").unwrap();
+/// ```
+/// Could be written as:
+/// ```rust
+/// let mut s = String::new();
+/// s.push_str("foo");
+/// ```
+declare_clippy_lint! {
+    pub UNWRAP_WRITE_TO_STRING,
+    style,
+    "unwrapping the result of `write!` into a `String`, which can't fail"
+}
+
 #[derive(Copy, Clone, Debug)]
 pub struct Pass;
 
 impl LintPass for Pass {
     fn get_lints(&self) -> LintArray {
-        lint_array![USELESS_FORMAT]
+        lint_array![USELESS_FORMAT, SINGLE_VALUE_FORMAT, UNWRAP_WRITE_TO_STRING]
     }
 
     fn name(&self) -> &'static str {
@@ -48,6 +101,7 @@ impl LintPass for Pass {
 
 impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
     fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        check_write_unwrap(cx, expr);
         if let Some(span) = is_expn_of(expr.span, "format") {
             if in_macro(span) {
                 return;
@@ -55,6 +109,7 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
             match expr.node {
                 // `format!("{}", foo)` expansion
                 ExprKind::Call(ref fun, ref args) => {
+                    check_single_debug_value(cx, span, fun, args);
                     if_chain! {
                         if let ExprKind::Path(ref qpath) = fun.node;
                         if let Some(fun_def_id) = opt_def_id(resolve_node(cx, qpath, fun.hir_id));
@@ -66,10 +121,19 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
                         );
                         if new_v1 || new_v1_fmt;
                         if check_single_piece(&args[0]);
-                        if let Some(format_arg) = get_single_string_arg(cx, &args[1]);
-                        if new_v1 || check_unformatted(&args[2]);
+                        if let Some((format_arg, arg_ty)) = get_single_arg(cx, &args[1], &paths::DISPLAY_FMT_METHOD);
                         if let ExprKind::AddrOf(_, ref format_arg) = format_arg.node;
                         then {
+                            if !is_string(cx, arg_ty) {
+                                // the flags like `+` aren't checked, they make a difference for other types
+                                if new_v1 {
+                                    check_single_value(cx, span, format_arg);
+                                }
+                                return;
+                            }
+                            if !new_v1 && !check_unformatted(&args[2]) {
+                                return;
+                            }
                             let (message, sugg) = if_chain! {
                                 if let ExprKind::MethodCall(ref path, _, _) = format_arg.node;
                                 if path.ident.as_interned_str() == "to_string";
@@ -115,8 +179,70 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
     }
 }
 
-/// Checks if the expressions matches `&[""]`
-fn check_single_piece(expr: &Expr) -> bool {
+/// Checks for `write!(s, ..).unwrap()` and `write!(s, ..).expect(..)` with
+/// `s: String`.
+fn check_write_unwrap<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+    if_chain! {
+        if let ExprKind::MethodCall(ref unwrap, _, ref unwrap_args) = expr.node;
+        if unwrap.ident.name == "unwrap" || unwrap.ident.name == "expect";
+        if !in_macro(expr.span);
+        if let ExprKind::MethodCall(ref write_fmt, _, ref write_args) = unwrap_args[0].node;
+        if write_fmt.ident.name == "write_fmt" && write_args.len() == 2;
+        if is_expn_of(unwrap_args[0].span, "write").is_some();
+        if match_type(cx, walk_ptrs_ty(cx.tables.expr_ty(&write_args[0])), &paths::STRING);
+        then {
+            let macro_name = if is_expn_of(unwrap_args[0].span, "writeln").is_some() {
+                "writeln"
+            } else {
+                "write"
+            };
+            let msg = format!("unwrapping the result of `{}!` into a `String`, which can't fail", macro_name);
+            span_lint_and_then(cx, UNWRAP_WRITE_TO_STRING, expr.span, &msg, |db| {
+                if let Some(sugg) = push_str_sugg(cx, &write_args[0], &write_args[1]) {
+                    db.span_suggestion(expr.span, "use `push_str`", sugg, Applicability::MachineApplicable);
+                    db.note("remove the `std::fmt::Write` import if it isn't used anymore");
+                } else {
+                    db.help(&format!(
+                        "consider ignoring the `Result` with `let _ = {}!(..);` and a comment why it can't fail",
+                        macro_name
+                    ));
+                }
+            });
+        }
+    }
+}
+
+/// Returns the `dst.push_str(..)` equivalent of writing the `format_args!`
+/// expansion `fmt_args` to `dst`, if `fmt_args` is a string literal without
+/// arguments or `"{}"` with a single string.
+fn push_str_sugg(cx: &LateContext<'_, '_>, dst: &Expr, fmt_args: &Expr) -> Option<String> {
+    if let ExprKind::Call(ref fun, ref args) = fmt_args.node {
+        if let ExprKind::Path(ref qpath) = fun.node {
+            let fun_def_id = opt_def_id(resolve_node(cx, qpath, fun.hir_id))?;
+            let new_v1 = match_def_path(cx.tcx, fun_def_id, &paths::FMT_ARGUMENTS_NEWV1);
+            let new_v1_fmt = match_def_path(cx.tcx, fun_def_id, &paths::FMT_ARGUMENTS_NEWV1FORMATTED);
+            let dst = Sugg::hir(cx, dst, "..").maybe_par();
+            let piece = get_single_piece(&args[0])?;
+            if new_v1 && !piece.is_empty() && is_without_args(&args[1]) {
+                return Some(format!("{}.push_str({:?})", dst, &*piece));
+            }
+            if piece.is_empty() && (new_v1 || (new_v1_fmt && check_unformatted(&args[2]))) {
+                if let ExprKind::AddrOf(_, ref arg) = get_single_string_arg(cx, &args[1])?.node {
+                    let snip = snippet(cx, arg.span, "..");
+                    return Some(if let ty::Ref(..) = cx.tables.expr_ty(arg).sty {
+                        format!("{}.push_str({})", dst, snip)
+                    } else {
+                        format!("{}.push_str(&{})", dst, snip)
+                    });
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Returns the piece of `&["piece"]`.
+fn get_single_piece(expr: &Expr) -> Option<LocalInternedString> {
     if_chain! {
         if let ExprKind::AddrOf(_, ref expr) = expr.node; // &[""]
         if let ExprKind::Array(ref exprs) = expr.node; // [""]
@@ -124,23 +250,94 @@ fn check_single_piece(expr: &Expr) -> bool {
         if let ExprKind::Lit(ref lit) = exprs[0].node;
         if let LitKind::Str(ref lit, _) = lit.node;
         then {
-            return lit.as_str().is_empty();
+            return Some(lit.as_str());
+        }
+    }
+
+    None
+}
+
+/// Checks if the expressions matches `&[""]`
+fn check_single_piece(expr: &Expr) -> bool {
+    get_single_piece(expr).map_or(false, |piece| piece.is_empty())
+}
+
+/// Checks if the expression matches `&match () { () => [] }`, the arguments
+/// of a format string without any.
+fn is_without_args(expr: &Expr) -> bool {
+    if_chain! {
+        if let ExprKind::AddrOf(_, ref expr) = expr.node;
+        if let ExprKind::Match(ref match_expr, _, _) = expr.node;
+        if let ExprKind::Tup(ref values) = match_expr.node;
+        then {
+            return values.is_empty();
         }
     }
 
     false
 }
 
+/// Lints `format!("{}", arg)`, where `arg` isn't a string.
+fn check_single_value(cx: &LateContext<'_, '_>, span: Span, arg: &Expr) {
+    let mut applicability = Applicability::MachineApplicable;
+    let arg = Sugg::hir_with_applicability(cx, arg, "<arg>", &mut applicability).maybe_par();
+    span_lint_and_sugg(
+        cx,
+        SINGLE_VALUE_FORMAT,
+        span,
+        "`format!` of a single value",
+        "use `to_string()`",
+        format!("{}.to_string()", arg),
+        applicability,
+    );
+}
+
+/// Lints `format!("{:?}", arg)`, where `arg` implements `Display` and isn't a
+/// string.
+fn check_single_debug_value(cx: &LateContext<'_, '_>, span: Span, fun: &Expr, args: &[Expr]) {
+    if_chain! {
+        if let ExprKind::Path(ref qpath) = fun.node;
+        if let Some(fun_def_id) = opt_def_id(resolve_node(cx, qpath, fun.hir_id));
+        // the flags like `#` make a difference
+        if match_def_path(cx.tcx, fun_def_id, &paths::FMT_ARGUMENTS_NEWV1);
+        if check_single_piece(&args[0]);
+        if let Some((format_arg, arg_ty)) = get_single_arg(cx, &args[1], &paths::DEBUG_FMT_METHOD);
+        if let ExprKind::AddrOf(_, ref format_arg) = format_arg.node;
+        // the quotes are the point of formatting strings with `Debug`
+        if !is_string(cx, arg_ty);
+        if let Some(display_trait) = get_trait_def_id(cx, &paths::DISPLAY_TRAIT);
+        if implements_trait(cx, arg_ty, display_trait, &[]);
+        then {
+            let arg = Sugg::hir(cx, format_arg, "<arg>").maybe_par();
+            span_lint_and_then(cx, SINGLE_VALUE_FORMAT, span, "`format!` of a single value", |db| {
+                db.note("`Debug` has no method like `to_string`, `format!(\"{:?}\", ..)` is the way to get its output");
+                db.help(&format!(
+                    "if the `Display` output is what you want, use `{}.to_string()`",
+                    arg
+                ));
+            });
+        }
+    }
+}
+
+fn is_string(cx: &LateContext<'_, '_>, ty: Ty<'_>) -> bool {
+    ty.sty == ty::Str || match_type(cx, ty, &paths::STRING)
+}
+
 /// Checks if the expressions matches
 /// ```rust,ignore
-/// &match (&"arg",) {
+/// &match (&arg,) {
 /// (__arg0,) => [::std::fmt::ArgumentV1::new(__arg0,
 /// ::std::fmt::Display::fmt)],
 /// }
 /// ```
-/// and that the type of `__arg0` is `&str` or `String`,
-/// then returns the span of first element of the matched tuple.
-fn get_single_string_arg<'a>(cx: &LateContext<'_, '_>, expr: &'a Expr) -> Option<&'a Expr> {
+/// with the `fmt` method `fmt_method`, and returns the first element of the
+/// matched tuple, with the type of `arg`.
+fn get_single_arg<'a, 'tcx>(
+    cx: &LateContext<'_, 'tcx>,
+    expr: &'a Expr,
+    fmt_method: &[&str],
+) -> Option<(&'a Expr, Ty<'tcx>)> {
     if_chain! {
         if let ExprKind::AddrOf(_, ref expr) = expr.node;
         if let ExprKind::Match(ref match_expr, ref arms, _) = expr.node;
@@ -154,20 +351,25 @@ fn get_single_string_arg<'a>(cx: &LateContext<'_, '_>, expr: &'a Expr) -> Option
         if args.len() == 2;
         if let ExprKind::Path(ref qpath) = args[1].node;
         if let Some(fun_def_id) = opt_def_id(resolve_node(cx, qpath, args[1].hir_id));
-        if match_def_path(cx.tcx, fun_def_id, &paths::DISPLAY_FMT_METHOD);
+        if match_def_path(cx.tcx, fun_def_id, fmt_method);
+        if let ExprKind::Tup(ref values) = match_expr.node;
         then {
-            let ty = walk_ptrs_ty(cx.tables.pat_ty(&pat[0]));
-            if ty.sty == ty::Str || match_type(cx, ty, &paths::STRING) {
-                if let ExprKind::Tup(ref values) = match_expr.node {
-                    return Some(&values[0]);
-                }
-            }
+            return Some((&values[0], walk_ptrs_ty(cx.tables.pat_ty(&pat[0]))));
         }
     }
 
     None
 }
 
+/// Returns the first element of the matched tuple if `expr` matches the
+/// arguments of `format!("{}", arg)` (see `get_single_arg`) and the
+/// type of `arg` is `&str` or `String`.
+fn get_single_string_arg<'a>(cx: &LateContext<'_, '_>, expr: &'a Expr) -> Option<&'a Expr> {
+    get_single_arg(cx, expr, &paths::DISPLAY_FMT_METHOD)
+        .filter(|&(_, ty)| is_string(cx, ty))
+        .map(|(arg, _)| arg)
+}
+
 /// Checks if the expression matches
 /// ```rust,ignore
 /// &[_ {
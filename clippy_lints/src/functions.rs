@@ -1,4 +1,6 @@
-use crate::utils::{iter_input_pats, snippet, span_lint, type_is_unsafe_function};
+use crate::utils::{
+    in_macro, iter_input_pats, snippet, span_help_and_lint, span_lint, span_lint_and_then, type_is_unsafe_function,
+};
 use matches::matches;
 use rustc::hir;
 use rustc::hir::def::Def;
@@ -10,9 +12,15 @@ use rustc_data_structures::fx::FxHashSet;
 use rustc_target::spec::abi::Abi;
 use syntax::ast;
 use syntax::source_map::Span;
+use syntax::symbol::keywords;
 
 /// **What it does:** Checks for functions with too many parameters.
 ///
+/// The `self` argument of methods is counted unless
+/// `too-many-arguments-exclude-self` is set. Type and const generic
+/// parameters are counted separately and only if
+/// `too-many-generic-parameters-threshold` is set.
+///
 /// **Why is this bad?** Functions with lots of parameters are considered bad
 /// style and reduce readability (“what does the 5th parameter mean?”). Consider
 /// grouping some parameters into a new type.
@@ -82,21 +90,67 @@ declare_clippy_lint! {
     "public functions dereferencing raw pointer arguments but not marked `unsafe`"
 }
 
+/// **What it does:** Checks for public functions with adjacent parameters of
+/// the same primitive type, like `fn resize(width: u32, height: u32)` or
+/// `fn open(read: bool, write: bool)`. Unlike `too_many_arguments`, it lints
+/// functions with only two parameters.
+///
+/// **Why is this bad?** Callers can pass the arguments in the wrong order, and
+/// neither the compiler nor the call site shows it. Newtypes, like `Width` and
+/// `Height`, or a struct with named fields make the order explicit.
+///
+/// **Known problems:** Parameters whose order is obvious, like the two
+/// coordinates of a point, are linted as well.
+///
+/// **Example:**
+/// ```rust
+/// pub fn resize(width: u32, height: u32) {}
+/// ```
+/// Could be written as:
+/// ```rust
+/// pub fn resize(size: Size) {}
+/// ```
+declare_clippy_lint! {
+    pub ADJACENT_SAME_TYPE_ARGUMENTS,
+    pedantic,
+    "public functions with adjacent parameters of the same primitive type"
+}
+
 #[derive(Copy, Clone)]
 pub struct Functions {
     threshold: u64,
+    exclude_self: bool,
+    generics_threshold: Option<u64>,
+    suggest_struct: bool,
     max_lines: u64,
 }
 
 impl Functions {
-    pub fn new(threshold: u64, max_lines: u64) -> Self {
-        Self { threshold, max_lines }
+    pub fn new(
+        threshold: u64,
+        exclude_self: bool,
+        generics_threshold: Option<u64>,
+        suggest_struct: bool,
+        max_lines: u64,
+    ) -> Self {
+        Self {
+            threshold,
+            exclude_self,
+            generics_threshold,
+            suggest_struct,
+            max_lines,
+        }
     }
 }
 
 impl LintPass for Functions {
     fn get_lints(&self) -> LintArray {
-        lint_array!(TOO_MANY_ARGUMENTS, TOO_MANY_LINES, NOT_UNSAFE_PTR_ARG_DEREF)
+        lint_array!(
+            TOO_MANY_ARGUMENTS,
+            TOO_MANY_LINES,
+            NOT_UNSAFE_PTR_ARG_DEREF,
+            ADJACENT_SAME_TYPE_ARGUMENTS
+        )
     }
 
     fn name(&self) -> &'static str {
@@ -131,7 +185,7 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Functions {
             // don't lint extern functions decls, it's not their fault either
             match kind {
                 hir::intravisit::FnKind::Method(
-                    _,
+                    ident,
                     &hir::MethodSig {
                         header: hir::FnHeader { abi: Abi::Rust, .. },
                         ..
@@ -139,8 +193,11 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Functions {
                     _,
                     _,
                 )
-                | hir::intravisit::FnKind::ItemFn(_, _, hir::FnHeader { abi: Abi::Rust, .. }, _, _) => {
-                    self.check_arg_number(cx, decl, span)
+                | hir::intravisit::FnKind::ItemFn(ident, _, hir::FnHeader { abi: Abi::Rust, .. }, _, _) => {
+                    let generics = fn_generics(cx, kind, nodeid);
+                    let arg_names = arg_names(body);
+                    self.check_arg_number(cx, decl, generics, &arg_names, ident.name, span);
+                    check_adjacent_args(cx, decl, &arg_names, nodeid, span);
                 },
                 _ => {},
             }
@@ -154,7 +211,19 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Functions {
         if let hir::TraitItemKind::Method(ref sig, ref eid) = item.node {
             // don't lint extern functions decls, it's not their fault
             if sig.header.abi == Abi::Rust {
-                self.check_arg_number(cx, &sig.decl, item.span);
+                let arg_names = match *eid {
+                    hir::TraitMethod::Required(ref names) => names.iter().map(|ident| Some(ident.name)).collect(),
+                    hir::TraitMethod::Provided(eid) => arg_names(cx.tcx.hir().body(eid)),
+                };
+                self.check_arg_number(
+                    cx,
+                    &sig.decl,
+                    Some(&item.generics),
+                    &arg_names,
+                    item.ident.name,
+                    item.span,
+                );
+                check_adjacent_args(cx, &sig.decl, &arg_names, item.id, item.span);
             }
 
             if let hir::TraitMethod::Provided(eid) = *eid {
@@ -166,16 +235,68 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Functions {
 }
 
 impl<'a, 'tcx> Functions {
-    fn check_arg_number(self, cx: &LateContext<'_, '_>, decl: &hir::FnDecl, span: Span) {
-        let args = decl.inputs.len() as u64;
+    fn check_arg_number(
+        self,
+        cx: &LateContext<'_, '_>,
+        decl: &hir::FnDecl,
+        generics: Option<&hir::Generics>,
+        arg_names: &[Option<ast::Name>],
+        fn_name: ast::Name,
+        span: Span,
+    ) {
+        let has_self = arg_names.first() == Some(&Some(keywords::SelfLower.name()));
+        let mut args = decl.inputs.len() as u64;
+        if self.exclude_self && has_self {
+            args -= 1;
+        }
         if args > self.threshold {
-            span_lint(
+            span_lint_and_then(
                 cx,
                 TOO_MANY_ARGUMENTS,
                 span,
                 &format!("this function has too many arguments ({}/{})", args, self.threshold),
+                |db| {
+                    if self.suggest_struct {
+                        let fields = arg_names
+                            .iter()
+                            .zip(&decl.inputs)
+                            .enumerate()
+                            .skip(if has_self { 1 } else { 0 })
+                            .map(|(i, (name, ty))| {
+                                let name =
+                                    name.map_or(String::new(), |name| name.as_str().trim_start_matches('_').into());
+                                let name = if name.is_empty() { format!("arg{}", i) } else { name };
+                                format!("{}: {}", name, snippet(cx, ty.span, ".."))
+                            })
+                            .collect::<Vec<_>>();
+                        db.help(&format!(
+                            "consider grouping the arguments into a struct: `struct {}Args {{ {} }}`",
+                            camel_case(&fn_name.as_str()),
+                            fields.join(", ")
+                        ));
+                    }
+                },
             );
         }
+
+        if let (Some(generics), Some(threshold)) = (generics, self.generics_threshold) {
+            let params = generics
+                .params
+                .iter()
+                .filter(|param| !matches!(param.kind, hir::GenericParamKind::Lifetime { .. }))
+                .count() as u64;
+            if params > threshold {
+                span_lint(
+                    cx,
+                    TOO_MANY_ARGUMENTS,
+                    span,
+                    &format!(
+                        "this function has too many generic parameters ({}/{})",
+                        params, threshold
+                    ),
+                );
+            }
+        }
     }
 
     fn check_line_number(self, cx: &LateContext<'_, '_>, span: Span) {
@@ -273,6 +394,88 @@ impl<'a, 'tcx> Functions {
     }
 }
 
+/// Returns the generics of a function or method.
+fn fn_generics<'tcx>(
+    cx: &LateContext<'_, 'tcx>,
+    kind: intravisit::FnKind<'tcx>,
+    nodeid: ast::NodeId,
+) -> Option<&'tcx hir::Generics> {
+    match kind {
+        intravisit::FnKind::ItemFn(_, generics, ..) => Some(generics),
+        intravisit::FnKind::Method(..) => match cx.tcx.hir().get(nodeid) {
+            hir::Node::ImplItem(item) => Some(&item.generics),
+            hir::Node::TraitItem(item) => Some(&item.generics),
+            _ => None,
+        },
+        intravisit::FnKind::Closure(_) => None,
+    }
+}
+
+/// Lints the runs of adjacent arguments of the same primitive type of the
+/// exported function `nodeid`.
+fn check_adjacent_args(
+    cx: &LateContext<'_, '_>,
+    decl: &hir::FnDecl,
+    arg_names: &[Option<ast::Name>],
+    nodeid: ast::NodeId,
+    span: Span,
+) {
+    if !cx.access_levels.is_exported(nodeid) || in_macro(span) {
+        return;
+    }
+    let sig = cx.tcx.fn_sig(cx.tcx.hir().local_def_id(nodeid));
+    let inputs = sig.skip_binder().inputs();
+    let mut start = 0;
+    while start < inputs.len() {
+        let ty = inputs[start];
+        let end = start + inputs[start..].iter().take_while(|&&other| other == ty).count() - 1;
+        if end > start && ty.is_primitive() {
+            let names = (start..=end)
+                .map(|i| match arg_names.get(i) {
+                    Some(Some(name)) => format!("`{}`", name),
+                    _ => "`_`".to_owned(),
+                })
+                .collect::<Vec<_>>();
+            span_help_and_lint(
+                cx,
+                ADJACENT_SAME_TYPE_ARGUMENTS,
+                decl.inputs[start].span.to(decl.inputs[end].span),
+                &format!(
+                    "the adjacent parameters {} have the same type `{}`",
+                    names.join(", "),
+                    ty
+                ),
+                "consider using newtypes or a struct, so arguments can't be passed in the wrong order",
+            );
+        }
+        start = end + 1;
+    }
+}
+
+/// Returns the names of the arguments, `None` for patterns other than
+/// bindings.
+fn arg_names(body: &hir::Body) -> Vec<Option<ast::Name>> {
+    body.arguments
+        .iter()
+        .map(|arg| match arg.pat.node {
+            hir::PatKind::Binding(.., ident, _) => Some(ident.name),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Converts a `snake_case` name to `CamelCase`.
+fn camel_case(name: &str) -> String {
+    name.split('_')
+        .filter_map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+        })
+        .collect()
+}
+
 fn raw_ptr_arg(arg: &hir::Arg, ty: &hir::Ty) -> Option<ast::NodeId> {
     if let (&hir::PatKind::Binding(_, id, _, _, _), &hir::TyKind::Ptr(_)) = (&arg.pat.node, &ty.node) {
         Some(id)
@@ -0,0 +1,265 @@
+//! lint on glob imports of deeply nested modules and glob imports shadowed by local items
+
+use crate::utils::{in_macro, snippet_opt, span_lint_node_and_then};
+use rustc::hir::def::Def;
+use rustc::hir::def_id::DefId;
+use rustc::hir::*;
+use rustc::lint::{in_external_macro, LateContext, LateLintPass, LintArray, LintContext, LintPass};
+use rustc::ty;
+use rustc::{declare_tool_lint, lint_array};
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use rustc_errors::Applicability;
+use syntax::ast::{Name, NodeId};
+use syntax::source_map::Span;
+
+/// **What it does:** Checks for glob imports like `use crate::a::b::c::d::*;`
+/// whose path has more segments than configured with
+/// `glob-import-max-depth` in `clippy.toml`. `crate`, `self` and `super`
+/// aren't counted.
+///
+/// **Why is this bad?** Readers can't tell where the names used in the module
+/// come from, and modules deep in a hierarchy are rarely meant to be glob
+/// imported, unlike preludes. The lint suggests importing the names used
+/// explicitly.
+///
+/// **Known problems:** The names used are found by their first path segment,
+/// names which are also local variables in some places may be suggested.
+///
+/// **Example:**
+/// ```rust
+/// use crate::parser::ast::visit::walk::*;
+/// ```
+/// Could be written as:
+/// ```rust
+/// use crate::parser::ast::visit::walk::{walk_expr, walk_item};
+/// ```
+declare_clippy_lint! {
+    pub DEEP_GLOB_IMPORTS,
+    pedantic,
+    "glob imports of modules nested deeper than configured"
+}
+
+/// **What it does:** Checks for glob imports which bring in names that items
+/// of the importing module define as well.
+///
+/// **Why is this bad?** The local items take precedence, so the glob imported
+/// items of the same name are silently hidden. If the local item is removed,
+/// the name refers to the imported item, which may still compile.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// mod shapes {
+///     pub fn area() {}
+///     pub fn perimeter() {}
+/// }
+///
+/// use shapes::*;
+///
+/// fn area() {}
+/// ```
+declare_clippy_lint! {
+    pub SHADOWED_GLOB_IMPORTS,
+    pedantic,
+    "glob imports bringing in names which items of the importing module define"
+}
+
+pub struct GlobImports {
+    max_depth: u64,
+    /// the first segments of the paths used in each module, except those
+    /// resolving to local variables
+    used_names: FxHashMap<DefId, FxHashSet<Name>>,
+}
+
+impl GlobImports {
+    pub fn new(max_depth: u64) -> Self {
+        Self {
+            max_depth,
+            used_names: FxHashMap::default(),
+        }
+    }
+}
+
+impl LintPass for GlobImports {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(DEEP_GLOB_IMPORTS, SHADOWED_GLOB_IMPORTS)
+    }
+
+    fn name(&self) -> &'static str {
+        "GlobImports"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for GlobImports {
+    fn check_path(&mut self, cx: &LateContext<'a, 'tcx>, path: &'tcx Path, id: NodeId) {
+        match path.def {
+            Def::Local(..) | Def::Upvar(..) | Def::Err => return,
+            _ => {},
+        }
+        if let Some(segment) = path.segments.first() {
+            let module = cx.tcx.hir().get_module_parent(id);
+            self.used_names.entry(module).or_default().insert(segment.ident.name);
+        }
+    }
+
+    fn check_mod_post(&mut self, cx: &LateContext<'a, 'tcx>, m: &'tcx Mod, _: Span, id: NodeId) {
+        let map = &cx.tcx.hir();
+        let module = map.local_def_id(id);
+        let items: Vec<&Item> = m.item_ids.iter().map(|item| map.expect_item(item.id)).collect();
+
+        let mut local_names = FxHashSet::default();
+        let mut imported_names = FxHashSet::default();
+        for item in &items {
+            match item.node {
+                ItemKind::Use(_, UseKind::Single) => {
+                    imported_names.insert(item.ident.name);
+                },
+                ItemKind::Use(..)
+                | ItemKind::Impl(..)
+                | ItemKind::ForeignMod(..)
+                | ItemKind::GlobalAsm(..)
+                | ItemKind::ExternCrate(..) => {},
+                _ => {
+                    local_names.insert(item.ident.name);
+                },
+            }
+        }
+        let empty = FxHashSet::default();
+        let used_names = self.used_names.get(&module).unwrap_or(&empty);
+
+        for item in items {
+            if in_macro(item.span) || in_external_macro(cx.sess(), item.span) || item.vis.node.is_pub() {
+                continue;
+            }
+            let (path, glob_module) = match item.node {
+                ItemKind::Use(ref path, UseKind::Glob) => match path.def {
+                    Def::Mod(def_id) => (path, def_id),
+                    _ => continue,
+                },
+                _ => continue,
+            };
+            let mut names = glob_names(cx, glob_module, module);
+            names.sort_by_key(|name| name.as_str());
+            names.dedup();
+
+            let shadowed: Vec<_> = names.iter().filter(|name| local_names.contains(*name)).collect();
+            if !shadowed.is_empty() {
+                span_lint_node_and_then(
+                    cx,
+                    SHADOWED_GLOB_IMPORTS,
+                    item.id,
+                    item.span,
+                    "this glob import brings in names which are shadowed by items of this module",
+                    |db| {
+                        db.note(&format!("the shadowed names are {}", backticked(&shadowed)));
+                    },
+                );
+            }
+
+            let depth = path
+                .segments
+                .iter()
+                .filter(|segment| match &*segment.ident.as_str() {
+                    "crate" | "self" | "super" | "{{root}}" => false,
+                    _ => true,
+                })
+                .count() as u64;
+            if depth > self.max_depth {
+                let used: Vec<_> = names
+                    .iter()
+                    .filter(|name| {
+                        used_names.contains(*name) && !local_names.contains(*name) && !imported_names.contains(*name)
+                    })
+                    .collect();
+                // unused imports are reported by rustc
+                if used.is_empty() {
+                    continue;
+                }
+                span_lint_node_and_then(
+                    cx,
+                    DEEP_GLOB_IMPORTS,
+                    item.id,
+                    item.span,
+                    &format!(
+                        "glob import of a module {} levels deep, the maximum is {}",
+                        depth, self.max_depth
+                    ),
+                    |db| {
+                        let prefix = snippet_opt(cx, item.span)
+                            .and_then(|snip| snip.get(..snip.len().saturating_sub(2)).map(str::to_owned))
+                            .filter(|prefix| prefix.ends_with("::"));
+                        match (prefix, used.as_slice()) {
+                            (Some(prefix), [name]) => {
+                                let sugg = format!("{}{};", prefix, name);
+                                let msg = "consider importing the name used explicitly";
+                                db.span_suggestion(item.span, msg, sugg, Applicability::MaybeIncorrect);
+                            },
+                            (Some(prefix), _) => {
+                                let names: Vec<_> = used.iter().map(ToString::to_string).collect();
+                                let sugg = format!("{}{{{}}};", prefix, names.join(", "));
+                                let msg = "consider importing the names used explicitly";
+                                db.span_suggestion(item.span, msg, sugg, Applicability::MaybeIncorrect);
+                            },
+                            (None, _) => {
+                                db.help(&format!("the names used are {}", backticked(&used)));
+                            },
+                        }
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Returns the names a glob import of `glob_module` in `module` brings in.
+fn glob_names(cx: &LateContext<'_, '_>, glob_module: DefId, module: DefId) -> Vec<Name> {
+    let map = &cx.tcx.hir();
+    let node = match map.as_local_node_id(glob_module) {
+        Some(node) => node,
+        None => {
+            return cx
+                .tcx
+                .item_children(glob_module)
+                .iter()
+                .filter(|export| export.vis == ty::Visibility::Public)
+                .map(|export| export.ident.name)
+                .collect();
+        },
+    };
+    let glob_mod = match map.find(node) {
+        Some(Node::Item(&Item {
+            node: ItemKind::Mod(ref glob_mod),
+            ..
+        })) => glob_mod,
+        Some(Node::Crate) => &map.krate().module,
+        _ => return vec![],
+    };
+    glob_mod
+        .item_ids
+        .iter()
+        .map(|item| map.expect_item(item.id))
+        .filter(|item| match item.node {
+            ItemKind::Use(_, UseKind::Glob)
+            | ItemKind::Use(_, UseKind::ListStem)
+            | ItemKind::Impl(..)
+            | ItemKind::ForeignMod(..)
+            | ItemKind::GlobalAsm(..) => false,
+            _ => true,
+        })
+        .filter(|item| {
+            cx.tcx
+                .visibility(map.local_def_id(item.id))
+                .is_accessible_from(module, cx.tcx)
+        })
+        .map(|item| item.ident.name)
+        .collect()
+}
+
+fn backticked(names: &[&Name]) -> String {
+    names
+        .iter()
+        .map(|name| format!("`{}`", name))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
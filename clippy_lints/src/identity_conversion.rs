@@ -1,5 +1,5 @@
 use crate::utils::{
-    in_macro, match_def_path, match_trait_method, same_tys, snippet, snippet_with_macro_callsite, span_lint_and_then,
+    in_macro, match_def_path, match_trait_method, same_tys, snippet, snippet_with_context, span_lint_and_then,
 };
 use crate::utils::{opt_def_id, paths, resolve_node};
 use rustc::hir::*;
@@ -68,15 +68,13 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for IdentityConversion {
                     let a = cx.tables.expr_ty(e);
                     let b = cx.tables.expr_ty(&args[0]);
                     if same_tys(cx, a, b) {
-                        let sugg = snippet_with_macro_callsite(cx, args[0].span, "<expr>").to_string();
+                        let mut applicability = Applicability::MachineApplicable;
+                        let (sugg, _) =
+                            snippet_with_context(cx, args[0].span, e.span.ctxt(), "<expr>", &mut applicability);
+                        let sugg = sugg.into_owned();
 
                         span_lint_and_then(cx, IDENTITY_CONVERSION, e.span, "identical conversion", |db| {
-                            db.span_suggestion(
-                                e.span,
-                                "consider removing `.into()`",
-                                sugg,
-                                Applicability::MachineApplicable, // snippet
-                            );
+                            db.span_suggestion(e.span, "consider removing `.into()`", sugg, applicability);
                         });
                     }
                 }
@@ -84,14 +82,12 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for IdentityConversion {
                     let a = cx.tables.expr_ty(e);
                     let b = cx.tables.expr_ty(&args[0]);
                     if same_tys(cx, a, b) {
-                        let sugg = snippet(cx, args[0].span, "<expr>").into_owned();
+                        let mut applicability = Applicability::MachineApplicable;
+                        let (sugg, _) =
+                            snippet_with_context(cx, args[0].span, e.span.ctxt(), "<expr>", &mut applicability);
+                        let sugg = sugg.into_owned();
                         span_lint_and_then(cx, IDENTITY_CONVERSION, e.span, "identical conversion", |db| {
-                            db.span_suggestion(
-                                e.span,
-                                "consider removing `.into_iter()`",
-                                sugg,
-                                Applicability::MachineApplicable, // snippet
-                            );
+                            db.span_suggestion(e.span, "consider removing `.into_iter()`", sugg, applicability);
                         });
                     }
                 }
@@ -104,16 +100,14 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for IdentityConversion {
                             let a = cx.tables.expr_ty(e);
                             let b = cx.tables.expr_ty(&args[0]);
                             if same_tys(cx, a, b) {
-                                let sugg = snippet(cx, args[0].span.source_callsite(), "<expr>").into_owned();
+                                let mut applicability = Applicability::MachineApplicable;
+                                let (sugg, _) =
+                                    snippet_with_context(cx, args[0].span, e.span.ctxt(), "<expr>", &mut applicability);
+                                let sugg = sugg.into_owned();
                                 let sugg_msg =
                                     format!("consider removing `{}()`", snippet(cx, path.span, "From::from"));
                                 span_lint_and_then(cx, IDENTITY_CONVERSION, e.span, "identical conversion", |db| {
-                                    db.span_suggestion(
-                                        e.span,
-                                        &sugg_msg,
-                                        sugg,
-                                        Applicability::MachineApplicable, // snippet
-                                    );
+                                    db.span_suggestion(e.span, &sugg_msg, sugg, applicability);
                                 });
                             }
                         }
@@ -0,0 +1,93 @@
+//! lint on locking a `Mutex` or an `RwLock` and dropping the guard right away
+
+use crate::utils::{in_macro, lock_guard_name, match_type, paths, span_lint_and_then};
+use rustc::hir::*;
+use rustc::lint::{in_external_macro, LateContext, LateLintPass, LintArray, LintContext, LintPass};
+use rustc::ty::{self, Ty};
+use rustc::{declare_tool_lint, lint_array};
+
+/// **What it does:** Checks for statements locking a `Mutex` or an `RwLock`
+/// whose guard isn't bound to a variable, like `mutex.lock().unwrap();` or
+/// `let _ = mutex.lock().unwrap();`. Results with a guard, like
+/// `let _ = mutex.lock();`, are linted as well.
+///
+/// **Why is this bad?** The guard is a temporary, which is dropped at the end
+/// of the statement, and `_` doesn't bind it either. So the lock is released
+/// right away, and the code after the statement isn't protected by it, which
+/// is rarely what's meant.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// let _ = counter.lock().unwrap();
+/// COUNT += 1;
+/// ```
+/// Could be written as:
+/// ```rust
+/// let _guard = counter.lock().unwrap();
+/// COUNT += 1;
+/// ```
+declare_clippy_lint! {
+    pub IMMEDIATELY_DROPPED_LOCK,
+    correctness,
+    "locking a `Mutex` or an `RwLock` and dropping the guard at the end of the statement"
+}
+
+#[derive(Copy, Clone)]
+pub struct Pass;
+
+impl LintPass for Pass {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(IMMEDIATELY_DROPPED_LOCK)
+    }
+
+    fn name(&self) -> &'static str {
+        "ImmediatelyDroppedLock"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
+    fn check_stmt(&mut self, cx: &LateContext<'a, 'tcx>, stmt: &'tcx Stmt) {
+        let (expr, note) = match stmt.node {
+            StmtKind::Semi(ref expr) => (
+                expr,
+                "the guard is a temporary, which is dropped at the end of the statement",
+            ),
+            StmtKind::Local(ref local) => match (&local.pat.node, &local.init) {
+                (PatKind::Wild, Some(init)) => (
+                    init,
+                    "`_` doesn't bind the guard, so it's dropped at the end of the statement",
+                ),
+                _ => return,
+            },
+            _ => return,
+        };
+        if in_macro(stmt.span) || in_external_macro(cx.sess(), stmt.span) {
+            return;
+        }
+        if let Some(name) = held_guard_name(cx, cx.tables.expr_ty(expr)) {
+            span_lint_and_then(
+                cx,
+                IMMEDIATELY_DROPPED_LOCK,
+                stmt.span,
+                &format!("the `{}` is dropped right away, which releases the lock again", name),
+                |db| {
+                    db.note(note);
+                    db.help("bind it to a variable like `_guard` to hold the lock until the end of the block");
+                },
+            );
+        }
+    }
+}
+
+/// Returns the name of the guard a value of `ty` holds, if it's a guard or a
+/// `Result` of one.
+fn held_guard_name(cx: &LateContext<'_, '_>, ty: Ty<'_>) -> Option<&'static str> {
+    if let ty::Adt(_, substs) = ty.sty {
+        if match_type(cx, ty, &paths::RESULT) {
+            return lock_guard_name(cx, substs.type_at(0));
+        }
+    }
+    lock_guard_name(cx, ty)
+}
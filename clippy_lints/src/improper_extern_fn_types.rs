@@ -0,0 +1,152 @@
+//! lint on exported `extern` functions with parameters or return types which aren't FFI-safe
+
+use crate::utils::{get_def_path, match_type, paths, span_lint_and_then};
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::ty::{self, Ty};
+use rustc::{declare_tool_lint, lint_array};
+use rustc_target::spec::abi::Abi;
+use syntax::attr;
+use syntax::source_map::Span;
+
+/// **What it does:** Checks for the definitions of exported `extern`
+/// functions with parameters or return types which have no stable layout:
+/// `String`, `Vec<T>`, fat pointers like `&str`, `&[T]` and `&dyn Trait`, and
+/// `Option`s which aren't represented like a nullable pointer, e.g.
+/// `Option<u32>`.
+///
+/// **Why is this bad?** Code in other languages can't pass or receive these
+/// types correctly, calling the function is undefined behavior. Rustc's
+/// `improper_ctypes` lint only checks the declarations of foreign functions,
+/// not the definitions of functions called from other languages.
+///
+/// **Known problems:** `extern` functions only called through function
+/// pointers from Rust code are linted as well.
+///
+/// **Example:**
+/// ```rust
+/// #[no_mangle]
+/// pub extern "C" fn greet(name: &str) -> String {
+///     format!("hello {}", name)
+/// }
+/// ```
+declare_clippy_lint! {
+    pub IMPROPER_EXTERN_FN_TYPES,
+    correctness,
+    "exported `extern` functions with parameters or return types which aren't FFI-safe"
+}
+
+#[derive(Copy, Clone)]
+pub struct Pass;
+
+impl LintPass for Pass {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(IMPROPER_EXTERN_FN_TYPES)
+    }
+
+    fn name(&self) -> &'static str {
+        "ImproperExternFnTypes"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
+        if let ItemKind::Fn(ref decl, header, ..) = item.node {
+            match header.abi {
+                Abi::Rust | Abi::RustCall | Abi::RustIntrinsic | Abi::PlatformIntrinsic => return,
+                _ => {},
+            }
+            if !cx.access_levels.is_exported(item.id) && !attr::contains_name(&item.attrs, "no_mangle") {
+                return;
+            }
+            let sig = cx.tcx.fn_sig(cx.tcx.hir().local_def_id(item.id));
+            let sig = sig.skip_binder();
+            for (input, ty) in decl.inputs.iter().zip(sig.inputs()) {
+                check_ty(cx, input.span, ty);
+            }
+            if let FunctionRetTy::Return(ref output) = decl.output {
+                check_ty(cx, output.span, sig.output());
+            }
+        }
+    }
+}
+
+fn check_ty<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, span: Span, ty: Ty<'tcx>) {
+    if let Some((note, help)) = improper_reason(cx, ty) {
+        span_lint_and_then(
+            cx,
+            IMPROPER_EXTERN_FN_TYPES,
+            span,
+            &format!("`{}` isn't FFI-safe", ty),
+            |db| {
+                db.note(note);
+                if let Some(help) = help {
+                    db.help(help);
+                }
+            },
+        );
+    }
+}
+
+/// Returns why `ty` isn't FFI-safe, and how to pass it instead if there's an
+/// obvious way.
+fn improper_reason<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, ty: Ty<'tcx>) -> Option<(&'static str, Option<&'static str>)> {
+    if let Some(pointee) = pointee(ty) {
+        return match pointee.sty {
+            ty::Str | ty::Slice(_) => Some((
+                "pointers to slices and string slices contain the length, they have no stable layout",
+                Some("consider passing a pointer to the data and the length separately"),
+            )),
+            ty::Dynamic(..) => Some((
+                "pointers to trait objects contain the vtable, they have no stable layout",
+                Some("consider passing a pointer to a sized type, e.g. to a `Box<dyn Trait>`"),
+            )),
+            _ => None,
+        };
+    }
+    match ty.sty {
+        ty::Adt(..) if match_type(cx, ty, &paths::STRING) || match_type(cx, ty, &paths::VEC) => Some((
+            "`String` and `Vec` have no stable layout",
+            Some("consider passing a pointer to the data and the length separately, e.g. from `as_ptr` and `len`"),
+        )),
+        ty::Adt(_, substs) if match_type(cx, ty, &paths::OPTION) => {
+            let inner = substs.type_at(0);
+            if let Some(reason) = improper_reason(cx, inner) {
+                Some(reason)
+            } else if is_non_null(cx, inner) {
+                None
+            } else {
+                Some((
+                    "only `Option`s of references, `Box`es, function pointers, `NonNull` and the `NonZero` integers \
+                     are represented like a nullable pointer",
+                    None,
+                ))
+            }
+        },
+        _ => None,
+    }
+}
+
+/// Returns the type `ty` points to, if it's a reference, a raw pointer or a
+/// `Box`.
+fn pointee(ty: Ty<'_>) -> Option<Ty<'_>> {
+    match ty.sty {
+        ty::Ref(_, pointee, _) | ty::RawPtr(ty::TypeAndMut { ty: pointee, .. }) => Some(pointee),
+        ty::Adt(..) if ty.is_box() => Some(ty.boxed_ty()),
+        _ => None,
+    }
+}
+
+/// Checks whether `None` of an `Option<ty>` is represented by the value zero,
+/// which no `ty` has.
+fn is_non_null<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, ty: Ty<'tcx>) -> bool {
+    match ty.sty {
+        ty::Ref(..) | ty::FnPtr(_) => true,
+        ty::Adt(..) if ty.is_box() || match_type(cx, ty, &paths::NON_NULL) => true,
+        ty::Adt(def, _) => {
+            let path = get_def_path(cx.tcx, def.did);
+            path.len() == 3 && path[0] == "core" && path[1] == "num" && path[2].starts_with("NonZero")
+        },
+        _ => false,
+    }
+}
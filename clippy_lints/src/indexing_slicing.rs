@@ -42,6 +42,9 @@ declare_clippy_lint! {
 /// **Why is this bad?** Indexing and slicing can panic at runtime and there are
 /// safe alternatives.
 ///
+/// `get_unwrap`, which suggests `[]` instead of `get(n).unwrap()`, isn't
+/// emitted where this lint is enabled.
+///
 /// **Known problems:** Hopefully none.
 ///
 /// **Example:**
@@ -84,8 +87,16 @@ declare_clippy_lint! {
     "indexing/slicing usage"
 }
 
-#[derive(Copy, Clone)]
-pub struct IndexingSlicing;
+#[derive(Clone)]
+pub struct IndexingSlicing {
+    allow_indexing_in: Vec<String>,
+}
+
+impl IndexingSlicing {
+    pub fn new(allow_indexing_in: Vec<String>) -> Self {
+        Self { allow_indexing_in }
+    }
+}
 
 impl LintPass for IndexingSlicing {
     fn get_lints(&self) -> LintArray {
@@ -146,7 +157,9 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for IndexingSlicing {
                     (None, None) => return, // [..] is ok.
                 };
 
-                utils::span_help_and_lint(cx, INDEXING_SLICING, expr.span, "slicing may panic.", help_msg);
+                if !utils::is_in_scope(cx, expr.id, expr.span, &self.allow_indexing_in) {
+                    utils::span_help_and_lint(cx, INDEXING_SLICING, expr.span, "slicing may panic.", help_msg);
+                }
             } else {
                 // Catchall non-range index, i.e. [n] or [n << m]
                 if let ty::Array(..) = ty.sty {
@@ -157,13 +170,15 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for IndexingSlicing {
                     }
                 }
 
-                utils::span_help_and_lint(
-                    cx,
-                    INDEXING_SLICING,
-                    expr.span,
-                    "indexing may panic.",
-                    "Consider using `.get(n)` or `.get_mut(n)` instead",
-                );
+                if !utils::is_in_scope(cx, expr.id, expr.span, &self.allow_indexing_in) {
+                    utils::span_help_and_lint(
+                        cx,
+                        INDEXING_SLICING,
+                        expr.span,
+                        "indexing may panic.",
+                        "Consider using `.get(n)` or `.get_mut(n)` instead",
+                    );
+                }
             }
         }
     }
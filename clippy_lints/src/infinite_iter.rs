@@ -135,6 +135,7 @@ static HEURISTICS: &[(&str, usize, Heuristic, Finiteness)] = &[
     ("peekable", 2, First, Infinite),
     ("fuse", 1, First, Infinite),
     ("skip", 2, First, Infinite),
+    ("step_by", 2, First, Infinite),
     ("skip_while", 1, First, Infinite),
     ("filter", 2, First, Infinite),
     ("filter_map", 2, First, Infinite),
@@ -170,7 +171,12 @@ fn is_infinite(cx: &LateContext<'_, '_>, expr: &Expr) -> Finiteness {
         ExprKind::Box(ref e) | ExprKind::AddrOf(_, ref e) => is_infinite(cx, e),
         ExprKind::Call(ref path, _) => {
             if let ExprKind::Path(ref qpath) = path.node {
-                match_qpath(qpath, &paths::REPEAT).into()
+                if match_qpath(qpath, &paths::ITER_FROM_FN) || match_qpath(qpath, &paths::ITER_SUCCESSORS) {
+                    // the closure may return `None` at some point
+                    MaybeInfinite
+                } else {
+                    (match_qpath(qpath, &paths::REPEAT) || match_qpath(qpath, &paths::REPEAT_WITH)).into()
+                }
             } else {
                 Finite
             }
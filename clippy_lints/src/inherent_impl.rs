@@ -40,18 +40,11 @@ declare_clippy_lint! {
     "Multiple inherent impl that could be grouped"
 }
 
+#[derive(Default)]
 pub struct Pass {
     impls: FxHashMap<def_id::DefId, (Span, Generics)>,
 }
 
-impl Default for Pass {
-    fn default() -> Self {
-        Self {
-            impls: FxHashMap::default(),
-        }
-    }
-}
-
 impl LintPass for Pass {
     fn get_lints(&self) -> LintArray {
         lint_array!(MULTIPLE_INHERENT_IMPL)
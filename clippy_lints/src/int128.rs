@@ -0,0 +1,243 @@
+//! lint on 128-bit integers in FFI and serialized types
+
+use crate::utils::{get_def_path, get_trait_def_id, in_macro, is_automatically_derived, span_note_and_lint};
+use rustc::hir::def_id::DefId;
+use rustc::hir::*;
+use rustc::lint::{in_external_macro, LateContext, LateLintPass, LintArray, LintContext, LintPass};
+use rustc::ty::{self, Ty};
+use rustc::{declare_tool_lint, lint_array};
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use rustc_target::spec::abi::Abi;
+use syntax::ast::{IntTy, UintTy};
+use syntax::source_map::Span;
+
+/// **What it does:** Checks for `i128` and `u128` in the fields of
+/// `#[repr(C)]` types and in the signatures of functions with a non-Rust ABI,
+/// like `extern "C" fn`, including types containing them, like `[u128; 2]`.
+/// The functions imported in `extern` blocks are already checked by rustc's
+/// `improper_ctypes`.
+///
+/// **Why is this bad?** 128-bit integers don't have a stable ABI. C compilers
+/// pass and align them differently than rustc on some targets, so the other
+/// side of the FFI may see garbage.
+///
+/// **Known problems:** Values which are only passed to other Rust code are
+/// linted as well.
+///
+/// **Example:**
+/// ```rust
+/// #[repr(C)]
+/// pub struct Balance {
+///     pub amount: u128,
+/// }
+/// ```
+/// Could be written as:
+/// ```rust
+/// #[repr(C)]
+/// pub struct Balance {
+///     pub amount_low: u64,
+///     pub amount_high: u64,
+/// }
+/// ```
+declare_clippy_lint! {
+    pub INT128_IN_FFI,
+    pedantic,
+    "128-bit integers in `#[repr(C)]` types or `extern` function signatures"
+}
+
+/// **What it does:** Checks for `i128` and `u128` fields in types deriving a
+/// serialization trait, by default `serde::Serialize`, `serde::Deserialize`,
+/// `rustc_serialize::Encodable` and `rustc_serialize::Decodable`. More traits
+/// can be configured with `serialization-derives` in `clippy.toml`.
+///
+/// **Why is this bad?** Many serialization formats don't support 128-bit
+/// integers, so serializing the type fails at runtime, or the values lose
+/// precision in the parsers on the other side, which is usually noticed late.
+///
+/// **Known problems:** The lint doesn't know which formats are used, which
+/// may all support 128-bit integers.
+///
+/// **Example:**
+/// ```rust
+/// #[derive(Serialize)]
+/// pub struct Balance {
+///     pub amount: u128,
+/// }
+/// ```
+/// Could be written as:
+/// ```rust
+/// #[derive(Serialize)]
+/// pub struct Balance {
+///     #[serde(with = "amount_as_string")]
+///     pub amount: u128,
+/// }
+/// ```
+declare_clippy_lint! {
+    pub SERIALIZED_INT128,
+    pedantic,
+    "128-bit integers in types deriving serialization traits"
+}
+
+/// The serialization traits which are always checked.
+const SERIALIZATION_DERIVES: [&str; 4] = [
+    "serde::Serialize",
+    "serde::Deserialize",
+    "rustc_serialize::Encodable",
+    "rustc_serialize::Decodable",
+];
+
+#[derive(Clone, Debug)]
+pub struct Int128 {
+    derives: Vec<String>,
+    /// the traits of `derives` which could be resolved
+    traits: FxHashSet<DefId>,
+    /// the first serialization trait each local type derives
+    derived: FxHashMap<DefId, DefId>,
+}
+
+impl Int128 {
+    pub fn new(derives: &[String]) -> Self {
+        Self {
+            derives: SERIALIZATION_DERIVES
+                .iter()
+                .map(|path| (*path).to_string())
+                .chain(derives.iter().cloned())
+                .collect(),
+            traits: FxHashSet::default(),
+            derived: FxHashMap::default(),
+        }
+    }
+
+    /// Checks whether `def_id` is a serialization trait.
+    fn is_serialization_trait(&self, cx: &LateContext<'_, '_>, def_id: DefId) -> bool {
+        if self.traits.contains(&def_id) {
+            return true;
+        }
+        // local traits can't be resolved by `path_to_def`
+        let def_path = get_def_path(cx.tcx, def_id).join("::");
+        self.derives.iter().any(|path| *path == def_path)
+    }
+
+    fn check_fields(&self, cx: &LateContext<'_, '_>, item: &Item, fields: &[StructField]) {
+        let def_id = cx.tcx.hir().local_def_id(item.id);
+        let is_repr_c = cx.tcx.adt_def(def_id).repr.c();
+        let derived = self.derived.get(&def_id);
+        for field in fields {
+            let int_ty = match int128(cx.tcx.type_of(cx.tcx.hir().local_def_id(field.id))) {
+                Some(int_ty) => int_ty,
+                None => continue,
+            };
+            if is_repr_c {
+                lint_ffi(cx, field.ty.span, &format!("`{}` in a `#[repr(C)]` type", int_ty));
+            }
+            if let Some(&trait_id) = derived {
+                span_note_and_lint(
+                    cx,
+                    SERIALIZED_INT128,
+                    field.ty.span,
+                    &format!("`{}` in a type deriving `{}`", int_ty, cx.tcx.item_name(trait_id)),
+                    field.ty.span,
+                    "many serialization formats don't support 128-bit integers",
+                );
+            }
+        }
+    }
+}
+
+impl LintPass for Int128 {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(INT128_IN_FFI, SERIALIZED_INT128)
+    }
+
+    fn name(&self) -> &'static str {
+        "Int128"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Int128 {
+    fn check_crate(&mut self, cx: &LateContext<'a, 'tcx>, krate: &'tcx Crate) {
+        for path in &self.derives {
+            let path: Vec<_> = path.split("::").collect();
+            if let Some(def_id) = get_trait_def_id(cx, &path) {
+                self.traits.insert(def_id);
+            }
+        }
+        // the types are checked before their derived impls, so these are collected up front
+        for item in krate.items.values() {
+            if let ItemKind::Impl(.., Some(ref trait_ref), _, _) = item.node {
+                if !is_automatically_derived(&item.attrs) && !in_macro(item.span) {
+                    continue;
+                }
+                let trait_id = trait_ref.trait_def_id();
+                if !self.is_serialization_trait(cx, trait_id) {
+                    continue;
+                }
+                if let Some(adt) = cx.tcx.type_of(cx.tcx.hir().local_def_id(item.id)).ty_adt_def() {
+                    self.derived.entry(adt.did).or_insert(trait_id);
+                }
+            }
+        }
+    }
+
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
+        if in_macro(item.span) || in_external_macro(cx.sess(), item.span) {
+            return;
+        }
+        match item.node {
+            ItemKind::Struct(ref data, _) | ItemKind::Union(ref data, _) => self.check_fields(cx, item, data.fields()),
+            ItemKind::Enum(ref def, _) => {
+                for variant in &def.variants {
+                    self.check_fields(cx, item, variant.node.data.fields());
+                }
+            },
+            ItemKind::Fn(ref decl, header, ..) => match header.abi {
+                Abi::Rust | Abi::RustCall | Abi::RustIntrinsic | Abi::PlatformIntrinsic => {},
+                _ => check_extern_fn(cx, item, decl),
+            },
+            _ => {},
+        }
+    }
+}
+
+fn check_extern_fn(cx: &LateContext<'_, '_>, item: &Item, decl: &FnDecl) {
+    let sig = cx.tcx.fn_sig(cx.tcx.hir().local_def_id(item.id));
+    let sig = sig.skip_binder();
+    let output = match decl.output {
+        FunctionRetTy::Return(ref output) => Some((output.span, sig.output())),
+        FunctionRetTy::DefaultReturn(_) => None,
+    };
+    let types = decl
+        .inputs
+        .iter()
+        .map(|input| input.span)
+        .zip(sig.inputs().iter().cloned());
+    for (span, ty) in types.chain(output) {
+        if let Some(int_ty) = int128(ty) {
+            lint_ffi(
+                cx,
+                span,
+                &format!("`{}` in the signature of an `extern` function", int_ty),
+            );
+        }
+    }
+}
+
+fn lint_ffi(cx: &LateContext<'_, '_>, span: Span, msg: &str) {
+    span_note_and_lint(
+        cx,
+        INT128_IN_FFI,
+        span,
+        msg,
+        span,
+        "128-bit integers don't have a stable ABI, C code may pass or align them differently",
+    );
+}
+
+/// Returns the 128-bit integer type `ty` is or contains.
+fn int128(ty: Ty<'_>) -> Option<&'static str> {
+    ty.walk().find_map(|ty| match ty.sty {
+        ty::Int(IntTy::I128) => Some("i128"),
+        ty::Uint(UintTy::U128) => Some("u128"),
+        _ => None,
+    })
+}
@@ -0,0 +1,81 @@
+//! lint on async code creating large futures
+
+use crate::utils::{in_macro_not_desugaring, span_help_and_lint};
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::ty::layout::LayoutOf;
+use rustc::{declare_tool_lint, lint_array};
+
+/// **What it does:** Checks for async functions and blocks whose futures are
+/// larger than a configurable size, 16 KiB by default. The size is set with
+/// `future-size-threshold` in `clippy.toml`.
+///
+/// **Why is this bad?** A future stores everything its code keeps alive
+/// across an `await!`, including the futures it awaits, so sizes add up
+/// along the chain. Futures are moved around by value until they're pinned,
+/// which is slow and overflows small stacks, e.g. on embedded targets.
+///
+/// **Known problems:** Generic functions aren't linted, since their sizes
+/// depend on the type parameters.
+///
+/// **Example:**
+/// ```rust
+/// async fn fill() {
+///     let buffer = [0u8; 20_000];
+///     await!(write(&buffer));
+/// }
+/// ```
+/// Could be awaited as:
+/// ```rust
+/// await!(Box::pin(fill()));
+/// ```
+declare_clippy_lint! {
+    pub LARGE_FUTURES,
+    pedantic,
+    "async code creating futures which are larger than a configured size"
+}
+
+#[derive(Copy, Clone)]
+pub struct LargeFutures {
+    future_size_threshold: u64,
+}
+
+impl LargeFutures {
+    pub fn new(future_size_threshold: u64) -> Self {
+        Self { future_size_threshold }
+    }
+}
+
+impl LintPass for LargeFutures {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(LARGE_FUTURES)
+    }
+
+    fn name(&self) -> &'static str {
+        "LargeFutures"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for LargeFutures {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if let ExprKind::Closure(.., Some(_)) = expr.node {
+            if in_macro_not_desugaring(expr.span) {
+                return;
+            }
+            // generic generators don't have a layout
+            let size = match cx.layout_of(cx.tables.expr_ty(expr)) {
+                Ok(layout) => layout.size.bytes(),
+                Err(_) => return,
+            };
+            if size > self.future_size_threshold {
+                span_help_and_lint(
+                    cx,
+                    LARGE_FUTURES,
+                    expr.span,
+                    &format!("this future is larger than {} bytes", self.future_size_threshold),
+                    "consider moving it to the heap with `Box::pin` where it's awaited or spawned",
+                );
+            }
+        }
+    }
+}
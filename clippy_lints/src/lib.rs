@@ -74,6 +74,7 @@ macro_rules! declare_clippy_lint {
 }
 
 mod consts;
+mod expr_passes;
 #[macro_use]
 mod utils;
 
@@ -83,20 +84,30 @@ pub mod arithmetic;
 pub mod assertions_on_constants;
 pub mod assign_ops;
 pub mod attrs;
+pub mod await_holding_lock;
 pub mod bit_mask;
 pub mod blacklisted_name;
 pub mod block_in_if_condition;
+pub mod blocking_in_async;
 pub mod booleans;
+pub mod boxed_trait_object_return;
 pub mod bytecount;
 pub mod cargo_common_metadata;
+pub mod chained_comparison;
+pub mod cognitive_complexity;
 pub mod collapsible_if;
 pub mod const_static_lifetime;
 pub mod copies;
 pub mod copy_iterator;
 pub mod cyclomatic_complexity;
 pub mod dbg_macro;
+pub mod default_only_trait;
 pub mod default_trait_access;
+pub mod derivable_impls;
 pub mod derive;
+pub mod detached_thread;
+pub mod disallowed_methods;
+pub mod disallowed_types;
 pub mod doc;
 pub mod double_comparison;
 pub mod double_parens;
@@ -113,31 +124,41 @@ pub mod erasing_op;
 pub mod escape;
 pub mod eta_reduction;
 pub mod eval_order_dependence;
+pub mod excessive_nesting;
 pub mod excessive_precision;
+pub mod exhaustive_items;
 pub mod explicit_write;
 pub mod fallible_impl_from;
 pub mod format;
 pub mod formatting;
 pub mod functions;
+pub mod glob_imports;
 pub mod identity_conversion;
 pub mod identity_op;
 pub mod if_not_else;
+pub mod immediately_dropped_lock;
 pub mod implicit_return;
+pub mod improper_extern_fn_types;
 pub mod indexing_slicing;
 pub mod infallible_destructuring_match;
 pub mod infinite_iter;
 pub mod inherent_impl;
 pub mod inline_fn_without_body;
+pub mod int128;
 pub mod int_plus_one;
 pub mod invalid_ref;
 pub mod items_after_statements;
 pub mod large_enum_variant;
+pub mod large_futures;
 pub mod len_zero;
 pub mod let_if_seq;
 pub mod lifetimes;
 pub mod literal_representation;
 pub mod loops;
+pub mod manual_digit_conversion;
+pub mod manual_filter;
 pub mod map_clone;
+pub mod map_for_side_effects;
 pub mod map_unit_fn;
 pub mod matches;
 pub mod mem_discriminant;
@@ -147,13 +168,19 @@ pub mod methods;
 pub mod minmax;
 pub mod misc;
 pub mod misc_early;
+pub mod mismatched_field_init;
 pub mod missing_const_for_fn;
 pub mod missing_doc;
 pub mod missing_inline;
+pub mod mixed_impl_trait_args;
+pub mod module_lines;
+pub mod monomorphization_bloat;
 pub mod multiple_crate_versions;
 pub mod mut_mut;
 pub mod mut_reference;
 pub mod mutex_atomic;
+pub mod mutual_recursion;
+pub mod naming_rules;
 pub mod needless_bool;
 pub mod needless_borrow;
 pub mod needless_borrowed_ref;
@@ -164,13 +191,17 @@ pub mod neg_cmp_op_on_partial_ord;
 pub mod neg_multiply;
 pub mod new_without_default;
 pub mod no_effect;
+pub mod no_mangle_with_rust_abi;
 pub mod non_copy_const;
 pub mod non_expressive_names;
 pub mod ok_if_let;
 pub mod open_options;
+pub mod option_literal_cmp;
 pub mod overflow_check_conditional;
+pub mod panic_in_result_fn;
 pub mod panic_unimplemented;
 pub mod partialeq_ne_impl;
+pub mod path_join;
 pub mod precedence;
 pub mod ptr;
 pub mod ptr_offset_with_cast;
@@ -179,27 +210,40 @@ pub mod ranges;
 pub mod redundant_clone;
 pub mod redundant_field_names;
 pub mod redundant_pattern_matching;
+pub mod redundant_phantom_data;
+pub mod redundant_turbofish;
 pub mod reference;
 pub mod regex;
 pub mod replace_consts;
 pub mod returns;
+pub mod self_assignment;
 pub mod serde_api;
 pub mod shadow;
 pub mod slow_vector_initialization;
 pub mod strings;
 pub mod suspicious_trait_impl;
 pub mod swap;
+pub mod swapped_arguments;
+pub mod system_time;
 pub mod temporary_assignment;
+pub mod time_subtraction;
+pub mod trait_bounds;
 pub mod transmute;
 pub mod trivially_copy_pass_by_ref;
 pub mod types;
+pub mod unbounded_reads;
+pub mod undocumented_unsafe_blocks;
 pub mod unicode;
+pub mod unnecessary_visibility;
 pub mod unsafe_removed_from_name;
+pub mod unused_async;
 pub mod unused_io_amount;
 pub mod unused_label;
+pub mod unused_pure_result;
 pub mod unwrap;
 pub mod use_self;
 pub mod vec;
+pub mod vec_init_clone;
 pub mod wildcard_dependencies;
 pub mod write;
 pub mod zero_div_zero;
@@ -221,7 +265,7 @@ pub fn register_pre_expansion_lints(
         Some(session),
         true,
         false,
-        box redundant_field_names::RedundantFieldNames,
+        box redundant_field_names::RedundantFieldNames::new(conf.msrv),
     );
     store.register_pre_expansion_pass(
         Some(session),
@@ -267,7 +311,7 @@ pub fn read_conf(reg: &rustc_plugin::Registry<'_>) -> Conf {
                 }
             });
 
-            let (conf, errors) = utils::conf::read(file_name.as_ref().map(|p| p.as_ref()));
+            let (conf, errors) = utils::conf::read(file_name.as_ref().map(AsRef::as_ref));
 
             // all conf errors are non-fatal, we just use the default conf in case of error
             for error in errors {
@@ -292,6 +336,46 @@ pub fn read_conf(reg: &rustc_plugin::Registry<'_>) -> Conf {
     }
 }
 
+/// Returns the `-W` and `-D` flags for the lint groups configured with
+/// `warn-groups` and `deny-groups` in `clippy.toml`.
+///
+/// The driver passes these before the flags from the command line, so the
+/// latter take precedence. Errors in the configuration are reported by
+/// `read_conf` later on.
+pub fn group_level_args() -> Vec<String> {
+    let conf = match utils::conf::lookup_conf_file() {
+        Ok(Some(path)) => utils::conf::read(Some(&path)).0,
+        _ => return Vec::new(),
+    };
+    let warn = conf.warn_groups.iter().map(|group| ("-W", group));
+    let deny = conf.deny_groups.iter().map(|group| ("-D", group));
+    warn.chain(deny)
+        .flat_map(|(flag, group)| vec![flag.to_owned(), format!("clippy::{}", group)])
+        .collect()
+}
+
+/// Sets the caps of Clippy's lints from the driver's flags, see
+/// `utils::lint_cap::set`.
+///
+/// # Errors
+///
+/// Fails if a flag isn't a lint level.
+pub fn set_lint_caps(deps_cap: Option<&str>, generated_cap: Option<&str>) -> Result<(), String> {
+    utils::lint_cap::set(deps_cap, generated_cap)
+}
+
+/// Wraps the late lint passes before the driver adds them to the lint store,
+/// to contain panics and to record the time the passes take if
+/// `CLIPPY_TIME_PASSES` is set.
+pub fn wrap_late_passes(passes: Vec<rustc::lint::LateLintPassObject>) -> Vec<rustc::lint::LateLintPassObject> {
+    utils::pass_wrapper::wrap_all(passes)
+}
+
+/// Writes the times recorded by the passes from `wrap_late_passes`.
+pub fn write_pass_times() {
+    utils::timing::write_times();
+}
+
 #[allow(clippy::too_many_lines)]
 #[rustfmt::skip]
 pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
@@ -350,71 +434,60 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
     reg.register_late_lint_pass(box utils::internal_lints::LintWithoutLintPass::default());
     reg.register_late_lint_pass(box utils::inspector::Pass);
     reg.register_late_lint_pass(box utils::author::Pass);
+    reg.register_early_lint_pass(box utils::msrvs::MsrvAttrs);
     reg.register_late_lint_pass(box types::TypePass);
     reg.register_late_lint_pass(box booleans::NonminimalBool);
-    reg.register_late_lint_pass(box eq_op::EqOp);
-    reg.register_early_lint_pass(box enum_variants::EnumVariantNames::new(conf.enum_variant_name_threshold));
+    // passes which only implement `check_expr` are combined in `expr_passes`
+    if utils::timing::is_enabled() {
+        // register the passes separately to time them one by one
+        for pass in expr_passes::ExprPasses::new(conf).into_passes() {
+            reg.register_late_lint_pass(pass);
+        }
+    } else {
+        reg.register_late_lint_pass(box expr_passes::ExprPasses::new(conf));
+    }
+    reg.register_early_lint_pass(box enum_variants::EnumVariantNames::new(
+            conf.enum_variant_name_threshold,
+            conf.enum_variant_allowed_prefixes.iter().cloned().collect(),
+            conf.check_variant_enum_name_prefix,
+    ));
     reg.register_late_lint_pass(box enum_glob_use::EnumGlobUse);
     reg.register_late_lint_pass(box enum_clike::UnportableVariant);
-    reg.register_late_lint_pass(box excessive_precision::ExcessivePrecision);
-    reg.register_late_lint_pass(box bit_mask::BitMask::new(conf.verbose_bit_mask_threshold));
     reg.register_late_lint_pass(box ptr::PointerPass);
-    reg.register_late_lint_pass(box needless_bool::NeedlessBool);
-    reg.register_late_lint_pass(box needless_bool::BoolComparison);
-    reg.register_late_lint_pass(box approx_const::Pass);
     reg.register_late_lint_pass(box misc::Pass);
     reg.register_early_lint_pass(box precedence::Precedence);
     reg.register_early_lint_pass(box needless_continue::NeedlessContinue);
-    reg.register_late_lint_pass(box eta_reduction::EtaPass);
-    reg.register_late_lint_pass(box identity_op::IdentityOp);
-    reg.register_late_lint_pass(box erasing_op::ErasingOp);
     reg.register_early_lint_pass(box items_after_statements::ItemsAfterStatements);
     reg.register_late_lint_pass(box mut_mut::MutMut);
-    reg.register_late_lint_pass(box mut_reference::UnnecessaryMutPassed);
     reg.register_late_lint_pass(box len_zero::LenZero);
     reg.register_late_lint_pass(box attrs::AttrPass);
     reg.register_early_lint_pass(box collapsible_if::CollapsibleIf);
-    reg.register_late_lint_pass(box block_in_if_condition::BlockInIfCondition);
-    reg.register_late_lint_pass(box unicode::Unicode);
-    reg.register_late_lint_pass(box strings::StringAdd);
     reg.register_early_lint_pass(box returns::ReturnPass);
     reg.register_late_lint_pass(box implicit_return::Pass);
-    reg.register_late_lint_pass(box methods::Pass);
-    reg.register_late_lint_pass(box map_clone::Pass);
+    reg.register_late_lint_pass(box methods::Pass::new(
+            conf.allow_unwrap_in.clone(),
+            conf.lock_unwrap_allow_expect,
+            conf.prefer_get_over_indexing,
+    ));
     reg.register_late_lint_pass(box shadow::Pass);
     reg.register_late_lint_pass(box types::LetPass);
-    reg.register_late_lint_pass(box types::UnitCmp);
-    reg.register_late_lint_pass(box loops::Pass);
+    reg.register_late_lint_pass(box loops::Pass::new(conf.msrv));
     reg.register_late_lint_pass(box lifetimes::LifetimePass);
-    reg.register_late_lint_pass(box entry::HashMapLint);
-    reg.register_late_lint_pass(box ranges::Pass);
-    reg.register_late_lint_pass(box types::CastPass);
+    reg.register_late_lint_pass(box ranges::Pass::new(conf.msrv));
     reg.register_late_lint_pass(box types::TypeComplexityPass::new(conf.type_complexity_threshold));
-    reg.register_late_lint_pass(box matches::MatchPass);
     reg.register_late_lint_pass(box minmax::MinMaxPass);
-    reg.register_late_lint_pass(box open_options::NonSensical);
-    reg.register_late_lint_pass(box zero_div_zero::Pass);
     reg.register_late_lint_pass(box mutex_atomic::MutexAtomic);
-    reg.register_late_lint_pass(box needless_update::Pass);
     reg.register_late_lint_pass(box needless_borrow::NeedlessBorrow::default());
     reg.register_late_lint_pass(box needless_borrowed_ref::NeedlessBorrowedRef);
     reg.register_late_lint_pass(box no_effect::Pass);
-    reg.register_late_lint_pass(box temporary_assignment::Pass);
-    reg.register_late_lint_pass(box transmute::Transmute);
     reg.register_late_lint_pass(
         box cyclomatic_complexity::CyclomaticComplexity::new(conf.cyclomatic_complexity_threshold)
     );
-    reg.register_late_lint_pass(box escape::Pass{too_large_for_stack: conf.too_large_for_stack});
+    reg.register_late_lint_pass(box escape::Pass{too_large_for_stack: conf.too_large_for_stack, msrv: conf.msrv});
     reg.register_early_lint_pass(box misc_early::MiscEarly);
-    reg.register_late_lint_pass(box panic_unimplemented::Pass);
-    reg.register_late_lint_pass(box strings::StringLitAsBytes);
     reg.register_late_lint_pass(box derive::Derive);
-    reg.register_late_lint_pass(box types::CharLitAsU8);
     reg.register_late_lint_pass(box vec::Pass);
-    reg.register_late_lint_pass(box drop_forget_ref::Pass);
     reg.register_late_lint_pass(box empty_enum::EmptyEnum);
-    reg.register_late_lint_pass(box types::AbsurdExtremeComparisons);
-    reg.register_late_lint_pass(box types::InvalidUpcastComparisons);
     reg.register_late_lint_pass(box regex::Pass::default());
     reg.register_late_lint_pass(box copies::CopyAndPaste);
     reg.register_late_lint_pass(box copy_iterator::CopyIterator);
@@ -424,81 +497,119 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
     reg.register_early_lint_pass(box if_not_else::IfNotElse);
     reg.register_early_lint_pass(box else_if_without_else::ElseIfWithoutElse);
     reg.register_early_lint_pass(box int_plus_one::IntPlusOne);
-    reg.register_late_lint_pass(box overflow_check_conditional::OverflowCheckConditional);
     reg.register_late_lint_pass(box unused_label::UnusedLabel);
     reg.register_late_lint_pass(box new_without_default::NewWithoutDefault::default());
     reg.register_late_lint_pass(box blacklisted_name::BlackListedName::new(
             conf.blacklisted_names.iter().cloned().collect()
     ));
-    reg.register_late_lint_pass(box functions::Functions::new(conf.too_many_arguments_threshold, conf.too_many_lines_threshold));
-    reg.register_early_lint_pass(box doc::Doc::new(conf.doc_valid_idents.iter().cloned().collect()));
-    reg.register_late_lint_pass(box neg_multiply::NegMultiply);
+    reg.register_late_lint_pass(box functions::Functions::new(
+        conf.too_many_arguments_threshold,
+        conf.too_many_arguments_exclude_self,
+        conf.too_many_generic_parameters_threshold,
+        conf.too_many_arguments_suggest_struct,
+        conf.too_many_lines_threshold,
+    ));
+    reg.register_late_lint_pass(box doc::Doc::new(conf.doc_valid_idents.iter().chain(&conf.doc_valid_idents_append).cloned().collect()));
     reg.register_early_lint_pass(box unsafe_removed_from_name::UnsafeNameRemoval);
-    reg.register_late_lint_pass(box mem_discriminant::MemDiscriminant);
-    reg.register_late_lint_pass(box mem_forget::MemForget);
-    reg.register_late_lint_pass(box mem_replace::MemReplace);
     reg.register_late_lint_pass(box arithmetic::Arithmetic::default());
-    reg.register_late_lint_pass(box assign_ops::AssignOps);
     reg.register_late_lint_pass(box let_if_seq::LetIfSeq);
     reg.register_late_lint_pass(box eval_order_dependence::EvalOrderDependence);
-    reg.register_late_lint_pass(box missing_doc::MissingDoc::new());
+    reg.register_late_lint_pass(box missing_doc::MissingDoc::new(
+            conf.missing_docs_allow_unit_structs,
+            conf.missing_docs_allowed_visibilities.clone(),
+            conf.missing_docs_allow_tests,
+    ));
     reg.register_late_lint_pass(box missing_inline::MissingInline);
-    reg.register_late_lint_pass(box ok_if_let::Pass);
-    reg.register_late_lint_pass(box redundant_pattern_matching::Pass);
     reg.register_late_lint_pass(box partialeq_ne_impl::Pass);
     reg.register_early_lint_pass(box reference::Pass);
     reg.register_early_lint_pass(box reference::DerefPass);
     reg.register_early_lint_pass(box double_parens::DoubleParens);
     reg.register_late_lint_pass(box unused_io_amount::UnusedIoAmount);
     reg.register_late_lint_pass(box large_enum_variant::LargeEnumVariant::new(conf.enum_variant_size_threshold));
-    reg.register_late_lint_pass(box explicit_write::Pass);
     reg.register_late_lint_pass(box needless_pass_by_value::NeedlessPassByValue);
     reg.register_late_lint_pass(box trivially_copy_pass_by_ref::TriviallyCopyPassByRef::new(
             conf.trivial_copy_size_limit,
             &reg.sess.target,
     ));
-    reg.register_early_lint_pass(box literal_representation::LiteralDigitGrouping);
+    reg.register_early_lint_pass(box literal_representation::LiteralDigitGrouping::new(
+            conf.unreadable_literal_threshold,
+            conf.binary_literal_group_size,
+            conf.octal_literal_group_size,
+            conf.decimal_literal_group_size,
+            conf.hex_literal_group_size,
+            conf.hex_literal_case,
+    ));
     reg.register_early_lint_pass(box literal_representation::LiteralRepresentation::new(
             conf.literal_representation_threshold
     ));
     reg.register_late_lint_pass(box use_self::UseSelf);
-    reg.register_late_lint_pass(box bytecount::ByteCount);
     reg.register_late_lint_pass(box infinite_iter::Pass);
     reg.register_late_lint_pass(box inline_fn_without_body::Pass);
-    reg.register_late_lint_pass(box invalid_ref::InvalidRef);
     reg.register_late_lint_pass(box identity_conversion::IdentityConversion::default());
     reg.register_late_lint_pass(box types::ImplicitHasher);
-    reg.register_early_lint_pass(box const_static_lifetime::StaticConst);
+    reg.register_early_lint_pass(box const_static_lifetime::StaticConst::new(conf.msrv));
     reg.register_late_lint_pass(box fallible_impl_from::FallibleImplFrom);
-    reg.register_late_lint_pass(box replace_consts::ReplaceConsts);
     reg.register_late_lint_pass(box types::UnitArg);
-    reg.register_late_lint_pass(box double_comparison::Pass);
-    reg.register_late_lint_pass(box question_mark::Pass);
-    reg.register_late_lint_pass(box suspicious_trait_impl::SuspiciousImpl);
     reg.register_early_lint_pass(box cargo_common_metadata::Pass);
     reg.register_early_lint_pass(box multiple_crate_versions::Pass);
     reg.register_early_lint_pass(box wildcard_dependencies::Pass);
     reg.register_late_lint_pass(box map_unit_fn::Pass);
     reg.register_late_lint_pass(box infallible_destructuring_match::Pass);
     reg.register_late_lint_pass(box inherent_impl::Pass::default());
-    reg.register_late_lint_pass(box neg_cmp_op_on_partial_ord::NoNegCompOpForPartialOrd);
     reg.register_late_lint_pass(box unwrap::Pass);
-    reg.register_late_lint_pass(box duration_subsec::DurationSubsec);
-    reg.register_late_lint_pass(box default_trait_access::DefaultTraitAccess);
-    reg.register_late_lint_pass(box indexing_slicing::IndexingSlicing);
-    reg.register_late_lint_pass(box non_copy_const::NonCopyConst);
-    reg.register_late_lint_pass(box ptr_offset_with_cast::Pass);
+    reg.register_late_lint_pass(box non_copy_const::NonCopyConst::new(conf.ignore_interior_mutability.clone()));
+    reg.register_late_lint_pass(box ptr_offset_with_cast::Pass::new(conf.msrv));
     reg.register_late_lint_pass(box redundant_clone::RedundantClone);
     reg.register_late_lint_pass(box slow_vector_initialization::Pass);
-    reg.register_late_lint_pass(box types::RefToMut);
-    reg.register_late_lint_pass(box assertions_on_constants::AssertionsOnConstants);
-    reg.register_late_lint_pass(box missing_const_for_fn::MissingConstForFn);
+    reg.register_late_lint_pass(box missing_const_for_fn::MissingConstForFn::new(conf.msrv));
+    reg.register_early_lint_pass(box trait_bounds::TraitBounds::new(conf.duplicated_method_bounds_threshold));
+    reg.register_early_lint_pass(box mixed_impl_trait_args::MixedImplTraitArgs::new(
+            conf.prefer_impl_trait_in_args,
+            conf.msrv,
+    ));
+    reg.register_late_lint_pass(box monomorphization_bloat::MonomorphizationBloat::new(
+            conf.monomorphization_bloat_threshold
+    ));
+    reg.register_late_lint_pass(box boxed_trait_object_return::Pass::new(conf.msrv));
+    reg.register_late_lint_pass(box redundant_phantom_data::Pass);
+    reg.register_late_lint_pass(box default_only_trait::Pass::default());
+    reg.register_late_lint_pass(box redundant_turbofish::Pass::new(conf.shorten_turbofish));
+    reg.register_late_lint_pass(box unused_pure_result::UnusedPureResult::new(&conf.pure_methods));
+    reg.register_early_lint_pass(box module_lines::ModuleLines::new(
+            conf.too_many_lines_in_module_threshold,
+            conf.count_all_module_lines,
+    ));
+    reg.register_early_lint_pass(box excessive_nesting::ExcessiveNesting::new(conf.nesting_depth_threshold));
+    reg.register_late_lint_pass(box naming_rules::NamingRules::new(conf.naming_rules.clone()));
+    reg.register_late_lint_pass(box mutual_recursion::Pass);
+    reg.register_late_lint_pass(box no_mangle_with_rust_abi::Pass);
+    reg.register_late_lint_pass(box improper_extern_fn_types::Pass);
+    reg.register_late_lint_pass(box detached_thread::DetachedThread::new(
+            conf.daemon_thread_fns.iter().cloned().collect()
+    ));
+    reg.register_late_lint_pass(box unnecessary_visibility::Pass::default());
+    reg.register_late_lint_pass(box glob_imports::GlobImports::new(conf.glob_import_max_depth));
+    reg.register_late_lint_pass(box disallowed_methods::DisallowedMethods::new(conf.disallowed_methods.clone()));
+    reg.register_late_lint_pass(box disallowed_types::DisallowedTypes::new(conf.disallowed_types.clone()));
+    reg.register_late_lint_pass(box unbounded_reads::UnboundedReads::new(conf.untrusted_stream_types.clone()));
+    reg.register_late_lint_pass(box cognitive_complexity::CognitiveComplexity::new(conf.cognitive_complexity_threshold));
+    reg.register_late_lint_pass(box vec_init_clone::Pass);
+    reg.register_late_lint_pass(box unused_async::UnusedAsync);
+    reg.register_late_lint_pass(box immediately_dropped_lock::Pass);
+    reg.register_late_lint_pass(box blocking_in_async::BlockingInAsync::new(&conf.blocking_fns));
+    reg.register_late_lint_pass(box mismatched_field_init::Pass);
+    reg.register_late_lint_pass(box exhaustive_items::Pass::new(conf.msrv));
+    reg.register_late_lint_pass(box int128::Int128::new(&conf.serialization_derives));
+    reg.register_late_lint_pass(box derivable_impls::Pass::new(conf.msrv));
 
     reg.register_lint_group("clippy::restriction", Some("clippy_restriction"), vec![
         arithmetic::FLOAT_ARITHMETIC,
         arithmetic::INTEGER_ARITHMETIC,
         dbg_macro::DBG_MACRO,
+        detached_thread::DETACHED_THREAD,
         else_if_without_else::ELSE_IF_WITHOUT_ELSE,
+        exhaustive_items::EXHAUSTIVE_ENUMS,
+        exhaustive_items::EXHAUSTIVE_STRUCTS,
         implicit_return::IMPLICIT_RETURN,
         indexing_slicing::INDEXING_SLICING,
         inherent_impl::MULTIPLE_INHERENT_IMPL,
@@ -506,53 +617,91 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         matches::WILDCARD_ENUM_MATCH_ARM,
         mem_forget::MEM_FORGET,
         methods::CLONE_ON_REF_PTR,
+        methods::LOCK_UNWRAP,
+        methods::OPTION_EXPECT_USED,
         methods::OPTION_UNWRAP_USED,
+        methods::RESULT_EXPECT_USED,
         methods::RESULT_UNWRAP_USED,
         methods::WRONG_PUB_SELF_CONVENTION,
         misc::FLOAT_CMP_CONST,
         missing_doc::MISSING_DOCS_IN_PRIVATE_ITEMS,
         missing_inline::MISSING_INLINE_IN_PUBLIC_ITEMS,
+        needless_update::DEFAULTED_REQUIRED_FIELDS,
+        panic_in_result_fn::PANIC_IN_RESULT_FN,
+        panic_in_result_fn::UNWRAP_IN_RESULT,
         panic_unimplemented::UNIMPLEMENTED,
         shadow::SHADOW_REUSE,
         shadow::SHADOW_SAME,
         strings::STRING_ADD,
+        unbounded_reads::UNBOUNDED_READS,
+        undocumented_unsafe_blocks::UNDOCUMENTED_UNSAFE_BLOCKS,
         write::PRINT_STDOUT,
         write::USE_DEBUG,
     ]);
 
     reg.register_lint_group("clippy::pedantic", Some("clippy_pedantic"), vec![
         attrs::INLINE_ALWAYS,
+        await_holding_lock::AWAIT_HOLDING_LOCK,
+        blocking_in_async::BLOCKING_IN_ASYNC,
+        boxed_trait_object_return::BOXED_TRAIT_OBJECT_RETURN,
         copies::MATCH_SAME_ARMS,
         copy_iterator::COPY_ITERATOR,
+        default_only_trait::DEFAULT_ONLY_TRAIT,
         default_trait_access::DEFAULT_TRAIT_ACCESS,
         derive::EXPL_IMPL_CLONE_ON_COPY,
         doc::DOC_MARKDOWN,
+        doc::MISSING_ERRORS_DOC,
+        doc::MISSING_PANICS_DOC,
         empty_enum::EMPTY_ENUM,
         enum_glob_use::ENUM_GLOB_USE,
         enum_variants::MODULE_NAME_REPETITIONS,
         enum_variants::PUB_ENUM_VARIANT_NAMES,
+        eta_reduction::REDUNDANT_CLOSURE_FOR_METHOD_CALLS,
+        excessive_nesting::EXCESSIVE_NESTING,
+        format::SINGLE_VALUE_FORMAT,
+        functions::ADJACENT_SAME_TYPE_ARGUMENTS,
         functions::TOO_MANY_LINES,
+        glob_imports::DEEP_GLOB_IMPORTS,
+        glob_imports::SHADOWED_GLOB_IMPORTS,
         if_not_else::IF_NOT_ELSE,
         infinite_iter::MAYBE_INFINITE_ITER,
+        int128::INT128_IN_FFI,
+        int128::SERIALIZED_INT128,
         items_after_statements::ITEMS_AFTER_STATEMENTS,
+        large_futures::LARGE_FUTURES,
         literal_representation::LARGE_DIGIT_GROUPS,
         loops::EXPLICIT_INTO_ITER_LOOP,
         loops::EXPLICIT_ITER_LOOP,
+        loops::NEEDLESS_RANGE_LOOP_ZIP,
+        manual_digit_conversion::MANUAL_DIGIT_CONVERSION,
+        map_for_side_effects::MAP_FOR_SIDE_EFFECTS,
         matches::SINGLE_MATCH_ELSE,
         methods::FILTER_MAP,
+        methods::IMPLICIT_CLONE,
         methods::MAP_FLATTEN,
         methods::OPTION_MAP_UNWRAP_OR,
         methods::OPTION_MAP_UNWRAP_OR_ELSE,
         methods::RESULT_MAP_UNWRAP_OR_ELSE,
         misc::USED_UNDERSCORE_BINDING,
         misc_early::UNSEPARATED_LITERAL_SUFFIX,
+        mismatched_field_init::MISMATCHED_FIELD_INIT,
+        mixed_impl_trait_args::MIXED_IMPL_TRAIT_ARGS,
+        module_lines::TOO_MANY_LINES_IN_MODULE,
+        monomorphization_bloat::MONOMORPHIZATION_BLOAT,
         mut_mut::MUT_MUT,
         needless_continue::NEEDLESS_CONTINUE,
         needless_pass_by_value::NEEDLESS_PASS_BY_VALUE,
+        no_mangle_with_rust_abi::NO_MANGLE_WITH_RUST_ABI,
         non_expressive_names::SIMILAR_NAMES,
+        option_literal_cmp::OPTION_LITERAL_CMP,
+        redundant_turbofish::REDUNDANT_TURBOFISH,
         replace_consts::REPLACE_CONSTS,
         shadow::SHADOW_UNRELATED,
         strings::STRING_ADD_ASSIGN,
+        system_time::DURATION_SINCE_EPOCH_UNWRAP,
+        system_time::FORMATTED_INSTANT,
+        system_time::SYSTEM_TIME_ELAPSED,
+        time_subtraction::UNCHECKED_TIME_SUBTRACTION,
         types::CAST_POSSIBLE_TRUNCATION,
         types::CAST_POSSIBLE_WRAP,
         types::CAST_PRECISION_LOSS,
@@ -561,7 +710,12 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         types::LINKEDLIST,
         unicode::NON_ASCII_LITERAL,
         unicode::UNICODE_NOT_NFC,
+        unnecessary_visibility::UNNECESSARY_VISIBILITY,
+        unused_async::UNUSED_ASYNC,
+        unused_pure_result::UNUSED_PURE_RESULT,
         use_self::USE_SELF,
+        vec_init_clone::EXPENSIVE_CLONE_IN_VEC_INIT,
+        write::CONFUSING_FORMAT_ARGS,
     ]);
 
     reg.register_lint_group("clippy::internal", Some("clippy_internal"), vec![
@@ -589,12 +743,18 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         booleans::LOGIC_BUG,
         booleans::NONMINIMAL_BOOL,
         bytecount::NAIVE_BYTECOUNT,
+        chained_comparison::CHAINED_COMPARISONS,
         collapsible_if::COLLAPSIBLE_IF,
         const_static_lifetime::CONST_STATIC_LIFETIME,
         copies::IFS_SAME_COND,
         copies::IF_SAME_THEN_ELSE,
         cyclomatic_complexity::CYCLOMATIC_COMPLEXITY,
+        derivable_impls::DERIVABLE_IMPLS,
         derive::DERIVE_HASH_XOR_EQ,
+        derive::INCONSISTENT_CMP_FIELDS,
+        disallowed_methods::DISALLOWED_METHODS,
+        disallowed_types::DISALLOWED_TYPES,
+        doc::MISSING_SAFETY_DOC,
         double_comparison::DOUBLE_COMPARISONS,
         double_parens::DOUBLE_PARENS,
         drop_forget_ref::DROP_COPY,
@@ -604,17 +764,20 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         duration_subsec::DURATION_SUBSEC,
         entry::MAP_ENTRY,
         enum_clike::ENUM_CLIKE_UNPORTABLE_VARIANT,
+        enum_clike::OVERFLOWING_ENUM_DISCRIMINANT,
         enum_variants::ENUM_VARIANT_NAMES,
         enum_variants::MODULE_INCEPTION,
         eq_op::EQ_OP,
         eq_op::OP_REF,
         erasing_op::ERASING_OP,
         escape::BOXED_LOCAL,
+        escape::BOXED_TRAIT_OBJECT_ARG,
         eta_reduction::REDUNDANT_CLOSURE,
         eval_order_dependence::DIVERGING_SUB_EXPRESSION,
         eval_order_dependence::EVAL_ORDER_DEPENDENCE,
         excessive_precision::EXCESSIVE_PRECISION,
         explicit_write::EXPLICIT_WRITE,
+        format::UNWRAP_WRITE_TO_STRING,
         format::USELESS_FORMAT,
         formatting::POSSIBLE_MISSING_COMMA,
         formatting::SUSPICIOUS_ASSIGNMENT_FORMATTING,
@@ -623,6 +786,8 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         functions::TOO_MANY_ARGUMENTS,
         identity_conversion::IDENTITY_CONVERSION,
         identity_op::IDENTITY_OP,
+        immediately_dropped_lock::IMMEDIATELY_DROPPED_LOCK,
+        improper_extern_fn_types::IMPROPER_EXTERN_FN_TYPES,
         indexing_slicing::OUT_OF_BOUNDS_INDEXING,
         infallible_destructuring_match::INFALLIBLE_DESTRUCTURING_MATCH,
         infinite_iter::INFINITE_ITER,
@@ -635,6 +800,7 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         let_if_seq::USELESS_LET_IF_SEQ,
         lifetimes::EXTRA_UNUSED_LIFETIMES,
         lifetimes::NEEDLESS_LIFETIMES,
+        literal_representation::HEX_LITERAL_CASE,
         literal_representation::INCONSISTENT_DIGIT_GROUPING,
         literal_representation::MISTYPED_LITERAL_SUFFIXES,
         literal_representation::UNREADABLE_LITERAL,
@@ -651,20 +817,24 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         loops::NEVER_LOOP,
         loops::REVERSE_RANGE_LOOP,
         loops::UNUSED_COLLECT,
+        loops::VEC_LEN_MUTATED_IN_LOOP,
         loops::WHILE_IMMUTABLE_CONDITION,
         loops::WHILE_LET_LOOP,
         loops::WHILE_LET_ON_ITERATOR,
+        manual_filter::MANUAL_FILTER,
         map_clone::MAP_CLONE,
         map_unit_fn::OPTION_MAP_UNIT_FN,
         map_unit_fn::RESULT_MAP_UNIT_FN,
         matches::MATCH_AS_REF,
         matches::MATCH_BOOL,
+        matches::MATCH_LIKE_MATCHES_MACRO,
         matches::MATCH_OVERLAPPING_ARM,
         matches::MATCH_REF_PATS,
         matches::MATCH_WILD_ERR_ARM,
         matches::SINGLE_MATCH,
         mem_discriminant::MEM_DISCRIMINANT_NON_ENUM,
         mem_replace::MEM_REPLACE_OPTION_WITH_NONE,
+        methods::AND_THEN_INSTEAD_OF_MAP,
         methods::CHARS_LAST_CMP,
         methods::CHARS_NEXT_CMP,
         methods::CLONE_DOUBLE_REF,
@@ -680,6 +850,7 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         methods::NEW_RET_NO_SELF,
         methods::OK_EXPECT,
         methods::OPTION_MAP_OR_NONE,
+        methods::OR_ELSE_INSTEAD_OF_OR,
         methods::OR_FUN_CALL,
         methods::SEARCH_IS_SOME,
         methods::SHOULD_IMPLEMENT_TRAIT,
@@ -708,6 +879,8 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         misc_early::ZERO_PREFIXED_LITERAL,
         mut_reference::UNNECESSARY_MUT_PASSED,
         mutex_atomic::MUTEX_ATOMIC,
+        mutual_recursion::MUTUAL_RECURSION,
+        naming_rules::NAMING_RULES,
         needless_bool::BOOL_COMPARISON,
         needless_bool::NEEDLESS_BOOL,
         needless_borrowed_ref::NEEDLESS_BORROWED_REFERENCE,
@@ -726,10 +899,12 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         overflow_check_conditional::OVERFLOW_CHECK_CONDITIONAL,
         panic_unimplemented::PANIC_PARAMS,
         partialeq_ne_impl::PARTIALEQ_NE_IMPL,
+        path_join::JOIN_ABSOLUTE_PATHS,
         precedence::PRECEDENCE,
         ptr::CMP_NULL,
         ptr::MUT_FROM_REF,
         ptr::PTR_ARG,
+        ptr::PTR_EQ,
         ptr_offset_with_cast::PTR_OFFSET_WITH_CAST,
         question_mark::QUESTION_MARK,
         ranges::ITERATOR_STEP_BY_ZERO,
@@ -738,6 +913,7 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         ranges::RANGE_ZIP_WITH_LEN,
         redundant_field_names::REDUNDANT_FIELD_NAMES,
         redundant_pattern_matching::REDUNDANT_PATTERN_MATCHING,
+        redundant_phantom_data::REDUNDANT_PHANTOM_DATA,
         reference::DEREF_ADDROF,
         reference::REF_IN_DEREF,
         regex::INVALID_REGEX,
@@ -746,6 +922,7 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         returns::LET_AND_RETURN,
         returns::NEEDLESS_RETURN,
         returns::UNUSED_UNIT,
+        self_assignment::SELF_ASSIGNMENT,
         serde_api::SERDE_API_MISUSE,
         slow_vector_initialization::SLOW_VECTOR_INITIALIZATION,
         strings::STRING_LIT_AS_BYTES,
@@ -753,7 +930,10 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         suspicious_trait_impl::SUSPICIOUS_OP_ASSIGN_IMPL,
         swap::ALMOST_SWAPPED,
         swap::MANUAL_SWAP,
+        swapped_arguments::SWAPPED_ARGUMENTS,
         temporary_assignment::TEMPORARY_ASSIGNMENT,
+        trait_bounds::DUPLICATED_METHOD_BOUNDS,
+        trait_bounds::TYPE_REPETITION_IN_BOUNDS,
         transmute::CROSSPOINTER_TRANSMUTE,
         transmute::TRANSMUTE_BYTES_TO_STR,
         transmute::TRANSMUTE_INT_TO_BOOL,
@@ -786,6 +966,7 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         unused_io_amount::UNUSED_IO_AMOUNT,
         unused_label::UNUSED_LABEL,
         vec::USELESS_VEC,
+        vec_init_clone::RC_CLONE_IN_VEC_INIT,
         write::PRINTLN_EMPTY_STRING,
         write::PRINT_LITERAL,
         write::PRINT_WITH_NEWLINE,
@@ -805,17 +986,22 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         block_in_if_condition::BLOCK_IN_IF_CONDITION_STMT,
         collapsible_if::COLLAPSIBLE_IF,
         const_static_lifetime::CONST_STATIC_LIFETIME,
+        disallowed_methods::DISALLOWED_METHODS,
+        disallowed_types::DISALLOWED_TYPES,
+        doc::MISSING_SAFETY_DOC,
         enum_variants::ENUM_VARIANT_NAMES,
         enum_variants::MODULE_INCEPTION,
         eq_op::OP_REF,
         eta_reduction::REDUNDANT_CLOSURE,
         excessive_precision::EXCESSIVE_PRECISION,
+        format::UNWRAP_WRITE_TO_STRING,
         formatting::SUSPICIOUS_ASSIGNMENT_FORMATTING,
         formatting::SUSPICIOUS_ELSE_FORMATTING,
         infallible_destructuring_match::INFALLIBLE_DESTRUCTURING_MATCH,
         len_zero::LEN_WITHOUT_IS_EMPTY,
         len_zero::LEN_ZERO,
         let_if_seq::USELESS_LET_IF_SEQ,
+        literal_representation::HEX_LITERAL_CASE,
         literal_representation::INCONSISTENT_DIGIT_GROUPING,
         literal_representation::UNREADABLE_LITERAL,
         loops::EMPTY_LOOP,
@@ -824,6 +1010,7 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         loops::WHILE_LET_ON_ITERATOR,
         map_clone::MAP_CLONE,
         matches::MATCH_BOOL,
+        matches::MATCH_LIKE_MATCHES_MACRO,
         matches::MATCH_OVERLAPPING_ARM,
         matches::MATCH_REF_PATS,
         matches::MATCH_WILD_ERR_ARM,
@@ -850,14 +1037,17 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         misc_early::MIXED_CASE_HEX_LITERALS,
         misc_early::UNNEEDED_FIELD_PATTERN,
         mut_reference::UNNECESSARY_MUT_PASSED,
+        naming_rules::NAMING_RULES,
         neg_multiply::NEG_MULTIPLY,
         new_without_default::NEW_WITHOUT_DEFAULT,
         non_expressive_names::JUST_UNDERSCORES_AND_DIGITS,
         non_expressive_names::MANY_SINGLE_CHAR_NAMES,
         ok_if_let::IF_LET_SOME_RESULT,
         panic_unimplemented::PANIC_PARAMS,
+        path_join::JOIN_ABSOLUTE_PATHS,
         ptr::CMP_NULL,
         ptr::PTR_ARG,
+        ptr::PTR_EQ,
         question_mark::QUESTION_MARK,
         redundant_field_names::REDUNDANT_FIELD_NAMES,
         redundant_pattern_matching::REDUNDANT_PATTERN_MATCHING,
@@ -867,11 +1057,14 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         returns::NEEDLESS_RETURN,
         returns::UNUSED_UNIT,
         strings::STRING_LIT_AS_BYTES,
+        trait_bounds::DUPLICATED_METHOD_BOUNDS,
+        trait_bounds::TYPE_REPETITION_IN_BOUNDS,
         types::FN_TO_NUMERIC_CAST,
         types::FN_TO_NUMERIC_CAST_WITH_TRUNCATION,
         types::IMPLICIT_HASHER,
         types::LET_UNIT_VALUE,
         unsafe_removed_from_name::UNSAFE_REMOVED_FROM_NAME,
+        vec_init_clone::RC_CLONE_IN_VEC_INIT,
         write::PRINTLN_EMPTY_STRING,
         write::PRINT_LITERAL,
         write::PRINT_WITH_NEWLINE,
@@ -885,6 +1078,7 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         attrs::DEPRECATED_CFG_ATTR,
         booleans::NONMINIMAL_BOOL,
         cyclomatic_complexity::CYCLOMATIC_COMPLEXITY,
+        derivable_impls::DERIVABLE_IMPLS,
         double_comparison::DOUBLE_COMPARISONS,
         double_parens::DOUBLE_PARENS,
         duration_subsec::DURATION_SUBSEC,
@@ -901,12 +1095,15 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         loops::EXPLICIT_COUNTER_LOOP,
         loops::MUT_RANGE_BOUND,
         loops::WHILE_LET_LOOP,
+        manual_filter::MANUAL_FILTER,
         map_unit_fn::OPTION_MAP_UNIT_FN,
         map_unit_fn::RESULT_MAP_UNIT_FN,
         matches::MATCH_AS_REF,
+        methods::AND_THEN_INSTEAD_OF_MAP,
         methods::CHARS_NEXT_CMP,
         methods::CLONE_ON_COPY,
         methods::FILTER_NEXT,
+        methods::OR_ELSE_INSTEAD_OF_OR,
         methods::SEARCH_IS_SOME,
         methods::UNNECESSARY_FILTER_MAP,
         methods::USELESS_ASREF,
@@ -927,6 +1124,7 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         ranges::RANGE_MINUS_ONE,
         ranges::RANGE_PLUS_ONE,
         ranges::RANGE_ZIP_WITH_LEN,
+        redundant_phantom_data::REDUNDANT_PHANTOM_DATA,
         reference::DEREF_ADDROF,
         reference::REF_IN_DEREF,
         swap::MANUAL_SWAP,
@@ -958,18 +1156,23 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         bit_mask::BAD_BIT_MASK,
         bit_mask::INEFFECTIVE_BIT_MASK,
         booleans::LOGIC_BUG,
+        chained_comparison::CHAINED_COMPARISONS,
         copies::IFS_SAME_COND,
         copies::IF_SAME_THEN_ELSE,
         derive::DERIVE_HASH_XOR_EQ,
+        derive::INCONSISTENT_CMP_FIELDS,
         drop_forget_ref::DROP_COPY,
         drop_forget_ref::DROP_REF,
         drop_forget_ref::FORGET_COPY,
         drop_forget_ref::FORGET_REF,
         enum_clike::ENUM_CLIKE_UNPORTABLE_VARIANT,
+        enum_clike::OVERFLOWING_ENUM_DISCRIMINANT,
         eq_op::EQ_OP,
         erasing_op::ERASING_OP,
         formatting::POSSIBLE_MISSING_COMMA,
         functions::NOT_UNSAFE_PTR_ARG_DEREF,
+        immediately_dropped_lock::IMMEDIATELY_DROPPED_LOCK,
+        improper_extern_fn_types::IMPROPER_EXTERN_FN_TYPES,
         indexing_slicing::OUT_OF_BOUNDS_INDEXING,
         infinite_iter::INFINITE_ITER,
         inline_fn_without_body::INLINE_FN_WITHOUT_BODY,
@@ -980,6 +1183,7 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         loops::ITER_NEXT_LOOP,
         loops::NEVER_LOOP,
         loops::REVERSE_RANGE_LOOP,
+        loops::VEC_LEN_MUTATED_IN_LOOP,
         loops::WHILE_IMMUTABLE_CONDITION,
         mem_discriminant::MEM_DISCRIMINANT_NON_ENUM,
         methods::CLONE_DOUBLE_REF,
@@ -989,16 +1193,19 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         misc::CMP_NAN,
         misc::FLOAT_CMP,
         misc::MODULO_ONE,
+        mutual_recursion::MUTUAL_RECURSION,
         non_copy_const::BORROW_INTERIOR_MUTABLE_CONST,
         non_copy_const::DECLARE_INTERIOR_MUTABLE_CONST,
         open_options::NONSENSICAL_OPEN_OPTIONS,
         ptr::MUT_FROM_REF,
         ranges::ITERATOR_STEP_BY_ZERO,
         regex::INVALID_REGEX,
+        self_assignment::SELF_ASSIGNMENT,
         serde_api::SERDE_API_MISUSE,
         suspicious_trait_impl::SUSPICIOUS_ARITHMETIC_IMPL,
         suspicious_trait_impl::SUSPICIOUS_OP_ASSIGN_IMPL,
         swap::ALMOST_SWAPPED,
+        swapped_arguments::SWAPPED_ARGUMENTS,
         transmute::WRONG_TRANSMUTE,
         types::ABSURD_EXTREME_COMPARISONS,
         types::CAST_PTR_ALIGNMENT,
@@ -1012,6 +1219,7 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
         bytecount::NAIVE_BYTECOUNT,
         entry::MAP_ENTRY,
         escape::BOXED_LOCAL,
+        escape::BOXED_TRAIT_OBJECT_ARG,
         large_enum_variant::LARGE_ENUM_VARIANT,
         loops::MANUAL_MEMCPY,
         loops::NEEDLESS_COLLECT,
@@ -1036,6 +1244,7 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry<'_>, conf: &Conf) {
 
     reg.register_lint_group("clippy::nursery", Some("clippy_nursery"), vec![
         attrs::EMPTY_LINE_AFTER_OUTER_ATTR,
+        cognitive_complexity::COGNITIVE_COMPLEXITY,
         fallible_impl_from::FALLIBLE_IMPL_FROM,
         missing_const_for_fn::MISSING_CONST_FOR_FN,
         mutex_atomic::MUTEX_INTEGER,
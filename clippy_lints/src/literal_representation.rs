@@ -1,6 +1,7 @@
 //! Lints concerned with the grouping of digits with underscores in integral or
 //! floating-point literal expressions.
 
+use crate::utils::conf::HexLiteralCase;
 use crate::utils::{snippet_opt, span_lint_and_sugg};
 use if_chain::if_chain;
 use rustc::lint::{in_external_macro, EarlyContext, EarlyLintPass, LintArray, LintContext, LintPass};
@@ -14,6 +15,11 @@ use syntax_pos;
 ///
 /// **Why is this bad?** Reading long numbers is difficult without separators.
 ///
+/// The number of digits allowed without separators can be configured with
+/// `unreadable-literal-threshold`, and the suggested group sizes with
+/// `binary-literal-group-size`, `octal-literal-group-size`,
+/// `decimal-literal-group-size` and `hex-literal-group-size`.
+///
 /// **Known problems:** None.
 ///
 /// **Example:**
@@ -103,6 +109,26 @@ declare_clippy_lint! {
     "using decimal representation when hexadecimal would be better"
 }
 
+/// **What it does:** Warns if the digits of a hexadecimal literal don't use
+/// the case configured with `hex-literal-case` (`"upper"` or `"lower"`) in
+/// `clippy.toml`. Does nothing if no case is configured.
+///
+/// **Why is this bad?** Mixing both cases in a code base is inconsistent.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+///
+/// ```rust
+/// // with `hex-literal-case = "upper"`
+/// let x = 0xdead_beef;
+/// ```
+declare_clippy_lint! {
+    pub HEX_LITERAL_CASE,
+    style,
+    "hexadecimal literal digits not using the configured case"
+}
+
 #[derive(Debug, PartialEq)]
 pub(super) enum Radix {
     Binary,
@@ -113,7 +139,7 @@ pub(super) enum Radix {
 
 impl Radix {
     /// Return a reasonable digit group size for this radix.
-    crate fn suggest_grouping(&self) -> usize {
+    fn suggest_grouping(&self) -> usize {
         match *self {
             Radix::Binary | Radix::Hexadecimal => 4,
             Radix::Octal | Radix::Decimal => 3,
@@ -190,7 +216,11 @@ impl<'a> DigitInfo<'a> {
 
     /// Returns literal formatted in a sensible way.
     crate fn grouping_hint(&self) -> String {
-        let group_size = self.radix.suggest_grouping();
+        self.grouping_hint_by(self.radix.suggest_grouping())
+    }
+
+    /// Returns literal formatted with digits grouped by `group_size`.
+    fn grouping_hint_by(&self, group_size: usize) -> String {
         if self.digits.contains('.') {
             let mut parts = self.digits.split('.');
             let int_part_hint = parts
@@ -259,10 +289,15 @@ impl<'a> DigitInfo<'a> {
                 .rev()
                 .collect::<Vec<String>>()
                 .join("_");
-            // Forces hexadecimal values to be grouped by 4 being filled with zeroes (e.g 0x00ab_cdef)
-            let nb_digits_to_fill = filtered_digits_vec.len() % 4;
+            // Forces hexadecimal values to fill the first group with zeroes (e.g 0x00ab_cdef)
+            let nb_digits_to_fill = filtered_digits_vec.len() % group_size;
             if self.radix == Radix::Hexadecimal && nb_digits_to_fill != 0 {
-                hint = format!("{:0>4}{}", &hint[..nb_digits_to_fill], &hint[nb_digits_to_fill..]);
+                hint = format!(
+                    "{:0>width$}{}",
+                    &hint[..nb_digits_to_fill],
+                    &hint[nb_digits_to_fill..],
+                    width = group_size
+                );
             }
             let suffix_hint = match self.suffix {
                 Some(suffix) if is_mistyped_suffix(suffix) => format!("_i{}", &suffix[1..]),
@@ -280,10 +315,11 @@ enum WarningType {
     LargeDigitGroups,
     DecimalRepresentation,
     MistypedLiteralSuffix,
+    HexLiteralCase,
 }
 
 impl WarningType {
-    crate fn display(&self, grouping_hint: &str, cx: &EarlyContext<'_>, span: syntax_pos::Span) {
+    fn display(&self, grouping_hint: &str, cx: &EarlyContext<'_>, span: syntax_pos::Span) {
         match self {
             WarningType::MistypedLiteralSuffix => span_lint_and_sugg(
                 cx,
@@ -321,6 +357,15 @@ impl WarningType {
                 grouping_hint.to_owned(),
                 Applicability::MachineApplicable,
             ),
+            WarningType::HexLiteralCase => span_lint_and_sugg(
+                cx,
+                HEX_LITERAL_CASE,
+                span,
+                "hexadecimal literal digits don't use the configured case",
+                "consider",
+                grouping_hint.to_owned(),
+                Applicability::MachineApplicable,
+            ),
             WarningType::DecimalRepresentation => span_lint_and_sugg(
                 cx,
                 DECIMAL_LITERAL_REPRESENTATION,
@@ -335,7 +380,14 @@ impl WarningType {
 }
 
 #[derive(Copy, Clone)]
-pub struct LiteralDigitGrouping;
+pub struct LiteralDigitGrouping {
+    threshold: u64,
+    binary_group_size: u64,
+    octal_group_size: u64,
+    decimal_group_size: u64,
+    hex_group_size: u64,
+    hex_case: Option<HexLiteralCase>,
+}
 
 impl LintPass for LiteralDigitGrouping {
     fn get_lints(&self) -> LintArray {
@@ -344,6 +396,7 @@ impl LintPass for LiteralDigitGrouping {
             INCONSISTENT_DIGIT_GROUPING,
             LARGE_DIGIT_GROUPS,
             MISTYPED_LITERAL_SUFFIXES,
+            HEX_LITERAL_CASE,
         )
     }
 
@@ -365,6 +418,37 @@ impl EarlyLintPass for LiteralDigitGrouping {
 }
 
 impl LiteralDigitGrouping {
+    #[allow(clippy::adjacent_same_type_arguments)]
+    pub fn new(
+        threshold: u64,
+        binary_group_size: u64,
+        octal_group_size: u64,
+        decimal_group_size: u64,
+        hex_group_size: u64,
+        hex_case: Option<HexLiteralCase>,
+    ) -> Self {
+        Self {
+            threshold,
+            binary_group_size,
+            octal_group_size,
+            decimal_group_size,
+            hex_group_size,
+            hex_case,
+        }
+    }
+
+    /// Returns the configured digit group size for `radix`.
+    fn group_size(self, radix: &Radix) -> usize {
+        let size = match *radix {
+            Radix::Binary => self.binary_group_size,
+            Radix::Octal => self.octal_group_size,
+            Radix::Decimal => self.decimal_group_size,
+            Radix::Hexadecimal => self.hex_group_size,
+        };
+        // a group size of 0 would make the hint loop forever
+        size.max(1) as usize
+    }
+
     fn check_lit(self, cx: &EarlyContext<'_>, lit: &Lit) {
         match lit.node {
             LitKind::Int(..) => {
@@ -375,9 +459,13 @@ impl LiteralDigitGrouping {
                     if char::to_digit(firstch, 10).is_some();
                     then {
                         let digit_info = DigitInfo::new(&src, false);
-                        let _ = Self::do_lint(digit_info.digits, digit_info.suffix).map_err(|warning_type| {
-                            warning_type.display(&digit_info.grouping_hint(), cx, lit.span)
+                        let group_size = self.group_size(&digit_info.radix);
+                        let _ = self.do_lint(digit_info.digits, digit_info.suffix, group_size).map_err(|warning_type| {
+                            warning_type.display(&digit_info.grouping_hint_by(group_size), cx, lit.span)
                         });
+                        if digit_info.radix == Radix::Hexadecimal {
+                            self.check_hex_case(cx, &digit_info, lit);
+                        }
                     }
                 }
             },
@@ -389,6 +477,7 @@ impl LiteralDigitGrouping {
                     if char::to_digit(firstch, 10).is_some();
                     then {
                         let digit_info = DigitInfo::new(&src, true);
+                        let group_size = self.group_size(&digit_info.radix);
                         // Separate digits into integral and fractional parts.
                         let parts: Vec<&str> = digit_info
                             .digits
@@ -397,12 +486,12 @@ impl LiteralDigitGrouping {
 
                         // Lint integral and fractional parts separately, and then check consistency of digit
                         // groups if both pass.
-                        let _ = Self::do_lint(parts[0], digit_info.suffix)
+                        let _ = self.do_lint(parts[0], digit_info.suffix, group_size)
                             .map(|integral_group_size| {
                                 if parts.len() > 1 {
                                     // Lint the fractional part of literal just like integral part, but reversed.
                                     let fractional_part = &parts[1].chars().rev().collect::<String>();
-                                    let _ = Self::do_lint(fractional_part, None)
+                                    let _ = self.do_lint(fractional_part, None, group_size)
                                         .map(|fractional_group_size| {
                                             let consistent = Self::parts_consistent(integral_group_size,
                                                                                     fractional_group_size,
@@ -410,18 +499,21 @@ impl LiteralDigitGrouping {
                                                                                     parts[1].len());
                                                 if !consistent {
                                                     WarningType::InconsistentDigitGrouping.display(
-                                                        &digit_info.grouping_hint(),
+                                                        &digit_info.grouping_hint_by(group_size),
                                                         cx,
                                                         lit.span,
                                                     );
                                                 }
                                         })
-                                    .map_err(|warning_type| warning_type.display(&digit_info.grouping_hint(),
+                                    .map_err(|warning_type| warning_type.display(
+                                    &digit_info.grouping_hint_by(group_size),
                                     cx,
                                     lit.span));
                                 }
                             })
-                        .map_err(|warning_type| warning_type.display(&digit_info.grouping_hint(), cx, lit.span));
+                        .map_err(|warning_type| {
+                            warning_type.display(&digit_info.grouping_hint_by(group_size), cx, lit.span)
+                        });
                     }
                 }
             },
@@ -445,9 +537,33 @@ impl LiteralDigitGrouping {
         }
     }
 
+    /// Checks that the digits of a hexadecimal literal use the configured case.
+    fn check_hex_case(self, cx: &EarlyContext<'_>, digit_info: &DigitInfo<'_>, lit: &Lit) {
+        let (wrong_case, hint) = match self.hex_case {
+            Some(HexLiteralCase::Upper) => (
+                digit_info.digits.chars().any(|c| c.is_ascii_lowercase()),
+                digit_info.digits.to_ascii_uppercase(),
+            ),
+            Some(HexLiteralCase::Lower) => (
+                digit_info.digits.chars().any(|c| c.is_ascii_uppercase()),
+                digit_info.digits.to_ascii_lowercase(),
+            ),
+            None => return,
+        };
+        if wrong_case {
+            let hint = format!(
+                "{}{}{}",
+                digit_info.prefix.unwrap_or(""),
+                hint,
+                digit_info.suffix.unwrap_or("")
+            );
+            WarningType::HexLiteralCase.display(&hint, cx, lit.span);
+        }
+    }
+
     /// Performs lint on `digits` (no decimal point) and returns the group
     /// size on success or `WarningType` when emitting a warning.
-    fn do_lint(digits: &str, suffix: Option<&str>) -> Result<usize, WarningType> {
+    fn do_lint(self, digits: &str, suffix: Option<&str>, suggested_group_size: usize) -> Result<usize, WarningType> {
         if let Some(suffix) = suffix {
             if is_mistyped_suffix(suffix) {
                 return Err(WarningType::MistypedLiteralSuffix);
@@ -463,7 +579,7 @@ impl LiteralDigitGrouping {
 
         if underscore_positions.is_empty() {
             // Check if literal needs underscores.
-            if digits.len() > 5 {
+            if digits.len() as u64 > self.threshold {
                 Err(WarningType::UnreadableLiteral)
             } else {
                 Ok(0)
@@ -480,7 +596,7 @@ impl LiteralDigitGrouping {
 
             if !consistent {
                 return Err(WarningType::InconsistentDigitGrouping);
-            } else if group_size > 4 {
+            } else if group_size > suggested_group_size.max(4) {
                 return Err(WarningType::LargeDigitGroups);
             }
             Ok(group_size)
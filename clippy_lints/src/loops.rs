@@ -28,8 +28,9 @@ use syntax_pos::BytePos;
 use crate::utils::paths;
 use crate::utils::{
     get_enclosing_block, get_parent_expr, higher, is_integer_literal, is_refutable, last_path_segment,
-    match_trait_method, match_type, match_var, multispan_sugg, snippet, snippet_opt, snippet_with_applicability,
-    span_help_and_lint, span_lint, span_lint_and_sugg, span_lint_and_then, SpanlessEq,
+    match_trait_method, match_type, match_var, meets_msrv, msrvs, multispan_sugg, snippet, snippet_opt,
+    snippet_with_applicability, span_help_and_lint, span_lint, span_lint_and_sugg, span_lint_and_then, walk_ptrs_ty,
+    RustcVersion, SpanlessEq,
 };
 
 /// **What it does:** Checks for for-loops that manually copy items between
@@ -71,6 +72,33 @@ declare_clippy_lint! {
     "for-looping over a range of indices where an iterator over items would do"
 }
 
+/// **What it does:** Checks for looping over the range of `0..a.len()` just to
+/// get the values of two collections by index, like `a[i]` and `b[i]`.
+///
+/// **Why is this bad?** Zipping the iterators of both collections makes the
+/// intent more clear and is probably faster.
+///
+/// **Known problems:** If `b` is shorter than `a`, the loop panics, while the
+/// zipped iterators stop at the end of `b`.
+///
+/// **Example:**
+/// ```rust
+/// for i in 0..a.len() {
+///     println!("{} {}", a[i], b[i]);
+/// }
+/// ```
+/// Could be written as:
+/// ```rust
+/// for (x, y) in a.iter().zip(&b) {
+///     println!("{} {}", x, y);
+/// }
+/// ```
+declare_clippy_lint! {
+    pub NEEDLESS_RANGE_LOOP_ZIP,
+    pedantic,
+    "for-looping over a range of indices to index two collections where zipped iterators would do"
+}
+
 /// **What it does:** Checks for loops on `x.iter()` where `&x` will do, and
 /// suggests the latter.
 ///
@@ -228,21 +256,28 @@ declare_clippy_lint! {
 }
 
 /// **What it does:** Checks for using `collect()` on an iterator without using
-/// the result.
+/// the result, and for using `count()` on a `map` or `inspect` iterator
+/// without using the count.
 ///
-/// **Why is this bad?** It is more idiomatic to use a `for` loop over the
-/// iterator instead.
+/// **Why is this bad?** The iterator is only consumed to run the side effects
+/// of its closures. It is more idiomatic to use a `for` loop over the
+/// iterator or `for_each` instead, and `collect()` allocates.
 ///
 /// **Known problems:** None.
 ///
 /// **Example:**
 /// ```rust
 /// vec.iter().map(|x| /* some operation returning () */).collect::<Vec<_>>();
+/// vec.iter().map(|x| /* some operation returning () */).count();
+/// ```
+/// Could be written as:
+/// ```rust
+/// vec.iter().for_each(|x| /* some operation returning () */);
 /// ```
 declare_clippy_lint! {
     pub UNUSED_COLLECT,
     perf,
-    "`collect()`ing an iterator without using the result; this is usually better written as a for loop"
+    "`collect()`ing or `count()`ing an iterator without using the result; this is usually better written as a for loop"
 }
 
 /// **What it does:** Checks for functions collecting an iterator when collect
@@ -439,14 +474,56 @@ declare_clippy_lint! {
     "variables used within while expression are not mutated in the body"
 }
 
+/// **What it does:** Checks for `for` loops over `0..v.len()` that remove
+/// elements from the `Vec` `v` or insert elements into it in their body.
+/// Elements added at the end with `push`, `extend` or `append` aren't visited,
+/// which is usually intended, so they aren't linted. Neither are loops which
+/// read `v.len()` again, e.g. to check the index.
+///
+/// **Why is this bad?** The range is evaluated once, before the loop starts.
+/// Removing elements shifts the following elements down, so the next one is
+/// skipped and the last indices are out of bounds; inserting elements shifts
+/// them up, so the same element is visited again.
+///
+/// **Known problems:** Mutations directly followed by `break` or `return` are
+/// allowed, but other ways of leaving the loop right after the mutation aren't
+/// recognized.
+///
+/// **Example:**
+/// ```rust
+/// for i in 0..v.len() {
+///     if v[i] == 0 {
+///         v.remove(i);
+///     }
+/// }
+/// ```
+/// Could be written as:
+/// ```rust
+/// v.retain(|&x| x != 0);
+/// ```
+declare_clippy_lint! {
+    pub VEC_LEN_MUTATED_IN_LOOP,
+    correctness,
+    "changing the length of a `Vec` while looping over its indices"
+}
+
 #[derive(Copy, Clone)]
-pub struct Pass;
+pub struct Pass {
+    msrv: Option<RustcVersion>,
+}
+
+impl Pass {
+    pub fn new(msrv: Option<RustcVersion>) -> Self {
+        Self { msrv }
+    }
+}
 
 impl LintPass for Pass {
     fn get_lints(&self) -> LintArray {
         lint_array!(
             MANUAL_MEMCPY,
             NEEDLESS_RANGE_LOOP,
+            NEEDLESS_RANGE_LOOP_ZIP,
             EXPLICIT_ITER_LOOP,
             EXPLICIT_INTO_ITER_LOOP,
             ITER_NEXT_LOOP,
@@ -463,6 +540,7 @@ impl LintPass for Pass {
             NEVER_LOOP,
             MUT_RANGE_BOUND,
             WHILE_IMMUTABLE_CONDITION,
+            VEC_LEN_MUTATED_IN_LOOP,
         )
     }
 
@@ -604,20 +682,70 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
     fn check_stmt(&mut self, cx: &LateContext<'a, 'tcx>, stmt: &'tcx Stmt) {
         if let StmtKind::Semi(ref expr) = stmt.node {
             if let ExprKind::MethodCall(ref method, _, ref args) = expr.node {
-                if args.len() == 1 && method.ident.name == "collect" && match_trait_method(cx, expr, &paths::ITERATOR) {
-                    span_lint(
-                        cx,
-                        UNUSED_COLLECT,
-                        expr.span,
-                        "you are collect()ing an iterator and throwing away the result. \
-                         Consider using an explicit for loop to exhaust the iterator",
-                    );
+                if args.len() == 1 && match_trait_method(cx, expr, &paths::ITERATOR) {
+                    let name = method.ident.name;
+                    // counting only runs side effects if there are closures to run
+                    if name == "collect" || (name == "count" && adaptor_args(&args[0], &["map", "inspect"]).is_some()) {
+                        check_unused_consumer(cx, expr, &args[0], &name.as_str(), self.msrv);
+                    }
                 }
             }
         }
     }
 }
 
+/// Returns the arguments of `expr` if it's a call of one of the iterator
+/// adaptors `names`, which take a closure.
+fn adaptor_args<'e>(expr: &'e Expr, names: &[&str]) -> Option<&'e [Expr]> {
+    match expr.node {
+        ExprKind::MethodCall(ref method, _, ref args)
+            if args.len() == 2 && names.iter().any(|name| method.ident.name == *name) =>
+        {
+            Some(args)
+        },
+        _ => None,
+    }
+}
+
+/// Lints the statement `expr`, calling `consumer` on `iter` and throwing away
+/// the result, and suggests `for_each` if `iter` maps to `()`.
+fn check_unused_consumer<'a, 'tcx>(
+    cx: &LateContext<'a, 'tcx>,
+    expr: &'tcx Expr,
+    iter: &'tcx Expr,
+    consumer: &str,
+    msrv: Option<RustcVersion>,
+) {
+    let msg = format!(
+        "you are {}()ing an iterator and throwing away the result. \
+         Consider using an explicit for loop to exhaust the iterator",
+        consumer
+    );
+    span_lint_and_then(cx, UNUSED_COLLECT, expr.span, &msg, |db| {
+        if_chain! {
+            if let Some(map_args) = adaptor_args(iter, &["map"]);
+            if match_trait_method(cx, iter, &paths::ITERATOR);
+            if let ExprKind::Closure(_, _, body_id, _, _) = map_args[1].node;
+            // `for_each` takes closures returning `()` only
+            if cx.tables.expr_ty(&cx.tcx.hir().body(body_id).value).is_unit();
+            if meets_msrv(cx.tcx, expr.id, msrv, msrvs::ITERATOR_FOR_EACH);
+            then {
+                let mut applicability = Applicability::MachineApplicable;
+                db.span_suggestion(
+                    expr.span,
+                    "or use `for_each`",
+                    format!(
+                        "{}.for_each({})",
+                        snippet_with_applicability(cx, map_args[0].span, "..", &mut applicability),
+                        snippet_with_applicability(cx, map_args[1].span, "..", &mut applicability)
+                    ),
+                    applicability,
+                );
+            }
+        }
+    });
+}
+
 enum NeverLoopResult {
     // A break/return always get triggered but not necessarily for the main loop.
     AlwaysBreak,
@@ -780,6 +908,7 @@ fn check_for_loop<'a, 'tcx>(
     check_for_loop_explicit_counter(cx, arg, body, expr);
     check_for_loop_over_map_kv(cx, pat, arg, body, expr);
     check_for_mut_range_bound(cx, arg, body);
+    check_for_vec_len_mutation(cx, arg, body);
     detect_manual_memcpy(cx, pat, arg, body, expr);
 }
 
@@ -1014,9 +1143,9 @@ fn detect_manual_memcpy<'a, 'tcx>(
                     let end_str = match limits {
                         ast::RangeLimits::Closed => {
                             let end = sugg::Sugg::hir(cx, end, "<count>");
-                            format!("{}", end + sugg::ONE)
+                            (end + sugg::ONE).to_string()
                         },
-                        ast::RangeLimits::HalfOpen => format!("{}", snippet(cx, end.span, "..")),
+                        ast::RangeLimits::HalfOpen => snippet(cx, end.span, "..").into_owned(),
                     };
 
                     print_sum(&Offset::positive(end_str), &offset)
@@ -1099,6 +1228,11 @@ fn check_for_loop_range<'a, 'tcx>(
             };
             walk_expr(&mut visitor, body);
 
+            if visitor.indexed_indirectly.is_empty() && visitor.indexed_directly.len() == 2 {
+                check_for_loop_range_zip(cx, pat, arg, expr, &visitor);
+                return;
+            }
+
             // linting condition: we only indexed one variable, and indexed it directly
             if visitor.indexed_indirectly.is_empty() && visitor.indexed_directly.len() == 1 {
                 let (indexed, (indexed_extent, indexed_ty)) = visitor
@@ -1230,6 +1364,97 @@ fn check_for_loop_range<'a, 'tcx>(
     }
 }
 
+/// Implementation of `NEEDLESS_RANGE_LOOP_ZIP`, for loops over `0..a.len()`
+/// which index `a` and one other collection directly with the loop variable.
+fn check_for_loop_range_zip<'a, 'tcx>(
+    cx: &LateContext<'a, 'tcx>,
+    pat: &'tcx Pat,
+    arg: &'tcx Expr,
+    expr: &'tcx Expr,
+    visitor: &VarVisitor<'a, 'tcx>,
+) {
+    if visitor.nonindex || !visitor.indexed_mut.is_empty() {
+        return;
+    }
+    let end = match higher::range(cx, arg) {
+        Some(higher::Range {
+            start: Some(start),
+            end: Some(end),
+            limits: ast::RangeLimits::HalfOpen,
+        }) if is_integer_literal(start, 0) => end,
+        _ => return,
+    };
+    let ident = match pat.node {
+        PatKind::Binding(_, _, ident, _) => ident,
+        _ => return,
+    };
+
+    // `a` is the collection whose length bounds the range
+    let names: Vec<Name> = visitor.indexed_directly.keys().cloned().collect();
+    let (a, b) = if is_len_call(end, names[0]) {
+        (names[0], names[1])
+    } else if is_len_call(end, names[1]) {
+        (names[1], names[0])
+    } else {
+        return;
+    };
+    if visitor.referenced.contains(&a) || visitor.referenced.contains(&b) {
+        return;
+    }
+    let (a_extent, a_ty) = visitor.indexed_directly[&a];
+    let (b_extent, b_ty) = visitor.indexed_directly[&b];
+    if !is_indexable_sequence(cx, a_ty) || !is_indexable_sequence(cx, b_ty) {
+        return;
+    }
+
+    // ensure that the indexed variables were declared before the loop, see #601
+    let parent_id = cx.tcx.hir().get_parent(expr.id);
+    let parent_def_id = cx.tcx.hir().local_def_id(parent_id);
+    let region_scope_tree = cx.tcx.region_scope_tree(parent_def_id);
+    let pat_extent = region_scope_tree.var_scope(pat.hir_id.local_id);
+    let declared_in_loop = |extent: Option<region::Scope>| {
+        extent.map_or(false, |extent| region_scope_tree.is_subscope_of(extent, pat_extent))
+    };
+    if declared_in_loop(a_extent) || declared_in_loop(b_extent) {
+        return;
+    }
+
+    let other = if let ty::Ref(..) = b_ty.sty {
+        format!("{}.iter()", b)
+    } else {
+        format!("&{}", b)
+    };
+    span_lint_and_then(
+        cx,
+        NEEDLESS_RANGE_LOOP_ZIP,
+        expr.span,
+        &format!(
+            "the loop variable `{}` is only used to index `{}` and `{}`",
+            ident.name, a, b
+        ),
+        |db| {
+            multispan_sugg(
+                db,
+                "consider zipping the iterators".to_string(),
+                vec![
+                    (pat.span, format!("(<{} item>, <{} item>)", a, b)),
+                    (arg.span, format!("{}.iter().zip({})", a, other)),
+                ],
+            );
+        },
+    );
+}
+
+/// Checks whether `ty` is an array, a slice, a `Vec` or a `VecDeque`, or a
+/// reference to one, which are indexed by position.
+fn is_indexable_sequence(cx: &LateContext<'_, '_>, ty: Ty<'_>) -> bool {
+    let ty = walk_ptrs_ty(ty);
+    match ty.sty {
+        ty::Array(..) | ty::Slice(_) => true,
+        _ => match_type(cx, ty, &paths::VEC) || match_type(cx, ty, &paths::VEC_DEQUE),
+    }
+}
+
 fn is_len_call(expr: &Expr, var: Name) -> bool {
     if_chain! {
         if let ExprKind::MethodCall(ref method, _, ref len_args) = expr.node;
@@ -1666,6 +1891,111 @@ fn check_for_mutation(
     delegate.mutation_span()
 }
 
+/// Methods of `Vec` that change the indices of its elements. Adding elements at
+/// the end doesn't.
+const LEN_CHANGING_METHODS: [&str; 10] = [
+    "clear",
+    "dedup",
+    "drain",
+    "insert",
+    "pop",
+    "remove",
+    "resize",
+    "retain",
+    "swap_remove",
+    "truncate",
+];
+
+fn check_for_vec_len_mutation(cx: &LateContext<'_, '_>, arg: &Expr, body: &Expr) {
+    if_chain! {
+        if let Some(higher::Range { end: Some(end), .. }) = higher::range(cx, arg);
+        if let ExprKind::MethodCall(ref method, _, ref len_args) = end.node;
+        if method.ident.name == "len" && len_args.len() == 1;
+        if match_type(cx, walk_ptrs_ty(cx.tables.expr_ty(&len_args[0])), &paths::VEC);
+        if let Some(var) = var_def_id(cx, &len_args[0]);
+        then {
+            let mut visitor = LenMutationVisitor {
+                cx,
+                var,
+                spans: Vec::new(),
+                reads_len: false,
+            };
+            walk_expr(&mut visitor, body);
+            if visitor.reads_len {
+                return;
+            }
+            for span in visitor.spans {
+                span_lint_and_then(
+                    cx,
+                    VEC_LEN_MUTATED_IN_LOOP,
+                    span,
+                    &format!(
+                        "the length of `{}` changes while looping over its indices",
+                        snippet(cx, len_args[0].span, "..")
+                    ),
+                    |db| {
+                        db.span_note(
+                            arg.span,
+                            "this range is only evaluated once, so elements are skipped or indexed out of bounds",
+                        );
+                        db.help("consider using a `while` loop that updates the index explicitly, or `retain`/`drain`");
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Collects the calls of methods changing the length of `var`, except those
+/// directly followed by `break` or `return`.
+struct LenMutationVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    var: NodeId,
+    spans: Vec<Span>,
+    /// whether `var.len()` is called, the loop likely checks the index then
+    reads_len: bool,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for LenMutationVisitor<'a, 'tcx> {
+    fn visit_block(&mut self, block: &'tcx Block) {
+        for (i, stmt) in block.stmts.iter().enumerate() {
+            let next = match block.stmts.get(i + 1) {
+                Some(next) => stmt_to_expr(next),
+                None => block.expr.as_ref().map(|expr| &**expr),
+            };
+            let exits = next.map_or(false, |next| match next.node {
+                ExprKind::Break(..) | ExprKind::Ret(..) => true,
+                _ => false,
+            });
+            if !exits {
+                walk_stmt(self, stmt);
+            }
+        }
+        if let Some(ref expr) = block.expr {
+            self.visit_expr(expr);
+        }
+    }
+
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        if_chain! {
+            if let ExprKind::MethodCall(ref method, _, ref args) = expr.node;
+            if var_def_id(self.cx, &args[0]) == Some(self.var);
+            then {
+                if method.ident.name == "len" {
+                    self.reads_len = true;
+                } else if LEN_CHANGING_METHODS.contains(&&*method.ident.as_str()) {
+                    self.spans.push(expr.span);
+                }
+            }
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
+
 /// Return true if the pattern is a `PatWild` or an ident prefixed with `'_'`.
 fn pat_is_wild<'tcx>(pat: &'tcx PatKind, body: &'tcx Expr) -> bool {
     match *pat {
@@ -0,0 +1,147 @@
+//! lint on converting between digits and `char`s with ASCII arithmetic
+
+use crate::utils::sugg::Sugg;
+use crate::utils::{in_constant, in_macro, snippet_with_applicability, span_lint_and_sugg};
+use if_chain::if_chain;
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::ty;
+use rustc::{declare_tool_lint, lint_array};
+use rustc_errors::Applicability;
+use syntax::ast::{LitKind, UintTy};
+
+/// **What it does:** Checks for digits converted to `char`s by adding them to
+/// `b'0'`, like `(b'0' + n) as char`, and for `char`s converted to digits by
+/// subtracting `b'0'`, like `c as u8 - b'0'`. Constants aren't linted.
+///
+/// **Why is this bad?** `std::char::from_digit` and `char::to_digit` state the
+/// intent, and check the value instead of silently producing another `char`
+/// or a wrapped number. If the value is known to be a digit, e.g. after
+/// checking it with `is_ascii_digit`, the `unwrap` documents that.
+///
+/// **Known problems:** The arithmetic is cheaper, which may matter in hot
+/// code.
+///
+/// **Example:**
+/// ```rust
+/// let c = (b'0' + n) as char;
+/// let n = c as u8 - b'0';
+/// ```
+/// Could be written as:
+/// ```rust
+/// let c = std::char::from_digit(u32::from(n), 10).unwrap();
+/// let n = c.to_digit(10).unwrap() as u8;
+/// ```
+declare_clippy_lint! {
+    pub MANUAL_DIGIT_CONVERSION,
+    pedantic,
+    "converting between digits and `char`s with ASCII arithmetic"
+}
+
+#[derive(Copy, Clone)]
+pub struct Pass;
+
+impl LintPass for Pass {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(MANUAL_DIGIT_CONVERSION)
+    }
+
+    fn name(&self) -> &'static str {
+        "ManualDigitConversion"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if in_macro(expr.span) || in_constant(cx, expr.id) {
+            return;
+        }
+        check_digit_to_char(cx, expr);
+        check_char_to_digit(cx, expr);
+    }
+}
+
+/// Lints `(b'0' + n) as char`.
+fn check_digit_to_char(cx: &LateContext<'_, '_>, expr: &Expr) {
+    if_chain! {
+        if let ExprKind::Cast(ref inner, _) = expr.node;
+        if let ty::Char = cx.tables.expr_ty(expr).sty;
+        if let Some(digit) = added_digit(inner);
+        then {
+            let mut applicability = Applicability::MaybeIncorrect;
+            let digit = snippet_with_applicability(cx, digit.span, "..", &mut applicability);
+            span_lint_and_sugg(
+                cx,
+                MANUAL_DIGIT_CONVERSION,
+                expr.span,
+                "converting a digit to a `char` with ASCII arithmetic",
+                "use `std::char::from_digit`",
+                format!("std::char::from_digit(u32::from({}), 10).unwrap()", digit),
+                applicability,
+            );
+        }
+    }
+}
+
+/// Lints `c as u8 - b'0'`.
+fn check_char_to_digit(cx: &LateContext<'_, '_>, expr: &Expr) {
+    if_chain! {
+        if let ExprKind::Binary(op, ref left, ref right) = expr.node;
+        if op.node == BinOpKind::Sub;
+        if is_zero_digit(right);
+        if let ExprKind::Cast(ref c, _) = left.node;
+        if let ty::Char = cx.tables.expr_ty(c).sty;
+        then {
+            let mut applicability = Applicability::MaybeIncorrect;
+            let c = Sugg::hir_with_applicability(cx, c, "..", &mut applicability).maybe_par();
+            let digit_ty = cx.tables.expr_ty(expr);
+            let sugg = match digit_ty.sty {
+                ty::Uint(UintTy::U32) => format!("{}.to_digit(10).unwrap()", c),
+                _ => format!("{}.to_digit(10).unwrap() as {}", c, digit_ty),
+            };
+            span_lint_and_sugg(
+                cx,
+                MANUAL_DIGIT_CONVERSION,
+                expr.span,
+                "converting a `char` to a digit with ASCII arithmetic",
+                "use `char::to_digit`",
+                sugg,
+                applicability,
+            );
+        }
+    }
+}
+
+/// Returns the digit `expr` adds to `b'0'`.
+fn added_digit(expr: &Expr) -> Option<&Expr> {
+    match expr.node {
+        ExprKind::Binary(op, ref left, ref right) if op.node == BinOpKind::Add => {
+            if is_zero_digit(left) {
+                Some(right)
+            } else if is_zero_digit(right) {
+                Some(left)
+            } else {
+                None
+            }
+        },
+        _ => None,
+    }
+}
+
+/// Checks whether `expr` is `b'0'` or `'0'` cast to an integer.
+fn is_zero_digit(expr: &Expr) -> bool {
+    match expr.node {
+        ExprKind::Lit(ref lit) => match lit.node {
+            LitKind::Byte(b'0') => true,
+            _ => false,
+        },
+        ExprKind::Cast(ref inner, _) => match inner.node {
+            ExprKind::Lit(ref lit) => match lit.node {
+                LitKind::Char('0') => true,
+                _ => false,
+            },
+            _ => false,
+        },
+        _ => false,
+    }
+}
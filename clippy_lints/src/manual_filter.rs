@@ -0,0 +1,244 @@
+//! lint on manual implementations of `Option::filter`
+
+use crate::utils::sugg::Sugg;
+use crate::utils::{
+    in_macro, is_copy, match_qpath, match_type, match_var, meets_msrv, msrvs, paths, snippet_with_applicability,
+    span_lint_and_sugg, RustcVersion,
+};
+use if_chain::if_chain;
+use rustc::hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::{declare_tool_lint, lint_array};
+use rustc_errors::Applicability;
+use syntax::ast::Ident;
+
+/// **What it does:** Checks for `if let Some(x) = opt` and `match`
+/// expressions, and `and_then` closures, which return `Some(x)` if a
+/// condition holds and `None` otherwise.
+///
+/// **Why is this bad?** This is what `Option::filter` does.
+///
+/// **Known problems:** `filter` passes a reference to the value, so the
+/// condition may need to be changed if the value isn't `Copy`.
+///
+/// **Example:**
+/// ```rust
+/// if let Some(x) = opt {
+///     if x > 2 {
+///         Some(x)
+///     } else {
+///         None
+///     }
+/// } else {
+///     None
+/// };
+/// opt.and_then(|x| if x > 2 { Some(x) } else { None });
+/// ```
+/// Could be written as:
+/// ```rust
+/// opt.filter(|&x| x > 2);
+/// opt.filter(|&x| x > 2);
+/// ```
+declare_clippy_lint! {
+    pub MANUAL_FILTER,
+    complexity,
+    "implementing `Option::filter` with `if let`, `match` or `and_then`"
+}
+
+#[derive(Copy, Clone)]
+pub struct Pass {
+    msrv: Option<RustcVersion>,
+}
+
+impl Pass {
+    pub fn new(msrv: Option<RustcVersion>) -> Self {
+        Self { msrv }
+    }
+}
+
+impl LintPass for Pass {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(MANUAL_FILTER)
+    }
+
+    fn name(&self) -> &'static str {
+        "ManualFilter"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if in_macro(expr.span) {
+            return;
+        }
+        let (option, binding, cond) = match expr.node {
+            ExprKind::Match(ref scrutinee, ref arms, MatchSource::Normal)
+            | ExprKind::Match(ref scrutinee, ref arms, MatchSource::IfLetDesugar { .. }) => {
+                match filtering_match(arms) {
+                    Some((binding, cond)) => (&**scrutinee, binding, cond),
+                    None => return,
+                }
+            },
+            ExprKind::MethodCall(ref method, _, ref args) if method.ident.name == "and_then" && args.len() == 2 => {
+                match filtering_closure(cx, &args[1]) {
+                    Some((binding, cond)) => (&args[0], binding, cond),
+                    None => return,
+                }
+            },
+            _ => return,
+        };
+        if !match_type(cx, cx.tables.expr_ty(option), &paths::OPTION) || has_control_flow(cond) {
+            return;
+        }
+        if !meets_msrv(cx.tcx, expr.id, self.msrv, msrvs::OPTION_FILTER) {
+            return;
+        }
+
+        // with `|&x|`, the condition sees the same type it did before
+        let mut applicability = Applicability::MachineApplicable;
+        let pat = if is_copy(cx, cx.tables.pat_ty(binding)) {
+            "&"
+        } else {
+            applicability = Applicability::MaybeIncorrect;
+            ""
+        };
+        let ident = match binding_name(binding) {
+            Some(ident) => ident,
+            None => return,
+        };
+        let option = Sugg::hir_with_applicability(cx, option, "..", &mut applicability).maybe_par();
+        let cond = snippet_with_applicability(cx, cond.span, "..", &mut applicability);
+        span_lint_and_sugg(
+            cx,
+            MANUAL_FILTER,
+            expr.span,
+            "manual implementation of `Option::filter`",
+            "use `filter` instead",
+            format!("{}.filter(|{}{}| {})", option, pat, ident, cond),
+            applicability,
+        );
+    }
+}
+
+/// Returns the binding of the `Some(x)` arm and the condition of the match
+/// `arms`, if one arm is `Some(x) => if cond { Some(x) } else { None }` and
+/// the other one returns `None`.
+fn filtering_match(arms: &[Arm]) -> Option<(&Pat, &Expr)> {
+    if arms.len() != 2 || arms.iter().any(|arm| arm.pats.len() != 1 || arm.guard.is_some()) {
+        return None;
+    }
+    let (some_arm, none_arm) = if is_some_pat(&arms[0].pats[0]).is_some() {
+        (&arms[0], &arms[1])
+    } else {
+        (&arms[1], &arms[0])
+    };
+    let binding = is_some_pat(&some_arm.pats[0])?;
+    match none_arm.pats[0].node {
+        PatKind::Wild => {},
+        PatKind::Path(ref qpath) if match_qpath(qpath, &paths::OPTION_NONE) => {},
+        _ => return None,
+    }
+    if !is_none(&none_arm.body) {
+        return None;
+    }
+    let cond = filtering_if(&some_arm.body, binding_name(binding)?)?;
+    Some((binding, cond))
+}
+
+/// Returns the argument and the condition of the closure `expr`, if it's
+/// `|x| if cond { Some(x) } else { None }`.
+fn filtering_closure<'e>(cx: &LateContext<'_, 'e>, expr: &Expr) -> Option<(&'e Pat, &'e Expr)> {
+    if let ExprKind::Closure(_, _, body_id, _, _) = expr.node {
+        let body = cx.tcx.hir().body(body_id);
+        if body.arguments.len() == 1 {
+            let binding = &body.arguments[0].pat;
+            let cond = filtering_if(&body.value, binding_name(binding)?)?;
+            return Some((binding, cond));
+        }
+    }
+    None
+}
+
+/// Returns the condition of `expr` if it's `if cond { Some(x) } else { None }`,
+/// where `x` is `binding`.
+fn filtering_if(expr: &Expr, binding: Ident) -> Option<&Expr> {
+    if_chain! {
+        if let ExprKind::If(ref cond, ref then, Some(ref els)) = peel_blocks(expr).node;
+        if let ExprKind::Call(ref fun, ref args) = peel_blocks(then).node;
+        if let ExprKind::Path(ref qpath) = fun.node;
+        if match_qpath(qpath, &paths::OPTION_SOME) && args.len() == 1;
+        if match_var(&args[0], binding.name);
+        if is_none(els);
+        then {
+            return Some(cond);
+        }
+    }
+    None
+}
+
+/// Returns the binding of the pattern `Some(x)`.
+fn is_some_pat(pat: &Pat) -> Option<&Pat> {
+    match pat.node {
+        PatKind::TupleStruct(ref qpath, ref pats, None)
+            if pats.len() == 1 && match_qpath(qpath, &paths::OPTION_SOME) =>
+        {
+            binding_name(&pats[0]).map(|_| &*pats[0])
+        },
+        _ => None,
+    }
+}
+
+/// Returns the name `pat` binds, if it's a plain binding like `x`.
+fn binding_name(pat: &Pat) -> Option<Ident> {
+    match pat.node {
+        PatKind::Binding(BindingAnnotation::Unannotated, _, ident, None) => Some(ident),
+        _ => None,
+    }
+}
+
+fn is_none(expr: &Expr) -> bool {
+    match peel_blocks(expr).node {
+        ExprKind::Path(ref qpath) => match_qpath(qpath, &paths::OPTION_NONE),
+        _ => false,
+    }
+}
+
+/// Returns the expression of blocks without statements, like `{ None }`.
+fn peel_blocks(expr: &Expr) -> &Expr {
+    match expr.node {
+        ExprKind::Block(ref block, None) if block.stmts.is_empty() && block.rules == DefaultBlock => {
+            block.expr.as_ref().map_or(expr, |inner| peel_blocks(inner))
+        },
+        _ => expr,
+    }
+}
+
+/// Checks whether `expr` contains `return`, `break`, `continue` or `?`, which
+/// would behave differently in the closure passed to `filter`.
+fn has_control_flow(expr: &Expr) -> bool {
+    let mut visitor = ControlFlowVisitor { found: false };
+    visitor.visit_expr(expr);
+    visitor.found
+}
+
+struct ControlFlowVisitor {
+    found: bool,
+}
+
+impl<'tcx> Visitor<'tcx> for ControlFlowVisitor {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        match expr.node {
+            ExprKind::Ret(_)
+            | ExprKind::Break(..)
+            | ExprKind::Continue(_)
+            | ExprKind::Match(_, _, MatchSource::TryDesugar) => self.found = true,
+            ExprKind::Closure(..) => {},
+            _ => walk_expr(self, expr),
+        }
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
@@ -0,0 +1,167 @@
+//! lint on `Iterator::map` calls which are only used for the side effects of the closure
+
+use crate::utils::{
+    get_parent_expr, in_macro, match_trait_method, match_var, meets_msrv, msrvs, paths, snippet_with_applicability,
+    span_help_and_lint, span_lint_and_sugg, RustcVersion,
+};
+use if_chain::if_chain;
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::ty;
+use rustc::{declare_tool_lint, lint_array};
+use rustc_errors::Applicability;
+
+/// **What it does:** Checks for `map` calls on iterators which are only used
+/// for the side effects of the closure: closures which return their argument
+/// unchanged, like `.map(|x| { println!("{}", x); x })`, and closures
+/// returning `()` when the result of `map` is thrown away or only counted.
+///
+/// **Why is this bad?** `inspect` and `for_each` make it explicit that the
+/// closure is only run for its side effects.
+///
+/// **Known problems:** `inspect` passes a reference to each item, so the
+/// closure may need to be changed.
+///
+/// **Example:**
+/// ```rust
+/// let v: Vec<_> = it.map(|x| { println!("{}", x); x }).collect();
+/// let n = it.map(|x| println!("{}", x)).count();
+/// it.map(|x| println!("{}", x));
+/// ```
+/// Could be written as:
+/// ```rust
+/// let v: Vec<_> = it.inspect(|x| println!("{}", x)).collect();
+/// let n = it.inspect(|x| println!("{}", x)).count();
+/// it.for_each(|x| println!("{}", x));
+/// ```
+declare_clippy_lint! {
+    pub MAP_FOR_SIDE_EFFECTS,
+    pedantic,
+    "using `Iterator::map` only for the side effects of the closure"
+}
+
+#[derive(Copy, Clone)]
+pub struct Pass {
+    msrv: Option<RustcVersion>,
+}
+
+impl Pass {
+    pub fn new(msrv: Option<RustcVersion>) -> Self {
+        Self { msrv }
+    }
+}
+
+impl LintPass for Pass {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(MAP_FOR_SIDE_EFFECTS)
+    }
+
+    fn name(&self) -> &'static str {
+        "MapForSideEffects"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if_chain! {
+            if let ExprKind::MethodCall(ref method, _, ref args) = expr.node;
+            if method.ident.name == "map" && args.len() == 2;
+            if let ExprKind::Closure(_, _, body_id, _, _) = args[1].node;
+            if !in_macro(expr.span);
+            if match_trait_method(cx, expr, &paths::ITERATOR);
+            then {
+                let body = cx.tcx.hir().body(body_id);
+                if returns_argument(cx, body) {
+                    span_help_and_lint(
+                        cx,
+                        MAP_FOR_SIDE_EFFECTS,
+                        expr.span,
+                        "this `map` returns the items unchanged, it's only used for the side effects of the closure",
+                        "use `inspect` instead",
+                    );
+                } else if cx.tables.expr_ty(&body.value).is_unit() {
+                    check_unit_map(cx, expr, args, self.msrv);
+                }
+            }
+        }
+    }
+}
+
+/// Checks whether the closure `body` runs some statements and returns its
+/// argument, which isn't a mutable binding or a mutable reference.
+fn returns_argument(cx: &LateContext<'_, '_>, body: &Body) -> bool {
+    if_chain! {
+        if body.arguments.len() == 1;
+        if let PatKind::Binding(BindingAnnotation::Unannotated, .., ident, None) = body.arguments[0].pat.node;
+        if let ExprKind::Block(ref block, _) = body.value.node;
+        if !block.stmts.is_empty();
+        if let Some(ref value) = block.expr;
+        if match_var(value, ident.name);
+        then {
+            return match cx.tables.pat_ty(&body.arguments[0].pat).sty {
+                ty::Ref(_, _, mutbl) => mutbl == MutImmutable,
+                _ => true,
+            };
+        }
+    }
+    false
+}
+
+/// Lints the `map` call `expr` with a closure returning `()` if its result is
+/// thrown away, or only counted. `unused_collect` lints throwing away the
+/// count.
+fn check_unit_map<'a, 'tcx>(
+    cx: &LateContext<'a, 'tcx>,
+    expr: &'tcx Expr,
+    args: &'tcx [Expr],
+    msrv: Option<RustcVersion>,
+) {
+    if is_statement(cx, expr) {
+        if !meets_msrv(cx.tcx, expr.id, msrv, msrvs::ITERATOR_FOR_EACH) {
+            return;
+        }
+        let mut applicability = Applicability::MachineApplicable;
+        span_lint_and_sugg(
+            cx,
+            MAP_FOR_SIDE_EFFECTS,
+            expr.span,
+            "this `map` is only used for the side effects of the closure, its result is thrown away",
+            "use `for_each`",
+            format!(
+                "{}.for_each({})",
+                snippet_with_applicability(cx, args[0].span, "..", &mut applicability),
+                snippet_with_applicability(cx, args[1].span, "..", &mut applicability)
+            ),
+            applicability,
+        );
+        return;
+    }
+    if_chain! {
+        if let Some(parent) = get_parent_expr(cx, expr);
+        if let ExprKind::MethodCall(ref method, _, ref parent_args) = parent.node;
+        if method.ident.name == "count" && parent_args.len() == 1;
+        if !is_statement(cx, parent);
+        then {
+            span_help_and_lint(
+                cx,
+                MAP_FOR_SIDE_EFFECTS,
+                expr.span,
+                "this `map` is only used for the side effects of the closure before counting the items",
+                "use `inspect` instead",
+            );
+        }
+    }
+}
+
+/// Checks whether `expr` is the expression of a statement, which throws its
+/// value away.
+fn is_statement(cx: &LateContext<'_, '_>, expr: &Expr) -> bool {
+    let map = cx.tcx.hir();
+    match map.find(map.get_parent_node(expr.id)) {
+        Some(Node::Stmt(stmt)) => match stmt.node {
+            StmtKind::Semi(_) => true,
+            _ => false,
+        },
+        _ => false,
+    }
+}
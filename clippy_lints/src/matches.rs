@@ -2,8 +2,9 @@ use crate::consts::{constant, Constant};
 use crate::utils::paths;
 use crate::utils::sugg::Sugg;
 use crate::utils::{
-    expr_block, in_macro, is_allowed, is_expn_of, match_qpath, match_type, multispan_sugg, remove_blocks, snippet,
-    snippet_with_applicability, span_lint_and_sugg, span_lint_and_then, span_note_and_lint, walk_ptrs_ty,
+    expr_block, in_macro, is_allowed, is_expn_of, match_qpath, match_type, meets_msrv, msrvs, multispan_sugg,
+    remove_blocks, snippet, snippet_with_applicability, span_lint_and_sugg, span_lint_and_then, span_note_and_lint,
+    walk_ptrs_ty, RustcVersion,
 };
 use if_chain::if_chain;
 use rustc::hir::*;
@@ -206,8 +207,44 @@ declare_clippy_lint! {
     "a wildcard enum match arm using `_`"
 }
 
+/// **What it does:** Checks for `match` and `if let` expressions which return
+/// `true` if a value matches some patterns and `false` otherwise.
+///
+/// **Why is this bad?** The `matches!` macro says the same in one line.
+///
+/// **Known problems:** `matches!` is only in `std` since Rust 1.42, so the
+/// lint respects the `msrv` configuration. Without one, it only lints if the
+/// compiler clippy runs in has `matches!`.
+///
+/// **Example:**
+/// ```rust
+/// let is_digit = match c {
+///     '0'..='9' => true,
+///     _ => false,
+/// };
+/// let is_a = if let Foo::A(_) = foo { true } else { false };
+/// ```
+/// Could be written as:
+/// ```rust
+/// let is_digit = matches!(c, '0'..='9');
+/// let is_a = matches!(foo, Foo::A(_));
+/// ```
+declare_clippy_lint! {
+    pub MATCH_LIKE_MATCHES_MACRO,
+    style,
+    "a `match` or `if let` returning a bool literal, which could be `matches!`"
+}
+
 #[allow(missing_copy_implementations)]
-pub struct MatchPass;
+pub struct MatchPass {
+    msrv: Option<RustcVersion>,
+}
+
+impl MatchPass {
+    pub fn new(msrv: Option<RustcVersion>) -> Self {
+        Self { msrv }
+    }
+}
 
 impl LintPass for MatchPass {
     fn get_lints(&self) -> LintArray {
@@ -219,7 +256,8 @@ impl LintPass for MatchPass {
             MATCH_OVERLAPPING_ARM,
             MATCH_WILD_ERR_ARM,
             MATCH_AS_REF,
-            WILDCARD_ENUM_MATCH_ARM
+            WILDCARD_ENUM_MATCH_ARM,
+            MATCH_LIKE_MATCHES_MACRO
         )
     }
 
@@ -241,8 +279,14 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for MatchPass {
             check_wild_enum_match(cx, ex, arms);
             check_match_as_ref(cx, ex, arms, expr);
         }
-        if let ExprKind::Match(ref ex, ref arms, _) = expr.node {
+        if let ExprKind::Match(ref ex, ref arms, ref source) = expr.node {
             check_match_ref_pats(cx, ex, arms, expr);
+            match *source {
+                MatchSource::Normal | MatchSource::IfLetDesugar { .. } => {
+                    check_match_like_matches(cx, ex, arms, expr, self.msrv);
+                },
+                _ => (),
+            }
         }
     }
 }
@@ -410,6 +454,85 @@ fn check_match_bool(cx: &LateContext<'_, '_>, ex: &Expr, arms: &[Arm], expr: &Ex
     }
 }
 
+fn check_match_like_matches(
+    cx: &LateContext<'_, '_>,
+    ex: &Expr,
+    arms: &[Arm],
+    expr: &Expr,
+    msrv: Option<RustcVersion>,
+) {
+    if_chain! {
+        if arms.len() == 2 && !in_macro(expr.span);
+        if arms[1].guard.is_none() && arms[1].pats.len() == 1 && is_wild(&arms[1].pats[0]);
+        if let Some(first) = bool_lit(&arms[0].body);
+        if bool_lit(&arms[1].body) == Some(!first);
+        // matching on bools is `match_bool`'s, and `Some(_)`, `None`, `Ok(_)` and `Err(_)` are
+        // `redundant_pattern_matching`'s
+        if cx.tables.expr_ty(ex).sty != ty::Bool;
+        if !(arms[0].pats.len() == 1 && is_variant_check(&arms[0].pats[0]));
+        if meets_msrv(cx.tcx, expr.id, msrv, msrvs::MATCHES_MACRO);
+        then {
+            let mut applicability = Applicability::MachineApplicable;
+            let pats = arms[0]
+                .pats
+                .iter()
+                .map(|pat| snippet_with_applicability(cx, pat.span, "..", &mut applicability))
+                .collect::<Vec<_>>()
+                .join(" | ");
+            let guard = match arms[0].guard {
+                Some(Guard::If(ref cond)) => {
+                    format!(" if {}", snippet_with_applicability(cx, cond.span, "..", &mut applicability))
+                },
+                None => String::new(),
+            };
+            let kind = if let ExprKind::Match(_, _, MatchSource::Normal) = expr.node {
+                "match"
+            } else {
+                "if let"
+            };
+            span_lint_and_sugg(
+                cx,
+                MATCH_LIKE_MATCHES_MACRO,
+                expr.span,
+                &format!("this `{}` expression can be written with the `matches!` macro", kind),
+                "try this",
+                format!(
+                    "{}matches!({}, {}{})",
+                    if first { "" } else { "!" },
+                    snippet_with_applicability(cx, ex.span, "..", &mut applicability),
+                    pats,
+                    guard
+                ),
+                applicability,
+            );
+        }
+    }
+}
+
+/// Returns the value of `expr` if it's `true` or `false`, maybe in a block.
+fn bool_lit(expr: &Expr) -> Option<bool> {
+    match remove_blocks(expr).node {
+        ExprKind::Lit(ref lit) => match lit.node {
+            LitKind::Bool(value) => Some(value),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Checks whether `pat` is `Some(_)`, `None`, `Ok(_)` or `Err(_)`.
+fn is_variant_check(pat: &Pat) -> bool {
+    match pat.node {
+        PatKind::TupleStruct(ref qpath, ref pats, None) if pats.len() == 1 && is_wild(&pats[0]) => {
+            match_qpath(qpath, &paths::OPTION_SOME)
+                || match_qpath(qpath, &paths::RESULT_OK)
+                || match_qpath(qpath, &paths::RESULT_ERR)
+        },
+        PatKind::Path(ref qpath) => match_qpath(qpath, &paths::OPTION_NONE),
+        _ => false,
+    }
+}
+
 fn check_overlapping_arms<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, ex: &'tcx Expr, arms: &'tcx [Arm]) {
     if arms.len() >= 2 && cx.tables.expr_ty(ex).is_integral() {
         let ranges = all_ranges(cx, arms);
@@ -0,0 +1,142 @@
+use crate::utils::paths;
+use crate::utils::{
+    in_macro, match_qpath, match_type, may_return, may_return_from_block, snippet_opt, span_lint_and_sugg,
+};
+use rustc::hir;
+use rustc::lint::LateContext;
+use rustc_errors::Applicability;
+
+use super::{AND_THEN_INSTEAD_OF_MAP, OR_ELSE_INSTEAD_OF_OR};
+
+/// lint use of `and_then(|x| Some(y))` for `Option`s and `and_then(|x| Ok(y))`
+/// for `Result`s
+pub(super) fn lint_and_then(cx: &LateContext<'_, '_>, expr: &hir::Expr, args: &[hir::Expr]) {
+    let (wrapper, name) = match wrapper(cx, &args[0]) {
+        Some(wrapper) => wrapper,
+        None => return,
+    };
+    if let hir::ExprKind::Closure(_, _, body_id, ..) = args[1].node {
+        let body = cx.tcx.hir().body(body_id);
+        let call = match always_wrapped(&body.value, wrapper) {
+            Some(call) => call,
+            None => return,
+        };
+        let value = match call.node {
+            hir::ExprKind::Call(_, ref call_args) => &call_args[0],
+            _ => return,
+        };
+        // the closure is suggested with the wrapped value instead of the call
+        let closure = args[1].span;
+        if let (Some(recv), Some(closure_snip), Some(value)) = (
+            snippet_opt(cx, args[0].span),
+            snippet_opt(cx, closure),
+            snippet_opt(cx, value.span),
+        ) {
+            let lo = (call.span.lo() - closure.lo()).0 as usize;
+            let hi = (call.span.hi() - closure.lo()).0 as usize;
+            span_lint_and_sugg(
+                cx,
+                AND_THEN_INSTEAD_OF_MAP,
+                expr.span,
+                &format!("using `and_then` with a closure which always returns `{}`", name),
+                "use `map` instead",
+                format!("{}.map({}{}{})", recv, &closure_snip[..lo], value, &closure_snip[hi..]),
+                Applicability::MachineApplicable,
+            );
+        }
+    }
+}
+
+/// lint use of `or_else(|| Some(y))` for `Option`s and `or_else(|_| Ok(y))`
+/// for `Result`s, if `y` is cheap to compute
+pub(super) fn lint_or_else(cx: &LateContext<'_, '_>, expr: &hir::Expr, args: &[hir::Expr]) {
+    let (wrapper, name) = match wrapper(cx, &args[0]) {
+        Some(wrapper) => wrapper,
+        None => return,
+    };
+    if let hir::ExprKind::Closure(_, _, body_id, ..) = args[1].node {
+        let body = cx.tcx.hir().body(body_id);
+        // the error passed to the closure of `Result::or_else` must be unused
+        if body.arguments.iter().any(|arg| match arg.pat.node {
+            hir::PatKind::Wild => false,
+            _ => true,
+        }) {
+            return;
+        }
+        let call = match body.value.node {
+            hir::ExprKind::Call(ref fun, ref call_args) if is_path(fun, wrapper) && is_cheap(&call_args[0]) => {
+                &body.value
+            },
+            _ => return,
+        };
+        if let (Some(recv), Some(call)) = (snippet_opt(cx, args[0].span), snippet_opt(cx, call.span)) {
+            span_lint_and_sugg(
+                cx,
+                OR_ELSE_INSTEAD_OF_OR,
+                expr.span,
+                &format!(
+                    "using `or_else` with a closure which returns `{}` of a cheap value",
+                    name
+                ),
+                "use `or` instead",
+                format!("{}.or({})", recv, call),
+                Applicability::MachineApplicable,
+            );
+        }
+    }
+}
+
+/// Returns the path of the `Some` or `Ok` variant of the type of `recv`, and
+/// its name.
+fn wrapper(cx: &LateContext<'_, '_>, recv: &hir::Expr) -> Option<(&'static [&'static str], &'static str)> {
+    let ty = cx.tables.expr_ty(recv);
+    if match_type(cx, ty, &paths::OPTION) {
+        Some((&paths::OPTION_SOME, "Some"))
+    } else if match_type(cx, ty, &paths::RESULT) {
+        Some((&paths::RESULT_OK, "Ok"))
+    } else {
+        None
+    }
+}
+
+/// Returns the call of `wrapper` the closure body `body` returns, if it has
+/// no other way to return.
+fn always_wrapped<'e>(body: &'e hir::Expr, wrapper: &[&str]) -> Option<&'e hir::Expr> {
+    let value = match body.node {
+        hir::ExprKind::Block(ref block, _) => {
+            if may_return_from_block(block) {
+                return None;
+            }
+            block.expr.as_ref()?
+        },
+        _ => body,
+    };
+    match value.node {
+        hir::ExprKind::Call(ref fun, ref args) if args.len() == 1 && is_path(fun, wrapper) && !in_macro(value.span) => {
+            if may_return(&args[0]) {
+                None
+            } else {
+                Some(value)
+            }
+        },
+        _ => None,
+    }
+}
+
+fn is_path(expr: &hir::Expr, path: &[&str]) -> bool {
+    match expr.node {
+        hir::ExprKind::Path(ref qpath) => match_qpath(qpath, path),
+        _ => false,
+    }
+}
+
+/// Checks whether computing `expr` eagerly costs nothing, like for literals
+/// and variables.
+fn is_cheap(expr: &hir::Expr) -> bool {
+    match expr.node {
+        hir::ExprKind::Lit(_) | hir::ExprKind::Path(_) => true,
+        hir::ExprKind::AddrOf(_, ref inner) | hir::ExprKind::Unary(hir::UnOp::UnNeg, ref inner) => is_cheap(inner),
+        hir::ExprKind::Tup(ref exprs) => exprs.iter().all(is_cheap),
+        _ => false,
+    }
+}
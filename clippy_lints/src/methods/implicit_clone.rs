@@ -0,0 +1,50 @@
+use crate::utils::sugg::Sugg;
+use crate::utils::{is_copy, match_def_path, match_type, paths, same_tys, span_lint_and_sugg};
+use rustc::hir;
+use rustc::lint::LateContext;
+use rustc::ty;
+use rustc_errors::Applicability;
+
+use super::IMPLICIT_CLONE;
+
+/// lint use of `to_string()` on `String`s, `to_vec()` on `Vec`s and
+/// `to_owned()` on owned values, which are all clones of the receiver
+pub(super) fn lint(cx: &LateContext<'_, '_>, expr: &hir::Expr, method: &str, args: &[hir::Expr]) {
+    if args.len() != 1 {
+        return;
+    }
+    let recv_ty = cx.tables.expr_ty(&args[0]);
+    if let ty::Ref(..) = recv_ty.sty {
+        return;
+    }
+    // `Copy` values are copied, not cloned
+    if is_copy(cx, recv_ty) || !same_tys(cx, recv_ty, cx.tables.expr_ty(expr)) {
+        return;
+    }
+    let def_id = match cx.tables.type_dependent_defs().get(expr.hir_id) {
+        Some(def) => def.def_id(),
+        None => return,
+    };
+    let is_clone = match method {
+        "to_string" => {
+            match_def_path(cx.tcx, def_id, &paths::TO_STRING_METHOD) && match_type(cx, recv_ty, &paths::STRING)
+        },
+        "to_vec" => match_def_path(cx.tcx, def_id, &paths::SLICE_TO_VEC) && match_type(cx, recv_ty, &paths::VEC),
+        "to_owned" => match_def_path(cx.tcx, def_id, &paths::TO_OWNED_METHOD),
+        _ => false,
+    };
+    if !is_clone {
+        return;
+    }
+    let mut applicability = Applicability::MachineApplicable;
+    let recv = Sugg::hir_with_applicability(cx, &args[0], "..", &mut applicability).maybe_par();
+    span_lint_and_sugg(
+        cx,
+        IMPLICIT_CLONE,
+        expr.span,
+        &format!("implicitly cloning a `{}` by calling `{}` on it", recv_ty, method),
+        "consider using",
+        format!("{}.clone()", recv),
+        applicability,
+    );
+}
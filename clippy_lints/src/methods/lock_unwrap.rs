@@ -0,0 +1,54 @@
+use crate::utils::{match_type, paths, snippet_with_applicability, span_lint_and_then, walk_ptrs_ty};
+use rustc::hir;
+use rustc::lint::LateContext;
+use rustc_errors::Applicability;
+
+use super::LOCK_UNWRAP;
+
+/// lint use of `unwrap()` and `expect()` on the result of `lock()`, `read()`
+/// or `write()` on a `Mutex` or an `RwLock`
+pub(super) fn lint(
+    cx: &LateContext<'_, '_>,
+    expr: &hir::Expr,
+    method: &str,
+    lock: &str,
+    lock_args: &[hir::Expr],
+    allow_expect: bool,
+) {
+    if method != "unwrap" && (method != "expect" || allow_expect) {
+        return;
+    }
+    let lock_ty = walk_ptrs_ty(cx.tables.expr_ty(&lock_args[0]));
+    let is_lock = match lock {
+        "lock" => match_type(cx, lock_ty, &paths::MUTEX),
+        "read" | "write" => match_type(cx, lock_ty, &paths::RWLOCK),
+        _ => false,
+    };
+    if !is_lock {
+        return;
+    }
+    span_lint_and_then(
+        cx,
+        LOCK_UNWRAP,
+        expr.span,
+        &format!("called `{}()` on the result of `{}()`", method, lock),
+        |db| {
+            let mut applicability = Applicability::MaybeIncorrect;
+            let sugg = format!(
+                "{}.{}().unwrap_or_else(std::sync::PoisonError::into_inner)",
+                snippet_with_applicability(cx, lock_args[0].span, "..", &mut applicability),
+                lock
+            );
+            db.note("this panics if the lock is poisoned, which happens when a thread panics while holding it");
+            db.span_suggestion(
+                expr.span,
+                "to ignore the poisoning, use the guard inside the `PoisonError`",
+                sugg,
+                applicability,
+            );
+            if allow_expect && method == "unwrap" {
+                db.help("to panic on purpose, say why with `expect`");
+            }
+        },
+    );
+}
@@ -1,10 +1,11 @@
+use crate::indexing_slicing::INDEXING_SLICING;
 use crate::utils::paths;
 use crate::utils::sugg;
 use crate::utils::{
-    get_arg_name, get_parent_expr, get_trait_def_id, implements_trait, in_macro, is_copy, is_expn_of, is_self,
-    is_self_ty, iter_input_pats, last_path_segment, match_def_path, match_path, match_qpath, match_trait_method,
-    match_type, match_var, method_calls, method_chain_args, remove_blocks, return_ty, same_tys, single_segment_path,
-    snippet, snippet_with_applicability, snippet_with_macro_callsite, span_lint, span_lint_and_sugg,
+    get_arg_name, get_parent_expr, get_trait_def_id, implements_trait, in_macro, is_allowed, is_copy, is_expn_of,
+    is_in_scope, is_self, is_self_ty, iter_input_pats, last_path_segment, match_def_path, match_path, match_qpath,
+    match_trait_method, match_type, match_var, method_calls, method_chain_args, remove_blocks, return_ty, same_tys,
+    single_segment_path, snippet, snippet_with_applicability, snippet_with_context, span_lint, span_lint_and_sugg,
     span_lint_and_then, span_note_and_lint, walk_ptrs_ty, walk_ptrs_ty_depth, SpanlessEq,
 };
 use if_chain::if_chain;
@@ -22,12 +23,30 @@ use syntax::ast;
 use syntax::source_map::{BytePos, Span};
 use syntax::symbol::LocalInternedString;
 
+mod and_then_or_else;
+mod implicit_clone;
+mod lock_unwrap;
 mod unnecessary_filter_map;
 
 #[derive(Clone)]
-pub struct Pass;
+pub struct Pass {
+    allow_unwrap_in: Vec<String>,
+    lock_unwrap_allow_expect: bool,
+    prefer_get_over_indexing: bool,
+}
+
+impl Pass {
+    pub fn new(allow_unwrap_in: Vec<String>, lock_unwrap_allow_expect: bool, prefer_get_over_indexing: bool) -> Self {
+        Self {
+            allow_unwrap_in,
+            lock_unwrap_allow_expect,
+            prefer_get_over_indexing,
+        }
+    }
+}
 
-/// **What it does:** Checks for `.unwrap()` calls on `Option`s.
+/// **What it does:** Checks for `.unwrap()` calls on `Option`s. `.expect()`
+/// calls are linted by `option_expect_used`.
 ///
 /// **Why is this bad?** Usually it is better to handle the `None` case, or to
 /// at least call `.expect(_)` with a more helpful message. Still, for a lot of
@@ -46,7 +65,8 @@ declare_clippy_lint! {
     "using `Option.unwrap()`, which should at least get a better message using `expect()`"
 }
 
-/// **What it does:** Checks for `.unwrap()` calls on `Result`s.
+/// **What it does:** Checks for `.unwrap()` calls on `Result`s. `.expect()`
+/// calls are linted by `result_expect_used`.
 ///
 /// **Why is this bad?** `result.unwrap()` will let the thread panic on `Err`
 /// values. Normally, you want to implement more sophisticated error handling,
@@ -68,6 +88,44 @@ declare_clippy_lint! {
     "using `Result.unwrap()`, which might be better handled"
 }
 
+/// **What it does:** Checks for `.expect()` calls on `Option`s. Unlike
+/// `option_unwrap_used`, it can be enabled on its own, e.g. to require
+/// handling `None` everywhere, or allowed where `.unwrap()` is denied.
+///
+/// **Why is this bad?** `option.expect()` panics on `None`, just like
+/// `unwrap()`, only with a better message.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// x.expect("x is set by `init`")
+/// ```
+declare_clippy_lint! {
+    pub OPTION_EXPECT_USED,
+    restriction,
+    "using `Option.expect()`, which panics on `None`"
+}
+
+/// **What it does:** Checks for `.expect()` calls on `Result`s. Unlike
+/// `result_unwrap_used`, it can be enabled on its own, e.g. to require
+/// propagating errors everywhere, or allowed where `.unwrap()` is denied.
+///
+/// **Why is this bad?** `result.expect()` panics on `Err` values, just like
+/// `unwrap()`, only with a better message.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// x.expect("the config was validated before")
+/// ```
+declare_clippy_lint! {
+    pub RESULT_EXPECT_USED,
+    restriction,
+    "using `Result.expect()`, which panics on `Err` values"
+}
+
 /// **What it does:** Checks for methods that should live in a trait
 /// implementation of a `std` trait (see [llogiq's blog
 /// post](http://llogiq.github.io/2015/07/30/traits.html) for further
@@ -556,6 +614,10 @@ declare_clippy_lint! {
 /// **Why is this bad?** Using the Index trait (`[]`) is more clear and more
 /// concise.
 ///
+/// Code preferring `get` over indexing can set `prefer-get-over-indexing =
+/// true` in `clippy.toml` to turn the lint off. It's also not emitted where
+/// `indexing_slicing` is enabled, which would lint the suggested `[]`.
+///
 /// **Known problems:** Not a replacement for error handling: Using either
 /// `.unwrap()` or the Index trait (`[]`) carries the risk of causing a `panic`
 /// if the value being accessed is `None`. If the use of `.get().unwrap()` is a
@@ -775,11 +837,118 @@ declare_clippy_lint! {
     "using `.into_iter()` on a reference"
 }
 
+/// **What it does:** Checks for `and_then` calls on `Option`s and `Result`s
+/// with a closure which always returns `Some(..)` or `Ok(..)`.
+///
+/// **Why is this bad?** Readability, this is what `map` does.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// opt.and_then(|x| Some(x + 1));
+/// res.and_then(|x| Ok(x.len()));
+/// ```
+/// Could be written as:
+/// ```rust
+/// opt.map(|x| x + 1);
+/// res.map(|x| x.len());
+/// ```
+declare_clippy_lint! {
+    pub AND_THEN_INSTEAD_OF_MAP,
+    complexity,
+    "using `and_then(|x| Some(y))`, which is more succinctly expressed as `map(|x| y)`"
+}
+
+/// **What it does:** Checks for `or_else` calls on `Option`s and `Result`s
+/// with a closure which just returns `Some(..)` or `Ok(..)` of a literal or a
+/// variable, and doesn't use the error.
+///
+/// **Why is this bad?** Readability, the value doesn't need to be computed
+/// lazily, so `or` can be used.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// opt.or_else(|| Some(0));
+/// res.or_else(|_| Ok(x));
+/// ```
+/// Could be written as:
+/// ```rust
+/// opt.or(Some(0));
+/// res.or(Ok(x));
+/// ```
+declare_clippy_lint! {
+    pub OR_ELSE_INSTEAD_OF_OR,
+    complexity,
+    "using `or_else(|| Some(x))` with a cheap `x`, which is more succinctly expressed as `or(Some(x))`"
+}
+
+/// **What it does:** Checks for `to_string()` on `String`s, `to_vec()` on
+/// `Vec`s and `to_owned()` on owned values, which are clones of the value.
+///
+/// **Why is this bad?** These methods are meant to turn a borrowed value into
+/// an owned one. On an owned value, they hide that it is cloned, and `clone`
+/// says so.
+///
+/// **Known problems:** If the value isn't used after the call, it doesn't need
+/// to be cloned at all. `redundant_clone` suggests removing the call then.
+///
+/// **Example:**
+/// ```rust
+/// let a = vec![1, 2, 3];
+/// let b = a.to_vec();
+/// let s = String::from("foo");
+/// let t = s.to_string();
+/// ```
+/// Could be written as:
+/// ```rust
+/// let a = vec![1, 2, 3];
+/// let b = a.clone();
+/// let s = String::from("foo");
+/// let t = s.clone();
+/// ```
+declare_clippy_lint! {
+    pub IMPLICIT_CLONE,
+    pedantic,
+    "calling `to_string`, `to_owned` or `to_vec` on a value which is already owned"
+}
+
+/// **What it does:** Checks for `unwrap()` and `expect()` on the result of
+/// `lock()` on a `Mutex`, and of `read()` or `write()` on an `RwLock`.
+/// `expect()` isn't linted if `lock-unwrap-allow-expect` is set in
+/// `clippy.toml`, so that its message documents why panicking is fine.
+///
+/// **Why is this bad?** These return an `Err` if another thread panicked
+/// while holding the lock, and unwrapping it spreads the panic to each thread
+/// which locks it afterwards. Which is often right, but it's a decision the
+/// code doesn't show. Teams which want it to be explicit can enable this
+/// lint.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// let guard = mutex.lock().unwrap();
+/// ```
+/// Could be written as:
+/// ```rust
+/// let guard = mutex.lock().unwrap_or_else(PoisonError::into_inner);
+/// ```
+declare_clippy_lint! {
+    pub LOCK_UNWRAP,
+    restriction,
+    "using `unwrap()` or `expect()` on the result of locking a `Mutex` or an `RwLock`"
+}
+
 impl LintPass for Pass {
     fn get_lints(&self) -> LintArray {
         lint_array!(
             OPTION_UNWRAP_USED,
             RESULT_UNWRAP_USED,
+            OPTION_EXPECT_USED,
+            RESULT_EXPECT_USED,
             SHOULD_IMPLEMENT_TRAIT,
             WRONG_SELF_CONVENTION,
             WRONG_PUB_SELF_CONVENTION,
@@ -812,6 +981,10 @@ impl LintPass for Pass {
             UNNECESSARY_FILTER_MAP,
             INTO_ITER_ON_ARRAY,
             INTO_ITER_ON_REF,
+            AND_THEN_INSTEAD_OF_MAP,
+            OR_ELSE_INSTEAD_OF_OR,
+            IMPLICIT_CLONE,
+            LOCK_UNWRAP,
         )
     }
 
@@ -829,13 +1002,24 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
 
         let (method_names, arg_lists) = method_calls(expr, 2);
         let method_names: Vec<LocalInternedString> = method_names.iter().map(|s| s.as_str()).collect();
-        let method_names: Vec<&str> = method_names.iter().map(|s| s.as_ref()).collect();
+        let method_names: Vec<&str> = method_names.iter().map(LocalInternedString::get).collect();
+
+        if let [method, lock] = *method_names.as_slice() {
+            if !is_in_scope(cx, expr.id, expr.span, &self.allow_unwrap_in) {
+                lock_unwrap::lint(cx, expr, method, lock, arg_lists[1], self.lock_unwrap_allow_expect);
+            }
+        }
 
         match method_names.as_slice() {
-            ["unwrap", "get"] => lint_get_unwrap(cx, expr, arg_lists[1], false),
-            ["unwrap", "get_mut"] => lint_get_unwrap(cx, expr, arg_lists[1], true),
-            ["unwrap", ..] => lint_unwrap(cx, expr, arg_lists[0]),
+            ["unwrap", "get"] => lint_get_unwrap(cx, expr, arg_lists[1], false, self.prefer_get_over_indexing),
+            ["unwrap", "get_mut"] => lint_get_unwrap(cx, expr, arg_lists[1], true, self.prefer_get_over_indexing),
+            ["unwrap", ..] if !is_in_scope(cx, expr.id, expr.span, &self.allow_unwrap_in) => {
+                lint_unwrap(cx, expr, arg_lists[0])
+            },
             ["expect", "ok"] => lint_ok_expect(cx, expr, arg_lists[1]),
+            ["expect", ..] if !is_in_scope(cx, expr.id, expr.span, &self.allow_unwrap_in) => {
+                lint_expect(cx, expr, arg_lists[0])
+            },
             ["unwrap_or", "map"] => lint_map_unwrap_or(cx, expr, arg_lists[1], arg_lists[0]),
             ["unwrap_or_else", "map"] => lint_map_unwrap_or_else(cx, expr, arg_lists[1], arg_lists[0]),
             ["map_or", ..] => lint_map_or_none(cx, expr, arg_lists[0]),
@@ -858,6 +1042,11 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
             ["as_mut"] => lint_asref(cx, expr, "as_mut", arg_lists[0]),
             ["fold", ..] => lint_unnecessary_fold(cx, expr, arg_lists[0]),
             ["filter_map", ..] => unnecessary_filter_map::lint(cx, expr, arg_lists[0]),
+            ["and_then", ..] => and_then_or_else::lint_and_then(cx, expr, arg_lists[0]),
+            ["or_else", ..] => and_then_or_else::lint_or_else(cx, expr, arg_lists[0]),
+            ["to_string", ..] | ["to_owned", ..] | ["to_vec", ..] => {
+                implicit_clone::lint(cx, expr, method_names[0], arg_lists[0])
+            },
             _ => {},
         }
 
@@ -1102,11 +1291,13 @@ fn lint_or_fun_call(cx: &LateContext<'_, '_>, expr: &hir::Expr, method_span: Spa
             return;
         }
 
-        let sugg: Cow<'_, _> = match (fn_has_arguments, !or_has_args) {
-            (true, _) => format!("|_| {}", snippet_with_macro_callsite(cx, arg.span, "..")).into(),
-            (false, false) => format!("|| {}", snippet_with_macro_callsite(cx, arg.span, "..")).into(),
-            (false, true) => snippet_with_macro_callsite(cx, fun_span, ".."),
+        let (closure, sugg_span) = match (fn_has_arguments, !or_has_args) {
+            (true, _) => ("|_| ", arg.span),
+            (false, false) => ("|| ", arg.span),
+            (false, true) => ("", fun_span),
         };
+        let mut applicability = Applicability::HasPlaceholders;
+        let (sugg, _) = snippet_with_context(cx, sugg_span, span.ctxt(), "..", &mut applicability);
         let span_replace_word = method_span.with_hi(span.hi());
         span_lint_and_sugg(
             cx,
@@ -1114,8 +1305,8 @@ fn lint_or_fun_call(cx: &LateContext<'_, '_>, expr: &hir::Expr, method_span: Spa
             span_replace_word,
             &format!("use of `{}` followed by a function call", name),
             "try this",
-            format!("{}_{}({})", name, suffix, sugg),
-            Applicability::HasPlaceholders,
+            format!("{}_{}({}{})", name, suffix, closure, sugg),
+            applicability,
         );
     }
 
@@ -1339,7 +1530,7 @@ fn lint_clone_on_copy(cx: &LateContext<'_, '_>, expr: &hir::Expr, arg: &hir::Exp
         if let Some(snippet) = sugg::Sugg::hir_opt(cx, arg) {
             // x.clone() might have dereferenced x, possibly through Deref impls
             if cx.tables.expr_ty(arg) == ty {
-                snip = Some(("try removing the `clone` call", format!("{}", snippet)));
+                snip = Some(("try removing the `clone` call", snippet.to_string()));
             } else {
                 let parent = cx.tcx.hir().get_parent_node(expr.id);
                 match cx.tcx.hir().get(parent) {
@@ -1596,7 +1787,18 @@ fn lint_iter_nth(cx: &LateContext<'_, '_>, expr: &hir::Expr, iter_args: &[hir::E
     );
 }
 
-fn lint_get_unwrap(cx: &LateContext<'_, '_>, expr: &hir::Expr, get_args: &[hir::Expr], is_mut: bool) {
+fn lint_get_unwrap(
+    cx: &LateContext<'_, '_>,
+    expr: &hir::Expr,
+    get_args: &[hir::Expr],
+    is_mut: bool,
+    prefer_get_over_indexing: bool,
+) {
+    // `[]` isn't wanted, suggesting it would make `indexing_slicing` lint the fix
+    if prefer_get_over_indexing || !is_allowed(cx, INDEXING_SLICING, expr.id) {
+        return;
+    }
+
     // Note: we don't want to lint `get_mut().unwrap` for HashMap or BTreeMap,
     // because they do not implement `IndexMut`
     let mut applicability = Applicability::MachineApplicable;
@@ -1695,7 +1897,7 @@ fn derefs_to_slice(cx: &LateContext<'_, '_>, expr: &hir::Expr, ty: Ty<'_>) -> Op
 
     if let hir::ExprKind::MethodCall(ref path, _, ref args) = expr.node {
         if path.ident.name == "iter" && may_slice(cx, cx.tables.expr_ty(&args[0])) {
-            sugg::Sugg::hir_opt(cx, &args[0]).map(|sugg| sugg.addr())
+            sugg::Sugg::hir_opt(cx, &args[0]).map(sugg::Sugg::addr)
         } else {
             None
         }
@@ -1742,6 +1944,31 @@ fn lint_unwrap(cx: &LateContext<'_, '_>, expr: &hir::Expr, unwrap_args: &[hir::E
     }
 }
 
+/// lint use of `expect()` for `Option`s and `Result`s
+fn lint_expect(cx: &LateContext<'_, '_>, expr: &hir::Expr, expect_args: &[hir::Expr]) {
+    let obj_ty = walk_ptrs_ty(cx.tables.expr_ty(&expect_args[0]));
+
+    let mess = if match_type(cx, obj_ty, &paths::OPTION) {
+        Some((OPTION_EXPECT_USED, "an Option", "None"))
+    } else if match_type(cx, obj_ty, &paths::RESULT) {
+        Some((RESULT_EXPECT_USED, "a Result", "Err"))
+    } else {
+        None
+    };
+
+    if let Some((lint, kind, none_value)) = mess {
+        span_lint(
+            cx,
+            lint,
+            expr.span,
+            &format!(
+                "used expect() on {} value. If this value is {} it will panic",
+                kind, none_value
+            ),
+        );
+    }
+}
+
 /// lint use of `ok().expect()` for `Result`s
 fn lint_ok_expect(cx: &LateContext<'_, '_>, expr: &hir::Expr, ok_args: &[hir::Expr]) {
     // lint if the caller of `ok()` is a `Result`
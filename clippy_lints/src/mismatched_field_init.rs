@@ -0,0 +1,171 @@
+//! lint on struct fields initialized from the parameter named like another field
+
+use crate::utils::{in_macro, span_lint_and_then};
+use rustc::hir::def::Def;
+use rustc::hir::intravisit::{walk_expr, FnKind, NestedVisitorMap, Visitor};
+use rustc::hir::*;
+use rustc::lint::{in_external_macro, LateContext, LateLintPass, LintArray, LintContext, LintPass};
+use rustc::ty;
+use rustc::{declare_tool_lint, lint_array};
+use rustc_data_structures::fx::FxHashMap;
+use syntax::ast::{Name, NodeId};
+use syntax::source_map::Span;
+
+/// **What it does:** Checks for struct expressions in constructors, i.e.
+/// functions named `new` and methods returning `Self`, which initialize a
+/// field from a parameter that has the name of another field of the struct,
+/// like `Self { width: height, height: width }` in
+/// `fn new(width: u32, height: u32)`.
+///
+/// **Why is this bad?** Parameters are usually named after the fields they
+/// initialize. Initializing a field from the parameter of another one is most
+/// likely a mix-up, which the compiler can't catch if the types are the same.
+///
+/// **Known problems:** Functions which swap the fields on purpose, e.g. to
+/// transpose something, are linted as well.
+///
+/// **Example:**
+/// ```rust
+/// fn new(width: u32, height: u32) -> Self {
+///     Self { width: height, height }
+/// }
+/// ```
+/// Could be written as:
+/// ```rust
+/// fn new(width: u32, height: u32) -> Self {
+///     Self { width, height }
+/// }
+/// ```
+declare_clippy_lint! {
+    pub MISMATCHED_FIELD_INIT,
+    pedantic,
+    "initializing a struct field from the parameter named like another field"
+}
+
+#[derive(Copy, Clone)]
+pub struct Pass;
+
+impl LintPass for Pass {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(MISMATCHED_FIELD_INIT)
+    }
+
+    fn name(&self) -> &'static str {
+        "MismatchedFieldInit"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'a, 'tcx>,
+        kind: FnKind<'tcx>,
+        _: &'tcx FnDecl,
+        body: &'tcx Body,
+        span: Span,
+        node_id: NodeId,
+    ) {
+        if !is_constructor(cx, kind, node_id) || in_macro(span) || in_external_macro(cx.sess(), span) {
+            return;
+        }
+        let params: FxHashMap<_, _> = body
+            .arguments
+            .iter()
+            .filter_map(|arg| match arg.pat.node {
+                PatKind::Binding(_, id, _, ident, None) => Some((id, ident.name)),
+                _ => None,
+            })
+            .collect();
+        if params.len() < 2 {
+            return;
+        }
+        StructVisitor { cx, params }.visit_expr(&body.value);
+    }
+}
+
+/// Checks whether the function is named `new` or is a method returning the
+/// type of its `impl`.
+fn is_constructor(cx: &LateContext<'_, '_>, kind: FnKind<'_>, node_id: NodeId) -> bool {
+    let ident = match kind {
+        FnKind::ItemFn(ident, ..) | FnKind::Method(ident, ..) => ident,
+        FnKind::Closure(_) => return false,
+    };
+    if ident.name == "new" {
+        return true;
+    }
+    let def_id = cx.tcx.hir().local_def_id(node_id);
+    cx.tcx.impl_of_method(def_id).map_or(false, |impl_id| {
+        cx.tcx.fn_sig(def_id).skip_binder().output() == cx.tcx.type_of(impl_id)
+    })
+}
+
+struct StructVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    /// the names of the parameters bound to a plain name, by their bindings
+    params: FxHashMap<NodeId, Name>,
+}
+
+impl<'a, 'tcx> StructVisitor<'a, 'tcx> {
+    fn check_struct(&self, expr: &'tcx Expr, qpath: &'tcx QPath, fields: &'tcx [Field]) {
+        let variant = match self.cx.tables.expr_ty(expr).sty {
+            ty::Adt(def, _) => def.variant_of_def(self.cx.tables.qpath_def(qpath, expr.hir_id)),
+            _ => return,
+        };
+        for field in fields {
+            if field.is_shorthand || in_macro(field.span) {
+                continue;
+            }
+            let param = match self.param_name(&field.expr) {
+                Some(param) => param,
+                None => continue,
+            };
+            // a field left without its parameter is a sign of a mix-up, `height: width` alone may
+            // just be a square
+            if param != field.ident.name
+                && self.params.values().any(|&name| name == field.ident.name)
+                && variant.fields.iter().any(|f| f.ident.name == param)
+            {
+                span_lint_and_then(
+                    self.cx,
+                    MISMATCHED_FIELD_INIT,
+                    field.span,
+                    &format!(
+                        "the field `{}` is initialized from the parameter `{}`",
+                        field.ident, param
+                    ),
+                    |db| {
+                        db.note(&format!(
+                            "`{}` is the name of another field of `{}`",
+                            param,
+                            self.cx.tcx.item_name(variant.did)
+                        ));
+                        db.help("check whether the parameters are mixed up");
+                    },
+                );
+            }
+        }
+    }
+
+    /// Returns the name of the parameter `expr` is, if it's one.
+    fn param_name(&self, expr: &Expr) -> Option<Name> {
+        if let ExprKind::Path(ref qpath) = expr.node {
+            if let Def::Local(id) = self.cx.tables.qpath_def(qpath, expr.hir_id) {
+                return self.params.get(&id).cloned();
+            }
+        }
+        None
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for StructVisitor<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        if let ExprKind::Struct(ref qpath, ref fields, _) = expr.node {
+            self.check_struct(expr, qpath, fields);
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
@@ -1,7 +1,7 @@
-use crate::utils::{is_entrypoint_fn, span_lint};
+use crate::utils::{is_entrypoint_fn, meets_msrv, msrvs, span_lint, RustcVersion};
 use rustc::hir;
 use rustc::hir::intravisit::FnKind;
-use rustc::hir::{Body, Constness, FnDecl};
+use rustc::hir::{Body, Constness, FnDecl, ItemKind, Node};
 use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
 use rustc::{declare_tool_lint, lint_array};
 use rustc_mir::transform::qualify_min_const_fn::is_min_const_fn;
@@ -37,6 +37,9 @@ use syntax_pos::Span;
 /// can't be const as it calls a non-const function. Making `a` const and running Clippy again,
 /// will suggest to make `b` const, too.
 ///
+/// Functions aren't linted if the `msrv` configured in `clippy.toml` or set
+/// with `#[clippy::msrv]` is older than 1.31, which stabilized `const fn`.
+///
 /// **Example:**
 ///
 /// ```rust
@@ -59,7 +62,15 @@ declare_clippy_lint! {
 }
 
 #[derive(Clone)]
-pub struct MissingConstForFn;
+pub struct MissingConstForFn {
+    msrv: Option<RustcVersion>,
+}
+
+impl MissingConstForFn {
+    pub fn new(msrv: Option<RustcVersion>) -> Self {
+        Self { msrv }
+    }
+}
 
 impl LintPass for MissingConstForFn {
     fn get_lints(&self) -> LintArray {
@@ -83,7 +94,7 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for MissingConstForFn {
     ) {
         let def_id = cx.tcx.hir().local_def_id(node_id);
 
-        if is_entrypoint_fn(cx, def_id) {
+        if is_entrypoint_fn(cx, def_id) || !meets_msrv(cx.tcx, node_id, self.msrv, msrvs::CONST_FN) {
             return;
         }
 
@@ -96,7 +107,7 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for MissingConstForFn {
                 }
             },
             FnKind::Method(_, sig, ..) => {
-                if already_const(sig.header) {
+                if already_const(sig.header) || in_trait_or_trait_impl(cx, node_id) {
                     return;
                 }
             },
@@ -119,3 +130,15 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for MissingConstForFn {
 fn already_const(header: hir::FnHeader) -> bool {
     header.constness == Constness::Const
 }
+
+// Methods of traits and trait impls can't be `const`
+fn in_trait_or_trait_impl(cx: &LateContext<'_, '_>, node_id: NodeId) -> bool {
+    let parent = cx.tcx.hir().get_parent_item(node_id);
+    match cx.tcx.hir().find(parent) {
+        Some(Node::Item(item)) => match item.node {
+            ItemKind::Trait(..) | ItemKind::Impl(_, _, _, _, Some(_), _, _) => true,
+            _ => false,
+        },
+        _ => false,
+    }
+}
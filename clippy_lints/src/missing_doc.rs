@@ -5,10 +5,11 @@
 // [`missing_doc`]: https://github.com/rust-lang/rust/blob/d6d05904697d89099b55da3331155392f1db9c00/src/librustc_lint/builtin.rs#L246
 //
 
-use crate::utils::{in_macro, span_lint};
+use crate::utils::conf::DeclaredVisibility;
+use crate::utils::{in_macro, is_in_test, span_lint};
 use rustc::hir;
 use rustc::lint::{LateContext, LateLintPass, LintArray, LintContext, LintPass};
-use rustc::ty;
+use rustc::ty::{self, TyCtxt};
 use rustc::{declare_tool_lint, lint_array};
 use syntax::ast;
 use syntax::attr;
@@ -22,6 +23,10 @@ use syntax::source_map::Span;
 /// public members, but has no way to enforce documentation of private items.
 /// This lint fixes that.
 ///
+/// Unit structs, items of some declared visibilities and tests can be allowed
+/// with `missing-docs-allow-unit-structs`, `missing-docs-allowed-visibilities`
+/// and `missing-docs-allow-tests` in `clippy.toml`.
+///
 /// **Known problems:** None.
 declare_clippy_lint! {
     pub MISSING_DOCS_IN_PRIVATE_ITEMS,
@@ -30,33 +35,27 @@ declare_clippy_lint! {
 }
 
 pub struct MissingDoc {
-    /// Stack of whether #[doc(hidden)] is set
-    /// at each level which has lint attributes.
-    doc_hidden_stack: Vec<bool>,
-}
-
-impl ::std::default::Default for MissingDoc {
-    fn default() -> Self {
-        Self::new()
-    }
+    allow_unit_structs: bool,
+    allowed_visibilities: Vec<DeclaredVisibility>,
+    allow_tests: bool,
 }
 
 impl MissingDoc {
-    pub fn new() -> Self {
+    pub fn new(allow_unit_structs: bool, allowed_visibilities: Vec<DeclaredVisibility>, allow_tests: bool) -> Self {
         Self {
-            doc_hidden_stack: vec![false],
+            allow_unit_structs,
+            allowed_visibilities,
+            allow_tests,
         }
     }
 
-    fn doc_hidden(&self) -> bool {
-        *self.doc_hidden_stack.last().expect("empty doc_hidden_stack")
-    }
-
     fn check_missing_docs_attrs(
         &self,
         cx: &LateContext<'_, '_>,
+        node: ast::NodeId,
         attrs: &[ast::Attribute],
         sp: Span,
+        vis: DeclaredVisibility,
         desc: &'static str,
     ) {
         // If we're building a test harness, then warning about
@@ -65,8 +64,12 @@ impl MissingDoc {
             return;
         }
 
+        if self.allowed_visibilities.contains(&vis) {
+            return;
+        }
+
         // `#[doc(hidden)]` disables missing_docs check.
-        if self.doc_hidden() {
+        if is_doc_hidden(cx.tcx, node) {
             return;
         }
 
@@ -74,8 +77,11 @@ impl MissingDoc {
             return;
         }
 
-        let has_doc = attrs.iter().any(|a| a.is_value_str() && a.name() == "doc");
-        if !has_doc {
+        if self.allow_tests && is_in_test(cx.tcx, node) {
+            return;
+        }
+
+        if !attrs.iter().any(is_doc) {
             span_lint(
                 cx,
                 MISSING_DOCS_IN_PRIVATE_ITEMS,
@@ -97,24 +103,8 @@ impl LintPass for MissingDoc {
 }
 
 impl<'a, 'tcx> LateLintPass<'a, 'tcx> for MissingDoc {
-    fn enter_lint_attrs(&mut self, _: &LateContext<'a, 'tcx>, attrs: &'tcx [ast::Attribute]) {
-        let doc_hidden = self.doc_hidden()
-            || attrs.iter().any(|attr| {
-                attr.check_name("doc")
-                    && match attr.meta_item_list() {
-                        None => false,
-                        Some(l) => attr::list_contains_name(&l[..], "hidden"),
-                    }
-            });
-        self.doc_hidden_stack.push(doc_hidden);
-    }
-
-    fn exit_lint_attrs(&mut self, _: &LateContext<'a, 'tcx>, _: &'tcx [ast::Attribute]) {
-        self.doc_hidden_stack.pop().expect("empty doc_hidden_stack");
-    }
-
     fn check_crate(&mut self, cx: &LateContext<'a, 'tcx>, krate: &'tcx hir::Crate) {
-        self.check_missing_docs_attrs(cx, &krate.attrs, krate.span, "crate");
+        self.check_missing_docs_attrs(cx, ast::CRATE_NODE_ID, &krate.attrs, krate.span, "pub", "crate");
     }
 
     fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, it: &'tcx hir::Item) {
@@ -134,6 +124,7 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for MissingDoc {
             },
             hir::ItemKind::Mod(..) => "a module",
             hir::ItemKind::Static(..) => "a static",
+            hir::ItemKind::Struct(hir::VariantData::Unit(..), _) if self.allow_unit_structs => return,
             hir::ItemKind::Struct(..) => "a struct",
             hir::ItemKind::Trait(..) => "a trait",
             hir::ItemKind::TraitAlias(..) => "a trait alias",
@@ -147,7 +138,7 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for MissingDoc {
             | hir::ItemKind::Use(..) => return,
         };
 
-        self.check_missing_docs_attrs(cx, &it.attrs, it.span, desc);
+        self.check_missing_docs_attrs(cx, it.id, &it.attrs, it.span, visibility(&it.vis), desc);
     }
 
     fn check_trait_item(&mut self, cx: &LateContext<'a, 'tcx>, trait_item: &'tcx hir::TraitItem) {
@@ -157,7 +148,8 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for MissingDoc {
             hir::TraitItemKind::Type(..) => "an associated type",
         };
 
-        self.check_missing_docs_attrs(cx, &trait_item.attrs, trait_item.span, desc);
+        let vis = parent_visibility(cx.tcx, trait_item.id);
+        self.check_missing_docs_attrs(cx, trait_item.id, &trait_item.attrs, trait_item.span, vis, desc);
     }
 
     fn check_impl_item(&mut self, cx: &LateContext<'a, 'tcx>, impl_item: &'tcx hir::ImplItem) {
@@ -178,16 +170,79 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for MissingDoc {
             hir::ImplItemKind::Type(_) => "an associated type",
             hir::ImplItemKind::Existential(_) => "an existential type",
         };
-        self.check_missing_docs_attrs(cx, &impl_item.attrs, impl_item.span, desc);
+        let vis = visibility(&impl_item.vis);
+        self.check_missing_docs_attrs(cx, impl_item.id, &impl_item.attrs, impl_item.span, vis, desc);
     }
 
     fn check_struct_field(&mut self, cx: &LateContext<'a, 'tcx>, sf: &'tcx hir::StructField) {
         if !sf.is_positional() {
-            self.check_missing_docs_attrs(cx, &sf.attrs, sf.span, "a struct field");
+            self.check_missing_docs_attrs(cx, sf.id, &sf.attrs, sf.span, visibility(&sf.vis), "a struct field");
         }
     }
 
     fn check_variant(&mut self, cx: &LateContext<'a, 'tcx>, v: &'tcx hir::Variant, _: &hir::Generics) {
-        self.check_missing_docs_attrs(cx, &v.node.attrs, v.span, "a variant");
+        let id = v.node.data.id();
+        let vis = parent_visibility(cx.tcx, id);
+        self.check_missing_docs_attrs(cx, id, &v.node.attrs, v.span, vis, "a variant");
+    }
+}
+
+/// Checks whether `attr` documents the item: `#[doc = "..."]`, `#[doc = ...]`
+/// with another value, e.g. from a macro, or `#[doc(include = "...")]`, which
+/// the `external_doc` feature expands to `#[doc(include(...))]`.
+fn is_doc(attr: &ast::Attribute) -> bool {
+    if !attr.check_name("doc") {
+        return false;
+    }
+    if attr.is_value_str() {
+        return true;
+    }
+    match attr.meta_item_list() {
+        Some(items) => items.iter().any(|item| item.check_name("include")),
+        None => !attr.tokens.is_empty(),
+    }
+}
+
+/// Returns the declared visibility `vis`, as configured in
+/// `missing-docs-allowed-visibilities`.
+fn visibility(vis: &hir::Visibility) -> DeclaredVisibility {
+    match vis.node {
+        hir::VisibilityKind::Public => DeclaredVisibility::Public,
+        hir::VisibilityKind::Crate(_) => DeclaredVisibility::Crate,
+        hir::VisibilityKind::Restricted { ref path, .. } => match path.segments[..] {
+            [ref segment] if segment.ident.name == "self" => DeclaredVisibility::Private,
+            [ref segment] if segment.ident.name == "crate" => DeclaredVisibility::Crate,
+            _ => DeclaredVisibility::Super,
+        },
+        hir::VisibilityKind::Inherited => DeclaredVisibility::Private,
+    }
+}
+
+/// Returns the visibility of the item enclosing `node`, for trait items and
+/// variants, which have the visibility of their trait or enum.
+fn parent_visibility(tcx: TyCtxt<'_, '_, '_>, node: ast::NodeId) -> DeclaredVisibility {
+    let map = &tcx.hir();
+    visibility(&map.expect_item(map.get_parent(node)).vis)
+}
+
+/// Checks whether `#[doc(hidden)]` is set on `node` or the nodes enclosing it.
+fn is_doc_hidden(tcx: TyCtxt<'_, '_, '_>, node: ast::NodeId) -> bool {
+    let map = &tcx.hir();
+    let mut prev_enclosing_node = None;
+    let mut enclosing_node = node;
+    while Some(enclosing_node) != prev_enclosing_node {
+        let doc_hidden = map.attrs(enclosing_node).iter().any(|attr| {
+            attr.check_name("doc")
+                && match attr.meta_item_list() {
+                    None => false,
+                    Some(l) => attr::list_contains_name(&l[..], "hidden"),
+                }
+        });
+        if doc_hidden {
+            return true;
+        }
+        prev_enclosing_node = Some(enclosing_node);
+        enclosing_node = map.get_parent_node(enclosing_node);
     }
+    false
 }
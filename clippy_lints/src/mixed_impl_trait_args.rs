@@ -0,0 +1,253 @@
+//! lint on functions mixing `impl Trait` arguments with named type parameters
+
+use crate::utils::msrvs::{self, MsrvStack};
+use crate::utils::{in_macro, span_help_and_lint, RustcVersion};
+use rustc::lint::{in_external_macro, EarlyContext, EarlyLintPass, LintArray, LintContext, LintPass};
+use rustc::{declare_tool_lint, lint_array};
+use syntax::ast::*;
+use syntax::source_map::Span;
+use syntax::visit::{walk_path, walk_ty, walk_where_predicate, Visitor};
+
+/// **What it does:** Checks for functions that take `impl Trait` arguments
+/// while also declaring named type parameters.
+///
+/// **Why is this bad?** A function with `impl Trait` arguments can't be
+/// called with turbofish syntax, so the named type parameters can't be
+/// specified explicitly either. It also makes the signature harder to read,
+/// since the generics of the function are spread over two places.
+///
+/// By default the lint suggests replacing the `impl Trait` arguments by named
+/// type parameters. Setting `prefer-impl-trait-in-args = true` in
+/// `clippy.toml` makes it suggest the opposite direction, if the MSRV allows
+/// `impl Trait` arguments. In that case
+/// functions whose named type parameters can't be replaced, since they are
+/// used in the return type, in other bounds, in the body or in more than one
+/// argument, aren't linted.
+///
+/// The methods of trait impls aren't linted, since their signatures are
+/// given by the trait.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// fn foo<T: Clone>(a: T, b: impl Display) {}
+/// ```
+/// Could be written as:
+/// ```rust
+/// fn foo<T: Clone, U: Display>(a: T, b: U) {}
+/// ```
+declare_clippy_lint! {
+    pub MIXED_IMPL_TRAIT_ARGS,
+    pedantic,
+    "functions mixing `impl Trait` arguments with named type parameters"
+}
+
+pub struct MixedImplTraitArgs {
+    prefer_impl_trait: bool,
+    msrv: MsrvStack,
+    /// whether each `impl` block the current node is in is a trait impl
+    trait_impls: Vec<bool>,
+}
+
+impl MixedImplTraitArgs {
+    pub fn new(prefer_impl_trait: bool, msrv: Option<RustcVersion>) -> Self {
+        Self {
+            prefer_impl_trait,
+            msrv: MsrvStack::new(msrv),
+            trait_impls: Vec::new(),
+        }
+    }
+
+    fn check_fn_sig(
+        &self,
+        cx: &EarlyContext<'_>,
+        decl: &FnDecl,
+        generics: &Generics,
+        body: Option<&Block>,
+        span: Span,
+    ) {
+        if in_macro(span) || in_external_macro(cx.sess(), span) {
+            return;
+        }
+        if !generics.params.iter().any(|param| match param.kind {
+            GenericParamKind::Type { .. } => true,
+            _ => false,
+        }) {
+            return;
+        }
+
+        let mut visitor = ImplTraitVisitor { spans: Vec::new() };
+        for arg in &decl.inputs {
+            visitor.visit_ty(&arg.ty);
+        }
+        if let Some(&first) = visitor.spans.first() {
+            if self.prefer_impl_trait {
+                if !self.msrv.meets_msrv(msrvs::IMPL_TRAIT) || !type_params_replaceable(decl, generics, body) {
+                    return;
+                }
+                span_help_and_lint(
+                    cx,
+                    MIXED_IMPL_TRAIT_ARGS,
+                    generics.span,
+                    "named type parameters mixed with `impl Trait` arguments",
+                    "consider replacing the named type parameters with `impl Trait` arguments",
+                );
+            } else {
+                span_help_and_lint(
+                    cx,
+                    MIXED_IMPL_TRAIT_ARGS,
+                    first,
+                    "`impl Trait` argument mixed with named type parameters",
+                    "consider replacing the `impl Trait` arguments with named type parameters",
+                );
+            }
+        }
+    }
+}
+
+impl LintPass for MixedImplTraitArgs {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(MIXED_IMPL_TRAIT_ARGS)
+    }
+
+    fn name(&self) -> &'static str {
+        "MixedImplTraitArgs"
+    }
+}
+
+impl EarlyLintPass for MixedImplTraitArgs {
+    fn check_item(&mut self, cx: &EarlyContext<'_>, item: &Item) {
+        match item.node {
+            ItemKind::Fn(ref decl, _, ref generics, ref body) => {
+                self.check_fn_sig(cx, decl, generics, Some(body), item.span);
+            },
+            ItemKind::Impl(_, _, _, _, ref trait_ref, _, _) => self.trait_impls.push(trait_ref.is_some()),
+            _ => {},
+        }
+    }
+
+    fn check_item_post(&mut self, _: &EarlyContext<'_>, item: &Item) {
+        if let ItemKind::Impl(..) = item.node {
+            self.trait_impls.pop();
+        }
+    }
+
+    fn check_impl_item(&mut self, cx: &EarlyContext<'_>, item: &ImplItem) {
+        // the signatures of trait impl methods are given by the trait
+        if self.trait_impls.last() == Some(&true) {
+            return;
+        }
+        if let ImplItemKind::Method(ref sig, ref body) = item.node {
+            self.check_fn_sig(cx, &sig.decl, &item.generics, Some(body), item.span);
+        }
+    }
+
+    fn check_trait_item(&mut self, cx: &EarlyContext<'_>, item: &TraitItem) {
+        if let TraitItemKind::Method(ref sig, ref body) = item.node {
+            self.check_fn_sig(
+                cx,
+                &sig.decl,
+                &item.generics,
+                body.as_ref().map(|body| &**body),
+                item.span,
+            );
+        }
+    }
+
+    fn enter_lint_attrs(&mut self, _: &EarlyContext<'_>, attrs: &[Attribute]) {
+        self.msrv.enter_lint_attrs(attrs);
+    }
+
+    fn exit_lint_attrs(&mut self, _: &EarlyContext<'_>, attrs: &[Attribute]) {
+        self.msrv.exit_lint_attrs(attrs);
+    }
+}
+
+/// Collects the spans of all `impl Trait` types.
+struct ImplTraitVisitor {
+    spans: Vec<Span>,
+}
+
+impl<'ast> Visitor<'ast> for ImplTraitVisitor {
+    fn visit_ty(&mut self, ty: &'ast Ty) {
+        if let TyKind::ImplTrait(..) = ty.node {
+            self.spans.push(ty.span);
+        }
+        walk_ty(self, ty);
+    }
+}
+
+/// Checks whether every named type parameter is only used in the type of a
+/// single argument, so it can be replaced by an `impl Trait` argument.
+fn type_params_replaceable(decl: &FnDecl, generics: &Generics, body: Option<&Block>) -> bool {
+    generics.params.iter().all(|param| {
+        if let GenericParamKind::Lifetime = param.kind {
+            return true;
+        }
+        let name = param.ident.name;
+        let used_in = |visit: &mut dyn FnMut(&mut TypeParamVisitor)| {
+            let mut visitor = TypeParamVisitor { name, count: 0 };
+            visit(&mut visitor);
+            visitor.count
+        };
+        let in_args = used_in(&mut |visitor| {
+            for arg in &decl.inputs {
+                visitor.visit_ty(&arg.ty);
+            }
+        });
+        let elsewhere = used_in(&mut |visitor| {
+            visitor.visit_fn_ret_ty(&decl.output);
+            for other in &generics.params {
+                for bound in &other.bounds {
+                    visitor.visit_param_bound(bound);
+                }
+            }
+            for predicate in &generics.where_clause.predicates {
+                match *predicate {
+                    // `where T: Bound` is fine, it becomes `impl Bound`
+                    WherePredicate::BoundPredicate(ref predicate) => {
+                        if !is_type_param(&predicate.bounded_ty, name) {
+                            visitor.visit_ty(&predicate.bounded_ty);
+                        }
+                        for bound in &predicate.bounds {
+                            visitor.visit_param_bound(bound);
+                        }
+                    },
+                    _ => walk_where_predicate(visitor, predicate),
+                }
+            }
+            if let Some(body) = body {
+                visitor.visit_block(body);
+            }
+        });
+        in_args == 1 && elsewhere == 0
+    })
+}
+
+fn is_type_param(ty: &Ty, name: Name) -> bool {
+    match ty.node {
+        TyKind::Path(None, ref path) => path.segments.len() == 1 && path.segments[0].ident.name == name,
+        _ => false,
+    }
+}
+
+/// Counts the paths starting with the name of a type parameter, e.g. `T` or
+/// `T::default`.
+struct TypeParamVisitor {
+    name: Name,
+    count: usize,
+}
+
+impl<'ast> Visitor<'ast> for TypeParamVisitor {
+    fn visit_path(&mut self, path: &'ast Path, _: NodeId) {
+        if path
+            .segments
+            .first()
+            .map_or(false, |segment| segment.ident.name == self.name)
+        {
+            self.count += 1;
+        }
+        walk_path(self, path);
+    }
+}
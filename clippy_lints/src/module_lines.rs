@@ -0,0 +1,136 @@
+//! lint on modules and files with too many lines
+
+use crate::utils::{in_macro, snippet, span_lint};
+use rustc::lint::{in_external_macro, EarlyContext, EarlyLintPass, LintArray, LintContext, LintPass};
+use rustc::{declare_tool_lint, lint_array};
+use syntax::ast::*;
+use syntax::source_map::Span;
+
+/// **What it does:** Checks for files and inline modules with a large number
+/// of lines.
+///
+/// The maximum can be configured with `too-many-lines-in-module-threshold`.
+/// Only lines containing code are counted, unless `count-all-module-lines`
+/// is set, in which case comments and blank lines inside items count too.
+/// Items removed by `#[cfg]` are never counted.
+///
+/// **Why is this bad?** Large modules are hard to navigate and usually mix
+/// several concerns. Consider splitting them into submodules.
+///
+/// **Known problems:** Attributes and doc comments of items aren't counted.
+/// Neither are items generated by macros.
+///
+/// **Example:**
+/// ```rust
+/// mod huge {
+///     // ... 1000 more LoC
+/// }
+/// ```
+declare_clippy_lint! {
+    pub TOO_MANY_LINES_IN_MODULE,
+    pedantic,
+    "files or modules with too many lines"
+}
+
+#[derive(Copy, Clone)]
+pub struct ModuleLines {
+    max_lines: u64,
+    count_all: bool,
+}
+
+impl ModuleLines {
+    pub fn new(max_lines: u64, count_all: bool) -> Self {
+        Self { max_lines, count_all }
+    }
+
+    fn check_module(self, cx: &EarlyContext<'_>, module: &Mod, span: Span, is_file: bool) {
+        if in_external_macro(cx.sess(), span) {
+            return;
+        }
+        if self.module_lines(cx, module) > self.max_lines {
+            span_lint(
+                cx,
+                TOO_MANY_LINES_IN_MODULE,
+                span,
+                if is_file {
+                    "this file has a large number of lines"
+                } else {
+                    "this module has a large number of lines"
+                },
+            );
+        }
+    }
+
+    /// Counts the lines of the items of `module`, not including the contents
+    /// of submodules in other files.
+    fn module_lines(self, cx: &EarlyContext<'_>, module: &Mod) -> u64 {
+        module
+            .items
+            .iter()
+            .filter(|item| !in_macro(item.span))
+            .map(|item| match item.node {
+                ItemKind::Mod(ref inner) if !inner.inline => 1,
+                // the lines with the braces
+                ItemKind::Mod(ref inner) => 2 + self.module_lines(cx, inner),
+                _ => self.lines(&snippet(cx, item.span, "")),
+            })
+            .sum()
+    }
+
+    fn lines(self, code: &str) -> u64 {
+        if self.count_all {
+            return code.lines().count() as u64;
+        }
+        let mut count = 0;
+        let mut in_comment = false;
+        for line in code.lines() {
+            let mut line = line.trim();
+            let mut has_code = false;
+            while !line.is_empty() {
+                if in_comment {
+                    match line.find("*/") {
+                        Some(i) => {
+                            line = line[i + 2..].trim_start();
+                            in_comment = false;
+                        },
+                        None => break,
+                    }
+                } else if line.starts_with("//") {
+                    break;
+                } else if line.starts_with("/*") {
+                    line = &line[2..];
+                    in_comment = true;
+                } else {
+                    has_code = true;
+                    break;
+                }
+            }
+            if has_code {
+                count += 1;
+            }
+        }
+        count
+    }
+}
+
+impl LintPass for ModuleLines {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(TOO_MANY_LINES_IN_MODULE)
+    }
+
+    fn name(&self) -> &'static str {
+        "ModuleLines"
+    }
+}
+
+impl EarlyLintPass for ModuleLines {
+    fn check_crate(&mut self, cx: &EarlyContext<'_>, krate: &Crate) {
+        self.check_module(cx, &krate.module, krate.span.shrink_to_lo(), true);
+    }
+
+    fn check_item(&mut self, cx: &EarlyContext<'_>, item: &Item) {
+        if let ItemKind::Mod(ref module) = item.node {
+            self.check_module(cx, module, cx.sess().source_map().def_span(item.span), !module.inline);
+        }
+    }
+}
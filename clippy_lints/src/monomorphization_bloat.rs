@@ -0,0 +1,216 @@
+//! lint on large generic functions that only use their type parameters in an initial conversion
+
+use crate::utils::{in_macro, snippet, span_help_and_lint};
+use if_chain::if_chain;
+use matches::matches;
+use rustc::hir::def::Def;
+use rustc::hir::intravisit::{walk_expr, FnKind, NestedVisitorMap, Visitor};
+use rustc::hir::*;
+use rustc::lint::{in_external_macro, LateContext, LateLintPass, LintArray, LintContext, LintPass};
+use rustc::ty::{self, TypeFoldable};
+use rustc::{declare_tool_lint, lint_array};
+use rustc_data_structures::fx::FxHashSet;
+use syntax::ast::NodeId;
+use syntax::source_map::Span;
+
+/// **What it does:** Checks for large generic functions that only use their
+/// generic arguments to convert them into a concrete type at the top of the
+/// body, e.g. `let s = s.into();` for an `s: impl Into<String>`.
+///
+/// **Why is this bad?** The whole function is compiled again for every type it
+/// is called with, although only the conversion depends on the type. Moving
+/// the rest of the body into a non-generic inner function cuts compile times
+/// and code size.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// fn open<P: AsRef<Path>>(path: P) -> io::Result<File> {
+///     let path = path.as_ref();
+///     // ... many lines using `path`
+/// }
+/// ```
+/// Could be written as:
+/// ```rust
+/// fn open<P: AsRef<Path>>(path: P) -> io::Result<File> {
+///     fn inner(path: &Path) -> io::Result<File> {
+///         // ... many lines using `path`
+///     }
+///     inner(path.as_ref())
+/// }
+/// ```
+declare_clippy_lint! {
+    pub MONOMORPHIZATION_BLOAT,
+    pedantic,
+    "large generic functions that only use their type parameters in an initial conversion"
+}
+
+#[derive(Copy, Clone)]
+pub struct MonomorphizationBloat {
+    max_lines: u64,
+}
+
+impl MonomorphizationBloat {
+    pub fn new(max_lines: u64) -> Self {
+        Self { max_lines }
+    }
+}
+
+impl LintPass for MonomorphizationBloat {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(MONOMORPHIZATION_BLOAT)
+    }
+
+    fn name(&self) -> &'static str {
+        "MonomorphizationBloat"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for MonomorphizationBloat {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'a, 'tcx>,
+        kind: FnKind<'tcx>,
+        _: &'tcx FnDecl,
+        body: &'tcx Body,
+        span: Span,
+        node_id: NodeId,
+    ) {
+        if in_macro(span) || in_external_macro(cx.sess(), span) {
+            return;
+        }
+        if let FnKind::Closure(..) = kind {
+            return;
+        }
+        // the signatures of trait methods can't be changed
+        if let Some(Node::Item(item)) = cx.tcx.hir().find(cx.tcx.hir().get_parent_node(node_id)) {
+            if matches!(
+                item.node,
+                ItemKind::Impl(_, _, _, _, Some(_), _, _) | ItemKind::Trait(..)
+            ) {
+                return;
+            }
+        }
+        let block = match body.value.node {
+            ExprKind::Block(ref block, _) => block,
+            _ => return,
+        };
+        if snippet(cx, body.value.span, "").lines().count() as u64 <= self.max_lines {
+            return;
+        }
+
+        let fn_def_id = cx.tcx.hir().local_def_id(node_id);
+        let fn_sig = cx.tcx.fn_sig(fn_def_id);
+        let fn_sig = cx.tcx.erase_late_bound_regions(&fn_sig);
+        if fn_sig.output().has_param_types() {
+            return;
+        }
+
+        let mut generic_args = FxHashSet::default();
+        for (ty, arg) in fn_sig.inputs().iter().zip(&body.arguments) {
+            if !ty.has_param_types() {
+                continue;
+            }
+            match (&ty.sty, &arg.pat.node) {
+                (ty::Param(_), &PatKind::Binding(_, id, _, _, None)) => {
+                    generic_args.insert(id);
+                },
+                _ => return,
+            }
+        }
+        if generic_args.is_empty() {
+            return;
+        }
+
+        // the leading `let` statements converting the generic arguments
+        let mut conversions = Vec::new();
+        let mut converted = FxHashSet::default();
+        for stmt in &block.stmts {
+            if_chain! {
+                if let StmtKind::Local(ref local) = stmt.node;
+                if let Some(ref init) = local.init;
+                if let Some(id) = converted_arg(cx, init, &generic_args);
+                if !cx.tables.expr_ty(init).has_param_types();
+                then {
+                    converted.insert(id);
+                    conversions.push(init.hir_id);
+                    continue;
+                }
+            }
+            break;
+        }
+        if converted.len() != generic_args.len() {
+            return;
+        }
+
+        // nothing else in the body may depend on the type parameters
+        let mut visitor = ParamUseVisitor {
+            cx,
+            skip: &conversions,
+            found: false,
+        };
+        visitor.visit_expr(&body.value);
+        if visitor.found {
+            return;
+        }
+
+        span_help_and_lint(
+            cx,
+            MONOMORPHIZATION_BLOAT,
+            cx.sess().source_map().def_span(span),
+            "this generic function only uses its type parameters to convert its arguments",
+            "consider moving the rest of the body into a non-generic inner function, so it is only compiled once",
+        );
+    }
+}
+
+/// If `expr` is a call or method call whose only argument is one of the
+/// generic arguments, returns the id of that argument.
+fn converted_arg(cx: &LateContext<'_, '_>, expr: &Expr, args: &FxHashSet<NodeId>) -> Option<NodeId> {
+    let arg = match expr.node {
+        ExprKind::MethodCall(_, _, ref call_args) | ExprKind::Call(_, ref call_args) if call_args.len() == 1 => {
+            &call_args[0]
+        },
+        _ => return None,
+    };
+    let arg = if let ExprKind::AddrOf(_, ref inner) = arg.node {
+        inner
+    } else {
+        arg
+    };
+    if_chain! {
+        if let ExprKind::Path(ref qpath) = arg.node;
+        if let Def::Local(id) = cx.tables.qpath_def(qpath, arg.hir_id);
+        if args.contains(&id);
+        then {
+            return Some(id);
+        }
+    }
+    None
+}
+
+/// Checks whether any expression, apart from the skipped ones, has a type or
+/// generic arguments depending on a type parameter.
+struct ParamUseVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    skip: &'a [HirId],
+    found: bool,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for ParamUseVisitor<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        if self.found || self.skip.contains(&expr.hir_id) {
+            return;
+        }
+        if self.cx.tables.expr_ty(expr).has_param_types() || self.cx.tables.node_substs(expr.hir_id).has_param_types() {
+            self.found = true;
+            return;
+        }
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
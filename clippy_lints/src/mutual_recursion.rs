@@ -0,0 +1,266 @@
+//! lint on trait and inherent methods calling each other in a cycle
+
+use crate::utils::{
+    match_def_path, may_return, may_return_from_block, opt_def_id, path_to_def, paths, span_lint_and_then,
+};
+use rustc::hir::def_id::DefId;
+use rustc::hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::ty::subst::Substs;
+use rustc::ty::{Instance, ParamEnv, TypeckTables};
+use rustc::{declare_tool_lint, lint_array};
+use rustc_data_structures::fx::FxHashSet;
+use syntax::source_map::Span;
+
+/// **What it does:** Checks for methods of implementations which always call
+/// each other in a cycle, like `Default::default` calling `Self::new`, which
+/// calls `Default::default`. Calls through the blanket implementations of
+/// `ToString` and `Into` count as calls of `Display::fmt` and `From::from`,
+/// so `Display::fmt` calling `self.to_string()` is a cycle as well.
+///
+/// **Why is this bad?** The methods recurse until the stack overflows. Rustc
+/// only detects functions calling themselves directly.
+///
+/// **Known problems:** Only calls outside of conditions, loops and closures,
+/// before any early `return` or `?`, are followed.
+///
+/// **Example:**
+/// ```rust
+/// impl Default for Foo {
+///     fn default() -> Self {
+///         Self::new()
+///     }
+/// }
+///
+/// impl Foo {
+///     fn new() -> Self {
+///         Default::default()
+///     }
+/// }
+/// ```
+declare_clippy_lint! {
+    pub MUTUAL_RECURSION,
+    correctness,
+    "methods of implementations calling each other in an endless cycle"
+}
+
+#[derive(Copy, Clone)]
+pub struct Pass;
+
+impl LintPass for Pass {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(MUTUAL_RECURSION)
+    }
+
+    fn name(&self) -> &'static str {
+        "MutualRecursion"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
+    fn check_impl_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx ImplItem) {
+        if let ImplItemKind::Method(..) = item.node {
+            let start = cx.tcx.hir().local_def_id(item.id);
+            let mut path = Vec::new();
+            if !find_cycle(cx, start, start, &mut path, &mut FxHashSet::default()) {
+                return;
+            }
+            // rustc lints functions calling themselves directly
+            if path.len() == 1 && path[0].through.is_none() {
+                return;
+            }
+            // only lint the cycle once, at its first function
+            if path.iter().any(|call| call.callee < start) {
+                return;
+            }
+            span_lint_and_then(
+                cx,
+                MUTUAL_RECURSION,
+                cx.tcx.def_span(start),
+                "this method always calls itself through other methods",
+                |db| {
+                    for call in &path {
+                        let callee = cx.tcx.item_path_str(call.callee);
+                        let msg = match call.through {
+                            Some(through) => format!("this calls `{}` through `{}`", callee, through),
+                            None => format!("this calls `{}`", callee),
+                        };
+                        db.span_note(call.span, &msg);
+                    }
+                },
+            );
+        }
+    }
+}
+
+/// An unconditional call of a local method.
+#[derive(Copy, Clone)]
+struct Call {
+    callee: DefId,
+    span: Span,
+    /// The blanket implementation the call goes through, if any.
+    through: Option<&'static str>,
+}
+
+/// Searches for a cycle of calls from `current` back to `start`, pushing the
+/// calls of the cycle to `path`.
+fn find_cycle<'a, 'tcx>(
+    cx: &'a LateContext<'a, 'tcx>,
+    start: DefId,
+    current: DefId,
+    path: &mut Vec<Call>,
+    visited: &mut FxHashSet<DefId>,
+) -> bool {
+    for call in unconditional_calls(cx, current) {
+        path.push(call);
+        if call.callee == start || (visited.insert(call.callee) && find_cycle(cx, start, call.callee, path, visited)) {
+            return true;
+        }
+        path.pop();
+    }
+    false
+}
+
+/// Returns the calls of local methods the method `def_id` always makes.
+fn unconditional_calls<'a, 'tcx>(cx: &'a LateContext<'a, 'tcx>, def_id: DefId) -> Vec<Call> {
+    let body_id = match cx.tcx.hir().as_local_node_id(def_id).map(|id| cx.tcx.hir().get(id)) {
+        Some(Node::ImplItem(&ImplItem {
+            node: ImplItemKind::Method(_, body_id),
+            ..
+        })) => body_id,
+        _ => return Vec::new(),
+    };
+    let mut visitor = CallVisitor {
+        cx,
+        tables: cx.tcx.typeck_tables_of(def_id),
+        param_env: cx.tcx.param_env(def_id),
+        calls: Vec::new(),
+        returned: false,
+    };
+    visitor.visit_expr(&cx.tcx.hir().body(body_id).value);
+    visitor.calls
+}
+
+struct CallVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    tables: &'tcx TypeckTables<'tcx>,
+    param_env: ParamEnv<'tcx>,
+    calls: Vec<Call>,
+    /// Whether the method may have returned already, all later calls are
+    /// conditional.
+    returned: bool,
+}
+
+impl<'a, 'tcx> CallVisitor<'a, 'tcx> {
+    fn callee(&self, expr: &Expr) -> Option<Call> {
+        let (def_id, substs) = match expr.node {
+            ExprKind::MethodCall(..) => (
+                self.tables.type_dependent_defs().get(expr.hir_id)?.def_id(),
+                self.tables.node_substs(expr.hir_id),
+            ),
+            ExprKind::Call(ref fun, _) => match fun.node {
+                ExprKind::Path(ref qpath) => (
+                    opt_def_id(self.tables.qpath_def(qpath, fun.hir_id))?,
+                    self.tables.node_substs(fun.hir_id),
+                ),
+                _ => return None,
+            },
+            _ => return None,
+        };
+        let (callee, through) = self.resolve(def_id, substs)?;
+        Some(Call {
+            callee,
+            span: expr.span,
+            through,
+        })
+    }
+
+    /// Returns the local method the call of `def_id` with `substs` ends up
+    /// in, looking through the blanket implementations of `ToString` and
+    /// `Into`.
+    fn resolve(&self, def_id: DefId, substs: &'tcx Substs<'tcx>) -> Option<(DefId, Option<&'static str>)> {
+        let tcx = self.cx.tcx;
+        let resolved = Instance::resolve(tcx, self.param_env, def_id, substs)?.def_id();
+        if resolved.is_local() {
+            return Some((resolved, None));
+        }
+        let (through, target, target_substs) = if match_def_path(tcx, def_id, &paths::TO_STRING_METHOD) {
+            // `impl<T: Display + ?Sized> ToString for T`
+            (
+                "ToString::to_string",
+                &paths::DISPLAY_FMT_METHOD[..],
+                tcx.mk_substs_trait(substs.type_at(0), &[]),
+            )
+        } else if match_def_path(tcx, def_id, &paths::INTO_INTO) {
+            // `impl<T, U: From<T>> Into<U> for T`
+            (
+                "Into::into",
+                &paths::FROM_FROM[..],
+                tcx.mk_substs_trait(substs.type_at(1), &[substs.type_at(0).into()]),
+            )
+        } else {
+            return None;
+        };
+        let target = opt_def_id(path_to_def(self.cx, target)?)?;
+        let resolved = Instance::resolve(tcx, self.param_env, target, target_substs)?.def_id();
+        if resolved.is_local() {
+            Some((resolved, Some(through)))
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for CallVisitor<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        if self.returned {
+            return;
+        }
+        match expr.node {
+            ExprKind::If(ref cond, ref then, ref otherwise) => {
+                self.visit_expr(cond);
+                self.returned = may_return(then) || otherwise.as_ref().map_or(false, |e| may_return(e));
+            },
+            ExprKind::Match(ref scrutinee, ref arms, source) => {
+                self.visit_expr(scrutinee);
+                self.returned = source == MatchSource::TryDesugar
+                    || arms.iter().any(|arm| {
+                        may_return(&arm.body)
+                            || match arm.guard {
+                                Some(Guard::If(ref guard)) => may_return(guard),
+                                None => false,
+                            }
+                    });
+            },
+            ExprKind::Binary(op, ref lhs, ref rhs) if op.node == BinOpKind::And || op.node == BinOpKind::Or => {
+                self.visit_expr(lhs);
+                self.returned = may_return(rhs);
+            },
+            ExprKind::While(ref cond, ref block, _) => {
+                self.visit_expr(cond);
+                self.returned = may_return_from_block(block);
+            },
+            ExprKind::Loop(ref block, ..) => self.returned = may_return_from_block(block),
+            ExprKind::Closure(..) => {},
+            ExprKind::Ret(ref value) => {
+                if let Some(ref value) = *value {
+                    self.visit_expr(value);
+                }
+                self.returned = true;
+            },
+            _ => {
+                walk_expr(self, expr);
+                if !self.returned {
+                    if let Some(call) = self.callee(expr) {
+                        self.calls.push(call);
+                    }
+                }
+            },
+        }
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
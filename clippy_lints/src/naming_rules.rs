@@ -0,0 +1,173 @@
+//! lint on names violating the naming rules configured in `clippy.toml`
+
+use crate::utils::conf::{NamingCase, NamingRule};
+use crate::utils::{in_macro, span_lint};
+use matches::matches;
+use rustc::hir::*;
+use rustc::lint::{in_external_macro, LateContext, LateLintPass, LintArray, LintContext, LintPass};
+use rustc::{declare_tool_lint, lint_array};
+use syntax::ast::{Attribute, Ident};
+
+/// **What it does:** Checks names against the rules configured with
+/// `naming-rules` in `clippy.toml`. Does nothing if no rules are configured.
+///
+/// A rule has a `reason` and any of:
+/// * `banned`: a regex names must not match,
+/// * `required`: a regex names must match,
+/// * `case`: the case names must use, `snake_case`, `UPPER_SNAKE_CASE` or `CamelCase`.
+///
+/// The regexes match anywhere in the name unless they're anchored with `^`
+/// and `$`. Invalid regexes and cases are configuration errors. The `kinds`
+/// of names a rule applies to can be restricted to `fn`, `test`, `static`,
+/// `const`, `type`, `mod`, `field` and `local`.
+///
+/// **Why is this bad?** Teams often have naming conventions that go beyond the
+/// compiler's case checks.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```toml
+/// naming-rules = [
+///     { banned = "mgr", reason = "write out `manager`" },
+///     { kinds = ["test"], required = "^test_", reason = "tests start with `test_`" },
+///     { kinds = ["fn"], banned = "^(do|handle)_", reason = "name functions after what they do" },
+/// ]
+/// ```
+declare_clippy_lint! {
+    pub NAMING_RULES,
+    style,
+    "names violating the configured naming rules"
+}
+
+#[derive(Clone, Debug)]
+pub struct NamingRules {
+    rules: Vec<NamingRule>,
+}
+
+impl NamingRules {
+    pub fn new(rules: Vec<NamingRule>) -> Self {
+        Self { rules }
+    }
+
+    fn check_name(&self, cx: &LateContext<'_, '_>, ident: Ident, kind: &str, is_test: bool) {
+        if in_macro(ident.span) || in_external_macro(cx.sess(), ident.span) {
+            return;
+        }
+        let name = ident.as_str();
+        for rule in &self.rules {
+            let applies = rule.kinds.is_empty()
+                || rule.kinds.iter().any(|k| k == kind)
+                || (is_test && rule.kinds.iter().any(|k| k == "test"));
+            if !applies {
+                continue;
+            }
+            let violated = rule.banned.as_ref().map_or(false, |re| re.is_match(&name))
+                || rule.required.as_ref().map_or(false, |re| !re.is_match(&name))
+                || rule.case.map_or(false, |case| !has_case(case, &name));
+            if violated {
+                span_lint(
+                    cx,
+                    NAMING_RULES,
+                    ident.span,
+                    &format!("`{}` violates a naming rule: {}", name, rule.reason),
+                );
+            }
+        }
+    }
+}
+
+impl LintPass for NamingRules {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(NAMING_RULES)
+    }
+
+    fn name(&self) -> &'static str {
+        "NamingRules"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for NamingRules {
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
+        if self.rules.is_empty() {
+            return;
+        }
+        let kind = match item.node {
+            ItemKind::Fn(..) => "fn",
+            ItemKind::Static(..) => "static",
+            ItemKind::Const(..) => "const",
+            ItemKind::Struct(..)
+            | ItemKind::Enum(..)
+            | ItemKind::Union(..)
+            | ItemKind::Trait(..)
+            | ItemKind::Ty(..)
+            | ItemKind::Existential(..) => "type",
+            ItemKind::Mod(..) => "mod",
+            _ => return,
+        };
+        self.check_name(cx, item.ident, kind, is_test(&item.attrs));
+    }
+
+    fn check_impl_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx ImplItem) {
+        if self.rules.is_empty() {
+            return;
+        }
+        // the names of trait items are given by the trait
+        if let Some(Node::Item(parent)) = cx.tcx.hir().find(cx.tcx.hir().get_parent(item.id)) {
+            if matches!(parent.node, ItemKind::Impl(_, _, _, _, Some(_), _, _)) {
+                return;
+            }
+        }
+        let kind = match item.node {
+            ImplItemKind::Method(..) => "fn",
+            ImplItemKind::Const(..) => "const",
+            ImplItemKind::Type(..) | ImplItemKind::Existential(..) => "type",
+        };
+        self.check_name(cx, item.ident, kind, false);
+    }
+
+    fn check_trait_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx TraitItem) {
+        if self.rules.is_empty() {
+            return;
+        }
+        let kind = match item.node {
+            TraitItemKind::Method(..) => "fn",
+            TraitItemKind::Const(..) => "const",
+            TraitItemKind::Type(..) => "type",
+        };
+        self.check_name(cx, item.ident, kind, false);
+    }
+
+    fn check_struct_field(&mut self, cx: &LateContext<'a, 'tcx>, field: &'tcx StructField) {
+        // tuple struct fields are named by their index
+        if self.rules.is_empty() || field.ident.as_str().starts_with(|c: char| c.is_digit(10)) {
+            return;
+        }
+        self.check_name(cx, field.ident, "field", false);
+    }
+
+    fn check_pat(&mut self, cx: &LateContext<'a, 'tcx>, pat: &'tcx Pat) {
+        if self.rules.is_empty() {
+            return;
+        }
+        if let PatKind::Binding(_, _, _, ident, _) = pat.node {
+            self.check_name(cx, ident, "local", false);
+        }
+    }
+}
+
+fn is_test(attrs: &[Attribute]) -> bool {
+    attrs
+        .iter()
+        .any(|attr| attr.name() == "test" || attr.name() == "rustc_test_marker")
+}
+
+/// Checks whether `name` uses `case`.
+fn has_case(case: NamingCase, name: &str) -> bool {
+    let name = name.trim_start_matches('_');
+    match case {
+        NamingCase::SnakeCase => !name.chars().any(char::is_uppercase),
+        NamingCase::UpperSnakeCase => !name.chars().any(char::is_lowercase),
+        NamingCase::CamelCase => name.chars().next().map_or(true, char::is_uppercase) && !name.contains('_'),
+    }
+}
@@ -4,6 +4,7 @@
 
 use crate::utils::sugg::Sugg;
 use crate::utils::{in_macro, span_lint, span_lint_and_sugg};
+use if_chain::if_chain;
 use rustc::hir::*;
 use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
 use rustc::{declare_tool_lint, lint_array};
@@ -13,7 +14,8 @@ use syntax::source_map::Spanned;
 
 /// **What it does:** Checks for expressions of the form `if c { true } else {
 /// false }`
-/// (or vice versa) and suggest using the condition directly.
+/// (or vice versa), and for `if c { return true; } return false;` at the end of
+/// a block, and suggest using the condition directly.
 ///
 /// **Why is this bad?** Redundant code.
 ///
@@ -29,6 +31,11 @@ use syntax::source_map::Spanned;
 /// } else {
 ///     true
 /// }
+///
+/// if x {
+///     return true;
+/// }
+/// return false;
 /// ```
 declare_clippy_lint! {
     pub NEEDLESS_BOOL,
@@ -70,6 +77,9 @@ impl LintPass for NeedlessBool {
 impl<'a, 'tcx> LateLintPass<'a, 'tcx> for NeedlessBool {
     fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, e: &'tcx Expr) {
         use self::Expression::*;
+        if let ExprKind::Block(ref block, _) = e.node {
+            check_return_after_if(cx, e, block);
+        }
         if let ExprKind::If(ref pred, ref then_block, Some(ref else_expr)) = e.node {
             let reduce = |ret, not| {
                 let mut applicability = Applicability::MachineApplicable;
@@ -125,6 +135,66 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for NeedlessBool {
     }
 }
 
+/// Checks for `if c { return true; }` followed by `return false;` or a tail
+/// `false` at the end of `block`, the body of `e`.
+fn check_return_after_if(cx: &LateContext<'_, '_>, e: &Expr, block: &Block) {
+    let len = block.stmts.len();
+    let (if_stmt, after, after_span, semi) = match block.expr {
+        Some(ref tail) if len >= 1 => (&block.stmts[len - 1], fetch_bool_expr(tail), tail.span, false),
+        None if len >= 2 => {
+            let last = &block.stmts[len - 1];
+            match last.node {
+                StmtKind::Semi(ref ret) => (&block.stmts[len - 2], fetch_bool_expr(ret), last.span, true),
+                _ => return,
+            }
+        },
+        _ => return,
+    };
+    let (ret, value) = match after {
+        Expression::RetBool(value) => (true, value),
+        // a tail `false` is only returned if the block is the body of a function
+        Expression::Bool(value) if block.expr.is_some() && parent_node_is_fn(e, cx) => (false, value),
+        _ => return,
+    };
+    let if_expr = match if_stmt.node {
+        StmtKind::Semi(ref expr) | StmtKind::Expr(ref expr) => expr,
+        _ => return,
+    };
+    if_chain! {
+        if let ExprKind::If(ref pred, ref then_block, None) = if_expr.node;
+        if let ExprKind::Block(ref then_block, _) = then_block.node;
+        if let Expression::RetBool(then_value) = fetch_bool_block(then_block);
+        if then_value != value && !in_macro(if_expr.span);
+        then {
+            let mut applicability = Applicability::MachineApplicable;
+            let snip = Sugg::hir_with_applicability(cx, pred, "<predicate>", &mut applicability);
+            let snip = if then_value { snip } else { !snip };
+            let snip = if ret { snip.make_return() } else { snip };
+            span_lint_and_sugg(
+                cx,
+                NEEDLESS_BOOL,
+                if_expr.span.to(after_span),
+                "this if-then expression and the code after it return bool literals",
+                "you can reduce it to",
+                format!("{}{}", snip, if semi { ";" } else { "" }),
+                applicability,
+            );
+        }
+    }
+}
+
+fn parent_node_is_fn<'a, 'b>(expr: &Expr, cx: &LateContext<'a, 'b>) -> bool {
+    let parent_id = cx.tcx.hir().get_parent_node(expr.id);
+    match cx.tcx.hir().get(parent_id) {
+        rustc::hir::Node::Item(_) | rustc::hir::Node::ImplItem(_) | rustc::hir::Node::TraitItem(_) => true,
+        rustc::hir::Node::Expr(e) => match e.node {
+            ExprKind::Closure(..) => true,
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
 fn parent_node_is_if_expr<'a, 'b>(expr: &Expr, cx: &LateContext<'a, 'b>) -> bool {
     let parent_id = cx.tcx.hir().get_parent_node(expr.id);
     let parent_node = cx.tcx.hir().get(parent_id);
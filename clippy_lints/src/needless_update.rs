@@ -1,8 +1,10 @@
-use crate::utils::span_lint;
+use crate::utils::{match_def_path, opt_def_id, paths, span_help_and_lint, span_lint};
+use if_chain::if_chain;
 use rustc::hir::{Expr, ExprKind};
 use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
 use rustc::ty;
 use rustc::{declare_tool_lint, lint_array};
+use rustc_data_structures::fx::FxHashSet;
 
 /// **What it does:** Checks for needlessly including a base struct on update
 /// when all fields are changed anyway.
@@ -26,12 +28,67 @@ declare_clippy_lint! {
     "using `Foo { ..base }` when there are no missing fields"
 }
 
-#[derive(Copy, Clone)]
-pub struct Pass;
+/// **What it does:** Checks for struct expressions leaving fields to
+/// `..Default::default()` which are configured to be required with
+/// `required-fields` in `clippy.toml`, by name like `id` or with the struct
+/// like `Connection::handle`. Bases which set all the fields anyway are linted
+/// by `needless_update`.
+///
+/// **Why is this bad?** Some fields, like IDs and handles, have no sensible
+/// default. A default value is accepted silently if the field is forgotten.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// // with `required-fields = ["id"]`
+/// User {
+///     name: "foo".to_owned(),
+///     ..Default::default()
+/// }
+/// ```
+/// Could be written as:
+/// ```rust
+/// User {
+///     id: next_id(),
+///     name: "foo".to_owned(),
+///     ..Default::default()
+/// }
+/// ```
+declare_clippy_lint! {
+    pub DEFAULTED_REQUIRED_FIELDS,
+    restriction,
+    "leaving fields configured as required to `..Default::default()`"
+}
+
+#[derive(Clone)]
+pub struct Pass {
+    required_fields: FxHashSet<String>,
+}
+
+impl Pass {
+    pub fn new(required_fields: FxHashSet<String>) -> Self {
+        Self { required_fields }
+    }
+
+    /// Checks whether the field `field` of the struct at `struct_path` is
+    /// required.
+    fn is_required(&self, struct_path: &str, field: &str) -> bool {
+        self.required_fields.iter().any(|required| {
+            let mut parts = required.rsplitn(2, "::");
+            if parts.next() != Some(field) {
+                return false;
+            }
+            parts.next().map_or(true, |ty| {
+                struct_path == ty || struct_path.ends_with(&format!("::{}", ty))
+            })
+        })
+    }
+}
 
 impl LintPass for Pass {
     fn get_lints(&self) -> LintArray {
-        lint_array!(NEEDLESS_UPDATE)
+        lint_array!(NEEDLESS_UPDATE, DEFAULTED_REQUIRED_FIELDS)
     }
 
     fn name(&self) -> &'static str {
@@ -44,15 +101,47 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
         if let ExprKind::Struct(_, ref fields, Some(ref base)) = expr.node {
             let ty = cx.tables.expr_ty(expr);
             if let ty::Adt(def, _) = ty.sty {
-                if fields.len() == def.non_enum_variant().fields.len() {
+                let variant = def.non_enum_variant();
+                if fields.len() == variant.fields.len() {
                     span_lint(
                         cx,
                         NEEDLESS_UPDATE,
                         base.span,
                         "struct update has no effect, all the fields in the struct have already been specified",
                     );
+                } else if !self.required_fields.is_empty() && is_default_call(cx, base) {
+                    let struct_path = cx.tcx.item_path_str(def.did);
+                    for field in &variant.fields {
+                        let name = field.ident.as_str();
+                        if !fields.iter().any(|f| f.ident.name == field.ident.name)
+                            && self.is_required(&struct_path, &name)
+                        {
+                            span_help_and_lint(
+                                cx,
+                                DEFAULTED_REQUIRED_FIELDS,
+                                base.span,
+                                &format!("the required field `{}` is left to `Default::default()`", name),
+                                &format!("set `{}` explicitly", name),
+                            );
+                        }
+                    }
                 }
             }
         }
     }
 }
+
+/// Checks whether `expr` is a call of `Default::default`, like
+/// `Default::default()` or `Foo::default()`.
+fn is_default_call(cx: &LateContext<'_, '_>, expr: &Expr) -> bool {
+    if_chain! {
+        if let ExprKind::Call(ref fun, ref args) = expr.node;
+        if args.is_empty();
+        if let ExprKind::Path(ref qpath) = fun.node;
+        if let Some(def_id) = opt_def_id(cx.tables.qpath_def(qpath, fun.hir_id));
+        then {
+            return match_def_path(cx.tcx, def_id, &paths::DEFAULT_TRAIT_METHOD);
+        }
+    }
+    false
+}
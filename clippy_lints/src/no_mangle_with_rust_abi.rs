@@ -0,0 +1,85 @@
+//! lint on `#[no_mangle]` functions with the Rust ABI
+
+use crate::utils::{in_macro, snippet_with_applicability, span_lint_and_then};
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::{declare_tool_lint, lint_array};
+use rustc_errors::Applicability;
+use rustc_target::spec::abi::Abi;
+use syntax::attr;
+
+/// **What it does:** Checks for `#[no_mangle]` functions which don't declare
+/// an ABI, like `#[no_mangle] pub fn foo() {}`.
+///
+/// **Why is this bad?** The symbol of the function is exported to be called
+/// from other languages, but it uses the Rust ABI, which is unspecified and
+/// may change between compiler versions. The function was most likely meant
+/// to be `extern "C"`.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// #[no_mangle]
+/// pub fn callback(x: i32) -> i32 {
+///     x + 1
+/// }
+/// ```
+/// Use instead:
+/// ```rust
+/// #[no_mangle]
+/// pub extern "C" fn callback(x: i32) -> i32 {
+///     x + 1
+/// }
+/// ```
+declare_clippy_lint! {
+    pub NO_MANGLE_WITH_RUST_ABI,
+    pedantic,
+    "`#[no_mangle]` functions with the Rust ABI"
+}
+
+#[derive(Copy, Clone)]
+pub struct Pass;
+
+impl LintPass for Pass {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(NO_MANGLE_WITH_RUST_ABI)
+    }
+
+    fn name(&self) -> &'static str {
+        "NoMangleWithRustAbi"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
+        if let ItemKind::Fn(_, header, ..) = item.node {
+            if header.abi != Abi::Rust || !attr::contains_name(&item.attrs, "no_mangle") || in_macro(item.span) {
+                return;
+            }
+            let span = cx.tcx.sess.source_map().def_span(item.span);
+            span_lint_and_then(
+                cx,
+                NO_MANGLE_WITH_RUST_ABI,
+                span,
+                "`#[no_mangle]` function with the Rust ABI",
+                |db| {
+                    let mut applicability = Applicability::MaybeIncorrect;
+                    // the visibility and qualifiers, up to and including the `fn` keyword
+                    let prefix = snippet_with_applicability(cx, span.until(item.ident.span), "fn", &mut applicability);
+                    let rest = snippet_with_applicability(cx, item.ident.span.to(span), "..", &mut applicability);
+                    let prefix = prefix.trim_end();
+                    if prefix.ends_with("fn") {
+                        db.span_suggestion(
+                            span,
+                            "set an ABI",
+                            format!("{}extern \"C\" fn {}", &prefix[..prefix.len() - 2], rest),
+                            applicability,
+                        );
+                    }
+                    db.help("or remove `#[no_mangle]` if the function isn't called from other languages");
+                },
+            );
+        }
+    }
+}
@@ -2,13 +2,15 @@
 //!
 //! This lint is **deny** by default.
 
-use crate::utils::{in_constant, in_macro, is_copy, span_lint_and_then};
+use crate::utils::{in_constant, in_macro, is_copy, resolve_type_paths, span_lint_and_then};
 use rustc::hir::def::Def;
+use rustc::hir::def_id::DefId;
 use rustc::hir::*;
 use rustc::lint::{LateContext, LateLintPass, Lint, LintArray, LintPass};
 use rustc::ty::adjustment::Adjust;
 use rustc::ty::{self, TypeFlags};
 use rustc::{declare_tool_lint, lint_array};
+use rustc_data_structures::fx::FxHashSet;
 use rustc_errors::Applicability;
 use rustc_typeck::hir_ty_to_ty;
 use std::ptr;
@@ -30,6 +32,9 @@ use syntax_pos::{Span, DUMMY_SP};
 /// `std::sync::ONCE_INIT` constant). In this case the use of `const` is legit,
 /// and this lint should be suppressed.
 ///
+/// Types whose interior mutability doesn't matter, e.g. because it's not
+/// observable, can be listed in `ignore-interior-mutability`.
+///
 /// **Example:**
 /// ```rust
 /// use std::sync::atomic::{AtomicUsize, Ordering::SeqCst};
@@ -60,7 +65,8 @@ declare_clippy_lint! {
 ///
 /// The `const` value should be stored inside a `static` item.
 ///
-/// **Known problems:** None
+/// **Known problems:** Types listed in `ignore-interior-mutability` are
+/// considered not to be interior mutable.
 ///
 /// **Example:**
 /// ```rust
@@ -106,11 +112,8 @@ impl Source {
     }
 }
 
-fn verify_ty_bound<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, ty: ty::Ty<'tcx>, source: Source) {
-    if ty.is_freeze(cx.tcx, cx.param_env, DUMMY_SP) || is_copy(cx, ty) {
-        // an UnsafeCell is !Copy, and an UnsafeCell is also the only type which
-        // is !Freeze, thus if our type is Copy we can be sure it must be Freeze
-        // as well.
+fn verify_ty_bound<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, ty: ty::Ty<'tcx>, source: Source, ignored: &FxHashSet<DefId>) {
+    if !is_interior_mutable(cx, ty, ignored) {
         return;
     }
 
@@ -141,7 +144,45 @@ fn verify_ty_bound<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, ty: ty::Ty<'tcx>, sourc
     });
 }
 
-pub struct NonCopyConst;
+/// Checks whether `ty` contains an `UnsafeCell` which isn't part of one of the
+/// `ignored` types.
+fn is_interior_mutable<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, ty: ty::Ty<'tcx>, ignored: &FxHashSet<DefId>) -> bool {
+    if ty.is_freeze(cx.tcx, cx.param_env, DUMMY_SP) || is_copy(cx, ty) {
+        // an UnsafeCell is !Copy, and an UnsafeCell is also the only type which
+        // is !Freeze, thus if our type is Copy we can be sure it must be Freeze
+        // as well.
+        return false;
+    }
+    match ty.sty {
+        ty::Adt(adt, _) if ignored.contains(&adt.did) => false,
+        ty::Adt(adt, substs) => {
+            let fields = adt
+                .all_fields()
+                .map(|field| field.ty(cx.tcx, substs))
+                .collect::<Vec<_>>();
+            // the type is `UnsafeCell` itself if none of its fields are the cause
+            fields.iter().all(|ty| ty.is_freeze(cx.tcx, cx.param_env, DUMMY_SP))
+                || fields.iter().any(|&ty| is_interior_mutable(cx, ty, ignored))
+        },
+        ty::Array(ty, _) | ty::Slice(ty) => is_interior_mutable(cx, ty, ignored),
+        ty::Tuple(tys) => tys.iter().any(|&ty| is_interior_mutable(cx, ty, ignored)),
+        _ => true,
+    }
+}
+
+pub struct NonCopyConst {
+    ignored_paths: Vec<String>,
+    ignored: FxHashSet<DefId>,
+}
+
+impl NonCopyConst {
+    pub fn new(ignored_paths: Vec<String>) -> Self {
+        Self {
+            ignored_paths,
+            ignored: FxHashSet::default(),
+        }
+    }
+}
 
 impl LintPass for NonCopyConst {
     fn get_lints(&self) -> LintArray {
@@ -154,10 +195,14 @@ impl LintPass for NonCopyConst {
 }
 
 impl<'a, 'tcx> LateLintPass<'a, 'tcx> for NonCopyConst {
+    fn check_crate(&mut self, cx: &LateContext<'a, 'tcx>, _: &'tcx Crate) {
+        self.ignored = resolve_type_paths(cx, &self.ignored_paths);
+    }
+
     fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, it: &'tcx Item) {
         if let ItemKind::Const(hir_ty, ..) = &it.node {
             let ty = hir_ty_to_ty(cx.tcx, hir_ty);
-            verify_ty_bound(cx, ty, Source::Item { item: it.span });
+            verify_ty_bound(cx, ty, Source::Item { item: it.span }, &self.ignored);
         }
     }
 
@@ -171,6 +216,7 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for NonCopyConst {
                     ty: hir_ty.span,
                     item: trait_item.span,
                 },
+                &self.ignored,
             );
         }
     }
@@ -189,6 +235,7 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for NonCopyConst {
                         ty: hir_ty.span,
                         item: impl_item.span,
                     },
+                    &self.ignored,
                 );
             }
         }
@@ -267,7 +314,7 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for NonCopyConst {
                 cx.tables.expr_ty(dereferenced_expr)
             };
 
-            verify_ty_bound(cx, ty, Source::Expr { expr: expr.span });
+            verify_ty_bound(cx, ty, Source::Expr { expr: expr.span }, &self.ignored);
         }
     }
 }
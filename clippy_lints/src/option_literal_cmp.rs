@@ -0,0 +1,157 @@
+//! lint on comparing an `Option` to `Some` of a literal or to `None`
+
+use crate::utils::sugg::Sugg;
+use crate::utils::{
+    get_parent_expr, in_macro, match_qpath, match_type, paths, snippet_with_applicability, span_lint_and_sugg,
+};
+use if_chain::if_chain;
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::{declare_tool_lint, lint_array};
+use rustc_errors::Applicability;
+use syntax::ast::LitKind;
+
+/// **What it does:** Checks for comparing an `Option` to `Some` of a literal,
+/// like `opt == Some(3)`, or to `None`.
+///
+/// **Why is this bad?** The comparison needs `PartialEq` for `Option`, which
+/// doesn't express the intent of checking for a variant. `is_none()` and
+/// `is_some()` are clearer than comparing to `None`, and `if let` or `map_or`
+/// check the value of a `Some` directly.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// if opt == Some(3) {}
+/// let missing = opt == None;
+/// ```
+/// Could be written as:
+/// ```rust
+/// if let Some(3) = opt {}
+/// let missing = opt.is_none();
+/// ```
+declare_clippy_lint! {
+    pub OPTION_LITERAL_CMP,
+    pedantic,
+    "comparing an `Option` to `Some` of a literal or to `None`"
+}
+
+#[derive(Copy, Clone)]
+pub struct Pass;
+
+impl LintPass for Pass {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(OPTION_LITERAL_CMP)
+    }
+
+    fn name(&self) -> &'static str {
+        "OptionLiteralCmp"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if_chain! {
+            if let ExprKind::Binary(op, ref left, ref right) = expr.node;
+            if op.node == BinOpKind::Eq || op.node == BinOpKind::Ne;
+            if !in_macro(expr.span);
+            then {
+                // the `Option` may be on either side
+                for &(opt, other) in &[(left, right), (right, left)] {
+                    if is_none_or_some_literal(other) {
+                        if !is_none_or_some_literal(opt) && match_type(cx, cx.tables.expr_ty(opt), &paths::OPTION) {
+                            check_cmp(cx, expr, op.node == BinOpKind::Eq, opt, other);
+                        }
+                        return;
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn check_cmp<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr, eq: bool, opt: &Expr, other: &Expr) {
+    let mut applicability = Applicability::MachineApplicable;
+    if is_none(other) {
+        let method = if eq { "is_none" } else { "is_some" };
+        let opt = Sugg::hir_with_applicability(cx, opt, "..", &mut applicability).maybe_par();
+        span_lint_and_sugg(
+            cx,
+            OPTION_LITERAL_CMP,
+            expr.span,
+            "comparing an `Option` to `None`",
+            &format!("use `{}()`", method),
+            format!("{}.{}()", opt, method),
+            applicability,
+        );
+        return;
+    }
+    let lit = match some_literal(other) {
+        Some(lit) => lit,
+        None => return,
+    };
+    let lit_snip = snippet_with_applicability(cx, lit.span, "..", &mut applicability);
+    let is_if_cond = get_parent_expr(cx, expr).map_or(false, |parent| match parent.node {
+        ExprKind::If(ref cond, ..) => cond.id == expr.id,
+        _ => false,
+    });
+    let (help, sugg) = if eq && is_if_cond && is_pattern_literal(lit) {
+        let opt = snippet_with_applicability(cx, opt.span, "..", &mut applicability);
+        ("use `if let`", format!("let Some({}) = {}", lit_snip, opt))
+    } else {
+        let opt = Sugg::hir_with_applicability(cx, opt, "..", &mut applicability).maybe_par();
+        // `None` isn't equal to any `Some`
+        let (default, op) = if eq { ("false", "==") } else { ("true", "!=") };
+        (
+            "use `map_or`",
+            format!("{}.map_or({}, |v| v {} {})", opt, default, op, lit_snip),
+        )
+    };
+    span_lint_and_sugg(
+        cx,
+        OPTION_LITERAL_CMP,
+        expr.span,
+        "comparing an `Option` to `Some` of a literal",
+        help,
+        sugg,
+        applicability,
+    );
+}
+
+fn is_none(expr: &Expr) -> bool {
+    match expr.node {
+        ExprKind::Path(ref qpath) => match_qpath(qpath, &paths::OPTION_NONE),
+        _ => false,
+    }
+}
+
+fn is_none_or_some_literal(expr: &Expr) -> bool {
+    is_none(expr) || some_literal(expr).is_some()
+}
+
+/// Returns the literal of `Some(literal)`.
+fn some_literal(expr: &Expr) -> Option<&Expr> {
+    if_chain! {
+        if let ExprKind::Call(ref fun, ref args) = expr.node;
+        if let ExprKind::Path(ref qpath) = fun.node;
+        if match_qpath(qpath, &paths::OPTION_SOME) && args.len() == 1;
+        if let ExprKind::Lit(_) = args[0].node;
+        then {
+            return Some(&args[0]);
+        }
+    }
+    None
+}
+
+/// Checks whether the literal `expr` can be matched by a pattern. Floats can't
+/// be matched reliably.
+fn is_pattern_literal(expr: &Expr) -> bool {
+    match expr.node {
+        ExprKind::Lit(ref lit) => match lit.node {
+            LitKind::Float(..) | LitKind::FloatUnsuffixed(_) => false,
+            _ => true,
+        },
+        _ => false,
+    }
+}
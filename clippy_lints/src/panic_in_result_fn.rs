@@ -0,0 +1,236 @@
+//! lint on panicking in functions which return a `Result`
+
+use crate::consts::constant;
+use crate::utils::{
+    in_macro_not_desugaring, is_in_scope, match_def_path, match_type, opt_def_id, paths, resolve_node,
+    span_help_and_lint, walk_ptrs_ty,
+};
+use if_chain::if_chain;
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::ty;
+use rustc::{declare_tool_lint, lint_array};
+use syntax::ast::NodeId;
+use syntax::source_map::Span;
+
+/// **What it does:** Checks for `unwrap()` and `expect()` on `Option`s and
+/// `Result`s in functions and closures which return a `Result`. Closures are
+/// checked against their own return type, not the one of the function they're
+/// in. It can be allowed on single statements.
+///
+/// **Why is this bad?** The function reports its errors by returning them, so
+/// callers expect it not to panic. The error can be returned with `?` instead,
+/// after converting an `Option` with `ok_or`.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// fn parse(input: &str) -> Result<u32, ParseIntError> {
+///     Ok(input.parse::<u32>().unwrap() * 2)
+/// }
+/// ```
+/// Could be written as:
+/// ```rust
+/// fn parse(input: &str) -> Result<u32, ParseIntError> {
+///     Ok(input.parse::<u32>()? * 2)
+/// }
+/// ```
+declare_clippy_lint! {
+    pub UNWRAP_IN_RESULT,
+    restriction,
+    "`unwrap()` or `expect()` in functions which return a `Result`"
+}
+
+/// **What it does:** Checks for `panic!`, `unimplemented!`, `unreachable!`,
+/// `todo!` and indexing in functions and closures which return a `Result`.
+/// Assertions aren't linted, since they check invariants rather than errors.
+/// Like `unwrap_in_result`, closures are checked against their own return
+/// type, and the lint can be allowed on single statements.
+///
+/// **Why is this bad?** The function reports its errors by returning them, so
+/// callers expect it not to panic.
+///
+/// **Known problems:** Indexing can't panic if the index is checked before,
+/// which isn't recognized.
+///
+/// **Example:**
+/// ```rust
+/// fn first(args: &[String]) -> Result<u32, ParseIntError> {
+///     args[0].parse()
+/// }
+/// ```
+/// Could be written as:
+/// ```rust
+/// fn first(args: &[String]) -> Result<u32, Error> {
+///     args.get(0).ok_or(Error::MissingArgument)?.parse().map_err(Error::from)
+/// }
+/// ```
+declare_clippy_lint! {
+    pub PANIC_IN_RESULT_FN,
+    restriction,
+    "panicking macros or indexing in functions which return a `Result`"
+}
+
+/// The macros which panic unconditionally and are linted.
+const PANIC_MACROS: [&str; 4] = ["panic", "unimplemented", "unreachable", "todo"];
+
+/// The assertion macros, whose panics aren't linted.
+const ASSERT_MACROS: [&str; 6] = [
+    "assert",
+    "assert_eq",
+    "assert_ne",
+    "debug_assert",
+    "debug_assert_eq",
+    "debug_assert_ne",
+];
+
+pub struct Pass {
+    allow_in: Vec<String>,
+}
+
+impl Pass {
+    pub fn new(allow_in: Vec<String>) -> Self {
+        Self { allow_in }
+    }
+}
+
+impl LintPass for Pass {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(UNWRAP_IN_RESULT, PANIC_IN_RESULT_FN)
+    }
+
+    fn name(&self) -> &'static str {
+        "PanicInResultFn"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        let (lint, span, msg, help) = match expr.node {
+            ExprKind::MethodCall(ref path, _, ref args) => {
+                let name = path.ident.as_str();
+                if (name != "unwrap" && name != "expect") || in_macro_not_desugaring(expr.span) {
+                    return;
+                }
+                let ty = walk_ptrs_ty(cx.tables.expr_ty(&args[0]));
+                if !match_type(cx, ty, &paths::OPTION) && !match_type(cx, ty, &paths::RESULT) {
+                    return;
+                }
+                (
+                    UNWRAP_IN_RESULT,
+                    expr.span,
+                    format!("`{}()` in a function which returns a `Result`", name),
+                    "return the error with `?` instead, after `ok_or` for an `Option`",
+                )
+            },
+            ExprKind::Block(ref block, _) => match panic_macro(cx, expr, block) {
+                Some((name, span)) => (
+                    PANIC_IN_RESULT_FN,
+                    span,
+                    format!("`{}!` in a function which returns a `Result`", name),
+                    "return an error instead",
+                ),
+                None => return,
+            },
+            ExprKind::Index(ref indexed, ref index) => {
+                if in_macro_not_desugaring(expr.span) {
+                    return;
+                }
+                if let ty::Array(..) = cx.tables.expr_ty_adjusted(indexed).sty {
+                    // constant indices into arrays are checked by the compiler
+                    if constant(cx, cx.tables, index).is_some() {
+                        return;
+                    }
+                }
+                (
+                    PANIC_IN_RESULT_FN,
+                    expr.span,
+                    "indexing in a function which returns a `Result`".to_owned(),
+                    "use `get`, and return an error if it's `None`",
+                )
+            },
+            _ => return,
+        };
+        if returns_result(cx, expr.id) && !is_in_scope(cx, expr.id, span, &self.allow_in) {
+            span_help_and_lint(cx, lint, span, &msg, help);
+        }
+    }
+}
+
+/// Returns the name of the macro and the span of its call if `expr` is the
+/// `begin_panic` block a panicking macro expands to.
+fn panic_macro(cx: &LateContext<'_, '_>, expr: &Expr, block: &Block) -> Option<(&'static str, Span)> {
+    if_chain! {
+        if let Some(ref ex) = block.expr;
+        if let ExprKind::Call(ref fun, _) = ex.node;
+        if let ExprKind::Path(ref qpath) = fun.node;
+        if let Some(fun_def_id) = opt_def_id(resolve_node(cx, qpath, fun.hir_id));
+        if match_def_path(cx.tcx, fun_def_id, &paths::BEGIN_PANIC)
+            || match_def_path(cx.tcx, fun_def_id, &paths::BEGIN_PANIC_FMT);
+        then {
+            // the outermost macro is the one which was called, e.g. `unimplemented!` expanding to `panic!`
+            let mut found = None;
+            let mut span = expr.span;
+            while let Some(info) = span.ctxt().outer().expn_info() {
+                let name = info.format.name();
+                if ASSERT_MACROS.iter().any(|assert| name == *assert) {
+                    return None;
+                }
+                if let Some(panic) = PANIC_MACROS.iter().find(|panic| name == **panic) {
+                    found = Some((*panic, info.call_site));
+                }
+                span = info.call_site;
+            }
+            found
+        } else {
+            None
+        }
+    }
+}
+
+/// Checks whether the function or closure `node` is in returns a `Result`.
+fn returns_result(cx: &LateContext<'_, '_>, mut node: NodeId) -> bool {
+    let map = cx.tcx.hir();
+    loop {
+        let parent = map.get_parent_node(node);
+        if parent == node {
+            return false;
+        }
+        let is_fn = match map.find(parent) {
+            Some(Node::Expr(expr)) => {
+                if let ExprKind::Closure(.., movability) = expr.node {
+                    // the body of an `async fn` or `async` block returns the future's output
+                    return movability.is_none()
+                        && match cx.tables.expr_ty(expr).sty {
+                            ty::Closure(def_id, substs) => {
+                                let output = substs.closure_sig(def_id, cx.tcx).output();
+                                match_type(cx, *output.skip_binder(), &paths::RESULT)
+                            },
+                            _ => false,
+                        };
+                }
+                false
+            },
+            Some(Node::Item(item)) => match item.node {
+                ItemKind::Fn(..) => true,
+                _ => return false,
+            },
+            Some(Node::ImplItem(item)) => match item.node {
+                ImplItemKind::Method(..) => true,
+                _ => return false,
+            },
+            Some(Node::TraitItem(item)) => match item.node {
+                TraitItemKind::Method(..) => true,
+                _ => return false,
+            },
+            None => return false,
+            _ => false,
+        };
+        if is_fn {
+            let output = cx.tcx.fn_sig(map.local_def_id(parent)).output();
+            return match_type(cx, *output.skip_binder(), &paths::RESULT);
+        }
+        node = parent;
+    }
+}
@@ -1,4 +1,6 @@
-use crate::utils::{is_direct_expn_of, is_expn_of, match_def_path, opt_def_id, paths, resolve_node, span_lint};
+use crate::utils::{
+    is_direct_expn_of, is_expn_of, is_in_scope, match_def_path, opt_def_id, paths, resolve_node, span_lint,
+};
 use if_chain::if_chain;
 use rustc::hir::*;
 use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
@@ -26,11 +28,14 @@ declare_clippy_lint! {
     "missing parameters in `panic!` calls"
 }
 
-/// **What it does:** Checks for usage of `unimplemented!`.
+/// **What it does:** Checks for usage of `unimplemented!`, `todo!` and
+/// `panic!("not implemented")`. Macros expanding to them are recognized as
+/// well. It can be allowed on the functions which are meant to be stubs.
 ///
-/// **Why is this bad?** This macro should not be present in production code
+/// **Why is this bad?** These macros should not be present in production code
 ///
-/// **Known problems:** None.
+/// **Known problems:** `unimplemented!` and `todo!` with a message aren't
+/// linted.
 ///
 /// **Example:**
 /// ```rust
@@ -39,10 +44,18 @@ declare_clippy_lint! {
 declare_clippy_lint! {
     pub UNIMPLEMENTED,
     restriction,
-    "`unimplemented!` should not be present in production code"
+    "`unimplemented!` and `todo!` should not be present in production code"
 }
 
-pub struct Pass;
+pub struct Pass {
+    allow_unimplemented_in: Vec<String>,
+}
+
+impl Pass {
+    pub fn new(allow_unimplemented_in: Vec<String>) -> Self {
+        Self { allow_unimplemented_in }
+    }
+}
 
 impl LintPass for Pass {
     fn get_lints(&self) -> LintArray {
@@ -65,12 +78,19 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
             if match_def_path(cx.tcx, fun_def_id, &paths::BEGIN_PANIC);
             if params.len() == 2;
             then {
-                if is_expn_of(expr.span, "unimplemented").is_some() {
-                    let span = get_outer_span(expr);
-                    span_lint(cx, UNIMPLEMENTED, span,
-                              "`unimplemented` should not be present in production code");
+                let stub = if let Some(span) = is_expn_of(expr.span, "todo") {
+                    Some((span, "`todo` should not be present in production code"))
+                } else if is_expn_of(expr.span, "unimplemented").is_some() {
+                    Some((get_outer_span(expr), "`unimplemented` should not be present in production code"))
                 } else {
                     match_panic(params, expr, cx);
+                    let msg = "`panic!(\"not implemented\")` should not be present in production code";
+                    is_not_implemented_panic(params, expr).map(|span| (span, msg))
+                };
+                if let Some((span, msg)) = stub {
+                    if !is_in_scope(cx, expr.id, span, &self.allow_unimplemented_in) {
+                        span_lint(cx, UNIMPLEMENTED, span, msg);
+                    }
                 }
             }
         }
@@ -89,6 +109,22 @@ fn get_outer_span(expr: &Expr) -> Span {
     }
 }
 
+/// Returns the span of the `panic!` call if `expr` is the expansion of
+/// `panic!("not implemented")` or `panic!("not yet implemented")`.
+fn is_not_implemented_panic(params: &P<[Expr]>, expr: &Expr) -> Option<Span> {
+    if_chain! {
+        if let ExprKind::Lit(ref lit) = params[0].node;
+        if let LitKind::Str(ref string, _) = lit.node;
+        let string = string.as_str();
+        if string == "not implemented" || string == "not yet implemented";
+        then {
+            is_direct_expn_of(expr.span, "panic")
+        } else {
+            None
+        }
+    }
+}
+
 fn match_panic(params: &P<[Expr]>, expr: &Expr, cx: &LateContext<'_, '_>) {
     if_chain! {
         if let ExprKind::Lit(ref lit) = params[0].node;
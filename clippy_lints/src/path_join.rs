@@ -0,0 +1,111 @@
+//! lint on `Path::join` with a rooted path argument
+
+use crate::utils::{in_macro, match_type, paths, span_lint_and_then, walk_ptrs_ty};
+use if_chain::if_chain;
+use rustc::hir::def::Def;
+use rustc::hir::*;
+use rustc::lint::{in_external_macro, LateContext, LateLintPass, LintArray, LintContext, LintPass};
+use rustc::{declare_tool_lint, lint_array};
+use rustc_errors::Applicability;
+use syntax::ast::LitKind;
+
+/// **What it does:** Checks for calls to `Path::join` or `PathBuf::join` with
+/// a string literal starting with a path separator: `/`, or also `\` when
+/// compiling for Windows. Immutable locals initialized with such a literal are
+/// checked too.
+///
+/// **Why is this bad?** Joining a rooted path replaces the base path
+/// entirely, so `Path::new("/srv/www").join("/index.html")` is
+/// `/index.html`. This is rarely intended.
+///
+/// **Known problems:** Other values, like user input, aren't checked, even
+/// though joining them is a common way to allow path traversal. Windows paths
+/// with a drive prefix, like `C:\foo`, are not detected.
+///
+/// **Example:**
+/// ```rust
+/// let path = Path::new("/srv/www").join("/index.html");
+/// ```
+/// Could be written as:
+/// ```rust
+/// let path = Path::new("/srv/www").join("index.html");
+/// ```
+declare_clippy_lint! {
+    pub JOIN_ABSOLUTE_PATHS,
+    style,
+    "calling `Path::join` with a rooted path, which replaces the base path"
+}
+
+#[derive(Copy, Clone)]
+pub struct Pass;
+
+impl LintPass for Pass {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(JOIN_ABSOLUTE_PATHS)
+    }
+
+    fn name(&self) -> &'static str {
+        "PathJoin"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if_chain! {
+            if !in_macro(expr.span) && !in_external_macro(cx.sess(), expr.span);
+            if let ExprKind::MethodCall(ref path, _, ref args) = expr.node;
+            if path.ident.name == "join" && args.len() == 2;
+            let ty = walk_ptrs_ty(cx.tables.expr_ty(&args[0]));
+            if match_type(cx, ty, &paths::PATH) || match_type(cx, ty, &paths::PATH_BUF);
+            let (arg, local) = peel_local(cx, &args[1]);
+            if let ExprKind::Lit(ref lit) = arg.node;
+            if let LitKind::Str(ref sym, _) = lit.node;
+            let joined = sym.as_str();
+            // `\` is only a separator on Windows
+            let separators: &[char] = if cx.sess().target.target.options.is_like_windows {
+                &['/', '\\']
+            } else {
+                &['/']
+            };
+            if joined.starts_with(separators);
+            then {
+                span_lint_and_then(
+                    cx,
+                    JOIN_ABSOLUTE_PATHS,
+                    args[1].span,
+                    "argument to `join` starts with a path separator",
+                    |db| {
+                        db.note("joining a rooted path replaces the path it is joined to");
+                        if local {
+                            db.span_note(arg.span, "the path is defined here");
+                        } else {
+                            db.span_suggestion(
+                                args[1].span,
+                                "if this isn't intended, remove the leading separator",
+                                format!("{:?}", joined.trim_start_matches(separators)),
+                                Applicability::MaybeIncorrect,
+                            );
+                        }
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Returns the initializer of `expr` if it is an immutable local, and whether it was one.
+fn peel_local<'tcx>(cx: &LateContext<'_, 'tcx>, expr: &'tcx Expr) -> (&'tcx Expr, bool) {
+    if_chain! {
+        if let ExprKind::Path(ref qpath) = expr.node;
+        if let Def::Local(id) = cx.tables.qpath_def(qpath, expr.hir_id);
+        let map = cx.tcx.hir();
+        if let Some(Node::Binding(pat)) = map.find(id);
+        if let PatKind::Binding(BindingAnnotation::Unannotated, ..) = pat.node;
+        if let Some(Node::Local(local)) = map.find(map.get_parent_node(id));
+        if let Some(ref init) = local.init;
+        then {
+            return (init, true);
+        }
+    }
+    (expr, false)
+}
@@ -1,7 +1,10 @@
 //! Checks for usage of  `&Vec[_]` and `&String`.
 
 use crate::utils::ptr::get_spans;
-use crate::utils::{match_qpath, match_type, paths, snippet_opt, span_lint, span_lint_and_then, walk_ptrs_hir_ty};
+use crate::utils::{
+    in_macro, match_qpath, match_type, paths, snippet_opt, snippet_with_applicability, span_lint, span_lint_and_sugg,
+    span_lint_and_then, walk_ptrs_hir_ty,
+};
 use if_chain::if_chain;
 use rustc::hir::QPath;
 use rustc::hir::*;
@@ -95,12 +98,36 @@ declare_clippy_lint! {
     "fns that create mutable refs from immutable ref args"
 }
 
+/// **What it does:** This lint checks for comparisons of pointers through
+/// casts, like `a as usize == b as usize` for raw pointers or
+/// `&x as *const _ == &y as *const _` for references.
+///
+/// **Why is this bad?** `std::ptr::eq` says what is meant without the casts.
+/// Casting to `usize` also only works for thin pointers, while `std::ptr::eq`
+/// works for any pointer.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// if &a as *const _ == &b as *const _ {}
+/// ```
+/// Could be written as:
+/// ```rust
+/// if std::ptr::eq(&a, &b) {}
+/// ```
+declare_clippy_lint! {
+    pub PTR_EQ,
+    style,
+    "comparing pointers through casts, suggesting to use `std::ptr::eq` instead"
+}
+
 #[derive(Copy, Clone)]
 pub struct PointerPass;
 
 impl LintPass for PointerPass {
     fn get_lints(&self) -> LintArray {
-        lint_array!(PTR_ARG, CMP_NULL, MUT_FROM_REF)
+        lint_array!(PTR_ARG, CMP_NULL, MUT_FROM_REF, PTR_EQ)
     }
 
     fn name(&self) -> &'static str {
@@ -146,6 +173,8 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for PointerPass {
                     expr.span,
                     "Comparing with null is better expressed by the .is_null() method",
                 );
+            } else if op.node == BinOpKind::Eq || op.node == BinOpKind::Ne {
+                check_ptr_eq(cx, expr, op.node, l, r);
             }
         }
     }
@@ -267,7 +296,7 @@ fn check_fn(cx: &LateContext<'_, '_>, decl: &FnDecl, fn_id: NodeId, opt_body_id:
             for (_, ref mutbl, ref argspan) in decl
                 .inputs
                 .iter()
-                .filter_map(|ty| get_rptr_lm(ty))
+                .filter_map(get_rptr_lm)
                 .filter(|&(lt, _, _)| lt.name == out.name)
             {
                 if *mutbl == MutMutable {
@@ -300,6 +329,70 @@ fn get_rptr_lm(ty: &Ty) -> Option<(&Lifetime, Mutability, Span)> {
     }
 }
 
+fn check_ptr_eq(cx: &LateContext<'_, '_>, expr: &Expr, op: BinOpKind, left: &Expr, right: &Expr) {
+    if in_macro(expr.span) {
+        return;
+    }
+    if let (Some((left, left_ty)), Some((right, right_ty))) = (uncast_ptr(cx, left), uncast_ptr(cx, right)) {
+        if left_ty != right_ty {
+            return;
+        }
+        let mut applicability = Applicability::MachineApplicable;
+        let left = snippet_with_applicability(cx, left.span, "..", &mut applicability);
+        let right = snippet_with_applicability(cx, right.span, "..", &mut applicability);
+        span_lint_and_sugg(
+            cx,
+            PTR_EQ,
+            expr.span,
+            "use `std::ptr::eq` when comparing raw pointers",
+            "try",
+            format!(
+                "{}std::ptr::eq({}, {})",
+                if op == BinOpKind::Ne { "!" } else { "" },
+                left,
+                right
+            ),
+            applicability,
+        );
+    }
+}
+
+/// If `expr` is a cast of a pointer to `usize` or of a reference to a raw
+/// pointer of the same type, returns the uncast pointer and its pointee type.
+fn uncast_ptr<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, expr: &'a Expr) -> Option<(&'a Expr, ty::Ty<'tcx>)> {
+    let mut inner = match expr.node {
+        ExprKind::Cast(ref inner, _) => &**inner,
+        _ => return None,
+    };
+    match cx.tables.expr_ty(expr).sty {
+        // `p as usize` or `&x as *const T as usize`
+        ty::Uint(UintTy::Usize) => {
+            let pointee = pointee_ty(cx.tables.expr_ty(inner))?;
+            if let ExprKind::Cast(ref ref_expr, _) = inner.node {
+                if let ty::Ref(_, ty, _) = cx.tables.expr_ty(ref_expr).sty {
+                    if ty == pointee {
+                        inner = ref_expr;
+                    }
+                }
+            }
+            Some((inner, pointee))
+        },
+        // `&x as *const T`
+        ty::RawPtr(ty::TypeAndMut { ty: target, .. }) => match cx.tables.expr_ty(inner).sty {
+            ty::Ref(_, ty, _) if ty == target => Some((inner, ty)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn pointee_ty(ty: ty::Ty<'_>) -> Option<ty::Ty<'_>> {
+    match ty.sty {
+        ty::Ref(_, ty, _) | ty::RawPtr(ty::TypeAndMut { ty, .. }) => Some(ty),
+        _ => None,
+    }
+}
+
 fn is_null_path(expr: &Expr) -> bool {
     if let ExprKind::Call(ref pathexp, ref args) = expr.node {
         if args.is_empty() {
@@ -1,4 +1,5 @@
 use crate::utils;
+use crate::utils::{meets_msrv, msrvs, RustcVersion};
 use rustc::{declare_tool_lint, hir, lint, lint_array};
 use rustc_errors::Applicability;
 use std::fmt;
@@ -40,7 +41,15 @@ declare_clippy_lint! {
 }
 
 #[derive(Copy, Clone, Debug)]
-pub struct Pass;
+pub struct Pass {
+    msrv: Option<RustcVersion>,
+}
+
+impl Pass {
+    pub fn new(msrv: Option<RustcVersion>) -> Self {
+        Self { msrv }
+    }
+}
 
 impl lint::LintPass for Pass {
     fn get_lints(&self) -> lint::LintArray {
@@ -66,6 +75,11 @@ impl<'a, 'tcx> lint::LateLintPass<'a, 'tcx> for Pass {
             None => return,
         };
 
+        // `add` and `sub` aren't available on older compilers
+        if !meets_msrv(cx.tcx, expr.id, self.msrv, msrvs::POINTER_ADD_SUB) {
+            return;
+        }
+
         let msg = format!("use of `{}` with a `usize` casted to an `isize`", method);
         if let Some(sugg) = build_suggestion(cx, method, receiver_expr, cast_lhs_expr) {
             utils::span_lint_and_sugg(
@@ -7,7 +7,7 @@ use rustc::{declare_tool_lint, lint_array};
 use syntax::ptr::P;
 
 use crate::utils::paths::*;
-use crate::utils::{match_def_path, match_type, span_lint_and_then, SpanlessEq};
+use crate::utils::{match_def_path, match_type, meets_msrv, msrvs, span_lint_and_then, RustcVersion, SpanlessEq};
 use rustc_errors::Applicability;
 
 /// **What it does:** Checks for expressions that could be replaced by the question mark operator
@@ -35,7 +35,9 @@ declare_clippy_lint! {
 }
 
 #[derive(Copy, Clone)]
-pub struct Pass;
+pub struct Pass {
+    msrv: Option<RustcVersion>,
+}
 
 impl LintPass for Pass {
     fn get_lints(&self) -> LintArray {
@@ -48,6 +50,10 @@ impl LintPass for Pass {
 }
 
 impl Pass {
+    pub fn new(msrv: Option<RustcVersion>) -> Self {
+        Self { msrv }
+    }
+
     /// Check if the given expression on the given context matches the following structure:
     ///
     /// ```ignore
@@ -57,7 +63,7 @@ impl Pass {
     /// ```
     ///
     /// If it matches, it will suggest to use the question mark operator instead
-    fn check_is_none_and_early_return_none(cx: &LateContext<'_, '_>, expr: &Expr) {
+    fn check_is_none_and_early_return_none(&self, cx: &LateContext<'_, '_>, expr: &Expr) {
         if_chain! {
             if let ExprKind::If(if_expr, body, else_) = &expr.node;
             if let ExprKind::MethodCall(segment, _, args) = &if_expr.node;
@@ -65,6 +71,7 @@ impl Pass {
             if Self::expression_returns_none(cx, body);
             if let Some(subject) = args.get(0);
             if Self::is_option(cx, subject);
+            if meets_msrv(cx.tcx, expr.id, self.msrv, msrvs::QUESTION_MARK_OPTION);
 
             then {
                 let receiver_str = &Sugg::hir(cx, subject, "..");
@@ -167,6 +174,6 @@ impl Pass {
 
 impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
     fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
-        Self::check_is_none_and_early_return_none(cx, expr);
+        self.check_is_none_and_early_return_none(cx, expr);
     }
 }
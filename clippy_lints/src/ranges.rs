@@ -1,5 +1,5 @@
 use crate::utils::sugg::Sugg;
-use crate::utils::{get_trait_def_id, higher, implements_trait, SpanlessEq};
+use crate::utils::{get_trait_def_id, higher, implements_trait, meets_msrv, msrvs, RustcVersion, SpanlessEq};
 use crate::utils::{is_integer_literal, paths, snippet, snippet_opt, span_lint, span_lint_and_then};
 use if_chain::if_chain;
 use rustc::hir::*;
@@ -86,7 +86,15 @@ declare_clippy_lint! {
 }
 
 #[derive(Copy, Clone)]
-pub struct Pass;
+pub struct Pass {
+    msrv: Option<RustcVersion>,
+}
+
+impl Pass {
+    pub fn new(msrv: Option<RustcVersion>) -> Self {
+        Self { msrv }
+    }
+}
 
 impl LintPass for Pass {
     fn get_lints(&self) -> LintArray {
@@ -155,6 +163,7 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
                 limits: RangeLimits::HalfOpen
             }) = higher::range(cx, expr);
             if let Some(y) = y_plus_one(end);
+            if meets_msrv(cx.tcx, expr.id, self.msrv, msrvs::RANGE_INCLUSIVE);
             then {
                 span_lint_and_then(
                     cx,
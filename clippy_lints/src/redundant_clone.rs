@@ -14,6 +14,7 @@ use rustc::mir::{
 };
 use rustc::ty;
 use rustc::{declare_tool_lint, lint_array};
+use rustc_data_structures::fx::FxHashSet;
 use rustc_errors::Applicability;
 use std::convert::TryFrom;
 use syntax::{
@@ -36,18 +37,8 @@ macro_rules! unwrap_or_continue {
 /// **Why is this bad?** It is not always possible for the compiler to eliminate useless
 /// allocations and deallocations generated by redundant `clone()`s.
 ///
-/// **Known problems:**
-///
-/// * Suggestions made by this lint could require NLL to be enabled.
-/// * False-positive if there is a borrow preventing the value from moving out.
-///
-/// ```rust
-/// let x = String::new();
-///
-/// let y = &x;
-///
-/// foo(x.clone()); // This lint suggests to remove this `clone()`
-/// ```
+/// **Known problems:** Suggestions made by this lint could require NLL to be
+/// enabled.
 ///
 /// **Example:**
 /// ```rust
@@ -172,6 +163,10 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for RedundantClone {
                 cloned
             };
 
+            // The value can't be moved out while something borrowing it is used
+            let mut locals = possible_borrowers(mir, referent);
+            locals.insert(referent);
+
             let used_later = traversal::ReversePostorder::new(&mir, bb).skip(1).any(|(tbb, tdata)| {
                 // Give up on loops
                 if tdata.terminator().successors().any(|s| *s == bb) {
@@ -179,7 +174,7 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for RedundantClone {
                 }
 
                 let mut vis = LocalUseVisitor {
-                    local: referent,
+                    locals: &locals,
                     used_other_than_drop: false,
                 };
                 vis.visit_basic_block_data(tbb, tdata);
@@ -309,12 +304,83 @@ fn base_local_and_movability<'tcx>(
     }
 }
 
-struct LocalUseVisitor {
-    local: mir::Local,
+/// Returns the locals which may hold a borrow of `local`: those assigned a
+/// value with references which is computed from `local` or from another such
+/// local, e.g. `&local`, `local.iter()` or `Some(&local.field)`. The order of
+/// the statements isn't taken into account.
+fn possible_borrowers<'tcx>(mir: &mir::Mir<'tcx>, local: mir::Local) -> FxHashSet<mir::Local> {
+    let mut borrowers = FxHashSet::default();
+    loop {
+        let mut changed = false;
+        for (block, bbdata) in mir.basic_blocks().iter_enumerated() {
+            let mut check = |lhs: &mir::Place<'tcx>, visit: &dyn Fn(&mut LocalCollector)| {
+                if let mir::Place::Local(lhs) = *lhs {
+                    if lhs == local || borrowers.contains(&lhs) || !may_hold_borrow(mir.local_decls[lhs].ty) {
+                        return;
+                    }
+                    let mut collector = LocalCollector { locals: vec![] };
+                    visit(&mut collector);
+                    if collector.locals.iter().any(|l| *l == local || borrowers.contains(l)) {
+                        borrowers.insert(lhs);
+                        changed = true;
+                    }
+                }
+            };
+            for (statement_index, stmt) in bbdata.statements.iter().enumerate() {
+                if let mir::StatementKind::Assign(ref lhs, ref rvalue) = stmt.kind {
+                    let location = mir::Location { block, statement_index };
+                    check(lhs, &|collector| collector.visit_rvalue(rvalue, location));
+                }
+            }
+            if let TerminatorKind::Call {
+                ref args,
+                destination: Some((ref dest, _)),
+                ..
+            } = bbdata.terminator().kind
+            {
+                let location = mir::Location {
+                    block,
+                    statement_index: bbdata.statements.len(),
+                };
+                check(dest, &|collector| {
+                    for arg in args {
+                        collector.visit_operand(arg, location);
+                    }
+                });
+            }
+        }
+        if !changed {
+            return borrowers;
+        }
+    }
+}
+
+/// Checks whether a value of type `ty` may contain references.
+fn may_hold_borrow(ty: ty::Ty<'_>) -> bool {
+    ty.walk().any(|ty| match ty.sty {
+        ty::Ref(..) | ty::RawPtr(_) => true,
+        ty::Adt(_, substs) => substs.regions().next().is_some(),
+        _ => false,
+    })
+}
+
+/// Collects the locals used in the visited MIR.
+struct LocalCollector {
+    locals: Vec<mir::Local>,
+}
+
+impl<'tcx> mir::visit::Visitor<'tcx> for LocalCollector {
+    fn visit_local(&mut self, local: &mir::Local, _: PlaceContext<'tcx>, _: mir::Location) {
+        self.locals.push(*local);
+    }
+}
+
+struct LocalUseVisitor<'a> {
+    locals: &'a FxHashSet<mir::Local>,
     used_other_than_drop: bool,
 }
 
-impl<'tcx> mir::visit::Visitor<'tcx> for LocalUseVisitor {
+impl<'a, 'tcx> mir::visit::Visitor<'tcx> for LocalUseVisitor<'a> {
     fn visit_basic_block_data(&mut self, block: mir::BasicBlock, data: &mir::BasicBlockData<'tcx>) {
         let statements = &data.statements;
         for (statement_index, statement) in statements.iter().enumerate() {
@@ -342,7 +408,7 @@ impl<'tcx> mir::visit::Visitor<'tcx> for LocalUseVisitor {
             _ => {},
         }
 
-        if *local == self.local {
+        if self.locals.contains(local) {
             self.used_other_than_drop = true;
         }
     }
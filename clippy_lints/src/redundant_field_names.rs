@@ -1,4 +1,5 @@
-use crate::utils::span_lint_and_sugg;
+use crate::utils::msrvs::{self, MsrvStack};
+use crate::utils::{span_lint_and_sugg, RustcVersion};
 use rustc::lint::{EarlyContext, EarlyLintPass, LintArray, LintPass};
 use rustc::{declare_tool_lint, lint_array};
 use rustc_errors::Applicability;
@@ -32,7 +33,17 @@ declare_clippy_lint! {
     "checks for fields in struct literals where shorthands could be used"
 }
 
-pub struct RedundantFieldNames;
+pub struct RedundantFieldNames {
+    msrv: MsrvStack,
+}
+
+impl RedundantFieldNames {
+    pub fn new(msrv: Option<RustcVersion>) -> Self {
+        Self {
+            msrv: MsrvStack::new(msrv),
+        }
+    }
+}
 
 impl LintPass for RedundantFieldNames {
     fn get_lints(&self) -> LintArray {
@@ -47,6 +58,9 @@ impl LintPass for RedundantFieldNames {
 impl EarlyLintPass for RedundantFieldNames {
     fn check_expr(&mut self, cx: &EarlyContext<'_>, expr: &Expr) {
         if let ExprKind::Struct(_, ref fields, _) = expr.node {
+            if !self.msrv.meets_msrv(msrvs::FIELD_INIT_SHORTHAND) {
+                return;
+            }
             for field in fields {
                 if field.is_shorthand {
                     continue;
@@ -70,4 +84,12 @@ impl EarlyLintPass for RedundantFieldNames {
             }
         }
     }
+
+    fn enter_lint_attrs(&mut self, _: &EarlyContext<'_>, attrs: &[Attribute]) {
+        self.msrv.enter_lint_attrs(attrs);
+    }
+
+    fn exit_lint_attrs(&mut self, _: &EarlyContext<'_>, attrs: &[Attribute]) {
+        self.msrv.exit_lint_attrs(attrs);
+    }
 }
@@ -0,0 +1,131 @@
+//! lint on `PhantomData` fields that don't affect the struct
+
+use crate::utils::{get_trait_def_id, implements_trait, in_macro, match_def_path, paths, span_lint_and_then};
+use rustc::hir::*;
+use rustc::lint::{in_external_macro, LateContext, LateLintPass, LintArray, LintContext, LintPass};
+use rustc::ty::{self, Ty, TypeFoldable};
+use rustc::{declare_tool_lint, lint_array};
+
+/// **What it does:** Checks for `PhantomData<T>` fields in structs that
+/// already own a `T` in another field, and for `PhantomData` of types that
+/// don't involve any generic parameter of the struct.
+///
+/// **Why is this bad?** `PhantomData` only exists to make the struct act as
+/// if it owned a `T`. If another field already does, or if the type doesn't
+/// depend on the struct's parameters, the marker is just noise.
+///
+/// **Known problems:** Only fields that plainly own a `T` (`T`, `Box<T>`,
+/// `Vec<T>`, `Option<T>`, arrays and tuples of those) are recognized; raw
+/// pointers and references don't count, since the marker changes variance and
+/// drop check there. `PhantomData` of types that aren't `Send` or `Sync` is
+/// allowed, as it is used to opt out of those auto traits.
+///
+/// **Example:**
+/// ```rust
+/// struct Wrapper<T> {
+///     value: T,
+///     _marker: PhantomData<T>,
+/// }
+/// ```
+declare_clippy_lint! {
+    pub REDUNDANT_PHANTOM_DATA,
+    complexity,
+    "`PhantomData` fields that don't change anything about the struct"
+}
+
+#[derive(Copy, Clone)]
+pub struct Pass;
+
+impl LintPass for Pass {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(REDUNDANT_PHANTOM_DATA)
+    }
+
+    fn name(&self) -> &'static str {
+        "RedundantPhantomData"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
+        if in_macro(item.span) || in_external_macro(cx.sess(), item.span) {
+            return;
+        }
+        let fields = match item.node {
+            ItemKind::Struct(ref data, _) => data.fields(),
+            _ => return,
+        };
+        let field_tys: Vec<Ty<'tcx>> = fields
+            .iter()
+            .map(|field| cx.tcx.type_of(cx.tcx.hir().local_def_id(field.id)))
+            .collect();
+
+        for (field, &ty) in fields.iter().zip(&field_tys) {
+            let marked = match ty.sty {
+                ty::Adt(adt, substs) if adt.is_phantom_data() => substs.type_at(0),
+                _ => continue,
+            };
+
+            if marked.has_param_types() || marked.has_free_regions() {
+                let owner = fields
+                    .iter()
+                    .zip(&field_tys)
+                    .find(|&(_, &other)| owns_ty(cx, other, marked));
+                if let Some((owner, _)) = owner {
+                    span_lint_and_then(
+                        cx,
+                        REDUNDANT_PHANTOM_DATA,
+                        field.span,
+                        &format!(
+                            "this `PhantomData` is redundant, `{}` is already owned by another field",
+                            marked
+                        ),
+                        |db| {
+                            db.span_note(owner.span, "the struct already owns it here");
+                            db.help("consider removing this field");
+                        },
+                    );
+                }
+            } else if is_send_and_sync(cx, marked) {
+                span_lint_and_then(
+                    cx,
+                    REDUNDANT_PHANTOM_DATA,
+                    field.span,
+                    "this `PhantomData` doesn't involve any of the struct's generic parameters",
+                    |db| {
+                        db.note("a `PhantomData` only affects variance, drop check and auto traits");
+                        db.help("consider removing this field");
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Checks whether a value of type `ty` plainly owns a value of type `target`.
+fn owns_ty<'tcx>(cx: &LateContext<'_, 'tcx>, ty: Ty<'tcx>, target: Ty<'tcx>) -> bool {
+    if ty == target {
+        return true;
+    }
+    match ty.sty {
+        ty::Array(inner, _) | ty::Slice(inner) => owns_ty(cx, inner, target),
+        ty::Tuple(tys) => tys.iter().any(|&inner| owns_ty(cx, inner, target)),
+        ty::Adt(adt, substs)
+            if adt.is_box()
+                || match_def_path(cx.tcx, adt.did, &paths::VEC)
+                || match_def_path(cx.tcx, adt.did, &paths::OPTION) =>
+        {
+            owns_ty(cx, substs.type_at(0), target)
+        },
+        _ => false,
+    }
+}
+
+fn is_send_and_sync<'tcx>(cx: &LateContext<'_, 'tcx>, ty: Ty<'tcx>) -> bool {
+    let send = get_trait_def_id(cx, &paths::SEND_TRAIT);
+    let sync = cx.tcx.lang_items().sync_trait();
+    match (send, sync) {
+        (Some(send), Some(sync)) => implements_trait(cx, ty, send, &[]) && implements_trait(cx, ty, sync, &[]),
+        _ => false,
+    }
+}
@@ -0,0 +1,242 @@
+//! lint on turbofish annotations that the surrounding code already determines
+
+use crate::utils::{in_macro, snippet, span_help_and_lint};
+use rustc::hir::intravisit::{walk_ty, FnKind, NestedVisitorMap, Visitor};
+use rustc::hir::*;
+use rustc::lint::{in_external_macro, LateContext, LateLintPass, LintArray, LintContext, LintPass};
+use rustc::ty::{self, TypeFoldable};
+use rustc::{declare_tool_lint, lint_array};
+use syntax::ast::NodeId;
+use syntax::source_map::Span;
+
+/// **What it does:** Checks for turbofish annotations on method calls like
+/// `collect::<Vec<i32>>()` where the type is already fully determined by the
+/// context: an annotated `let`, the return type of the function or the type
+/// of a struct field.
+///
+/// With `shorten-turbofish` in `clippy.toml`, it suggests shortening the
+/// turbofish to placeholders like `collect::<Vec<_>>()` instead, which keeps
+/// the kind of collection visible at the call.
+///
+/// **Why is this bad?** The type is written down twice.
+///
+/// **Known problems:** Some prefer spelling out the type at the call. This
+/// lint is allow-by-default for that reason.
+///
+/// **Example:**
+/// ```rust
+/// let v: Vec<i32> = (0..10).collect::<Vec<i32>>();
+/// ```
+/// Could be written as:
+/// ```rust
+/// let v: Vec<i32> = (0..10).collect();
+/// ```
+declare_clippy_lint! {
+    pub REDUNDANT_TURBOFISH,
+    pedantic,
+    "turbofish annotations on method calls whose type is already determined by the context"
+}
+
+#[derive(Copy, Clone)]
+pub struct Pass {
+    shorten: bool,
+}
+
+impl Pass {
+    pub fn new(shorten: bool) -> Self {
+        Self { shorten }
+    }
+}
+
+impl LintPass for Pass {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(REDUNDANT_TURBOFISH)
+    }
+
+    fn name(&self) -> &'static str {
+        "RedundantTurbofish"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
+    fn check_local(&mut self, cx: &LateContext<'a, 'tcx>, local: &'tcx Local) {
+        if let (Some(ref ty), Some(ref init)) = (&local.ty, &local.init) {
+            if is_explicit(ty) {
+                check_turbofish(cx, self.shorten, init);
+            }
+        }
+    }
+
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'a, 'tcx>,
+        kind: FnKind<'tcx>,
+        decl: &'tcx FnDecl,
+        body: &'tcx Body,
+        _: Span,
+        _: NodeId,
+    ) {
+        if let FnKind::Closure(..) = kind {
+            return;
+        }
+        if let FunctionRetTy::Return(ref ty) = decl.output {
+            if is_explicit(ty) {
+                if let ExprKind::Block(ref block, _) = body.value.node {
+                    if let Some(ref tail) = block.expr {
+                        check_turbofish(cx, self.shorten, tail);
+                    }
+                }
+            }
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if let ExprKind::Struct(_, ref fields, _) = expr.node {
+            if let ty::Adt(adt, _) = cx.tables.expr_ty(expr).sty {
+                if !adt.is_struct() {
+                    return;
+                }
+                for field in fields {
+                    let def = adt
+                        .non_enum_variant()
+                        .fields
+                        .iter()
+                        .find(|def| def.ident == field.ident);
+                    if let Some(def) = def {
+                        if !cx.tcx.type_of(def.did).has_param_types() {
+                            check_turbofish(cx, self.shorten, &field.expr);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Lints `expr` if it is a method call with a turbofish that could be inferred
+/// from the expected type of `expr`. If `shorten` is set, the turbofish is
+/// only linted if its types can be replaced by placeholders.
+fn check_turbofish<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, shorten: bool, expr: &'tcx Expr) {
+    if in_macro(expr.span) || in_external_macro(cx.sess(), expr.span) {
+        return;
+    }
+    let segment = match expr.node {
+        ExprKind::MethodCall(ref segment, _, _) => segment,
+        _ => return,
+    };
+    let args = match segment.args {
+        Some(ref args) if !args.args.is_empty() => &args.args,
+        _ => return,
+    };
+    // with a coercion, the expected type differs from the type of the call
+    if !cx.tables.expr_adjustments(expr).is_empty() {
+        return;
+    }
+    let def_id = match cx.tables.type_dependent_defs().get(expr.hir_id) {
+        Some(def) => def.def_id(),
+        None => return,
+    };
+    let output = cx.tcx.fn_sig(def_id).output().skip_binder();
+    let generics = cx.tcx.generics_of(def_id);
+    let params: Vec<_> = generics
+        .params
+        .iter()
+        .filter(|param| match param.kind {
+            ty::GenericParamDefKind::Type { .. } => true,
+            _ => false,
+        })
+        .collect();
+    if params.len() != args.len() || !params.iter().all(|param| determines_param(output, param.index)) {
+        return;
+    }
+
+    let span = args[0].span().to(args[args.len() - 1].span());
+    let help = if shorten {
+        let shortened = match args.iter().map(|arg| placeholders(cx, arg)).collect::<Option<Vec<_>>>() {
+            Some(shortened) => shortened.join(", "),
+            None => return,
+        };
+        format!(
+            "consider shortening the turbofish `::<{}>` to `::<{}>`",
+            snippet(cx, span, ".."),
+            shortened
+        )
+    } else {
+        format!("consider removing the turbofish `::<{}>`", snippet(cx, span, ".."))
+    };
+    span_help_and_lint(
+        cx,
+        REDUNDANT_TURBOFISH,
+        span,
+        "the type of this turbofish is already determined by the context",
+        &help,
+    );
+}
+
+/// Returns `arg` with placeholders for its type arguments, like `Vec<_>` for
+/// `Vec<i32>`. `None` is returned if `arg` has no type arguments, only
+/// placeholders already, or lifetime arguments.
+fn placeholders(cx: &LateContext<'_, '_>, arg: &GenericArg) -> Option<String> {
+    let path = match *arg {
+        GenericArg::Type(Ty {
+            node: TyKind::Path(QPath::Resolved(None, ref path)),
+            ..
+        }) => path,
+        _ => return None,
+    };
+    let args = path.segments.last()?.args.as_ref()?;
+    let mut inferred = true;
+    for arg in &args.args {
+        match *arg {
+            GenericArg::Type(Ty {
+                node: TyKind::Infer, ..
+            }) => {},
+            GenericArg::Type(_) => inferred = false,
+            GenericArg::Lifetime(_) => return None,
+        }
+    }
+    if args.args.is_empty() || !args.bindings.is_empty() || inferred {
+        return None;
+    }
+    let ty = snippet(cx, arg.span(), "..");
+    let name = ty.split('<').next().unwrap_or("..");
+    Some(format!("{}<{}>", name, vec!["_"; args.args.len()].join(", ")))
+}
+
+/// Checks whether knowing `ty` determines the type parameter with the given
+/// index. Parameters only used in projections are not determined.
+fn determines_param(ty: ty::Ty<'_>, index: u32) -> bool {
+    match ty.sty {
+        ty::Param(param) => param.idx == index,
+        ty::Adt(_, substs) => substs.types().any(|ty| determines_param(ty, index)),
+        ty::Ref(_, ty, _) | ty::Array(ty, _) | ty::Slice(ty) | ty::RawPtr(ty::TypeAndMut { ty, .. }) => {
+            determines_param(ty, index)
+        },
+        ty::Tuple(tys) => tys.iter().any(|&ty| determines_param(ty, index)),
+        _ => false,
+    }
+}
+
+/// Checks that a type annotation has no `_` or `impl Trait` in it.
+fn is_explicit(ty: &Ty) -> bool {
+    let mut visitor = ExplicitTyVisitor { explicit: true };
+    visitor.visit_ty(ty);
+    visitor.explicit
+}
+
+struct ExplicitTyVisitor {
+    explicit: bool,
+}
+
+impl<'tcx> Visitor<'tcx> for ExplicitTyVisitor {
+    fn visit_ty(&mut self, ty: &'tcx Ty) {
+        match ty.node {
+            TyKind::Infer | TyKind::Def(..) => self.explicit = false,
+            _ => walk_ty(self, ty),
+        }
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
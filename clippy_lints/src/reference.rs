@@ -56,7 +56,7 @@ impl EarlyLintPass for Pass {
                     e.span,
                     "immediately dereferencing a reference",
                     "try this",
-                    format!("{}", snippet_with_applicability(cx, addrof_target.span, "_", &mut applicability)),
+                    snippet_with_applicability(cx, addrof_target.span, "_", &mut applicability).into_owned(),
                     applicability,
                 );
             }
@@ -0,0 +1,108 @@
+//! lint on assignments of a place to itself
+
+use crate::reexport::*;
+use crate::utils::{in_macro, snippet, span_lint, span_lint_and_then, SpanlessEq};
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::{declare_tool_lint, lint_array};
+
+/// **What it does:** Checks for assignments of a place to itself, like
+/// `x = x` or `self.field = self.field`.
+///
+/// **Why is this bad?** The assignment has no effect. Most of the time the
+/// right hand side was meant to be something else, typically a function
+/// argument with the same name as the field.
+///
+/// Sequences like `a = b; b = a;` are covered by `almost_swapped`.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// fn set_name(&mut self, name: String) {
+///     self.name = self.name;
+/// }
+/// ```
+declare_clippy_lint! {
+    pub SELF_ASSIGNMENT,
+    correctness,
+    "assigning a place to itself"
+}
+
+#[derive(Copy, Clone)]
+pub struct Pass;
+
+impl LintPass for Pass {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(SELF_ASSIGNMENT)
+    }
+
+    fn name(&self) -> &'static str {
+        "SelfAssignment"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if in_macro(expr.span) {
+            return;
+        }
+        if let ExprKind::Assign(ref lhs, ref rhs) = expr.node {
+            if !is_place(lhs) || !SpanlessEq::new(cx).eq_expr(lhs, rhs) {
+                return;
+            }
+            let place = snippet(cx, lhs.span, "..");
+            if let ExprKind::Field(_, ident) = lhs.node {
+                let msg = format!("self-assignment of `{}`, which has no effect", place);
+                if has_argument_named(cx, expr, ident.name) {
+                    span_lint_and_then(cx, SELF_ASSIGNMENT, expr.span, &msg, |db| {
+                        db.help(&format!(
+                            "did you mean to assign the argument: `{} = {}`?",
+                            place, ident
+                        ));
+                    });
+                } else {
+                    span_lint(cx, SELF_ASSIGNMENT, expr.span, &msg);
+                }
+            } else {
+                span_lint(
+                    cx,
+                    SELF_ASSIGNMENT,
+                    expr.span,
+                    &format!("`{}` is assigned to itself, which has no effect", place),
+                );
+            }
+        }
+    }
+}
+
+/// Checks whether `expr` is a place without side effects when evaluated:
+/// a local, a field or an index by a local or literal.
+fn is_place(expr: &Expr) -> bool {
+    match expr.node {
+        ExprKind::Path(_) => true,
+        ExprKind::Field(ref base, _) | ExprKind::Unary(UnOp::UnDeref, ref base) => is_place(base),
+        ExprKind::Index(ref base, ref index) => {
+            is_place(base)
+                && match index.node {
+                    ExprKind::Lit(_) | ExprKind::Path(_) => true,
+                    _ => false,
+                }
+        },
+        _ => false,
+    }
+}
+
+/// Checks whether the function containing `expr` has an argument with the given
+/// name.
+fn has_argument_named(cx: &LateContext<'_, '_>, expr: &Expr, name: Name) -> bool {
+    let map = cx.tcx.hir();
+    let body_id = match map.maybe_body_owned_by(map.get_parent(expr.id)) {
+        Some(body_id) => body_id,
+        None => return false,
+    };
+    map.body(body_id).arguments.iter().any(|arg| match arg.pat.node {
+        PatKind::Binding(_, _, _, ident, _) => ident.name == name,
+        _ => false,
+    })
+}
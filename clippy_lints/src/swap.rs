@@ -15,7 +15,9 @@ use rustc_errors::Applicability;
 /// **Why is this bad?** The `std::mem::swap` function exposes the intent better
 /// without deinitializing or copying either variable.
 ///
-/// **Known problems:** None.
+/// **Known problems:** No suggestion is given for places inside elements of
+/// the same container, like `v[i].x` and `v[j].x`, as `std::mem::swap` would
+/// have to borrow the container mutably twice.
 ///
 /// **Example:**
 /// ```rust,ignore
@@ -116,7 +118,11 @@ fn check_manual_swap(cx: &LateContext<'_, '_>, block: &Block) {
                     None
                 }
 
-                let (replace, what, sugg) = if let Some((slice, idx1, idx2)) = check_for_slice(cx, lhs1, lhs2) {
+                let slice = check_for_slice(cx, lhs1, lhs2);
+                // `std::mem::swap(&mut v[i].x, &mut v[j].x)` doesn't borrowck
+                let double_borrow = slice.is_none() && index_same_container(cx, lhs1, rhs1);
+
+                let (replace, what, sugg) = if let Some((slice, idx1, idx2)) = slice {
                     if let Some(slice) = Sugg::hir_opt(cx, slice) {
                         (false,
                          format!(" elements of `{}`", slice),
@@ -127,6 +133,9 @@ fn check_manual_swap(cx: &LateContext<'_, '_>, block: &Block) {
                     } else {
                         (false, String::new(), String::new())
                     }
+                } else if double_borrow {
+                    (false, format!(" `{}` and `{}`", snippet(cx, lhs1.span, ".."), snippet(cx, rhs1.span, "..")),
+                        String::new())
                 } else if let (Some(first), Some(second)) = (Sugg::hir_opt(cx, lhs1), Sugg::hir_opt(cx, rhs1)) {
                     (true, format!(" `{}` and `{}`", first, second),
                         format!("std::mem::swap({}, {})", first.mut_addr(), second.mut_addr()))
@@ -153,12 +162,45 @@ fn check_manual_swap(cx: &LateContext<'_, '_>, block: &Block) {
                                                db.note("or maybe you should use `std::mem::replace`?");
                                            }
                                        }
+                                       if double_borrow {
+                                           db.note(
+                                               "both places index into the same container, so `std::mem::swap` \
+                                                can't borrow them mutably at the same time; consider `split_at_mut`",
+                                           );
+                                       }
                                    });
             }
         }
     }
 }
 
+/// Checks whether both places are inside elements of the same indexed
+/// container, e.g. `v[i].x` and `v[j].y`.
+fn index_same_container(cx: &LateContext<'_, '_>, first: &Expr, second: &Expr) -> bool {
+    fn indexed_containers<'a>(mut expr: &'a Expr, containers: &mut Vec<&'a Expr>) {
+        loop {
+            match expr.node {
+                ExprKind::Index(ref base, _) => {
+                    containers.push(base);
+                    expr = base;
+                },
+                ExprKind::Field(ref base, _) | ExprKind::Unary(UnOp::UnDeref, ref base) => expr = base,
+                _ => return,
+            }
+        }
+    }
+
+    let mut first_containers = Vec::new();
+    indexed_containers(first, &mut first_containers);
+    let mut second_containers = Vec::new();
+    indexed_containers(second, &mut second_containers);
+    first_containers.iter().any(|first| {
+        second_containers
+            .iter()
+            .any(|second| SpanlessEq::new(cx).ignore_fn().eq_expr(first, second))
+    })
+}
+
 /// Implementation of the `ALMOST_SWAPPED` lint.
 fn check_suspicious_swap(cx: &LateContext<'_, '_>, block: &Block) {
     for w in block.stmts.windows(2) {
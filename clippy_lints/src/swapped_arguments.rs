@@ -0,0 +1,143 @@
+//! lint on arguments passed to the parameters named like each other
+
+use crate::utils::{in_macro, snippet_with_applicability, span_lint_and_sugg};
+use rustc::hir::def::Def;
+use rustc::hir::def_id::DefId;
+use rustc::hir::*;
+use rustc::lint::{in_external_macro, LateContext, LateLintPass, LintArray, LintContext, LintPass};
+use rustc::{declare_tool_lint, lint_array};
+use rustc_errors::Applicability;
+use syntax::ast::Name;
+use syntax::symbol::keywords;
+
+/// **What it does:** Checks for calls which pass two variables named like
+/// parameters of the function to each other's parameters, like `draw(y, x)`
+/// for `fn draw(x: u32, y: u32)`. The receivers of method calls and `self`
+/// aren't checked, so delegating to the other value like `other.cmp(self)`
+/// isn't linted.
+///
+/// **Why is this bad?** Variables are usually named after what they mean,
+/// and so are parameters. Passing them in the wrong order is an easy mistake,
+/// which the compiler can't catch if the types are the same.
+///
+/// **Known problems:** Swapping the arguments on purpose, e.g. to compute
+/// a distance in the other direction, is linted as well.
+///
+/// **Example:**
+/// ```rust
+/// fn draw(x: u32, y: u32) {}
+///
+/// draw(y, x);
+/// ```
+/// Should be written as:
+/// ```rust
+/// draw(x, y);
+/// ```
+declare_clippy_lint! {
+    pub SWAPPED_ARGUMENTS,
+    correctness,
+    "passing variables to the parameters with each other's names"
+}
+
+#[derive(Copy, Clone)]
+pub struct Pass;
+
+impl LintPass for Pass {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(SWAPPED_ARGUMENTS)
+    }
+
+    fn name(&self) -> &'static str {
+        "SwappedArguments"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        // the receiver of a method call is the first argument
+        let (def_id, args, first) = match expr.node {
+            ExprKind::Call(ref callee, ref args) => match callee.node {
+                ExprKind::Path(ref qpath) => match cx.tables.qpath_def(qpath, callee.hir_id) {
+                    Def::Fn(def_id) | Def::Method(def_id) => (Some(def_id), args, 0),
+                    _ => return,
+                },
+                _ => return,
+            },
+            ExprKind::MethodCall(_, _, ref args) => (
+                cx.tables.type_dependent_defs().get(expr.hir_id).map(Def::def_id),
+                args,
+                1,
+            ),
+            _ => return,
+        };
+        if args.len() < 2 || in_macro(expr.span) || in_external_macro(cx.sess(), expr.span) {
+            return;
+        }
+        let params = match def_id.and_then(|def_id| param_names(cx, def_id)) {
+            Some(params) => params,
+            None => return,
+        };
+        if params.len() != args.len() {
+            return;
+        }
+        let arg_names: Vec<_> = args.iter().map(arg_name).collect();
+        for i in first..args.len() {
+            for j in i + 1..args.len() {
+                if arg_names[i] == Some(params[j]) && arg_names[j] == Some(params[i]) {
+                    let mut applicability = Applicability::MaybeIncorrect;
+                    let sugg = format!(
+                        "{}{}{}",
+                        snippet_with_applicability(cx, args[j].span, "..", &mut applicability),
+                        snippet_with_applicability(cx, args[i].span.between(args[j].span), "..", &mut applicability),
+                        snippet_with_applicability(cx, args[i].span, "..", &mut applicability),
+                    );
+                    span_lint_and_sugg(
+                        cx,
+                        SWAPPED_ARGUMENTS,
+                        args[i].span.to(args[j].span),
+                        &format!(
+                            "`{}` is passed as `{}`, and `{}` as `{}`",
+                            params[j], params[i], params[i], params[j]
+                        ),
+                        "if this isn't on purpose, swap them",
+                        sugg,
+                        applicability,
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Returns the names of the parameters of the function or method `def_id`,
+/// `None` if they aren't known.
+fn param_names(cx: &LateContext<'_, '_>, def_id: DefId) -> Option<Vec<Name>> {
+    let map = cx.tcx.hir();
+    match map.as_local_node_id(def_id) {
+        Some(node_id) => {
+            let body = map.body(map.maybe_body_owned_by(node_id)?);
+            body.arguments
+                .iter()
+                .map(|arg| match arg.pat.node {
+                    PatKind::Binding(.., ident, _) => Some(ident.name),
+                    _ => None,
+                })
+                .collect()
+        },
+        None => Some(cx.tcx.fn_arg_names(def_id)),
+    }
+}
+
+/// Returns the name of the variable `arg` is, or borrows. `self` has no
+/// name, it's the receiver even if it's passed like `Ord::cmp(other, self)`.
+fn arg_name(arg: &Expr) -> Option<Name> {
+    match arg.node {
+        ExprKind::Path(QPath::Resolved(None, ref path))
+            if path.segments.len() == 1 && path.segments[0].ident.name != keywords::SelfLower.name() =>
+        {
+            Some(path.segments[0].ident.name)
+        },
+        ExprKind::AddrOf(_, ref inner) => arg_name(inner),
+        _ => None,
+    }
+}
@@ -0,0 +1,249 @@
+//! lint on mixing up `SystemTime` and `Instant`, and on unwrapping the time since the Unix epoch
+
+use crate::utils::{
+    last_path_segment, match_def_path, match_type, opt_def_id, paths, snippet_with_context, span_help_and_lint,
+    span_lint_and_then, walk_ptrs_ty,
+};
+use if_chain::if_chain;
+use rustc::hir::def::Def;
+use rustc::hir::*;
+use rustc::lint::{in_external_macro, LateContext, LateLintPass, LintArray, LintContext, LintPass};
+use rustc::{declare_tool_lint, lint_array};
+use rustc_errors::Applicability;
+
+/// **What it does:** Checks for elapsed time measured with `SystemTime`, i.e.
+/// `elapsed` or `duration_since` calls on values of `SystemTime::now()`.
+///
+/// **Why is this bad?** `SystemTime` is a wall-clock time that can jump, e.g.
+/// when the clock is adjusted. Measured durations can be wrong or fail
+/// altogether. `Instant` is monotonic and meant for measuring time.
+///
+/// **Known problems:** Only values coming directly from `SystemTime::now()`
+/// or from a `let` binding of it are recognized.
+///
+/// **Example:**
+/// ```rust
+/// let start = SystemTime::now();
+/// do_work();
+/// let took = start.elapsed().unwrap();
+/// ```
+/// Could be written as:
+/// ```rust
+/// let start = Instant::now();
+/// do_work();
+/// let took = start.elapsed();
+/// ```
+declare_clippy_lint! {
+    pub SYSTEM_TIME_ELAPSED,
+    pedantic,
+    "measuring elapsed time with `SystemTime` instead of `Instant`"
+}
+
+/// **What it does:** Checks for `Instant`s formatted with `{:?}`.
+///
+/// **Why is this bad?** An `Instant` is an opaque point on a monotonic clock
+/// and has no meaning as a wall-clock time. Its `Debug` output is platform
+/// specific and is not a point in time a reader can make sense of.
+///
+/// **Known problems:** Debug output of structs containing an `Instant` is not
+/// recognized.
+///
+/// **Example:**
+/// ```rust
+/// println!("started at {:?}", Instant::now());
+/// ```
+/// Could be written as:
+/// ```rust
+/// println!("started at {:?}", SystemTime::now());
+/// ```
+declare_clippy_lint! {
+    pub FORMATTED_INSTANT,
+    pedantic,
+    "formatting an `Instant` as if it were a wall-clock time"
+}
+
+/// **What it does:** Checks for `duration_since(UNIX_EPOCH)` on a `SystemTime`
+/// followed by `unwrap()` or `expect(..)`, the usual way to get a timestamp.
+///
+/// **Why is this bad?** `duration_since` fails if the system clock is set
+/// before the Unix epoch. That happens more often than one would think, e.g.
+/// on devices without a battery backed clock or in freshly started VMs, and
+/// the program panics.
+///
+/// **Known problems:** The suggested `unwrap_or_default()` changes the
+/// behaviour for clocks set before the epoch: the timestamp is silently zero
+/// instead of a panic, which may be stored or compared as if it were real. The
+/// suggestion is therefore not applied automatically.
+///
+/// **Example:**
+/// ```rust
+/// let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+/// ```
+/// Could be written as:
+/// ```rust
+/// let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+/// ```
+declare_clippy_lint! {
+    pub DURATION_SINCE_EPOCH_UNWRAP,
+    pedantic,
+    "unwrapping the time since the Unix epoch, which panics if the clock is set before it"
+}
+
+#[derive(Copy, Clone)]
+pub struct Pass;
+
+impl LintPass for Pass {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(SYSTEM_TIME_ELAPSED, FORMATTED_INSTANT, DURATION_SINCE_EPOCH_UNWRAP)
+    }
+
+    fn name(&self) -> &'static str {
+        "SystemTime"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        match expr.node {
+            ExprKind::MethodCall(ref method, _, ref args) => {
+                if in_external_macro(cx.sess(), expr.span) {
+                    return;
+                }
+                let start = match (&*method.ident.as_str(), args.len()) {
+                    ("elapsed", 1) => &args[0],
+                    ("duration_since", 2) => &args[1],
+                    ("unwrap", 1) | ("expect", 2) => return check_epoch_unwrap(cx, expr, &args[0]),
+                    _ => return,
+                };
+                if match_type(cx, cx.tables.expr_ty(&args[0]), &paths::SYSTEM_TIME) && is_system_time_now(cx, start) {
+                    span_help_and_lint(
+                        cx,
+                        SYSTEM_TIME_ELAPSED,
+                        expr.span,
+                        "measuring elapsed time with `SystemTime`, which is not monotonic",
+                        "consider using `Instant` instead",
+                    );
+                }
+            },
+            // the expansion of `format_args!` is always external
+            ExprKind::Match(ref values, ref arms, MatchSource::Normal) => check_format_args(cx, values, arms),
+            _ => {},
+        }
+    }
+}
+
+/// Checks whether `expr` is `SystemTime::now()`, or a local initialized with it.
+fn is_system_time_now(cx: &LateContext<'_, '_>, expr: &Expr) -> bool {
+    let mut expr = expr;
+    if_chain! {
+        if let ExprKind::Path(ref qpath) = expr.node;
+        if let Def::Local(id) = cx.tables.qpath_def(qpath, expr.hir_id);
+        let map = cx.tcx.hir();
+        if let Some(Node::Local(local)) = map.find(map.get_parent_node(id));
+        if let Some(ref init) = local.init;
+        then {
+            expr = init;
+        }
+    }
+    if_chain! {
+        if let ExprKind::Call(ref fun, ref args) = expr.node;
+        if args.is_empty();
+        if let ExprKind::Path(ref qpath) = fun.node;
+        if let Some(def_id) = opt_def_id(cx.tables.qpath_def(qpath, fun.hir_id));
+        then {
+            return match_def_path(cx.tcx, def_id, &paths::SYSTEM_TIME_NOW);
+        }
+    }
+    false
+}
+
+/// Checks whether `recv`, which is unwrapped by `expr`, is `duration_since(UNIX_EPOCH)` on
+/// a `SystemTime`.
+fn check_epoch_unwrap(cx: &LateContext<'_, '_>, expr: &Expr, recv: &Expr) {
+    if_chain! {
+        if let ExprKind::MethodCall(ref method, _, ref args) = recv.node;
+        if method.ident.name == "duration_since" && args.len() == 2;
+        if match_type(cx, cx.tables.expr_ty(&args[0]), &paths::SYSTEM_TIME);
+        if is_unix_epoch(cx, &args[1]);
+        then {
+            span_lint_and_then(
+                cx,
+                DURATION_SINCE_EPOCH_UNWRAP,
+                expr.span,
+                "unwrapping the time since the Unix epoch, which panics if the clock is set before it",
+                |db| {
+                    // a zero timestamp for clocks set before 1970 isn't always what the caller wants
+                    let mut applicability = Applicability::MaybeIncorrect;
+                    let (recv, _) = snippet_with_context(cx, recv.span, expr.span.ctxt(), "..", &mut applicability);
+                    db.span_suggestion(
+                        expr.span,
+                        "consider falling back to a zero duration",
+                        format!("{}.unwrap_or_default()", recv),
+                        applicability,
+                    );
+                    db.note("otherwise handle the `Err` case with a `match`, e.g. to report the wrong clock");
+                },
+            );
+        }
+    }
+}
+
+/// Checks whether `expr` is `UNIX_EPOCH` or `SystemTime::UNIX_EPOCH`.
+fn is_unix_epoch(cx: &LateContext<'_, '_>, expr: &Expr) -> bool {
+    if let ExprKind::Path(ref qpath) = expr.node {
+        match cx.tables.qpath_def(qpath, expr.hir_id) {
+            Def::Const(def_id) => match_def_path(cx.tcx, def_id, &paths::UNIX_EPOCH),
+            Def::AssociatedConst(_) => {
+                last_path_segment(qpath).ident.name == "UNIX_EPOCH"
+                    && match_type(cx, cx.tables.expr_ty(expr), &paths::SYSTEM_TIME)
+            },
+            _ => false,
+        }
+    } else {
+        false
+    }
+}
+
+/// Checks the expansion of `format_args!`:
+/// ```rust,ignore
+/// match (&arg0, &arg1) {
+///     (__arg0, __arg1) => [ArgumentV1::new(__arg0, Debug::fmt), ArgumentV1::new(__arg1, Display::fmt)],
+/// }
+/// ```
+fn check_format_args(cx: &LateContext<'_, '_>, values: &Expr, arms: &[Arm]) {
+    if_chain! {
+        if let ExprKind::Tup(ref values) = values.node;
+        if arms.len() == 1 && arms[0].pats.len() == 1;
+        if let PatKind::Tuple(ref pats, None) = arms[0].pats[0].node;
+        if pats.len() == values.len();
+        if let ExprKind::Array(ref calls) = arms[0].body.node;
+        then {
+            for call in calls {
+                if_chain! {
+                    if let ExprKind::Call(_, ref args) = call.node;
+                    if args.len() == 2;
+                    if let ExprKind::Path(ref qpath) = args[1].node;
+                    if let Some(fun_def_id) = opt_def_id(cx.tables.qpath_def(qpath, args[1].hir_id));
+                    if match_def_path(cx.tcx, fun_def_id, &paths::DEBUG_FMT_METHOD);
+                    if let ExprKind::Path(ref arg_qpath) = args[0].node;
+                    if let Def::Local(arg_id) = cx.tables.qpath_def(arg_qpath, args[0].hir_id);
+                    if let Some(index) = pats.iter().position(|pat| pat.id == arg_id);
+                    if match_type(cx, walk_ptrs_ty(cx.tables.expr_ty(&values[index])), &paths::INSTANT);
+                    then {
+                        let value = match values[index].node {
+                            ExprKind::AddrOf(_, ref value) => value,
+                            _ => &values[index],
+                        };
+                        span_help_and_lint(
+                            cx,
+                            FORMATTED_INSTANT,
+                            value.span,
+                            "formatting an `Instant`, which is not a wall-clock time",
+                            "consider formatting a `SystemTime`, or a `Duration` relative to a known `Instant`",
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,127 @@
+//! lint on subtractions of `Instant`s and `Duration`s that can panic
+
+use crate::utils::{
+    in_macro, match_def_path, match_type, meets_msrv, msrvs, opt_def_id, paths, snippet, span_help_and_lint, span_lint,
+    RustcVersion,
+};
+use if_chain::if_chain;
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::{declare_tool_lint, lint_array};
+
+/// **What it does:** Checks for subtractions of two `Instant`s or two
+/// `Duration`s using the `-` or `-=` operators.
+///
+/// **Why is this bad?** The subtraction panics if the right hand side is
+/// larger, e.g. if the instants are not in the order that was assumed. Using
+/// `checked_sub` or `checked_duration_since` makes the failure case explicit.
+///
+/// Subtracting an earlier instant from `Instant::now()` is not linted, as
+/// `Instant` is monotonic. `checked_duration_since` is only suggested if the
+/// MSRV is at least Rust 1.39.
+///
+/// **Known problems:** The order of the operands is often guaranteed by the
+/// program logic, which the lint can't see.
+///
+/// **Example:**
+/// ```rust
+/// let remaining = timeout - start.elapsed();
+/// ```
+/// Could be written as:
+/// ```rust
+/// let remaining = timeout.checked_sub(start.elapsed());
+/// ```
+declare_clippy_lint! {
+    pub UNCHECKED_TIME_SUBTRACTION,
+    pedantic,
+    "subtracting `Instant`s or `Duration`s with `-`, which panics if the result would be negative"
+}
+
+#[derive(Copy, Clone)]
+pub struct TimeSubtraction {
+    msrv: Option<RustcVersion>,
+}
+
+impl TimeSubtraction {
+    pub fn new(msrv: Option<RustcVersion>) -> Self {
+        Self { msrv }
+    }
+}
+
+impl LintPass for TimeSubtraction {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(UNCHECKED_TIME_SUBTRACTION)
+    }
+
+    fn name(&self) -> &'static str {
+        "TimeSubtraction"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for TimeSubtraction {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if in_macro(expr.span) {
+            return;
+        }
+        let (left, right, is_assign) = match expr.node {
+            ExprKind::Binary(op, ref left, ref right) if op.node == BinOpKind::Sub => (left, right, false),
+            ExprKind::AssignOp(op, ref left, ref right) if op.node == BinOpKind::Sub => (left, right, true),
+            _ => return,
+        };
+        let left_ty = cx.tables.expr_ty(left);
+        let right_ty = cx.tables.expr_ty(right);
+
+        if match_type(cx, left_ty, &paths::INSTANT) && match_type(cx, right_ty, &paths::INSTANT) {
+            if is_instant_now(cx, left) {
+                return;
+            }
+            let msg = "unchecked subtraction of two `Instant`s, which panics if the second one is later";
+            if meets_msrv(cx.tcx, expr.id, self.msrv, msrvs::INSTANT_DURATION_SINCE) {
+                span_help_and_lint(
+                    cx,
+                    UNCHECKED_TIME_SUBTRACTION,
+                    expr.span,
+                    msg,
+                    &format!(
+                        "consider using `{0}.checked_duration_since({1})` or `{0}.saturating_duration_since({1})`",
+                        snippet(cx, left.span, ".."),
+                        snippet(cx, right.span, "..")
+                    ),
+                );
+            } else {
+                span_lint(cx, UNCHECKED_TIME_SUBTRACTION, expr.span, msg);
+            }
+        } else if match_type(cx, left_ty, &paths::DURATION) && match_type(cx, right_ty, &paths::DURATION) {
+            let left = snippet(cx, left.span, "..");
+            let right = snippet(cx, right.span, "..");
+            let help = if is_assign {
+                format!(
+                    "consider using `{0} = {0}.checked_sub({1})` and handling the `None` case",
+                    left, right
+                )
+            } else {
+                format!("consider using `{}.checked_sub({})`", left, right)
+            };
+            span_help_and_lint(
+                cx,
+                UNCHECKED_TIME_SUBTRACTION,
+                expr.span,
+                "unchecked subtraction of two `Duration`s, which panics if the result would be negative",
+                &help,
+            );
+        }
+    }
+}
+
+fn is_instant_now(cx: &LateContext<'_, '_>, expr: &Expr) -> bool {
+    if_chain! {
+        if let ExprKind::Call(ref fun, ref args) = expr.node;
+        if args.is_empty();
+        if let ExprKind::Path(ref qpath) = fun.node;
+        if let Some(def_id) = opt_def_id(cx.tables.qpath_def(qpath, fun.hir_id));
+        then {
+            return match_def_path(cx.tcx, def_id, &paths::INSTANT_NOW);
+        }
+    }
+    false
+}
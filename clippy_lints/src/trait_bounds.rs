@@ -0,0 +1,350 @@
+//! lint on trait bounds that could be written more concisely
+
+use crate::utils::{in_macro, span_help_and_lint};
+use rustc::lint::{in_external_macro, EarlyContext, EarlyLintPass, LintArray, LintContext, LintPass};
+use rustc::{declare_tool_lint, lint_array};
+use rustc_data_structures::fx::FxHashSet;
+use std::slice;
+use syntax::ast::*;
+use syntax::print::pprust::{bounds_to_string, ty_to_string};
+use syntax::source_map::Span;
+use syntax::visit::Visitor;
+
+/// **What it does:** Checks for `impl` blocks and traits where every method
+/// repeats the same `where` bound on a generic parameter of the `impl` or
+/// trait.
+///
+/// **Why is this bad?** The bound belongs on the `impl` block or trait
+/// definition. Repeating it on every method adds noise and makes it easy to
+/// forget it on the next method.
+///
+/// **Known problems:** Moving a bound from the methods of a trait to the trait
+/// itself requires every implementor to satisfy it. Bounds on `Self: Sized`
+/// are never linted, because they affect object safety.
+///
+/// **Example:**
+/// ```rust
+/// impl<T> Foo<T> {
+///     fn a(&self) where T: Clone { .. }
+///     fn b(&self) where T: Clone { .. }
+///     fn c(&self) where T: Clone { .. }
+/// }
+/// ```
+/// Could be written as:
+/// ```rust
+/// impl<T> Foo<T> where T: Clone {
+///     fn a(&self) { .. }
+///     fn b(&self) { .. }
+///     fn c(&self) { .. }
+/// }
+/// ```
+declare_clippy_lint! {
+    pub DUPLICATED_METHOD_BOUNDS,
+    style,
+    "the same `where` bound repeated on every method of an `impl` block or trait"
+}
+
+/// **What it does:** Checks for types that are bounded more than once in the
+/// same list of generics, either by several `where` predicates or by an inline
+/// bound and a `where` predicate, and for bounds that are listed twice.
+///
+/// **Why is this bad?** Splitting the bounds of a type makes it harder to see
+/// all requirements at once, and repeated bounds are just noise.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// fn func<T: Clone>(arg: T) where T: Debug, T: Clone {}
+/// ```
+/// Could be written as:
+/// ```rust
+/// fn func<T: Clone + Debug>(arg: T) {}
+/// ```
+declare_clippy_lint! {
+    pub TYPE_REPETITION_IN_BOUNDS,
+    style,
+    "types that are bounded more than once in the same generics"
+}
+
+#[derive(Copy, Clone)]
+pub struct TraitBounds {
+    method_bounds_threshold: u64,
+}
+
+impl TraitBounds {
+    pub fn new(method_bounds_threshold: u64) -> Self {
+        Self {
+            method_bounds_threshold,
+        }
+    }
+}
+
+impl LintPass for TraitBounds {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(DUPLICATED_METHOD_BOUNDS, TYPE_REPETITION_IN_BOUNDS)
+    }
+
+    fn name(&self) -> &'static str {
+        "TraitBounds"
+    }
+}
+
+impl EarlyLintPass for TraitBounds {
+    fn check_generics(&mut self, cx: &EarlyContext<'_>, generics: &Generics) {
+        if in_macro(generics.span) || in_external_macro(cx.sess(), generics.span) {
+            return;
+        }
+
+        // every place a type is bounded at, in source order
+        let mut occurrences: Vec<BoundOccurrence> = Vec::new();
+        for param in &generics.params {
+            if let (GenericParamKind::Type { .. }, Some(last)) = (&param.kind, param.bounds.last()) {
+                occurrences.push(BoundOccurrence {
+                    ty: param.ident.to_string(),
+                    span: param.ident.span.to(last.span()),
+                    bounds: param.bounds.iter().map(|b| (bound_to_string(b), b.span())).collect(),
+                });
+            }
+        }
+        for predicate in &generics.where_clause.predicates {
+            if let WherePredicate::BoundPredicate(ref predicate) = *predicate {
+                // higher-ranked predicates can't always be merged with the others
+                if predicate.bound_generic_params.is_empty() {
+                    occurrences.push(BoundOccurrence {
+                        ty: ty_to_string(&predicate.bounded_ty),
+                        span: predicate.span,
+                        bounds: predicate
+                            .bounds
+                            .iter()
+                            .map(|b| (bound_to_string(b), b.span()))
+                            .collect(),
+                    });
+                }
+            }
+        }
+
+        for (i, occurrence) in occurrences.iter().enumerate() {
+            let same_ty = || occurrences.iter().filter(|other| other.ty == occurrence.ty);
+            let first = occurrences.iter().position(|other| other.ty == occurrence.ty) == Some(i);
+            let mut combined: Vec<&str> = Vec::new();
+            for (bound, _) in same_ty().flat_map(|o| &o.bounds) {
+                if !combined.contains(&&**bound) {
+                    combined.push(bound);
+                }
+            }
+            let help = format!(
+                "consider combining the bounds: `{}: {}`",
+                occurrence.ty,
+                combined.join(" + ")
+            );
+
+            if !first {
+                span_help_and_lint(
+                    cx,
+                    TYPE_REPETITION_IN_BOUNDS,
+                    occurrence.span,
+                    "this type has already been used as a bound predicate",
+                    &help,
+                );
+            } else if same_ty().count() == 1 {
+                for (j, (bound, span)) in occurrence.bounds.iter().enumerate() {
+                    if occurrence.bounds[..j].iter().any(|(other, _)| other == bound) {
+                        span_help_and_lint(
+                            cx,
+                            TYPE_REPETITION_IN_BOUNDS,
+                            *span,
+                            &format!("`{}` is listed more than once for `{}`", bound, occurrence.ty),
+                            &help,
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    fn check_item(&mut self, cx: &EarlyContext<'_>, item: &Item) {
+        if in_macro(item.span) || in_external_macro(cx.sess(), item.span) {
+            return;
+        }
+        let (methods, container) = match item.node {
+            // bounds on the methods of a trait impl have to match the trait, so only inherent impls
+            // are checked
+            ItemKind::Impl(_, _, _, _, None, _, ref items) => (
+                items
+                    .iter()
+                    .filter_map(|item| match item.node {
+                        ImplItemKind::Method(..) => Some(&item.generics),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>(),
+                "impl block",
+            ),
+            ItemKind::Trait(_, _, _, _, ref items) => (
+                items
+                    .iter()
+                    .filter_map(|item| match item.node {
+                        TraitItemKind::Method(..) => Some(&item.generics),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>(),
+                "trait",
+            ),
+            _ => return,
+        };
+        if methods.is_empty() || (methods.len() as u64) < self.method_bounds_threshold {
+            return;
+        }
+        let is_trait = container == "trait";
+
+        // the bounds of the first method, and how many methods share each of them
+        let mut shared: Vec<(HoistableBound, usize)> = hoistable_bounds(methods[0], is_trait)
+            .into_iter()
+            .map(|bound| (bound, 1))
+            .collect();
+        for generics in &methods[1..] {
+            let bounds = hoistable_bounds(generics, is_trait);
+            for (bound, count) in &mut shared {
+                if bounds.iter().any(|other| other.key == bound.key) {
+                    *count += 1;
+                }
+            }
+        }
+
+        for (bound, count) in shared {
+            if count == methods.len() {
+                span_help_and_lint(
+                    cx,
+                    DUPLICATED_METHOD_BOUNDS,
+                    bound.span,
+                    &format!("this bound is repeated on all {} methods of this {}", count, container),
+                    &format!(
+                        "consider moving `{}` to the where clause of the {}",
+                        bound.display, container
+                    ),
+                );
+            }
+        }
+    }
+}
+
+/// A list of bounds on a type, either inline in a generic parameter list or in
+/// a `where` clause.
+struct BoundOccurrence {
+    ty: String,
+    span: Span,
+    bounds: Vec<(String, Span)>,
+}
+
+fn bound_to_string(bound: &GenericBound) -> String {
+    bounds_to_string(slice::from_ref(bound))
+}
+
+/// A single `where` bound of a method that only refers to generic parameters
+/// of the surrounding `impl` or trait.
+struct HoistableBound {
+    /// The bound with higher-ranked lifetime names normalized, used to compare
+    /// bounds of different methods.
+    key: String,
+    /// The bound as written in the first method.
+    display: String,
+    span: Span,
+}
+
+fn hoistable_bounds(generics: &Generics, is_trait: bool) -> Vec<HoistableBound> {
+    let locals: FxHashSet<Name> = generics.params.iter().map(|param| param.ident.name).collect();
+    let mut seen = FxHashSet::default();
+    let mut result = Vec::new();
+    for predicate in &generics.where_clause.predicates {
+        if let WherePredicate::BoundPredicate(ref predicate) = *predicate {
+            let bounded_ty = ty_to_string(&predicate.bounded_ty);
+            for bound in &predicate.bounds {
+                let bound_str = bound_to_string(bound);
+                if is_trait && bounded_ty == "Self" && bound_str == "Sized" {
+                    continue;
+                }
+                let mut visitor = LocalGenericsVisitor {
+                    locals: &locals,
+                    found: false,
+                };
+                visitor.visit_ty(&predicate.bounded_ty);
+                visitor.visit_param_bound(bound);
+                if visitor.found {
+                    continue;
+                }
+
+                let mut binders: Vec<Ident> = predicate.bound_generic_params.iter().map(|p| p.ident).collect();
+                if let GenericBound::Trait(ref poly_trait_ref, _) = *bound {
+                    binders.extend(poly_trait_ref.bound_generic_params.iter().map(|p| p.ident));
+                }
+                let display = if predicate.bound_generic_params.is_empty() {
+                    format!("{}: {}", bounded_ty, bound_str)
+                } else {
+                    let params: Vec<_> = predicate
+                        .bound_generic_params
+                        .iter()
+                        .map(|p| p.ident.to_string())
+                        .collect();
+                    format!("for<{}> {}: {}", params.join(", "), bounded_ty, bound_str)
+                };
+                let key = normalize_binders(&display, &binders);
+                if seen.insert(key.clone()) {
+                    result.push(HoistableBound {
+                        key,
+                        display,
+                        span: predicate.span,
+                    });
+                }
+            }
+        }
+    }
+    result
+}
+
+/// Renames the given higher-ranked lifetimes to positional names, so that
+/// `for<'a> Fn(&'a T)` and `for<'b> Fn(&'b T)` compare equal.
+fn normalize_binders(bound: &str, binders: &[Ident]) -> String {
+    if binders.is_empty() {
+        return bound.to_string();
+    }
+    let mut result = String::with_capacity(bound.len());
+    let mut chars = bound.chars().peekable();
+    while let Some(c) = chars.next() {
+        result.push(c);
+        if c != '\'' {
+            continue;
+        }
+        let mut name = String::new();
+        while let Some(&c) = chars.peek() {
+            if c.is_alphanumeric() || c == '_' {
+                name.push(c);
+                chars.next();
+            } else {
+                break;
+            }
+        }
+        match binders
+            .iter()
+            .position(|binder| binder.as_str().trim_start_matches('\'') == name)
+        {
+            Some(index) => result.push_str(&index.to_string()),
+            None => result.push_str(&name),
+        }
+    }
+    result
+}
+
+/// Checks whether a type or bound mentions one of the given generic
+/// parameters.
+struct LocalGenericsVisitor<'a> {
+    locals: &'a FxHashSet<Name>,
+    found: bool,
+}
+
+impl<'a, 'ast> Visitor<'ast> for LocalGenericsVisitor<'a> {
+    fn visit_ident(&mut self, ident: Ident) {
+        if self.locals.contains(&ident.name) {
+            self.found = true;
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use crate::utils::{last_path_segment, match_def_path, paths, snippet, span_lint, span_lint_and_then};
-use crate::utils::{opt_def_id, sugg};
+use crate::utils::{meets_msrv, msrvs, opt_def_id, sugg, RustcVersion};
 use if_chain::if_chain;
 use rustc::hir::*;
 use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
@@ -203,7 +203,15 @@ declare_clippy_lint! {
     "transmutes from a pointer to a pointer / a reference to a reference"
 }
 
-pub struct Transmute;
+pub struct Transmute {
+    msrv: Option<RustcVersion>,
+}
+
+impl Transmute {
+    pub fn new(msrv: Option<RustcVersion>) -> Self {
+        Self { msrv }
+    }
+}
 
 impl LintPass for Transmute {
     fn get_lints(&self) -> LintArray {
@@ -453,29 +461,35 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Transmute {
                                     },
                                 )
                             },
-                            (&ty::Int(_), &ty::Float(_)) | (&ty::Uint(_), &ty::Float(_)) => span_lint_and_then(
-                                cx,
-                                TRANSMUTE_INT_TO_FLOAT,
-                                e.span,
-                                &format!("transmute from a `{}` to a `{}`", from_ty, to_ty),
-                                |db| {
-                                    let arg = sugg::Sugg::hir(cx, &args[0], "..");
-                                    let arg = if let ty::Int(int_ty) = from_ty.sty {
-                                        arg.as_ty(format!(
-                                            "u{}",
-                                            int_ty.bit_width().map_or_else(|| "size".to_string(), |v| v.to_string())
-                                        ))
-                                    } else {
-                                        arg
-                                    };
-                                    db.span_suggestion(
-                                        e.span,
-                                        "consider using",
-                                        format!("{}::from_bits({})", to_ty, arg.to_string()),
-                                        Applicability::Unspecified,
-                                    );
-                                },
-                            ),
+                            (&ty::Int(_), &ty::Float(_)) | (&ty::Uint(_), &ty::Float(_))
+                                if meets_msrv(cx.tcx, e.id, self.msrv, msrvs::FLOAT_FROM_BITS) =>
+                            {
+                                span_lint_and_then(
+                                    cx,
+                                    TRANSMUTE_INT_TO_FLOAT,
+                                    e.span,
+                                    &format!("transmute from a `{}` to a `{}`", from_ty, to_ty),
+                                    |db| {
+                                        let arg = sugg::Sugg::hir(cx, &args[0], "..");
+                                        let arg = if let ty::Int(int_ty) = from_ty.sty {
+                                            arg.as_ty(format!(
+                                                "u{}",
+                                                int_ty
+                                                    .bit_width()
+                                                    .map_or_else(|| "size".to_string(), |v| v.to_string())
+                                            ))
+                                        } else {
+                                            arg
+                                        };
+                                        db.span_suggestion(
+                                            e.span,
+                                            "consider using",
+                                            format!("{}::from_bits({})", to_ty, arg.to_string()),
+                                            Applicability::Unspecified,
+                                        );
+                                    },
+                                )
+                            },
                             _ => return,
                         };
                     }
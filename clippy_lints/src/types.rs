@@ -6,8 +6,8 @@ use crate::utils::paths;
 use crate::utils::{
     clip, comparisons, differing_macro_contexts, higher, in_constant, in_macro, int_bits, last_path_segment,
     match_def_path, match_path, multispan_sugg, opt_def_id, same_tys, sext, snippet, snippet_opt,
-    snippet_with_applicability, span_help_and_lint, span_lint, span_lint_and_sugg, span_lint_and_then, unsext,
-    AbsolutePathBuffer,
+    snippet_with_applicability, span_help_and_lint, span_lint, span_lint_and_sugg, span_lint_and_then,
+    span_lint_node_and_then_lazy, span_lint_node_lazy, unsext, AbsolutePathBuffer,
 };
 use if_chain::if_chain;
 use rustc::hir;
@@ -928,18 +928,15 @@ fn span_precision_loss_lint(cx: &LateContext<'_, '_>, expr: &Expr, cast_from: Ty
     let mantissa_nbits = if cast_to_f64 { 52 } else { 23 };
     let arch_dependent = is_isize_or_usize(cast_from) && cast_to_f64;
     let arch_dependent_str = "on targets with 64-bit wide pointers ";
-    let from_nbits_str = if arch_dependent {
-        "64".to_owned()
-    } else if is_isize_or_usize(cast_from) {
-        "32 or 64".to_owned()
-    } else {
-        int_ty_to_nbits(cast_from, cx.tcx).to_string()
-    };
-    span_lint(
-        cx,
-        CAST_PRECISION_LOSS,
-        expr.span,
-        &format!(
+    span_lint_node_lazy(cx, CAST_PRECISION_LOSS, expr.id, expr.span, || {
+        let from_nbits_str = if arch_dependent {
+            "64".to_owned()
+        } else if is_isize_or_usize(cast_from) {
+            "32 or 64".to_owned()
+        } else {
+            int_ty_to_nbits(cast_from, cx.tcx).to_string()
+        };
+        format!(
             "casting {0} to {1} causes a loss of precision {2}({0} is {3} bits wide, but {1}'s mantissa \
              is only {4} bits wide)",
             cast_from,
@@ -947,8 +944,8 @@ fn span_precision_loss_lint(cx: &LateContext<'_, '_>, expr: &Expr, cast_from: Ty
             if arch_dependent { arch_dependent_str } else { "" },
             from_nbits_str,
             mantissa_nbits
-        ),
-    );
+        )
+    });
 }
 
 fn should_strip_parens(op: &Expr, snip: &str) -> bool {
@@ -965,32 +962,34 @@ fn span_lossless_lint(cx: &LateContext<'_, '_>, expr: &Expr, op: &Expr, cast_fro
     if in_constant(cx, expr.id) {
         return;
     }
-    // The suggestion is to use a function call, so if the original expression
-    // has parens on the outside, they are no longer needed.
-    let mut applicability = Applicability::MachineApplicable;
-    let opt = snippet_opt(cx, op.span);
-    let sugg = if let Some(ref snip) = opt {
-        if should_strip_parens(op, snip) {
-            &snip[1..snip.len() - 1]
-        } else {
-            snip.as_str()
-        }
-    } else {
-        applicability = Applicability::HasPlaceholders;
-        ".."
-    };
-
-    span_lint_and_sugg(
+    span_lint_node_and_then_lazy(
         cx,
         CAST_LOSSLESS,
+        expr.id,
         expr.span,
-        &format!(
-            "casting {} to {} may become silently lossy if types change",
-            cast_from, cast_to
-        ),
-        "try",
-        format!("{}::from({})", cast_to, sugg),
-        applicability,
+        || {
+            format!(
+                "casting {} to {} may become silently lossy if types change",
+                cast_from, cast_to
+            )
+        },
+        |db| {
+            // The suggestion is to use a function call, so if the original expression
+            // has parens on the outside, they are no longer needed.
+            let mut applicability = Applicability::MachineApplicable;
+            let opt = snippet_opt(cx, op.span);
+            let sugg = if let Some(ref snip) = opt {
+                if should_strip_parens(op, snip) {
+                    &snip[1..snip.len() - 1]
+                } else {
+                    snip.as_str()
+                }
+            } else {
+                applicability = Applicability::HasPlaceholders;
+                ".."
+            };
+            db.span_suggestion(expr.span, "try", format!("{}::from({})", cast_to, sugg), applicability);
+        },
     );
 }
 
@@ -1017,12 +1016,9 @@ fn check_loss_of_sign(cx: &LateContext<'_, '_>, expr: &Expr, op: &Expr, cast_fro
         }
     }
 
-    span_lint(
-        cx,
-        CAST_SIGN_LOSS,
-        expr.span,
-        &format!("casting {} to {} may lose the sign of the value", cast_from, cast_to),
-    );
+    span_lint_node_lazy(cx, CAST_SIGN_LOSS, expr.id, expr.span, || {
+        format!("casting {} to {} may lose the sign of the value", cast_from, cast_to)
+    });
 }
 
 fn check_truncation_and_wrapping(cx: &LateContext<'_, '_>, expr: &Expr, cast_from: Ty<'_>, cast_to: Ty<'_>) {
@@ -1061,11 +1057,8 @@ fn check_truncation_and_wrapping(cx: &LateContext<'_, '_>, expr: &Expr, cast_fro
             ),
         };
     if span_truncation {
-        span_lint(
-            cx,
-            CAST_POSSIBLE_TRUNCATION,
-            expr.span,
-            &format!(
+        span_lint_node_lazy(cx, CAST_POSSIBLE_TRUNCATION, expr.id, expr.span, || {
+            format!(
                 "casting {} to {} may truncate the value{}",
                 cast_from,
                 cast_to,
@@ -1074,15 +1067,12 @@ fn check_truncation_and_wrapping(cx: &LateContext<'_, '_>, expr: &Expr, cast_fro
                     ArchSuffix::_64 => arch_64_suffix,
                     ArchSuffix::None => "",
                 }
-            ),
-        );
+            )
+        });
     }
     if span_wrap {
-        span_lint(
-            cx,
-            CAST_POSSIBLE_WRAP,
-            expr.span,
-            &format!(
+        span_lint_node_lazy(cx, CAST_POSSIBLE_WRAP, expr.id, expr.span, || {
+            format!(
                 "casting {} to {} may wrap around the value{}",
                 cast_from,
                 cast_to,
@@ -1091,8 +1081,8 @@ fn check_truncation_and_wrapping(cx: &LateContext<'_, '_>, expr: &Expr, cast_fro
                     ArchSuffix::_64 => arch_64_suffix,
                     ArchSuffix::None => "",
                 }
-            ),
-        );
+            )
+        });
     }
 }
 
@@ -1184,19 +1174,13 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for CastPass {
                         }
                     },
                     (false, true) => {
-                        span_lint(
-                            cx,
-                            CAST_POSSIBLE_TRUNCATION,
-                            expr.span,
-                            &format!("casting {} to {} may truncate the value", cast_from, cast_to),
-                        );
+                        span_lint_node_lazy(cx, CAST_POSSIBLE_TRUNCATION, expr.id, expr.span, || {
+                            format!("casting {} to {} may truncate the value", cast_from, cast_to)
+                        });
                         if !cast_to.is_signed() {
-                            span_lint(
-                                cx,
-                                CAST_SIGN_LOSS,
-                                expr.span,
-                                &format!("casting {} to {} may lose the sign of the value", cast_from, cast_to),
-                            );
+                            span_lint_node_lazy(cx, CAST_SIGN_LOSS, expr.id, expr.span, || {
+                                format!("casting {} to {} may lose the sign of the value", cast_from, cast_to)
+                            });
                         }
                     },
                     (true, true) => {
@@ -2176,7 +2160,7 @@ impl<'tcx> ImplicitHasherType<'tcx> {
     fn type_arguments(&self) -> String {
         match *self {
             ImplicitHasherType::HashMap(.., ref k, ref v) => format!("{}, {}", k, v),
-            ImplicitHasherType::HashSet(.., ref t) => format!("{}", t),
+            ImplicitHasherType::HashSet(.., ref t) => t.to_string(),
         }
     }
 
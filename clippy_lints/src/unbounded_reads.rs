@@ -0,0 +1,126 @@
+//! lint on reading whole network streams without a size limit
+
+use crate::utils::{get_def_path, match_def_path, match_trait_method, path_to_def, paths, span_help_and_lint};
+use rustc::hir::def::Def;
+use rustc::hir::def_id::DefId;
+use rustc::hir::*;
+use rustc::lint::{in_external_macro, LateContext, LateLintPass, LintArray, LintContext, LintPass};
+use rustc::ty::{self, Ty};
+use rustc::{declare_tool_lint, lint_array};
+use rustc_data_structures::fx::FxHashSet;
+
+/// **What it does:** Checks for `read_to_end` and `read_to_string` calls on
+/// the stream types configured with `untrusted-stream-types` in
+/// `clippy.toml`, or on readers wrapping them, like `BufReader<TcpStream>`.
+/// By default these are `std::net::TcpStream` and
+/// `std::os::unix::net::UnixStream`. Readers limited with `take` aren't
+/// linted.
+///
+/// **Why is this bad?** The data is read until the peer closes the stream, so
+/// a peer can make the program allocate as much memory as it wants.
+///
+/// **Known problems:** Streams which are only read through trait objects or
+/// generics aren't recognized.
+///
+/// **Example:**
+/// ```rust
+/// let mut request = String::new();
+/// stream.read_to_string(&mut request)?;
+/// ```
+/// Could be written as:
+/// ```rust
+/// let mut request = String::new();
+/// stream.take(MAX_REQUEST_SIZE).read_to_string(&mut request)?;
+/// ```
+declare_clippy_lint! {
+    pub UNBOUNDED_READS,
+    restriction,
+    "reading whole network streams without a size limit"
+}
+
+#[derive(Clone, Debug)]
+pub struct UnboundedReads {
+    stream_types: Vec<String>,
+    /// the types of `stream_types` which could be resolved
+    resolved: FxHashSet<DefId>,
+}
+
+impl UnboundedReads {
+    pub fn new(stream_types: Vec<String>) -> Self {
+        Self {
+            stream_types,
+            resolved: FxHashSet::default(),
+        }
+    }
+
+    fn is_stream_type(&self, cx: &LateContext<'_, '_>, def_id: DefId) -> bool {
+        if self.resolved.contains(&def_id) {
+            return true;
+        }
+        // local types can't be resolved by `path_to_def`
+        let def_path = get_def_path(cx.tcx, def_id).join("::");
+        self.stream_types.iter().any(|path| *path == def_path)
+    }
+
+    /// Returns the configured stream type `ty` is or wraps, unless the stream
+    /// is wrapped in `Take`.
+    fn find_stream<'tcx>(&self, cx: &LateContext<'_, 'tcx>, ty: Ty<'tcx>) -> Option<Ty<'tcx>> {
+        match ty.sty {
+            ty::Ref(_, ty, _) => self.find_stream(cx, ty),
+            ty::Adt(adt, substs) => {
+                if match_def_path(cx.tcx, adt.did, &paths::IO_TAKE) {
+                    return None;
+                }
+                if self.is_stream_type(cx, adt.did) {
+                    return Some(ty);
+                }
+                substs.types().find_map(|ty| self.find_stream(cx, ty))
+            },
+            _ => None,
+        }
+    }
+}
+
+impl LintPass for UnboundedReads {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(UNBOUNDED_READS)
+    }
+
+    fn name(&self) -> &'static str {
+        "UnboundedReads"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for UnboundedReads {
+    fn check_crate(&mut self, cx: &LateContext<'a, 'tcx>, _: &'tcx Crate) {
+        for path in &self.stream_types {
+            let path: Vec<_> = path.split("::").collect();
+            match path_to_def(cx, &path) {
+                Some(Def::Struct(def_id)) | Some(Def::Enum(def_id)) => {
+                    self.resolved.insert(def_id);
+                },
+                _ => {},
+            }
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if self.stream_types.is_empty() || in_external_macro(cx.sess(), expr.span) {
+            return;
+        }
+        if let ExprKind::MethodCall(ref path, _, ref args) = expr.node {
+            let name = path.ident.as_str();
+            if (name == "read_to_end" || name == "read_to_string") && match_trait_method(cx, expr, &paths::IO_READ) {
+                if let Some(stream) = self.find_stream(cx, cx.tables.expr_ty_adjusted(&args[0])) {
+                    span_help_and_lint(
+                        cx,
+                        UNBOUNDED_READS,
+                        expr.span,
+                        &format!("reading a whole `{}` without a size limit", stream),
+                        "limit the size with `take`, e.g. `stream.by_ref().take(limit)`",
+                    );
+                }
+            }
+        }
+    }
+}
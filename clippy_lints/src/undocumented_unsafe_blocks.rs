@@ -0,0 +1,99 @@
+//! lint on `unsafe` blocks without a comment explaining why they are sound
+
+use crate::utils::{in_macro, span_help_and_lint};
+use rustc::hir::*;
+use rustc::lint::{in_external_macro, LateContext, LateLintPass, LintArray, LintContext, LintPass};
+use rustc::{declare_tool_lint, lint_array};
+use syntax::source_map::Span;
+
+/// **What it does:** Checks for `unsafe` blocks which aren't preceded by a
+/// `// SAFETY:` comment. The comment has to be on the lines right above the
+/// line the block starts on, other line comments may come in between. The
+/// prefix can be configured with `unsafe-comment-prefix` in `clippy.toml`.
+///
+/// **Why is this bad?** The reasons an `unsafe` block upholds the invariants
+/// the compiler can't check are easily lost, and have to be known when the
+/// code around it changes.
+///
+/// **Known problems:** Block comments aren't recognized.
+///
+/// **Example:**
+/// ```rust
+/// let first = unsafe { *v.get_unchecked(0) };
+/// ```
+/// Could be written as:
+/// ```rust
+/// // SAFETY: `v` was checked not to be empty above
+/// let first = unsafe { *v.get_unchecked(0) };
+/// ```
+declare_clippy_lint! {
+    pub UNDOCUMENTED_UNSAFE_BLOCKS,
+    restriction,
+    "`unsafe` blocks without a `// SAFETY:` comment"
+}
+
+#[derive(Clone)]
+pub struct Pass {
+    prefix: String,
+}
+
+impl Pass {
+    pub fn new(prefix: String) -> Self {
+        Self { prefix }
+    }
+}
+
+impl LintPass for Pass {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(UNDOCUMENTED_UNSAFE_BLOCKS)
+    }
+
+    fn name(&self) -> &'static str {
+        "UndocumentedUnsafeBlocks"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if let ExprKind::Block(ref block, _) = expr.node {
+            if block.rules != BlockCheckMode::UnsafeBlock(UnsafeSource::UserProvided)
+                || in_macro(block.span)
+                || in_external_macro(cx.sess(), block.span)
+            {
+                return;
+            }
+            if has_comment(cx, block.span, &self.prefix) == Some(false) {
+                span_help_and_lint(
+                    cx,
+                    UNDOCUMENTED_UNSAFE_BLOCKS,
+                    block.span,
+                    &format!("unsafe block without a `// {}` comment", self.prefix),
+                    &format!(
+                        "explain why the block is sound in a comment starting with `// {}` above it",
+                        self.prefix
+                    ),
+                );
+            }
+        }
+    }
+}
+
+/// Checks whether the line comments right above the line `span` starts on
+/// include one starting with `prefix`. Returns `None` if the source isn't
+/// available.
+fn has_comment(cx: &LateContext<'_, '_>, span: Span, prefix: &str) -> Option<bool> {
+    let line = cx.sess().source_map().lookup_line(span.lo()).ok()?;
+    let file = &line.sf;
+    let src = file.src.as_ref()?;
+    let line_start = |line: usize| (file.lines[line] - file.start_pos).0 as usize;
+    for line in (0..line.line).rev() {
+        let text = src.get(line_start(line)..line_start(line + 1))?.trim();
+        if !text.starts_with("//") {
+            return Some(false);
+        }
+        if text.trim_start_matches('/').trim_start().starts_with(prefix) {
+            return Some(true);
+        }
+    }
+    Some(false)
+}
@@ -0,0 +1,183 @@
+//! lint on `pub(crate)` and `pub(super)` items which are only used in their own module
+
+use crate::utils::{in_macro, opt_def_id, span_lint_node_and_then};
+use rustc::hir::def_id::DefId;
+use rustc::hir::*;
+use rustc::lint::{in_external_macro, LateContext, LateLintPass, LintArray, LintContext, LintPass};
+use rustc::ty::DefIdTree;
+use rustc::{declare_tool_lint, lint_array};
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use syntax::ast::NodeId;
+use syntax::source_map::Span;
+
+/// **What it does:** Checks for functions, methods, constants and statics
+/// declared `pub(crate)`, `pub(super)` or `pub(in path)` which are only used in
+/// the module they are defined in and its submodules, where they'd be visible
+/// if they were private.
+///
+/// **Why is this bad?** The visibility says the item is used elsewhere in the
+/// crate, so readers have to search for uses which don't exist. Private items
+/// which aren't used at all are reported by rustc's `dead_code`, items with a
+/// broader visibility aren't.
+///
+/// **Known problems:** Items only used by macros expanded in other modules
+/// are linted if the macros aren't used yet. Types and traits aren't linted,
+/// since other items visible outside of the module may need them in their
+/// signatures.
+///
+/// **Example:**
+/// ```rust
+/// mod parser {
+///     pub(crate) fn skip_whitespace(s: &str) -> &str {
+///         s.trim_start()
+///     }
+///
+///     pub fn parse(s: &str) {
+///         let s = skip_whitespace(s);
+///         // ..
+///     }
+/// }
+/// ```
+/// Could be written as:
+/// ```rust
+/// mod parser {
+///     fn skip_whitespace(s: &str) -> &str {
+///         s.trim_start()
+///     }
+///
+///     pub fn parse(s: &str) {
+///         let s = skip_whitespace(s);
+///         // ..
+///     }
+/// }
+/// ```
+declare_clippy_lint! {
+    pub UNNECESSARY_VISIBILITY,
+    pedantic,
+    "`pub(crate)` or `pub(super)` items which are only used in their own module"
+}
+
+#[derive(Default)]
+pub struct Pass {
+    /// candidate items, their nodes, spans, and the modules they are defined in
+    items: Vec<(DefId, NodeId, Span, DefId)>,
+    /// the modules each item is used in
+    uses: FxHashMap<DefId, FxHashSet<DefId>>,
+}
+
+impl Pass {
+    fn check_vis(&mut self, cx: &LateContext<'_, '_>, id: NodeId, vis: &Visibility, span: Span) {
+        if in_macro(span) || in_external_macro(cx.sess(), span) || !is_restricted(vis) {
+            return;
+        }
+        let map = &cx.tcx.hir();
+        self.items.push((
+            map.local_def_id(id),
+            id,
+            cx.sess().source_map().def_span(span),
+            map.get_module_parent(id),
+        ));
+    }
+
+    /// Records that `def_id` is used at `node`.
+    fn record_use(&mut self, cx: &LateContext<'_, '_>, def_id: DefId, node: NodeId) {
+        if def_id.is_local() {
+            let module = cx.tcx.hir().get_module_parent(node);
+            self.uses.entry(def_id).or_default().insert(module);
+        }
+    }
+}
+
+impl LintPass for Pass {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(UNNECESSARY_VISIBILITY)
+    }
+
+    fn name(&self) -> &'static str {
+        "UnnecessaryVisibility"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
+    fn check_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx Item) {
+        match item.node {
+            ItemKind::Fn(..) | ItemKind::Const(..) | ItemKind::Static(..) => {
+                self.check_vis(cx, item.id, &item.vis, item.span)
+            },
+            _ => {},
+        }
+    }
+
+    fn check_impl_item(&mut self, cx: &LateContext<'a, 'tcx>, impl_item: &'tcx ImplItem) {
+        match impl_item.node {
+            ImplItemKind::Method(..) | ImplItemKind::Const(..) => {
+                self.check_vis(cx, impl_item.id, &impl_item.vis, impl_item.span)
+            },
+            _ => {},
+        }
+    }
+
+    fn check_path(&mut self, cx: &LateContext<'a, 'tcx>, path: &'tcx Path, id: NodeId) {
+        if let Some(def_id) = opt_def_id(path.def) {
+            self.record_use(cx, def_id, id);
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        match expr.node {
+            ExprKind::MethodCall(..) | ExprKind::Path(QPath::TypeRelative(..)) => {
+                if let Some(def) = cx.tables.type_dependent_defs().get(expr.hir_id) {
+                    self.record_use(cx, def.def_id(), expr.id);
+                }
+            },
+            _ => {},
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'a, 'tcx>, _: &'tcx Crate) {
+        for &(def_id, node, span, module) in &self.items {
+            // unused items are reported by `dead_code`
+            let modules = match self.uses.get(&def_id) {
+                Some(modules) => modules,
+                None => continue,
+            };
+            if modules.iter().all(|&used_in| is_within(cx, used_in, module)) {
+                span_lint_node_and_then(
+                    cx,
+                    UNNECESSARY_VISIBILITY,
+                    node,
+                    span,
+                    "this item is only used in the module it is defined in",
+                    |db| {
+                        db.help("consider making it private");
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Checks whether `vis` makes an item visible in more modules than private
+/// items are, but not outside of the crate.
+fn is_restricted(vis: &Visibility) -> bool {
+    match vis.node {
+        VisibilityKind::Crate(_) => true,
+        VisibilityKind::Restricted { ref path, .. } => match path.segments[..] {
+            [ref segment] => segment.ident.name != "self",
+            _ => true,
+        },
+        VisibilityKind::Public | VisibilityKind::Inherited => false,
+    }
+}
+
+/// Checks whether the module `inner` is `outer` or one of its submodules.
+fn is_within(cx: &LateContext<'_, '_>, inner: DefId, outer: DefId) -> bool {
+    let mut module = Some(inner);
+    while let Some(current) = module {
+        if current == outer {
+            return true;
+        }
+        module = cx.tcx.parent(current);
+    }
+    false
+}
@@ -0,0 +1,115 @@
+//! lint on `async fn`s which never `await!`
+
+use crate::utils::{in_macro, span_help_and_lint};
+use rustc::hir::intravisit::{walk_expr, FnKind, NestedVisitorMap, Visitor};
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::{declare_tool_lint, lint_array};
+use syntax::ast::NodeId;
+use syntax::source_map::Span;
+
+/// **What it does:** Checks for `async fn`s whose bodies don't `await!`
+/// anything. `await!`s in closures and `async` blocks inside them don't
+/// count, since they don't suspend the function.
+///
+/// **Why is this bad?** The `async` only makes callers `await!` the function,
+/// and turns the body into a future, which is larger and slower than calling
+/// the function directly.
+///
+/// **Known problems:** The `async` can't be removed if the function has to
+/// return a future, e.g. because it's passed where one is expected.
+///
+/// **Example:**
+/// ```rust
+/// async fn add(a: u32, b: u32) -> u32 {
+///     a + b
+/// }
+/// ```
+/// Could be written as:
+/// ```rust
+/// fn add(a: u32, b: u32) -> u32 {
+///     a + b
+/// }
+/// ```
+declare_clippy_lint! {
+    pub UNUSED_ASYNC,
+    pedantic,
+    "`async fn`s which don't `await!` anything"
+}
+
+#[derive(Copy, Clone)]
+pub struct UnusedAsync;
+
+impl LintPass for UnusedAsync {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(UNUSED_ASYNC)
+    }
+
+    fn name(&self) -> &'static str {
+        "UnusedAsync"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for UnusedAsync {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'a, 'tcx>,
+        kind: FnKind<'tcx>,
+        _: &'tcx FnDecl,
+        body: &'tcx Body,
+        span: Span,
+        _: NodeId,
+    ) {
+        let header = match kind {
+            FnKind::ItemFn(_, _, header, ..) => header,
+            FnKind::Method(_, sig, ..) => sig.header,
+            FnKind::Closure(_) => return,
+        };
+        if header.asyncness != IsAsync::Async || in_macro(span) {
+            return;
+        }
+        let mut visitor = AwaitVisitor {
+            cx,
+            in_generator: false,
+            found_await: false,
+        };
+        visitor.visit_expr(&body.value);
+        if !visitor.found_await {
+            span_help_and_lint(
+                cx,
+                UNUSED_ASYNC,
+                span,
+                "this `async fn` doesn't `await!` anything",
+                "consider removing the `async` and the `await!`s of its calls",
+            );
+        }
+    }
+}
+
+/// Looks for the suspension points of the generator an `async fn` body is
+/// desugared to, which `await!` expands to.
+struct AwaitVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    in_generator: bool,
+    found_await: bool,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for AwaitVisitor<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        match expr.node {
+            ExprKind::Yield(_) => self.found_await = true,
+            // the body of the `async fn`
+            ExprKind::Closure(.., Some(_)) if !self.in_generator => {
+                self.in_generator = true;
+                walk_expr(self, expr);
+            },
+            // nested closures and `async` blocks suspend on their own
+            ExprKind::Closure(..) => {},
+            _ => walk_expr(self, expr),
+        }
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::OnlyBodies(&self.cx.tcx.hir())
+    }
+}
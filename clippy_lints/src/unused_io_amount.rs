@@ -13,6 +13,9 @@ use rustc::{declare_tool_lint, lint_array};
 /// partial-write/read, use
 /// `write_all`/`read_exact` instead.
 ///
+/// The amount is considered unused if the result is `?`'d away, unwrapped,
+/// converted with `ok` or `is_ok`, bound to `_` or not used at all.
+///
 /// **Known problems:** Detects only common patterns.
 ///
 /// **Example:**
@@ -46,6 +49,10 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for UnusedIoAmount {
     fn check_stmt(&mut self, cx: &LateContext<'_, '_>, s: &hir::Stmt) {
         let expr = match s.node {
             hir::StmtKind::Semi(ref expr) | hir::StmtKind::Expr(ref expr) => &**expr,
+            hir::StmtKind::Local(ref local) => match (&local.pat.node, &local.init) {
+                (hir::PatKind::Wild, Some(init)) => &**init,
+                _ => return,
+            },
             _ => return,
         };
 
@@ -63,10 +70,10 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for UnusedIoAmount {
             },
 
             hir::ExprKind::MethodCall(ref path, _, ref args) => match &*path.ident.as_str() {
-                "expect" | "unwrap" | "unwrap_or" | "unwrap_or_else" => {
+                "expect" | "unwrap" | "unwrap_or" | "unwrap_or_else" | "unwrap_or_default" | "ok" | "is_ok" => {
                     check_method_call(cx, &args[0], expr);
                 },
-                _ => (),
+                _ => check_method_call(cx, expr, expr),
             },
 
             _ => (),
@@ -0,0 +1,167 @@
+//! lint on statements discarding the result of a side-effect-free std method
+
+use crate::utils::{get_def_path, in_macro, span_help_and_lint};
+use if_chain::if_chain;
+use rustc::hir::def_id::DefId;
+use rustc::hir::*;
+use rustc::lint::{in_external_macro, LateContext, LateLintPass, LintArray, LintContext, LintPass};
+use rustc::ty;
+use rustc::{declare_tool_lint, lint_array};
+use rustc_data_structures::fx::FxHashSet;
+
+/// **What it does:** Checks for statements calling a std method without side
+/// effects, like `str::trim` or `u32::checked_add`, and throwing away the
+/// result.
+///
+/// More methods can be configured by path with `pure-methods` in
+/// `clippy.toml`.
+///
+/// **Why is this bad?** The call does nothing. Usually the author expected
+/// the method to modify its receiver in place, e.g. `s.trim();` doesn't
+/// remove the whitespace from `s`. Not all of these methods are marked
+/// `#[must_use]`, so the compiler doesn't warn about them.
+///
+/// **Known problems:** Calls which are only made for their panics, like
+/// `x.pow(2);` to check for overflows in debug builds, are linted as well.
+///
+/// **Example:**
+/// ```rust
+/// let s = String::from(" foo ");
+/// s.trim();
+/// ```
+/// Could be written as:
+/// ```rust
+/// let s = String::from(" foo ");
+/// let s = s.trim();
+/// ```
+declare_clippy_lint! {
+    pub UNUSED_PURE_RESULT,
+    pedantic,
+    "discarding the result of a std method without side effects"
+}
+
+/// The std methods without side effects, by path.
+const PURE_METHODS: [&str; 27] = [
+    "core::str::<impl str>::trim",
+    "core::str::<impl str>::trim_start",
+    "core::str::<impl str>::trim_end",
+    "core::str::<impl str>::trim_matches",
+    "core::str::<impl str>::trim_start_matches",
+    "core::str::<impl str>::trim_end_matches",
+    "core::str::<impl str>::split",
+    "core::str::<impl str>::split_whitespace",
+    "core::str::<impl str>::lines",
+    "core::str::<impl str>::chars",
+    "core::str::<impl str>::bytes",
+    "core::str::<impl str>::len",
+    "core::str::<impl str>::is_empty",
+    "alloc::str::<impl str>::to_uppercase",
+    "alloc::str::<impl str>::to_lowercase",
+    "alloc::str::<impl str>::to_ascii_uppercase",
+    "alloc::str::<impl str>::to_ascii_lowercase",
+    "core::slice::<impl [T]>::len",
+    "core::slice::<impl [T]>::is_empty",
+    "alloc::slice::<impl [T]>::to_vec",
+    "alloc::string::String::len",
+    "alloc::string::String::is_empty",
+    "alloc::vec::Vec::len",
+    "alloc::vec::Vec::is_empty",
+    "core::clone::Clone::clone",
+    "alloc::borrow::ToOwned::to_owned",
+    "alloc::string::ToString::to_string",
+];
+
+/// The methods without side effects of all integer types, which are in
+/// `core::num::<impl i32>` etc.
+const PURE_INT_METHODS: [&str; 14] = [
+    "checked_add",
+    "checked_sub",
+    "checked_mul",
+    "checked_div",
+    "checked_rem",
+    "checked_neg",
+    "wrapping_add",
+    "wrapping_sub",
+    "wrapping_mul",
+    "saturating_add",
+    "saturating_sub",
+    "saturating_mul",
+    "abs",
+    "pow",
+];
+
+const INT_TYPES: [&str; 12] = [
+    "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128", "usize",
+];
+
+#[derive(Clone, Debug)]
+pub struct UnusedPureResult {
+    paths: FxHashSet<String>,
+}
+
+impl UnusedPureResult {
+    pub fn new(methods: &[String]) -> Self {
+        let int_methods = INT_TYPES.iter().flat_map(|ty| {
+            PURE_INT_METHODS
+                .iter()
+                .map(move |method| format!("core::num::<impl {}>::{}", ty, method))
+        });
+        Self {
+            paths: PURE_METHODS
+                .iter()
+                .map(|path| (*path).to_string())
+                .chain(int_methods)
+                .chain(methods.iter().cloned())
+                .collect(),
+        }
+    }
+}
+
+impl LintPass for UnusedPureResult {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(UNUSED_PURE_RESULT)
+    }
+
+    fn name(&self) -> &'static str {
+        "UnusedPureResult"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for UnusedPureResult {
+    fn check_stmt(&mut self, cx: &LateContext<'a, 'tcx>, stmt: &'tcx Stmt) {
+        if_chain! {
+            if let StmtKind::Semi(ref expr) = stmt.node;
+            if !in_macro(expr.span) && !in_external_macro(cx.sess(), expr.span);
+            if let ExprKind::MethodCall(ref path, _, _) = expr.node;
+            if let Some(def) = cx.tables.type_dependent_defs().get(expr.hir_id);
+            let def_id = def.def_id();
+            if self.paths.contains(&get_def_path(cx.tcx, def_id).join("::"));
+            // the compiler already warns about these
+            if !is_must_use(cx, def_id);
+            let ty = cx.tables.expr_ty(expr);
+            if !ty.is_unit() && !ty.is_never();
+            if !is_must_use_ty(cx, ty);
+            then {
+                span_help_and_lint(
+                    cx,
+                    UNUSED_PURE_RESULT,
+                    stmt.span,
+                    &format!("the result of `{}` is unused and the call has no effect", path.ident),
+                    "this method doesn't modify its receiver, consider using the returned value",
+                );
+            }
+        }
+    }
+}
+
+fn is_must_use(cx: &LateContext<'_, '_>, def_id: DefId) -> bool {
+    cx.tcx.get_attrs(def_id).iter().any(|attr| attr.name() == "must_use")
+}
+
+fn is_must_use_ty(cx: &LateContext<'_, '_>, ty: ty::Ty<'_>) -> bool {
+    match ty.sty {
+        ty::Adt(adt, _) => is_must_use(cx, adt.did),
+        ty::Tuple(tys) => tys.iter().any(|&ty| is_must_use_ty(cx, ty)),
+        _ => false,
+    }
+}
@@ -3,6 +3,8 @@
 #![deny(clippy::missing_docs_in_private_items)]
 
 use lazy_static::lazy_static;
+use regex::Regex;
+use serde_derive::Deserialize;
 use std::default::Default;
 use std::io::Read;
 use std::sync::Mutex;
@@ -11,10 +13,14 @@ use syntax::{ast, source_map};
 use toml;
 
 /// Get the configuration file from arguments.
+///
+/// # Errors
+///
+/// Fails if the `conf_file` argument isn't a string.
 pub fn file_from_args(
     args: &[source_map::Spanned<ast::NestedMetaItemKind>],
 ) -> Result<Option<path::PathBuf>, (&'static str, source_map::Span)> {
-    for arg in args.iter().filter_map(|a| a.meta_item()) {
+    for arg in args.iter().filter_map(ast::NestedMetaItem::meta_item) {
         if arg.name() == "conf_file" {
             return match arg.node {
                 ast::MetaItemKind::Word | ast::MetaItemKind::List(_) => {
@@ -62,6 +68,115 @@ lazy_static! {
     static ref ERRORS: Mutex<Vec<Error>> = Mutex::new(Vec::new());
 }
 
+/// A rule of the `NAMING_RULES` lint.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct NamingRule {
+    /// The kinds of names the rule applies to, all of them if empty.
+    #[serde(default)]
+    pub kinds: Vec<String>,
+    /// A regex names must not match.
+    #[serde(default, deserialize_with = "deserialize_regex")]
+    pub banned: Option<Regex>,
+    /// A regex names must match.
+    #[serde(default, deserialize_with = "deserialize_regex")]
+    pub required: Option<Regex>,
+    /// The case names must use.
+    #[serde(default)]
+    pub case: Option<NamingCase>,
+    /// Why the rule exists, shown in the lint message.
+    pub reason: String,
+}
+
+/// The case a `NamingRule` requires.
+#[derive(Copy, Clone, Debug, Deserialize)]
+pub enum NamingCase {
+    /// `snake_case`, without uppercase letters.
+    #[serde(rename = "snake_case")]
+    SnakeCase,
+    /// `UPPER_SNAKE_CASE`, without lowercase letters.
+    #[serde(rename = "UPPER_SNAKE_CASE")]
+    UpperSnakeCase,
+    /// `CamelCase`, starting with an uppercase letter and without underscores.
+    #[serde(rename = "CamelCase")]
+    CamelCase,
+}
+
+/// Compiles the pattern of a `NamingRule`, invalid ones are configuration errors.
+fn deserialize_regex<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Option<Regex>, D::Error> {
+    let pattern: Option<String> = serde::Deserialize::deserialize(deserializer)?;
+    match pattern {
+        Some(pattern) => Regex::new(&pattern)
+            .map(Some)
+            .map_err(|e| serde::de::Error::custom(format!("`{}` is not a valid regex: {}", pattern, e))),
+        None => Ok(None),
+    }
+}
+
+/// The case of the digits of hexadecimal literals required by the
+/// `HEX_LITERAL_CASE` lint.
+#[derive(Copy, Clone, Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HexLiteralCase {
+    /// `0xFF`
+    Upper,
+    /// `0xff`
+    Lower,
+}
+
+/// A declared visibility, as configured for the
+/// `MISSING_DOCS_IN_PRIVATE_ITEMS` lint.
+#[derive(Copy, Clone, Debug, PartialEq, Deserialize)]
+pub enum DeclaredVisibility {
+    /// No visibility, or `pub(self)`.
+    #[serde(rename = "private")]
+    Private,
+    /// `pub(super)`, also used for `pub(in path)`.
+    #[serde(rename = "pub(super)")]
+    Super,
+    /// `pub(crate)`, or `crate`.
+    #[serde(rename = "pub(crate)")]
+    Crate,
+    /// `pub`
+    #[serde(rename = "pub")]
+    Public,
+}
+
+/// A function, method or type disallowed by the `DISALLOWED_METHODS` or
+/// `DISALLOWED_TYPES` lint.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum DisallowedPath {
+    /// Just the path, e.g. `"std::env::set_var"`.
+    Path(String),
+    /// The path and why the item is disallowed, e.g.
+    /// `{ path = "std::env::set_var", reason = "not thread safe" }`.
+    WithReason {
+        /// The path of the item.
+        path: String,
+        /// Why the item is disallowed, shown in the lint message.
+        #[serde(default)]
+        reason: Option<String>,
+    },
+}
+
+impl DisallowedPath {
+    /// The path of the item.
+    pub fn path(&self) -> &str {
+        match self {
+            DisallowedPath::Path(path) | DisallowedPath::WithReason { path, .. } => path,
+        }
+    }
+
+    /// Why the item is disallowed, if configured.
+    pub fn reason(&self) -> Option<&str> {
+        match self {
+            DisallowedPath::Path(_) => None,
+            DisallowedPath::WithReason { reason, .. } => reason.as_ref().map(String::as_str),
+        }
+    }
+}
+
 macro_rules! define_Conf {
     ($(#[$doc: meta] ($rust_name: ident, $rust_name_str: expr, $default: expr => $($ty: tt)+),)+) => {
         pub use self::helpers::Conf;
@@ -110,9 +225,11 @@ macro_rules! define_Conf {
 define_Conf! {
     /// Lint: BLACKLISTED_NAME. The list of blacklisted names to lint about
     (blacklisted_names, "blacklisted_names", ["foo", "bar", "baz", "quux"] => Vec<String>),
+    /// Lint: COGNITIVE_COMPLEXITY. The maximum cognitive complexity a function can have
+    (cognitive_complexity_threshold, "cognitive_complexity_threshold", 15 => u64),
     /// Lint: CYCLOMATIC_COMPLEXITY. The maximum cyclomatic complexity a function can have
     (cyclomatic_complexity_threshold, "cyclomatic_complexity_threshold", 25 => u64),
-    /// Lint: DOC_MARKDOWN. The list of words this lint should not consider as identifiers needing ticks
+    /// Lint: DOC_MARKDOWN. The list of words this lint should not consider as identifiers needing ticks, entries with characters other than letters, digits and `_` are regular expressions
     (doc_valid_idents, "doc_valid_idents", [
         "KiB", "MiB", "GiB", "TiB", "PiB", "EiB",
         "DirectX",
@@ -130,8 +247,16 @@ define_Conf! {
         "MinGW",
         "CamelCase",
     ] => Vec<String>),
+    /// Lint: DOC_MARKDOWN. More words this lint should not consider as identifiers needing ticks, added to `doc-valid-idents`
+    (doc_valid_idents_append, "doc_valid_idents_append", [""; 0] => Vec<String>),
     /// Lint: TOO_MANY_ARGUMENTS. The maximum number of argument a function or method can have
     (too_many_arguments_threshold, "too_many_arguments_threshold", 7 => u64),
+    /// Lint: TOO_MANY_ARGUMENTS. Whether not to count the `self` argument of methods
+    (too_many_arguments_exclude_self, "too_many_arguments_exclude_self", false => bool),
+    /// Lint: TOO_MANY_ARGUMENTS. The maximum number of type and const generic parameters a function or method can have, unlimited if not set
+    (too_many_generic_parameters_threshold, "too_many_generic_parameters_threshold", None => Option<u64>),
+    /// Lint: TOO_MANY_ARGUMENTS. Whether to suggest a struct grouping the arguments
+    (too_many_arguments_suggest_struct, "too_many_arguments_suggest_struct", false => bool),
     /// Lint: TYPE_COMPLEXITY. The maximum complexity a type can have
     (type_complexity_threshold, "type_complexity_threshold", 250 => u64),
     /// Lint: MANY_SINGLE_CHAR_NAMES. The maximum number of single char bindings a scope may have
@@ -140,16 +265,93 @@ define_Conf! {
     (too_large_for_stack, "too_large_for_stack", 200 => u64),
     /// Lint: ENUM_VARIANT_NAMES. The minimum number of enum variants for the lints about variant names to trigger
     (enum_variant_name_threshold, "enum_variant_name_threshold", 3 => u64),
+    /// Lint: ENUM_VARIANT_NAMES. The list of prefixes all variants of an enum may share
+    (enum_variant_allowed_prefixes, "enum_variant_allowed_prefixes", [""; 0] => Vec<String>),
+    /// Lint: ENUM_VARIANT_NAMES. Whether to lint variant names starting with the enum's name
+    (check_variant_enum_name_prefix, "check_variant_enum_name_prefix", true => bool),
     /// Lint: LARGE_ENUM_VARIANT. The maximum size of a enum's variant to avoid box suggestion
     (enum_variant_size_threshold, "enum_variant_size_threshold", 200 => u64),
+    /// Lint: LARGE_FUTURES. The maximum size of a future in bytes, larger ones should be boxed
+    (future_size_threshold, "future_size_threshold", 16384 => u64),
     /// Lint: VERBOSE_BIT_MASK. The maximum allowed size of a bit mask before suggesting to use 'trailing_zeros'
     (verbose_bit_mask_threshold, "verbose_bit_mask_threshold", 1 => u64),
     /// Lint: DECIMAL_LITERAL_REPRESENTATION. The lower bound for linting decimal literals
     (literal_representation_threshold, "literal_representation_threshold", 16384 => u64),
+    /// Lint: UNREADABLE_LITERAL. The maximum number of digits a literal can have without separators
+    (unreadable_literal_threshold, "unreadable_literal_threshold", 5 => u64),
+    /// Lint: UNREADABLE_LITERAL. The digit group size to suggest for binary literals
+    (binary_literal_group_size, "binary_literal_group_size", 4 => u64),
+    /// Lint: UNREADABLE_LITERAL. The digit group size to suggest for octal literals
+    (octal_literal_group_size, "octal_literal_group_size", 3 => u64),
+    /// Lint: UNREADABLE_LITERAL. The digit group size to suggest for decimal and floating-point literals
+    (decimal_literal_group_size, "decimal_literal_group_size", 3 => u64),
+    /// Lint: UNREADABLE_LITERAL. The digit group size to suggest for hexadecimal literals
+    (hex_literal_group_size, "hex_literal_group_size", 4 => u64),
+    /// Lint: HEX_LITERAL_CASE. The case hexadecimal literal digits must use, `"upper"` or `"lower"`
+    (hex_literal_case, "hex_literal_case", None => Option<crate::utils::conf::HexLiteralCase>),
     /// Lint: TRIVIALLY_COPY_PASS_BY_REF. The maximum size (in bytes) to consider a `Copy` type for passing by value instead of by reference.
     (trivial_copy_size_limit, "trivial_copy_size_limit", None => Option<u64>),
     /// Lint: TOO_MANY_LINES. The maximum number of lines a function or method can have
     (too_many_lines_threshold, "too_many_lines_threshold", 100 => u64),
+    /// Lint: TOO_MANY_LINES_IN_MODULE. The maximum number of lines a file or inline module can have
+    (too_many_lines_in_module_threshold, "too_many_lines_in_module_threshold", 1000 => u64),
+    /// Lint: TOO_MANY_LINES_IN_MODULE. Whether to count comment and blank lines too
+    (count_all_module_lines, "count_all_module_lines", false => bool),
+    /// Lint: EXCESSIVE_NESTING. The maximum depth control flow in a function can be nested to
+    (nesting_depth_threshold, "nesting_depth_threshold", 5 => u64),
+    /// Lint: DUPLICATED_METHOD_BOUNDS. The minimum number of methods an `impl` block or trait must have before a bound shared by all of them is linted
+    (duplicated_method_bounds_threshold, "duplicated_method_bounds_threshold", 3 => u64),
+    /// Lint: MIXED_IMPL_TRAIT_ARGS. Whether to suggest `impl Trait` arguments instead of named type parameters
+    (prefer_impl_trait_in_args, "prefer_impl_trait_in_args", false => bool),
+    /// Lint: REDUNDANT_TURBOFISH. Whether to suggest shortening turbofishes to placeholders like `::<Vec<_>>` instead of removing them
+    (shorten_turbofish, "shorten_turbofish", false => bool),
+    /// Lint: MONOMORPHIZATION_BLOAT. The maximum number of lines a generic function only converting its arguments can have
+    (monomorphization_bloat_threshold, "monomorphization_bloat_threshold", 20 => u64),
+    /// Lint: UNUSED_PURE_RESULT. More methods without side effects whose result has to be used, by path like `"core::str::<impl str>::trim"` or `"regex::Regex::is_match"`
+    (pure_methods, "pure_methods", [""; 0] => Vec<String>),
+    /// Lint: NAMING_RULES. The naming rules to check, see the lint documentation
+    (naming_rules, "naming_rules", vec![] => Vec<crate::utils::conf::NamingRule>),
+    /// Lint: OPTION_UNWRAP_USED, RESULT_UNWRAP_USED, OPTION_EXPECT_USED, RESULT_EXPECT_USED, LOCK_UNWRAP, UNIMPLEMENTED, INDEXING_SLICING, UNWRAP_IN_RESULT, PANIC_IN_RESULT_FN. The scopes these lints are allowed in, any of `"tests"`, `"examples"`, `"benches"` and `"build.rs"`
+    (allow_unwrap_in, "allow_unwrap_in", [""; 0] => Vec<String>),
+    /// Lint: LOCK_UNWRAP. Whether `expect` on the result of locking is allowed, since its message documents why panicking on poisoning is fine
+    (lock_unwrap_allow_expect, "lock_unwrap_allow_expect", false => bool),
+    /// Lint: GET_UNWRAP. Whether `get` is preferred over indexing, which turns the lint off. It's also off where `indexing_slicing` is enabled
+    (prefer_get_over_indexing, "prefer_get_over_indexing", false => bool),
+    /// Lint: ALL. The lint groups to warn about, e.g. `pedantic`. Flags on the command line take precedence
+    (warn_groups, "warn_groups", [""; 0] => Vec<String>),
+    /// Lint: ALL. The lint groups to deny, e.g. `correctness`. Flags on the command line take precedence
+    (deny_groups, "deny_groups", [""; 0] => Vec<String>),
+    /// Lint: DECLARE_INTERIOR_MUTABLE_CONST, BORROW_INTERIOR_MUTABLE_CONST. The paths of types whose interior mutability is ignored, e.g. `bytes::Bytes`
+    (ignore_interior_mutability, "ignore_interior_mutability", [""; 0] => Vec<String>),
+    /// Lint: BOXED_TRAIT_OBJECT_ARG, BOXED_TRAIT_OBJECT_RETURN, CONST_STATIC_LIFETIME, DERIVABLE_IMPLS, DURATION_SUBSEC, EXHAUSTIVE_ENUMS, EXHAUSTIVE_STRUCTS, MANUAL_FILTER, MAP_FOR_SIDE_EFFECTS, MATCH_LIKE_MATCHES_MACRO, MISSING_CONST_FOR_FN, MIXED_IMPL_TRAIT_ARGS, PTR_OFFSET_WITH_CAST, QUESTION_MARK, RANGE_PLUS_ONE, REDUNDANT_FIELD_NAMES, TRANSMUTE_INT_TO_FLOAT, UNCHECKED_TIME_SUBTRACTION, UNUSED_COLLECT. The minimum Rust version the code supports, e.g. `"1.30.0"`, features stabilized later aren't suggested. Defaults to the version of the compiler clippy runs in. `#[clippy::msrv = "1.30.0"]` overrides it for a crate, module or item
+    (msrv, "msrv", None => Option<crate::utils::RustcVersion>),
+    /// Lint: DETACHED_THREAD. The names or paths of the functions which are allowed to detach threads, e.g. `spawn_logger`
+    (daemon_thread_fns, "daemon_thread_fns", [""; 0] => Vec<String>),
+    /// Lint: DEFAULTED_REQUIRED_FIELDS. The struct fields which must not be left to `Default::default()`, by name or with the struct, e.g. `id` or `Connection::handle`
+    (required_fields, "required_fields", [""; 0] => Vec<String>),
+    /// Lint: MISSING_DOCS_IN_PRIVATE_ITEMS. Whether unit structs don't need to be documented
+    (missing_docs_allow_unit_structs, "missing_docs_allow_unit_structs", false => bool),
+    /// Lint: MISSING_DOCS_IN_PRIVATE_ITEMS. The declared visibilities of the items which don't need to be documented, any of `"private"`, `"pub(super)"` (also for `pub(in path)`), `"pub(crate)"` and `"pub"`
+    (missing_docs_allowed_visibilities, "missing_docs_allowed_visibilities", vec![] => Vec<crate::utils::conf::DeclaredVisibility>),
+    /// Lint: MISSING_DOCS_IN_PRIVATE_ITEMS. Whether `#[test]` functions and items with `#[cfg(test)]` don't need to be documented
+    (missing_docs_allow_tests, "missing_docs_allow_tests", false => bool),
+    /// Lint: DEEP_GLOB_IMPORTS. The maximum number of path segments of glob imported modules, not counting `crate`, `self` and `super`
+    (glob_import_max_depth, "glob_import_max_depth", 3 => u64),
+    /// Lint: UNDOCUMENTED_UNSAFE_BLOCKS. The text the comments above `unsafe` blocks have to start with
+    (unsafe_comment_prefix, "unsafe_comment_prefix", "SAFETY:".to_owned() => String),
+    /// Lint: DISALLOWED_METHODS. The functions and methods which must not be called, by path like `"std::env::set_var"` or with a reason like `{ path = "regex::Regex::new", reason = "use the cached regexes" }`
+    (disallowed_methods, "disallowed_methods", vec![] => Vec<crate::utils::conf::DisallowedPath>),
+    /// Lint: DISALLOWED_TYPES. The types and traits which must not be used, by path like `"std::collections::HashMap"` or with a reason like `{ path = "std::collections::HashMap", reason = "the iteration order isn't deterministic" }`
+    (disallowed_types, "disallowed_types", vec![] => Vec<crate::utils::conf::DisallowedPath>),
+    /// Lint: UNBOUNDED_READS. The stream types whose data may come from untrusted peers, by path like `"std::net::TcpStream"`
+    (untrusted_stream_types, "untrusted_stream_types", [
+        "std::net::TcpStream",
+        "std::os::unix::net::UnixStream",
+    ] => Vec<String>),
+    /// Lint: BLOCKING_IN_ASYNC. More blocking functions and methods, by path like `"std::sync::mpsc::Receiver::recv"`, or by module like `"diesel::*"`
+    (blocking_fns, "blocking_fns", [""; 0] => Vec<String>),
+    /// Lint: SERIALIZED_INT128. More serialization traits whose derives are checked, by path like `"bincode::Encode"`
+    (serialization_derives, "serialization_derives", [""; 0] => Vec<String>),
 }
 
 impl Default for Conf {
@@ -159,6 +361,10 @@ impl Default for Conf {
 }
 
 /// Search for the configuration file.
+///
+/// # Errors
+///
+/// Fails if a directory can't be searched.
 pub fn lookup_conf_file() -> io::Result<Option<path::PathBuf>> {
     /// Possible filename to search for.
     const CONFIG_FILE_NAMES: [&str; 2] = [".clippy.toml", "clippy.toml"];
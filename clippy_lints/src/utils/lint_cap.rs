@@ -0,0 +1,98 @@
+//! Caps the level of Clippy's lints in crates outside the workspace and in
+//! generated code, like rustc's `--cap-lints` but without affecting rustc's
+//! own lints.
+
+use rustc::lint::{Level, LintContext};
+use rustc_errors::Level as DiagnosticLevel;
+use std::env;
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use syntax::errors::DiagnosticBuilder;
+use syntax::source_map::{FileName, Span};
+
+/// The caps, `0` if there is none and the `Level` plus one otherwise.
+static CRATE_CAP: AtomicUsize = AtomicUsize::new(0);
+static GENERATED_CAP: AtomicUsize = AtomicUsize::new(0);
+
+/// Sets the caps from the driver's `--clippy-cap-deps-lints` and
+/// `--clippy-cap-generated-lints` flags, which both default to `allow`.
+///
+/// The cap for dependencies applies if `cargo clippy` passed the directories
+/// of the workspace members in `CLIPPY_WORKSPACE_MEMBERS` and the crate isn't
+/// one of them. The cap for generated code applies to files in `OUT_DIR`.
+///
+/// # Errors
+///
+/// Fails if a flag isn't a lint level.
+pub fn set(deps_cap: Option<&str>, generated_cap: Option<&str>) -> Result<(), String> {
+    let parse = |level: Option<&str>| match level {
+        Some(level) => Level::from_str(level).ok_or_else(|| {
+            format!(
+                "unknown lint level `{}`, expected `allow`, `warn`, `deny` or `forbid`",
+                level
+            )
+        }),
+        None => Ok(Level::Allow),
+    };
+    let deps_cap = parse(deps_cap)?;
+    let generated_cap = parse(generated_cap)?;
+
+    if is_dependency() {
+        CRATE_CAP.store(deps_cap as usize + 1, Ordering::Relaxed);
+    }
+    GENERATED_CAP.store(generated_cap as usize + 1, Ordering::Relaxed);
+    Ok(())
+}
+
+fn load(cap: &AtomicUsize) -> Option<Level> {
+    match cap.load(Ordering::Relaxed) {
+        1 => Some(Level::Allow),
+        2 => Some(Level::Warn),
+        3 => Some(Level::Deny),
+        4 => Some(Level::Forbid),
+        _ => None,
+    }
+}
+
+/// Lowers the level of `db`, which lints the code at `sp`, to the cap.
+pub fn apply<'a, T: LintContext<'a>>(cx: &T, db: &mut DiagnosticBuilder<'_>, sp: Span) {
+    let mut cap = load(&CRATE_CAP);
+    if let Some(generated_cap) = load(&GENERATED_CAP) {
+        if cap.map_or(true, |cap| generated_cap < cap) && is_generated(cx, sp) {
+            cap = Some(generated_cap);
+        }
+    }
+    match cap {
+        Some(Level::Allow) => db.cancel(),
+        Some(Level::Warn) if db.level == DiagnosticLevel::Error => db.level = DiagnosticLevel::Warning,
+        _ => {},
+    }
+}
+
+/// Checks whether the crate is outside the workspace `cargo clippy` runs on.
+fn is_dependency() -> bool {
+    match (
+        env::var_os("CLIPPY_WORKSPACE_MEMBERS"),
+        env::var_os("CARGO_MANIFEST_DIR"),
+    ) {
+        (Some(members), Some(dir)) => !env::split_paths(&members).any(|member| member == Path::new(&dir)),
+        _ => false,
+    }
+}
+
+/// Checks whether `sp` is in a file in `OUT_DIR`, e.g. one included with
+/// `include!(concat!(env!("OUT_DIR"), "/generated.rs"))`.
+fn is_generated<'a, T: LintContext<'a>>(cx: &T, sp: Span) -> bool {
+    let out_dir = match env::var_os("OUT_DIR") {
+        Some(dir) => dir,
+        None => return false,
+    };
+    match cx.sess().source_map().span_to_filename(sp.source_callsite()) {
+        // rustc is given paths relative to the directory it runs in
+        FileName::Real(path) => env::current_dir()
+            .map(|dir| dir.join(&path))
+            .unwrap_or(path)
+            .starts_with(out_dir),
+        _ => false,
+    }
+}
@@ -4,8 +4,9 @@ use matches::matches;
 use rustc::hir;
 use rustc::hir::def::Def;
 use rustc::hir::def_id::{DefId, CRATE_DEF_INDEX, LOCAL_CRATE};
-use rustc::hir::intravisit::{NestedVisitorMap, Visitor};
+use rustc::hir::intravisit::{walk_expr, NestedVisitorMap, Visitor};
 use rustc::hir::Node;
+#[allow(clippy::shadowed_glob_imports)] // `ptr` is shadowed by `utils::ptr`
 use rustc::hir::*;
 use rustc::lint::{LateContext, Level, Lint, LintContext};
 use rustc::session::Session;
@@ -16,18 +17,21 @@ use rustc::ty::{
     subst::Kind,
     Binder, Ty, TyCtxt,
 };
+use rustc_data_structures::fx::FxHashSet;
 use rustc_data_structures::sync::Lrc;
 use rustc_errors::{Applicability, CodeSuggestion, Substitution, SubstitutionPart};
 use std::borrow::Cow;
 use std::env;
 use std::mem;
+use std::path::Path;
 use std::str::FromStr;
 use syntax::ast::{self, LitKind};
 use syntax::attr;
 use syntax::errors::DiagnosticBuilder;
-use syntax::source_map::{Span, DUMMY_SP};
+use syntax::source_map::{ExpnFormat, FileName, Span, DUMMY_SP};
 use syntax::symbol;
 use syntax::symbol::{keywords, Symbol};
+use syntax_pos::hygiene::SyntaxContext;
 
 pub mod camel_case;
 
@@ -38,11 +42,16 @@ pub mod constants;
 mod hir_utils;
 pub mod inspector;
 pub mod internal_lints;
+pub mod lint_cap;
+pub mod msrvs;
+pub mod pass_wrapper;
 pub mod paths;
 pub mod ptr;
 pub mod sugg;
+pub mod timing;
 pub mod usage;
 pub use self::hir_utils::{SpanlessEq, SpanlessHash};
+pub use self::msrvs::{meets_msrv, RustcVersion};
 
 pub mod higher;
 
@@ -95,6 +104,15 @@ pub fn in_macro(span: Span) -> bool {
     span.ctxt().outer().expn_info().is_some()
 }
 
+/// Returns true if this `expn_info` was expanded by a macro, but not by a
+/// desugaring like `for` loops, `?` or `async`.
+pub fn in_macro_not_desugaring(span: Span) -> bool {
+    span.ctxt().outer().expn_info().map_or(false, |info| match info.format {
+        ExpnFormat::CompilerDesugaring(_) => false,
+        _ => true,
+    })
+}
+
 /// Used to store the absolute path to a type.
 ///
 /// See `match_def_path` for usage.
@@ -142,7 +160,7 @@ pub fn match_def_path(tcx: TyCtxt<'_, '_, '_>, def_id: DefId, path: &[&str]) ->
 pub fn get_def_path(tcx: TyCtxt<'_, '_, '_>, def_id: DefId) -> Vec<&'static str> {
     let mut apb = AbsolutePathBuffer { names: vec![] };
     tcx.push_item_path(&mut apb, def_id, false);
-    apb.names.iter().map(|n| n.get()).collect()
+    apb.names.iter().map(symbol::LocalInternedString::get).collect()
 }
 
 /// Check if type is struct, enum or union type with given def path.
@@ -265,6 +283,61 @@ pub fn path_to_def(cx: &LateContext<'_, '_>, path: &[&str]) -> Option<def::Def>
     }
 }
 
+/// Resolves the type paths from the configuration of a lint, like
+/// `bytes::Bytes`, to the `DefId`s of the types. Paths can go through
+/// re-exports, but have to start with the name of a dependency. Paths which
+/// don't name a type are skipped.
+pub fn resolve_type_paths(cx: &LateContext<'_, '_>, paths: &[String]) -> FxHashSet<DefId> {
+    paths
+        .iter()
+        .filter_map(|path| match path_to_def(cx, &path.split("::").collect::<Vec<_>>()) {
+            Some(Def::Struct(def_id)) | Some(Def::Enum(def_id)) | Some(Def::Union(def_id)) => Some(def_id),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Resolves the path of a function, or of a method of a type or trait, to its
+/// `DefId`. Paths have to start with the name of a dependency.
+pub fn resolve_fn_path(cx: &LateContext<'_, '_>, path: &[&str]) -> Option<DefId> {
+    if let Some(Def::Fn(def_id)) = path_to_def(cx, path) {
+        return Some(def_id);
+    }
+    let (name, parent) = path.split_last()?;
+    if parent.is_empty() {
+        return None;
+    }
+    let items = match path_to_def(cx, parent)? {
+        Def::Struct(def_id) | Def::Enum(def_id) | Def::Union(def_id) => cx
+            .tcx
+            .inherent_impls(def_id)
+            .iter()
+            .flat_map(|&impl_id| cx.tcx.associated_items(impl_id))
+            .collect::<Vec<_>>(),
+        Def::Trait(def_id) => cx.tcx.associated_items(def_id).collect(),
+        _ => return None,
+    };
+    items
+        .into_iter()
+        .find(|item| item.kind == ty::AssociatedKind::Method && item.ident.name == *name)
+        .map(|item| item.def_id)
+}
+
+/// Checks whether `path` is the path of an item of the crate being linted,
+/// like `my_crate::helpers::reset`, which `path_to_def` can't resolve.
+pub fn is_local_def_path(cx: &LateContext<'_, '_>, path: &str) -> bool {
+    if path.split("::").next() != Some(&*cx.tcx.crate_name(LOCAL_CRATE).as_str()) {
+        return false;
+    }
+    let krate = cx.tcx.hir().krate();
+    krate
+        .items
+        .keys()
+        .chain(krate.trait_items.keys().map(|id| &id.node_id))
+        .chain(krate.impl_items.keys().map(|id| &id.node_id))
+        .any(|&id| get_def_path(cx.tcx, cx.tcx.hir().local_def_id(id)).join("::") == path)
+}
+
 /// Convenience function to get the `DefId` of a trait by path.
 pub fn get_trait_def_id(cx: &LateContext<'_, '_>, path: &[&str]) -> Option<DefId> {
     let def = match path_to_def(cx, path) {
@@ -418,6 +491,40 @@ pub fn contains_name(name: Name, expr: &Expr) -> bool {
     cn.result
 }
 
+/// Looks for `return` and `?` outside of closures.
+struct ReturnVisitor {
+    found: bool,
+}
+
+impl<'tcx> Visitor<'tcx> for ReturnVisitor {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        match expr.node {
+            ExprKind::Ret(_) | ExprKind::Match(_, _, MatchSource::TryDesugar) => self.found = true,
+            ExprKind::Closure(..) => {},
+            _ => walk_expr(self, expr),
+        }
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
+
+/// Checks whether `expr` may return from the enclosing function, i.e. whether
+/// it contains a `return` or `?` outside of closures.
+pub fn may_return(expr: &Expr) -> bool {
+    let mut visitor = ReturnVisitor { found: false };
+    visitor.visit_expr(expr);
+    visitor.found
+}
+
+/// Like `may_return`, for a block.
+pub fn may_return_from_block(block: &Block) -> bool {
+    let mut visitor = ReturnVisitor { found: false };
+    visitor.visit_block(block);
+    visitor.found
+}
+
 /// Convert a span to a code snippet if available, otherwise use default.
 ///
 /// This is useful if you want to provide suggestions for your lint or more generally, if you want
@@ -457,8 +564,51 @@ pub fn snippet_with_applicability<'a, 'b, T: LintContext<'b>>(
     )
 }
 
+/// Same as `snippet_with_applicability`, but takes the snippet of the code the expansion
+/// of `span` was written as in the syntax context `outer`, which should be the one of the
+/// expression the suggestion replaces. E.g. for `vec![1].len()` and the span of `vec![1]`
+/// in the expansion, this is the snippet `vec![1]` instead of the code in the definition
+/// of `vec!`.
+///
+/// If `span` isn't inside `outer`, because it comes from the definition of a macro called
+/// in `outer`, the applicability level is changed to `MaybeIncorrect`, the suggestion would
+/// edit the macro. The returned `bool` is whether `span` is a macro call in `outer`, which
+/// may need parentheses or a block around it in the suggestion.
+///
+/// # Example
+/// ```rust,ignore
+/// let (recv, is_macro_call) = snippet_with_context(cx, recv.span, expr.span.ctxt(), "..", &mut applicability);
+/// ```
+pub fn snippet_with_context<'a, 'b, T: LintContext<'b>>(
+    cx: &T,
+    span: Span,
+    outer: SyntaxContext,
+    default: &'a str,
+    applicability: &mut Applicability,
+) -> (Cow<'a, str>, bool) {
+    let (span, is_macro_call) = match walk_span_to_context(span, outer) {
+        Some(outer_span) => (outer_span, span.ctxt() != outer),
+        None => {
+            if *applicability != Applicability::Unspecified {
+                *applicability = Applicability::MaybeIncorrect;
+            }
+            (span, false)
+        },
+    };
+    (snippet_with_applicability(cx, span, default, applicability), is_macro_call)
+}
+
+/// Walks the call sites of the expansions `span` is in up to the syntax context `outer`.
+/// Returns `None` if `span` isn't inside `outer`.
+pub fn walk_span_to_context(mut span: Span, outer: SyntaxContext) -> Option<Span> {
+    while span.ctxt() != outer {
+        span = span.ctxt().outer().expn_info()?.call_site;
+    }
+    Some(span)
+}
+
 /// Same as `snippet`, but should only be used when it's clear that the input span is
-/// not a macro argument.
+/// not a macro argument. Prefer `snippet_with_context` for suggestions.
 pub fn snippet_with_macro_callsite<'a, 'b, T: LintContext<'b>>(cx: &T, span: Span, default: &'a str) -> Cow<'a, str> {
     snippet(cx, span.source_callsite(), default)
 }
@@ -608,16 +758,22 @@ pub fn get_enclosing_block<'a, 'tcx: 'a>(cx: &LateContext<'a, 'tcx>, node: NodeI
     }
 }
 
-pub struct DiagnosticWrapper<'a>(pub DiagnosticBuilder<'a>);
+pub struct DiagnosticWrapper<'a>(pub DiagnosticBuilder<'a>, &'static Lint);
 
 impl<'a> Drop for DiagnosticWrapper<'a> {
     fn drop(&mut self) {
-        self.0.emit();
+        // the lint panicked while building the diagnostic
+        if std::thread::panicking() {
+            pass_wrapper::report_in_diagnostic(&mut self.0, self.1);
+        } else {
+            self.0.emit();
+        }
     }
 }
 
 impl<'a> DiagnosticWrapper<'a> {
-    fn docs_link(&mut self, lint: &'static Lint) {
+    fn docs_link(&mut self) {
+        let lint = self.1;
         if env::var("CLIPPY_DISABLE_DOCS_LINKS").is_err() {
             self.0.help(&format!(
                 "for further information visit https://rust-lang.github.io/rust-clippy/{}/index.html#{}",
@@ -632,7 +788,9 @@ impl<'a> DiagnosticWrapper<'a> {
 }
 
 pub fn span_lint<'a, T: LintContext<'a>>(cx: &T, lint: &'static Lint, sp: Span, msg: &str) {
-    DiagnosticWrapper(cx.struct_span_lint(lint, sp, msg)).docs_link(lint);
+    let mut db = DiagnosticWrapper(cx.struct_span_lint(lint, sp, msg), lint);
+    lint_cap::apply(cx, &mut db.0, sp);
+    db.docs_link();
 }
 
 pub fn span_help_and_lint<'a, 'tcx: 'a, T: LintContext<'tcx>>(
@@ -642,9 +800,10 @@ pub fn span_help_and_lint<'a, 'tcx: 'a, T: LintContext<'tcx>>(
     msg: &str,
     help: &str,
 ) {
-    let mut db = DiagnosticWrapper(cx.struct_span_lint(lint, span, msg));
+    let mut db = DiagnosticWrapper(cx.struct_span_lint(lint, span, msg), lint);
+    lint_cap::apply(cx, &mut db.0, span);
     db.0.help(help);
-    db.docs_link(lint);
+    db.docs_link();
 }
 
 pub fn span_note_and_lint<'a, 'tcx: 'a, T: LintContext<'tcx>>(
@@ -655,13 +814,14 @@ pub fn span_note_and_lint<'a, 'tcx: 'a, T: LintContext<'tcx>>(
     note_span: Span,
     note: &str,
 ) {
-    let mut db = DiagnosticWrapper(cx.struct_span_lint(lint, span, msg));
+    let mut db = DiagnosticWrapper(cx.struct_span_lint(lint, span, msg), lint);
+    lint_cap::apply(cx, &mut db.0, span);
     if note_span == span {
         db.0.note(note);
     } else {
         db.0.span_note(note_span, note);
     }
-    db.docs_link(lint);
+    db.docs_link();
 }
 
 pub fn span_lint_and_then<'a, 'tcx: 'a, T: LintContext<'tcx>, F>(
@@ -673,13 +833,16 @@ pub fn span_lint_and_then<'a, 'tcx: 'a, T: LintContext<'tcx>, F>(
 ) where
     F: for<'b> FnOnce(&mut DiagnosticBuilder<'b>),
 {
-    let mut db = DiagnosticWrapper(cx.struct_span_lint(lint, sp, msg));
+    let mut db = DiagnosticWrapper(cx.struct_span_lint(lint, sp, msg), lint);
+    lint_cap::apply(cx, &mut db.0, sp);
     f(&mut db.0);
-    db.docs_link(lint);
+    db.docs_link();
 }
 
 pub fn span_lint_node(cx: &LateContext<'_, '_>, lint: &'static Lint, node: NodeId, sp: Span, msg: &str) {
-    DiagnosticWrapper(cx.tcx.struct_span_lint_node(lint, node, sp, msg)).docs_link(lint);
+    let mut db = DiagnosticWrapper(cx.tcx.struct_span_lint_node(lint, node, sp, msg), lint);
+    lint_cap::apply(cx, &mut db.0, sp);
+    db.docs_link();
 }
 
 pub fn span_lint_node_and_then(
@@ -690,9 +853,40 @@ pub fn span_lint_node_and_then(
     msg: &str,
     f: impl FnOnce(&mut DiagnosticBuilder<'_>),
 ) {
-    let mut db = DiagnosticWrapper(cx.tcx.struct_span_lint_node(lint, node, sp, msg));
+    let mut db = DiagnosticWrapper(cx.tcx.struct_span_lint_node(lint, node, sp, msg), lint);
+    lint_cap::apply(cx, &mut db.0, sp);
     f(&mut db.0);
-    db.docs_link(lint);
+    db.docs_link();
+}
+
+/// Like `span_lint_node`, but only builds the message if `lint` isn't allowed
+/// at `node`. Use this if building the message is expensive and the lint is
+/// often allowed, e.g. because it is in the `pedantic` group.
+pub fn span_lint_node_lazy(
+    cx: &LateContext<'_, '_>,
+    lint: &'static Lint,
+    node: NodeId,
+    sp: Span,
+    msg: impl FnOnce() -> String,
+) {
+    if !is_allowed(cx, lint, node) {
+        span_lint_node(cx, lint, node, sp, &msg());
+    }
+}
+
+/// Like `span_lint_node_and_then`, but only builds the message and calls `f`
+/// if `lint` isn't allowed at `node`.
+pub fn span_lint_node_and_then_lazy(
+    cx: &LateContext<'_, '_>,
+    lint: &'static Lint,
+    node: NodeId,
+    sp: Span,
+    msg: impl FnOnce() -> String,
+    f: impl FnOnce(&mut DiagnosticBuilder<'_>),
+) {
+    if !is_allowed(cx, lint, node) {
+        span_lint_node_and_then(cx, lint, node, sp, &msg(), f);
+    }
 }
 
 /// Add a span lint with a suggestion on how to fix it.
@@ -798,31 +992,32 @@ pub fn is_adjusted(cx: &LateContext<'_, '_>, e: &Expr) -> bool {
     cx.tables.adjustments().get(e.hir_id).is_some()
 }
 
-pub struct LimitStack {
-    stack: Vec<u64>,
-}
-
-impl Drop for LimitStack {
-    fn drop(&mut self) {
-        assert_eq!(self.stack.len(), 1);
+/// Returns the innermost limit set with `#[clippy::name = "limit"]` on `node`
+/// or the nodes enclosing it, or `default` if there is none.
+///
+/// The attributes are looked up in the HIR instead of being tracked in
+/// `enter_lint_attrs` and `exit_lint_attrs`, so the result doesn't depend on
+/// the order the nodes are visited in.
+pub fn attr_limit(tcx: TyCtxt<'_, '_, '_>, node: NodeId, name: &'static str, default: u64) -> u64 {
+    let map = &tcx.hir();
+    let mut prev_enclosing_node = None;
+    let mut enclosing_node = node;
+    while Some(enclosing_node) != prev_enclosing_node {
+        let limit = get_attr(map.attrs(enclosing_node), name)
+            .filter_map(|attr| attr.value_str()?.as_str().parse::<u64>().ok())
+            .last();
+        if let Some(limit) = limit {
+            return limit;
+        }
+        prev_enclosing_node = Some(enclosing_node);
+        enclosing_node = map.get_parent_node(enclosing_node);
     }
+    default
 }
 
-impl LimitStack {
-    pub fn new(limit: u64) -> Self {
-        Self { stack: vec![limit] }
-    }
-    pub fn limit(&self) -> u64 {
-        *self.stack.last().expect("there should always be a value in the stack")
-    }
-    pub fn push_attrs(&mut self, sess: &Session, attrs: &[ast::Attribute], name: &'static str) {
-        let stack = &mut self.stack;
-        parse_attrs(sess, attrs, name, |val| stack.push(val));
-    }
-    pub fn pop_attrs(&mut self, sess: &Session, attrs: &[ast::Attribute], name: &'static str) {
-        let stack = &mut self.stack;
-        parse_attrs(sess, attrs, name, |val| assert_eq!(stack.pop(), Some(val)));
-    }
+/// Reports the `#[clippy::name]` attributes which don't set a limit.
+pub fn check_limit_attrs(sess: &Session, attrs: &[ast::Attribute], name: &'static str) {
+    parse_attrs(sess, attrs, name, |_| {});
 }
 
 pub fn get_attr<'a>(attrs: &'a [ast::Attribute], name: &'static str) -> impl Iterator<Item = &'a ast::Attribute> {
@@ -921,6 +1116,19 @@ pub fn is_copy<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, ty: Ty<'tcx>) -> bool {
     ty.is_copy_modulo_regions(cx.tcx.global_tcx(), cx.param_env, DUMMY_SP)
 }
 
+/// Returns the name of `ty` if it's one of the lock guards of `std::sync`.
+pub fn lock_guard_name(cx: &LateContext<'_, '_>, ty: Ty<'_>) -> Option<&'static str> {
+    if match_type(cx, ty, &paths::MUTEX_GUARD) {
+        Some("MutexGuard")
+    } else if match_type(cx, ty, &paths::RWLOCK_READ_GUARD) {
+        Some("RwLockReadGuard")
+    } else if match_type(cx, ty, &paths::RWLOCK_WRITE_GUARD) {
+        Some("RwLockWriteGuard")
+    } else {
+        None
+    }
+}
+
 /// Return whether a pattern is refutable.
 pub fn is_refutable(cx: &LateContext<'_, '_>, pat: &Pat) -> bool {
     fn is_enum_variant(cx: &LateContext<'_, '_>, qpath: &QPath, id: HirId) -> bool {
@@ -1096,12 +1304,15 @@ pub fn unsext(tcx: TyCtxt<'_, '_, '_>, u: i128, ity: ast::IntTy) -> u128 {
     ((u as u128) << amt) >> amt
 }
 
+pub fn uint_bits(tcx: TyCtxt<'_, '_, '_>, ity: ast::UintTy) -> u64 {
+    layout::Integer::from_attr(&tcx, attr::IntType::UnsignedInt(ity))
+        .size()
+        .bits()
+}
+
 /// clip unused bytes
 pub fn clip(tcx: TyCtxt<'_, '_, '_>, u: u128, ity: ast::UintTy) -> u128 {
-    let bits = layout::Integer::from_attr(&tcx, attr::IntType::UnsignedInt(ity))
-        .size()
-        .bits();
-    let amt = 128 - bits;
+    let amt = 128 - uint_bits(tcx, ity);
     (u << amt) >> amt
 }
 
@@ -1152,6 +1363,97 @@ pub fn any_parent_is_automatically_derived(tcx: TyCtxt<'_, '_, '_>, node: NodeId
     false
 }
 
+/// Checks whether `node` is part of a test, i.e. a `#[test]` function or an
+/// item with `#[cfg(test)]`.
+pub fn is_in_test(tcx: TyCtxt<'_, '_, '_>, node: NodeId) -> bool {
+    let map = &tcx.hir();
+    let mut prev_enclosing_node = None;
+    let mut enclosing_node = node;
+    while Some(enclosing_node) != prev_enclosing_node {
+        if map.attrs(enclosing_node).iter().any(is_cfg_test) || is_test_fn(tcx, enclosing_node) {
+            return true;
+        }
+        prev_enclosing_node = Some(enclosing_node);
+        enclosing_node = map.get_parent(enclosing_node);
+    }
+    false
+}
+
+/// Checks whether `attr` is `#[cfg(test)]`.
+pub fn is_cfg_test(attr: &ast::Attribute) -> bool {
+    attr.name() == "cfg"
+        && attr
+            .meta_item_list()
+            .map_or(false, |list| list.iter().any(|item| item.check_name("test")))
+}
+
+/// `#[test]` functions lose their attribute during expansion, instead a
+/// `#[rustc_test_marker]` constant with the same name is added next to them.
+fn is_test_fn(tcx: TyCtxt<'_, '_, '_>, node: NodeId) -> bool {
+    let map = &tcx.hir();
+    let name = match map.find(node) {
+        Some(Node::Item(&Item {
+            node: ItemKind::Fn(..),
+            ident,
+            ..
+        })) => ident.name,
+        _ => return false,
+    };
+    let module = match map.find(map.get_parent(node)) {
+        Some(Node::Item(&Item {
+            node: ItemKind::Mod(ref module),
+            ..
+        })) => module,
+        Some(Node::Crate) => &map.krate().module,
+        _ => return false,
+    };
+    module
+        .item_ids
+        .iter()
+        .map(|id| map.expect_item(id.id))
+        .any(|item| item.ident.name == name && attr::contains_name(&item.attrs, "rustc_test_marker"))
+}
+
+/// Checks whether `node` is in one of `scopes`, as configured for example with
+/// `allow-unwrap-in`. The scopes are `"tests"`, which are tests (see
+/// `is_in_test`) and files in the `tests` directory, `"examples"`,
+/// `"benches"` and `"build.rs"`. Directories are relative to
+/// `CARGO_MANIFEST_DIR`.
+pub fn is_in_scope(cx: &LateContext<'_, '_>, node: NodeId, span: Span, scopes: &[String]) -> bool {
+    let has_scope = |scope: &str| scopes.iter().any(|s| s == scope);
+    if scopes.is_empty() {
+        return false;
+    }
+    if has_scope("tests") && is_in_test(cx.tcx, node) {
+        return true;
+    }
+
+    let path = match cx.sess().source_map().span_to_filename(span) {
+        FileName::Real(path) => path,
+        _ => return false,
+    };
+    // rustc is given paths relative to the directory it runs in
+    let path = env::current_dir().map(|dir| dir.join(&path)).unwrap_or(path);
+    let manifest_dir = match env::var_os("CARGO_MANIFEST_DIR") {
+        Some(dir) => dir,
+        None => return false,
+    };
+    let relative = match path.strip_prefix(manifest_dir) {
+        Ok(relative) => relative,
+        Err(_) => return false,
+    };
+    if relative == Path::new("build.rs") {
+        return has_scope("build.rs");
+    }
+    relative
+        .components()
+        .next()
+        .map_or(false, |dir| match dir.as_os_str().to_str() {
+            Some(dir @ "tests") | Some(dir @ "examples") | Some(dir @ "benches") => has_scope(dir),
+            _ => false,
+        })
+}
+
 #[cfg(test)]
 mod test {
     use super::{trim_multiline, without_block_comments};
@@ -0,0 +1,187 @@
+//! The minimum supported Rust version (MSRV) of the linted code, set with
+//! `msrv` in `clippy.toml` or with `#[clippy::msrv = "1.30.0"]` on the crate,
+//! a module or an item. Lints don't suggest features stabilized after it. If
+//! no MSRV is set, it's the version of the compiler clippy runs in.
+
+use crate::utils::get_attr;
+use rustc::lint::{EarlyContext, EarlyLintPass, LintArray, LintContext, LintPass};
+use rustc::lint_array;
+use rustc::ty::TyCtxt;
+use serde::de::{self, Deserialize, Deserializer};
+use std::slice;
+use syntax::ast::{Attribute, NodeId};
+
+/// A Rust version, e.g. `1.30.0`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct RustcVersion {
+    major: u64,
+    minor: u64,
+    patch: u64,
+}
+
+impl RustcVersion {
+    pub const fn new(major: u64, minor: u64, patch: u64) -> Self {
+        Self { major, minor, patch }
+    }
+
+    /// Parses versions like `1.30.0`, or `1.30` for the first release of a
+    /// minor version.
+    pub fn parse(version: &str) -> Option<Self> {
+        let mut parts = version.split('.').map(|part| part.parse::<u64>().ok());
+        let major = parts.next()??;
+        let minor = parts.next()??;
+        let patch = parts.next().unwrap_or(Some(0))?;
+        if parts.next().is_some() {
+            return None;
+        }
+        Some(Self::new(major, minor, patch))
+    }
+}
+
+impl<'de> Deserialize<'de> for RustcVersion {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let version = String::deserialize(deserializer)?;
+        Self::parse(&version)
+            .ok_or_else(|| de::Error::custom(format!("`{}` is not a Rust version like `1.30.0`", version)))
+    }
+}
+
+/// Returns the version of the compiler clippy runs in, which clippy is always
+/// built with, or `None` if the build script couldn't find it out.
+pub fn rustc_version() -> Option<RustcVersion> {
+    RustcVersion::parse(env!("RUSTC_RELEASE"))
+}
+
+// the versions which stabilized the features suggested by lints, by version
+
+/// `const` and `static` items with references infer `'static`
+pub const STATIC_IN_CONST: RustcVersion = RustcVersion::new(1, 17, 0);
+/// `Foo { bar }` struct field shorthands
+pub const FIELD_INIT_SHORTHAND: RustcVersion = RustcVersion::new(1, 17, 0);
+/// `f32::from_bits` and `f64::from_bits`
+pub const FLOAT_FROM_BITS: RustcVersion = RustcVersion::new(1, 20, 0);
+/// `Iterator::for_each`
+pub const ITERATOR_FOR_EACH: RustcVersion = RustcVersion::new(1, 21, 0);
+/// `?` on `Option`s
+pub const QUESTION_MARK_OPTION: RustcVersion = RustcVersion::new(1, 22, 0);
+/// `..=` ranges
+pub const RANGE_INCLUSIVE: RustcVersion = RustcVersion::new(1, 26, 0);
+/// `add`, `sub`, `wrapping_add` and `wrapping_sub` on pointers
+pub const POINTER_ADD_SUB: RustcVersion = RustcVersion::new(1, 26, 0);
+/// `Duration::subsec_millis` and `Duration::subsec_micros`
+pub const DURATION_SUBSEC_MILLIS: RustcVersion = RustcVersion::new(1, 27, 0);
+/// `Option::filter`
+pub const OPTION_FILTER: RustcVersion = RustcVersion::new(1, 27, 0);
+/// `const fn`
+pub const CONST_FN: RustcVersion = RustcVersion::new(1, 31, 0);
+/// `impl Trait` in argument and return position
+pub const IMPL_TRAIT: RustcVersion = RustcVersion::new(1, 26, 0);
+/// `Instant::checked_duration_since` and `Instant::saturating_duration_since`
+pub const INSTANT_DURATION_SINCE: RustcVersion = RustcVersion::new(1, 39, 0);
+/// `#[non_exhaustive]`
+pub const NON_EXHAUSTIVE: RustcVersion = RustcVersion::new(1, 40, 0);
+/// `std::matches!`
+pub const MATCHES_MACRO: RustcVersion = RustcVersion::new(1, 42, 0);
+/// `#[default]` on enum variants for `#[derive(Default)]`
+pub const DEFAULT_ENUM_VARIANT: RustcVersion = RustcVersion::new(1, 62, 0);
+
+/// Returns the minimum supported Rust version at `node`: the innermost one
+/// set with `#[clippy::msrv]` on the node or the nodes enclosing it, or
+/// `conf` if there is none, or the version of the compiler if neither is set.
+///
+/// Like `attr_limit`, the attributes are looked up in the HIR, so the result
+/// doesn't depend on the order the nodes are visited in.
+pub fn msrv(tcx: TyCtxt<'_, '_, '_>, node: NodeId, conf: Option<RustcVersion>) -> Option<RustcVersion> {
+    let map = &tcx.hir();
+    let mut prev_enclosing_node = None;
+    let mut enclosing_node = node;
+    while Some(enclosing_node) != prev_enclosing_node {
+        if let Some(msrv) = attr_msrv(map.attrs(enclosing_node)) {
+            return Some(msrv);
+        }
+        prev_enclosing_node = Some(enclosing_node);
+        enclosing_node = map.get_parent_node(enclosing_node);
+    }
+    conf.or_else(rustc_version)
+}
+
+/// Checks whether features stabilized in the Rust version `required` can be
+/// suggested at `node`, which is the case if the MSRV isn't known.
+///
+/// This walks up the HIR, so lints should check it after everything else.
+pub fn meets_msrv(tcx: TyCtxt<'_, '_, '_>, node: NodeId, conf: Option<RustcVersion>, required: RustcVersion) -> bool {
+    msrv(tcx, node, conf).map_or(true, |msrv| msrv >= required)
+}
+
+/// The minimum supported Rust version for early lint passes, which can't look
+/// up the attributes in the HIR. The passes have to forward their
+/// `enter_lint_attrs` and `exit_lint_attrs` calls.
+pub struct MsrvStack {
+    conf: Option<RustcVersion>,
+    stack: Vec<RustcVersion>,
+}
+
+impl MsrvStack {
+    pub fn new(conf: Option<RustcVersion>) -> Self {
+        Self {
+            conf,
+            stack: Vec::new(),
+        }
+    }
+
+    /// Checks whether features stabilized in the Rust version `required` can
+    /// be suggested in the current node.
+    pub fn meets_msrv(&self, required: RustcVersion) -> bool {
+        self.stack
+            .last()
+            .cloned()
+            .or(self.conf)
+            .or_else(rustc_version)
+            .map_or(true, |msrv| msrv >= required)
+    }
+
+    pub fn enter_lint_attrs(&mut self, attrs: &[Attribute]) {
+        if let Some(msrv) = attr_msrv(attrs) {
+            self.stack.push(msrv);
+        }
+    }
+
+    pub fn exit_lint_attrs(&mut self, attrs: &[Attribute]) {
+        if attr_msrv(attrs).is_some() {
+            self.stack.pop();
+        }
+    }
+}
+
+/// Returns the version the last valid `#[clippy::msrv]` attribute in `attrs`
+/// sets.
+fn attr_msrv(attrs: &[Attribute]) -> Option<RustcVersion> {
+    get_attr(attrs, "msrv")
+        .filter_map(|attr| RustcVersion::parse(&attr.value_str()?.as_str()))
+        .last()
+}
+
+/// Reports the `#[clippy::msrv]` attributes which don't set a Rust version.
+#[derive(Copy, Clone)]
+pub struct MsrvAttrs;
+
+impl LintPass for MsrvAttrs {
+    fn get_lints(&self) -> LintArray {
+        lint_array!()
+    }
+
+    fn name(&self) -> &'static str {
+        "MsrvAttrs"
+    }
+}
+
+impl EarlyLintPass for MsrvAttrs {
+    fn check_attribute(&mut self, cx: &EarlyContext<'_>, attr: &Attribute) {
+        if get_attr(slice::from_ref(attr), "msrv").next().is_some() && attr_msrv(slice::from_ref(attr)).is_none() {
+            cx.sess().span_err(
+                attr.span,
+                "`#[clippy::msrv]` expects a Rust version, like `#[clippy::msrv = \"1.30.0\"]`",
+            );
+        }
+    }
+}
@@ -0,0 +1,215 @@
+//! Wraps the late lint passes, so that a panic in one pass is reported as a
+//! warning at the item being linted and doesn't abort Clippy. The pass isn't
+//! run for the rest of the crate, but the other passes are. Panics of rustc
+//! itself, like bugs in the queries a pass runs and fatal errors, are
+//! re-raised though, rustc's state is broken after them.
+//!
+//! If the pass panics while building the diagnostic of a lint, the diagnostic
+//! is replaced by a warning naming the lint, see `DiagnosticWrapper`.
+//!
+//! The wrapper also records the time the passes take, see `utils::timing`.
+
+use crate::utils::timing;
+use rustc::late_lint_methods;
+use rustc::lint::{LateContext, LateLintPass, LateLintPassObject, Lint, LintArray, LintContext, LintPass};
+use rustc_errors::{Diagnostic, DiagnosticBuilder, ExplicitBug, FatalErrorMarker, Level};
+use std::any::Any;
+use std::cell::Cell;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Once};
+use std::time::Instant;
+use syntax::source_map::Span;
+
+const REPORT_URL: &str = "https://github.com/rust-lang/rust-clippy/issues";
+
+thread_local! {
+    /// Whether a wrapped pass is running. The panic hook doesn't print its
+    /// panics, they are reported as warnings.
+    static IN_PASS: Cell<bool> = Cell::new(false);
+}
+
+/// Wraps `passes`.
+pub fn wrap_all(passes: Vec<LateLintPassObject>) -> Vec<LateLintPassObject> {
+    let timed = timing::is_enabled();
+    passes
+        .into_iter()
+        .map(|pass| -> LateLintPassObject {
+            let nanos = if timed {
+                Some(timing::counter(pass.name()))
+            } else {
+                None
+            };
+            box WrappedPass {
+                pass,
+                nanos,
+                panicked: false,
+                items: Vec::new(),
+            }
+        })
+        .collect()
+}
+
+/// Replaces the diagnostic `db` of `lint`, which was being built when its
+/// pass panicked, by a warning about the panic at the same span.
+pub fn report_in_diagnostic(db: &mut DiagnosticBuilder<'_>, lint: &Lint) {
+    // the lint is allowed
+    if db.cancelled() {
+        return;
+    }
+    let mut warning = Diagnostic::new(
+        Level::Warning,
+        &format!(
+            "internal error in lint `{}` (please report this at {})",
+            lint.name_lower(),
+            REPORT_URL
+        ),
+    );
+    warning.set_span(db.span.clone());
+    warning.note("the lint panicked while building this diagnostic");
+    **db = warning;
+    db.emit();
+}
+
+/// Runs `f`, and returns the message of its panic if it panics. The panic
+/// hook doesn't print the panic. Panics of rustc are re-raised.
+fn catch_panic(f: impl FnOnce()) -> Result<(), Option<String>> {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let hook = panic::take_hook();
+        panic::set_hook(box move |info| {
+            if !IN_PASS.with(Cell::get) || is_rustc_panic(info.payload()) {
+                hook(info);
+            }
+        });
+    });
+    IN_PASS.with(|in_pass| in_pass.set(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    IN_PASS.with(|in_pass| in_pass.set(false));
+    match result {
+        Ok(()) => Ok(()),
+        Err(payload) => {
+            if is_rustc_panic(&*payload) {
+                panic::resume_unwind(payload);
+            }
+            Err(panic_message(&*payload))
+        },
+    }
+}
+
+/// Checks whether the panic comes from rustc, a bug it found in its state or
+/// a fatal error, and not from the code of the pass.
+fn is_rustc_panic(payload: &(dyn Any + Send)) -> bool {
+    payload.is::<ExplicitBug>() || payload.is::<FatalErrorMarker>()
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> Option<String> {
+    payload
+        .downcast_ref::<&str>()
+        .map(ToString::to_string)
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+}
+
+struct WrappedPass {
+    pass: LateLintPassObject,
+    /// What to add the time the pass takes to, if it is timed.
+    nanos: Option<Arc<AtomicUsize>>,
+    /// Whether the pass panicked. It isn't run after that, its state may be
+    /// inconsistent.
+    panicked: bool,
+    /// The spans of the items, impl items and trait items the pass is in, the
+    /// innermost last. A panic is reported at the innermost one.
+    items: Vec<Span>,
+}
+
+impl WrappedPass {
+    /// Runs `f` on the pass, unless it panicked before.
+    fn run(&mut self, cx: &LateContext<'_, '_>, f: impl FnOnce(&mut LateLintPassObject)) {
+        if self.panicked {
+            return;
+        }
+        let start = self.nanos.as_ref().map(|_| Instant::now());
+        let pass = &mut self.pass;
+        let result = catch_panic(|| f(pass));
+        if let (Some(nanos), Some(start)) = (&self.nanos, start) {
+            let elapsed = start.elapsed();
+            let elapsed = elapsed.as_secs() as usize * 1_000_000_000 + elapsed.subsec_nanos() as usize;
+            nanos.fetch_add(elapsed, Ordering::Relaxed);
+        }
+        if let Err(message) = result {
+            self.report(cx, message);
+        }
+    }
+
+    fn report(&mut self, cx: &LateContext<'_, '_>, message: Option<String>) {
+        self.panicked = true;
+        let msg = format!(
+            "internal error in lint pass `{}` (please report this at {})",
+            self.pass.name(),
+            REPORT_URL
+        );
+        // `check_crate` and `check_crate_post` run outside of the items
+        let mut db = match self.items.last() {
+            Some(&span) => cx.sess().struct_span_warn(span, &msg),
+            None => cx.sess().struct_warn(&msg),
+        };
+        if let Some(message) = message {
+            db.note(&format!("the pass panicked with: {}", message));
+        }
+        let lints: Vec<_> = self
+            .pass
+            .get_lints()
+            .iter()
+            .map(|lint| format!("`{}`", lint.name_lower()))
+            .collect();
+        db.note(&format!("the rest of the crate isn't checked for {}", lints.join(", ")));
+        db.emit();
+    }
+}
+
+impl LintPass for WrappedPass {
+    fn get_lints(&self) -> LintArray {
+        self.pass.get_lints()
+    }
+
+    fn name(&self) -> &'static str {
+        self.pass.name()
+    }
+}
+
+/// Pushes the span of the item the `check_*item` methods enter, and pops it
+/// after the `check_*item_post` methods leave the item.
+macro_rules! track_item {
+    (enter, $self:ident, check_item, $item:ident) => { $self.items.push($item.span) };
+    (enter, $self:ident, check_impl_item, $item:ident) => { $self.items.push($item.span) };
+    (enter, $self:ident, check_trait_item, $item:ident) => { $self.items.push($item.span) };
+    (leave, $self:ident, check_item_post, $item:ident) => { $self.items.pop() };
+    (leave, $self:ident, check_impl_item_post, $item:ident) => { $self.items.pop() };
+    (leave, $self:ident, check_trait_item_post, $item:ident) => { $self.items.pop() };
+    ($when:ident, $self:ident, $name:ident, $($param:ident),*) => {};
+}
+
+macro_rules! expand_wrapped_methods {
+    ([], [$hir:tt], [$($(#[$attr:meta])* fn $name:ident($($param:ident: $arg:ty),*);)*]) => (
+        $(fn $name(&mut self, cx: &LateContext<'a, $hir>, $($param: $arg),*) {
+            track_item!(enter, self, $name, $($param),*);
+            self.run(cx, |pass| pass.$name(cx, $($param),*));
+            track_item!(leave, self, $name, $($param),*);
+        })*
+    )
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for WrappedPass {
+    late_lint_methods!(expand_wrapped_methods, [], ['tcx]);
+}
+
+#[test]
+fn test_catch_panic() {
+    assert_eq!(catch_panic(|| ()), Ok(()));
+    assert_eq!(catch_panic(|| panic!("static")), Err(Some("static".to_owned())));
+    assert_eq!(catch_panic(|| panic!("formatted {}", 1)), Err(Some("formatted 1".to_owned())));
+    assert_eq!(catch_panic(|| panic::resume_unwind(box 1)), Err(None));
+    // rustc's panics aren't caught
+    assert!(panic::catch_unwind(|| catch_panic(|| panic::resume_unwind(box ExplicitBug))).is_err());
+    assert!(!IN_PASS.with(Cell::get));
+}
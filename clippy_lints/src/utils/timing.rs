@@ -0,0 +1,50 @@
+//! Records the time each late lint pass takes, if the `CLIPPY_TIME_PASSES`
+//! environment variable names the file to append the times to. Used by
+//! `util/dev bench`.
+
+use lazy_static::lazy_static;
+use std::env;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+lazy_static! {
+    /// The names of the timed passes and the nanoseconds they took.
+    static ref TIMES: Mutex<Vec<(&'static str, Arc<AtomicUsize>)>> = Mutex::new(Vec::new());
+}
+
+pub fn is_enabled() -> bool {
+    env::var_os("CLIPPY_TIME_PASSES").is_some()
+}
+
+/// Returns the counter to add the nanoseconds the pass `name` takes to.
+pub fn counter(name: &'static str) -> Arc<AtomicUsize> {
+    let nanos = Arc::new(AtomicUsize::new(0));
+    TIMES
+        .lock()
+        .expect("no pass panicked while recording its time")
+        .push((name, Arc::clone(&nanos)));
+    nanos
+}
+
+/// Appends a `<pass name> <nanoseconds>` line for each timed pass to the file
+/// in `CLIPPY_TIME_PASSES`.
+pub fn write_times() {
+    let path = match env::var_os("CLIPPY_TIME_PASSES") {
+        Some(path) => path,
+        None => return,
+    };
+    let mut out = String::new();
+    for (name, nanos) in TIMES.lock().expect("no pass panicked while recording its time").iter() {
+        out.push_str(&format!("{} {}\n", name, nanos.load(Ordering::Relaxed)));
+    }
+    let written = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .and_then(|mut file| file.write_all(out.as_bytes()));
+    if let Err(error) = written {
+        eprintln!("error writing the pass times to `{:?}`: {}", path, error);
+    }
+}
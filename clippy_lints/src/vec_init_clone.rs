@@ -0,0 +1,197 @@
+//! lint on `vec![elem; len]` with elements which are expensive to clone or share an allocation
+
+use crate::consts::{constant_simple, Constant};
+use crate::utils::{higher, in_macro, match_type, opt_def_id, paths, snippet_with_applicability, span_lint_and_then};
+use if_chain::if_chain;
+use rustc::hir::*;
+use rustc::lint::{LateContext, LateLintPass, Lint, LintArray, LintPass};
+use rustc::ty::Ty;
+use rustc::{declare_tool_lint, lint_array};
+use rustc_errors::Applicability;
+use syntax::source_map::Span;
+
+/// **What it does:** Checks for `vec![elem; len]` where `elem` is a call of
+/// an inherent constructor of `Rc` or `Arc`, like `vec![Rc::new(RefCell::new(0)); len]`.
+///
+/// **Why is this bad?** `elem` is created once and cloned into each element,
+/// so all of them point to the same value, which is rarely intended.
+///
+/// **Known problems:** Other functions creating an `Rc` aren't linted, since
+/// they may return a shared one.
+///
+/// **Example:**
+/// ```rust
+/// let counters = vec![Rc::new(Cell::new(0)); len];
+/// ```
+/// If each element should have its own counter:
+/// ```rust
+/// let counters = (0..len).map(|_| Rc::new(Cell::new(0))).collect::<Vec<_>>();
+/// ```
+declare_clippy_lint! {
+    pub RC_CLONE_IN_VEC_INIT,
+    style,
+    "`vec![elem; len]` with `elem` creating an `Rc` or an `Arc` which all elements share"
+}
+
+/// **What it does:** Checks for `vec![elem; len]` where `elem` owns heap
+/// memory, like a non-empty `String`, `Vec` or `Box`, and `len` isn't a
+/// constant of at most 16.
+///
+/// **Why is this bad?** `elem` is cloned into each element, which allocates
+/// for each of them. This isn't obvious from the `vec!`, and cloning may be
+/// slower than creating the element for each slot.
+///
+/// **Known problems:** Creating the elements evaluates `elem` for each slot,
+/// which is only the same if it has no side effects.
+///
+/// **Example:**
+/// ```rust
+/// let rows = vec![vec![0; width]; height];
+/// ```
+/// Could be written as:
+/// ```rust
+/// let rows = (0..height).map(|_| vec![0; width]).collect::<Vec<_>>();
+/// ```
+declare_clippy_lint! {
+    pub EXPENSIVE_CLONE_IN_VEC_INIT,
+    pedantic,
+    "`vec![elem; len]` with `elem` owning heap memory, which is cloned for each element"
+}
+
+/// Lengths up to this are cheap enough to clone anything for.
+const SMALL_LEN: u128 = 16;
+
+/// The types which allocate when they are cloned, unless they are empty.
+const HEAP_TYPES: [&[&str]; 9] = [
+    &paths::STRING,
+    &paths::VEC,
+    &paths::VEC_DEQUE,
+    &paths::HASHMAP,
+    &paths::HASHSET,
+    &paths::BTREEMAP,
+    &paths::BTREESET,
+    &paths::BINARY_HEAP,
+    &paths::LINKED_LIST,
+];
+
+#[derive(Copy, Clone)]
+pub struct Pass;
+
+impl LintPass for Pass {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(RC_CLONE_IN_VEC_INIT, EXPENSIVE_CLONE_IN_VEC_INIT)
+    }
+
+    fn name(&self) -> &'static str {
+        "VecInitClone"
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        let (elem, len) = match higher::vec_macro(cx, expr) {
+            Some(higher::VecArgs::Repeat(elem, len)) => (elem, len),
+            _ => return,
+        };
+        // report the error around the `vec!` not inside `<std macros>:`
+        let span = match expr.span.ctxt().outer().expn_info() {
+            Some(info) => info.call_site,
+            None => return,
+        };
+        if in_macro(span) {
+            return;
+        }
+        let ty = cx.tables.expr_ty(elem);
+        if let Some(rc) = rc_name(cx, ty) {
+            // cloning an existing `Rc` into each element is sharing it on purpose
+            if !is_rc_constructor(cx, elem) {
+                return;
+            }
+            report(
+                cx,
+                RC_CLONE_IN_VEC_INIT,
+                span,
+                elem,
+                len,
+                &format!("all elements of this `vec!` point to the same `{}`", rc),
+                &format!("the `{}` is created once, and cloned into each element", rc),
+            );
+        } else if owns_heap_memory(cx, ty) && !is_empty_constructor(elem) && !is_small(cx, len) {
+            report(
+                cx,
+                EXPENSIVE_CLONE_IN_VEC_INIT,
+                span,
+                elem,
+                len,
+                &format!("`{}` is cloned into each element of this `vec!`", ty),
+                "each clone allocates",
+            );
+        }
+    }
+}
+
+fn report(cx: &LateContext<'_, '_>, lint: &'static Lint, span: Span, elem: &Expr, len: &Expr, msg: &str, note: &str) {
+    span_lint_and_then(cx, lint, span, msg, |db| {
+        let mut applicability = Applicability::MaybeIncorrect;
+        let sugg = format!(
+            "(0..{}).map(|_| {}).collect::<Vec<_>>()",
+            snippet_with_applicability(cx, len.span, "len", &mut applicability),
+            snippet_with_applicability(cx, elem.span.source_callsite(), "elem", &mut applicability)
+        );
+        db.note(note);
+        db.span_suggestion(
+            span,
+            "if each element should be created separately",
+            sugg,
+            applicability,
+        );
+    });
+}
+
+/// Returns the name of `ty` if it's an `Rc` or an `Arc`.
+fn rc_name(cx: &LateContext<'_, '_>, ty: Ty<'_>) -> Option<&'static str> {
+    if match_type(cx, ty, &paths::RC) {
+        Some("Rc")
+    } else if match_type(cx, ty, &paths::ARC) {
+        Some("Arc")
+    } else {
+        None
+    }
+}
+
+/// Checks whether `expr` calls an inherent associated function of `Rc` or
+/// `Arc`, like `Rc::new`, which creates a new allocation.
+fn is_rc_constructor(cx: &LateContext<'_, '_>, expr: &Expr) -> bool {
+    if_chain! {
+        if let ExprKind::Call(ref callee, _) = expr.node;
+        if let ExprKind::Path(ref qpath) = callee.node;
+        if let Some(def_id) = opt_def_id(cx.tables.qpath_def(qpath, callee.hir_id));
+        if let Some(impl_id) = cx.tcx.impl_of_method(def_id);
+        then {
+            return rc_name(cx, cx.tcx.type_of(impl_id)).is_some();
+        }
+    }
+    false
+}
+
+/// Checks whether cloning a value of `ty` allocates, unless it's empty.
+fn owns_heap_memory(cx: &LateContext<'_, '_>, ty: Ty<'_>) -> bool {
+    ty.is_box() || HEAP_TYPES.iter().any(|path| match_type(cx, ty, path))
+}
+
+/// Checks whether `expr` is a call without arguments, like `Vec::new()` or
+/// `vec![]`, whose values are cheap to clone.
+fn is_empty_constructor(expr: &Expr) -> bool {
+    match expr.node {
+        ExprKind::Call(_, ref args) => args.is_empty(),
+        _ => false,
+    }
+}
+
+/// Checks whether `len` is a constant which is at most `SMALL_LEN`.
+fn is_small(cx: &LateContext<'_, '_>, len: &Expr) -> bool {
+    match constant_simple(cx, cx.tables, len) {
+        Some(Constant::Int(len)) => len <= SMALL_LEN,
+        _ => false,
+    }
+}
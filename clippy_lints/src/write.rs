@@ -1,10 +1,11 @@
-use crate::utils::{snippet_with_applicability, span_lint, span_lint_and_sugg};
+use crate::utils::{snippet_with_applicability, span_help_and_lint, span_lint, span_lint_and_sugg};
 use rustc::lint::{EarlyContext, EarlyLintPass, LintArray, LintPass};
 use rustc::{declare_tool_lint, lint_array};
 use rustc_errors::Applicability;
 use std::borrow::Cow;
 use syntax::ast::*;
 use syntax::parse::{parser, token};
+use syntax::source_map::Span;
 use syntax::tokenstream::TokenStream;
 
 /// **What it does:** This lint warns when you use `println!("")` to
@@ -159,6 +160,32 @@ declare_clippy_lint! {
     "writing a literal with a format string"
 }
 
+/// **What it does:** This lint warns about format strings of `format!`,
+/// `print!` and `write!` calls which mix implicit `{}` arguments with
+/// positional ones like `{0}`, or which use positional arguments out of order.
+///
+/// **Why is this bad?** Readers have to count the arguments to know which
+/// one goes where. Named arguments say it directly.
+///
+/// **Known problems:** Deliberate reordering, like `println!("{1} {0}", a, b)`,
+/// is linted too, e.g. in format strings shared with translations.
+///
+/// **Example:**
+/// ```rust
+/// println!("{} {0}", name);
+/// println!("{1} {0}", greeting, name);
+/// ```
+/// Could be written as:
+/// ```rust
+/// println!("{name} {name}", name = name);
+/// println!("{name} {greeting}", greeting = greeting, name = name);
+/// ```
+declare_clippy_lint! {
+    pub CONFUSING_FORMAT_ARGS,
+    pedantic,
+    "format strings mixing `{}` with `{0}`, or using positional arguments out of order"
+}
+
 #[derive(Copy, Clone, Debug)]
 pub struct Pass;
 
@@ -172,7 +199,8 @@ impl LintPass for Pass {
             PRINT_LITERAL,
             WRITE_WITH_NEWLINE,
             WRITELN_EMPTY_STRING,
-            WRITE_LITERAL
+            WRITE_LITERAL,
+            CONFUSING_FORMAT_ARGS
         )
     }
 
@@ -247,6 +275,8 @@ impl EarlyLintPass for Pass {
                     );
                 }
             }
+        } else if mac.node.path == "format" {
+            check_format_tts(cx, &mac.node.tts);
         }
     }
 }
@@ -285,6 +315,7 @@ fn check_tts<'a>(cx: &EarlyContext<'a>, tts: &TokenStream, is_write: bool) -> (O
         Ok(token) => token.0.to_string(),
         Err(_) => return (None, expr),
     };
+    let fmtspan = parser.prev_span;
     let tmp = fmtstr.clone();
     let mut args = vec![];
     let mut fmt_parser = Parser::new(&tmp, None, Vec::new(), false);
@@ -300,6 +331,7 @@ fn check_tts<'a>(cx: &EarlyContext<'a>, tts: &TokenStream, is_write: bool) -> (O
             args.push(arg);
         }
     }
+    check_positions(cx, &args, fmtspan);
     let lint = if is_write { WRITE_LITERAL } else { PRINT_LITERAL };
     let mut idx = 0;
     loop {
@@ -365,6 +397,72 @@ fn check_tts<'a>(cx: &EarlyContext<'a>, tts: &TokenStream, is_write: bool) -> (O
     }
 }
 
+/// Checks the format string of `format!` calls, whose other lints are in
+/// `format.rs`.
+fn check_format_tts(cx: &EarlyContext<'_>, tts: &TokenStream) {
+    use fmt_macros::*;
+    let mut parser = parser::Parser::new(&cx.sess.parse_sess, tts.clone(), None, false, false);
+    let fmtstr = match parser.parse_str().map_err(|mut err| err.cancel()) {
+        Ok(token) => token.0.to_string(),
+        Err(_) => return,
+    };
+    let mut args = vec![];
+    let mut fmt_parser = Parser::new(&fmtstr, None, Vec::new(), false);
+    while let Some(piece) = fmt_parser.next() {
+        if !fmt_parser.errors.is_empty() {
+            return;
+        }
+        if let Piece::NextArgument(arg) = piece {
+            args.push(arg);
+        }
+    }
+    check_positions(cx, &args, parser.prev_span);
+}
+
+/// Lints the format string at `fmtspan` if its `args` mix implicit and
+/// positional arguments, or if the positional arguments are first used out of
+/// order.
+fn check_positions(cx: &EarlyContext<'_>, args: &[fmt_macros::Argument<'_>], fmtspan: Span) {
+    use fmt_macros::Position::*;
+    let mut implicit = false;
+    let mut positions = vec![];
+    for arg in args {
+        match arg.position {
+            ArgumentImplicitlyIs(_) => implicit = true,
+            ArgumentIs(n) => positions.push(n),
+            ArgumentNamed(_) => {},
+        }
+    }
+    if implicit && !positions.is_empty() {
+        span_help_and_lint(
+            cx,
+            CONFUSING_FORMAT_ARGS,
+            fmtspan,
+            "format string mixes implicit `{}` arguments with positional ones",
+            "consider using named arguments, like `{name}`",
+        );
+        return;
+    }
+    // using an argument again is fine, as long as each one is first used after the previous one
+    let mut seen: Vec<usize> = vec![];
+    for n in positions {
+        if seen.contains(&n) {
+            continue;
+        }
+        if seen.last().map_or(false, |&last| n < last) {
+            span_help_and_lint(
+                cx,
+                CONFUSING_FORMAT_ARGS,
+                fmtspan,
+                "format string uses positional arguments out of order",
+                "consider using named arguments, like `{name}`",
+            );
+            return;
+        }
+        seen.push(n);
+    }
+}
+
 // Checks if `s` constains a single newline that terminates it
 fn check_newlines(s: &str) -> bool {
     if s.len() < 2 {
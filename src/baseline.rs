@@ -0,0 +1,256 @@
+//! `cargo clippy --save-baseline` and `--baseline`: records the current
+//! diagnostics in a file, so later runs only show new ones.
+
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+const VERSION: u64 = 1;
+
+/// Identifies a diagnostic by the lint, the file and a fingerprint of the
+/// message and the source lines of the primary span. Diagnostics are still
+/// identified after code above them moved.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+struct Key {
+    lint: String,
+    file: String,
+    fingerprint: String,
+}
+
+impl Key {
+    /// Returns the key of the diagnostic `message`, if it's a lint with a
+    /// span. Compiler errors can't be recorded.
+    fn of(message: &Value) -> Option<Self> {
+        let diagnostic = &message["message"];
+        let lint = diagnostic["code"]["code"].as_str()?;
+        if is_error_code(lint) {
+            return None;
+        }
+        let span = diagnostic["spans"]
+            .as_array()?
+            .iter()
+            .find(|span| span["is_primary"].as_bool() == Some(true))?;
+        let mut text = format!("{}\n{}", lint, diagnostic["message"].as_str()?);
+        for line in span["text"].as_array()? {
+            text.push('\n');
+            text.push_str(line["text"].as_str()?.trim());
+        }
+        Some(Self {
+            lint: lint.to_owned(),
+            file: span["file_name"].as_str()?.to_owned(),
+            fingerprint: format!("{:016x}", fnv1a(text.as_bytes())),
+        })
+    }
+}
+
+/// The number of times each diagnostic was reported when the baseline was
+/// saved.
+pub struct Baseline {
+    counts: HashMap<Key, u64>,
+}
+
+impl Baseline {
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let invalid = || io::Error::new(io::ErrorKind::InvalidData, "not a clippy baseline");
+        let baseline: Value = serde_json::from_str(&fs::read_to_string(path)?).map_err(|_| invalid())?;
+        if baseline["version"].as_u64() != Some(VERSION) {
+            return Err(invalid());
+        }
+        let mut counts = HashMap::new();
+        for entry in baseline["diagnostics"].as_array().ok_or_else(invalid)? {
+            let field = |name: &str| entry[name].as_str().map(str::to_owned).ok_or_else(invalid);
+            let key = Key {
+                lint: field("lint")?,
+                file: field("file")?,
+                fingerprint: field("fingerprint")?,
+            };
+            counts.insert(key, entry["count"].as_u64().ok_or_else(invalid)?);
+        }
+        Ok(Self { counts })
+    }
+
+    /// Saves the diagnostics in cargo's JSON `messages` to the baseline at
+    /// `path`. Returns the number of diagnostics saved.
+    pub fn save(path: &Path, messages: &[Value]) -> io::Result<u64> {
+        let mut counts: HashMap<Key, u64> = HashMap::new();
+        for key in messages.iter().filter_map(Key::of) {
+            *counts.entry(key).or_insert(0) += 1;
+        }
+        // sorted, to keep the diff small when the baseline is updated
+        let mut counts: Vec<_> = counts.into_iter().collect();
+        counts.sort();
+        let diagnostics: Vec<Value> = counts
+            .iter()
+            .map(|(key, count)| {
+                json!({
+                    "lint": key.lint,
+                    "file": key.file,
+                    "fingerprint": key.fingerprint,
+                    "count": count,
+                })
+            })
+            .collect();
+        let baseline = json!({
+            "version": VERSION,
+            "diagnostics": diagnostics,
+        });
+        let mut baseline = serde_json::to_string_pretty(&baseline).expect("JSON values serialize");
+        baseline.push('\n');
+        fs::write(path, baseline)?;
+        Ok(counts.iter().map(|&(_, count)| count).sum())
+    }
+
+    /// Splits `messages` into the diagnostics which are new and the ones
+    /// recorded in the baseline. Summaries like "aborting due to 2 previous
+    /// errors" are new.
+    pub fn filter(&mut self, messages: Vec<Value>) -> (Vec<Value>, Vec<Value>) {
+        messages.into_iter().partition(|message| {
+            let count = Key::of(message).and_then(|key| self.counts.get_mut(&key));
+            match count {
+                // a diagnostic reported more often than recorded is new
+                Some(count) if *count > 0 => {
+                    *count -= 1;
+                    false
+                },
+                _ => true,
+            }
+        })
+    }
+}
+
+/// Checks whether cargo aborted for another reason than denied lints, going
+/// by its JSON `messages`: a compiler error, or failing without any denied
+/// lint, e.g. because a build script failed. Call it only if cargo failed.
+pub fn aborted(messages: &[Value]) -> bool {
+    let mut denied_lints = false;
+    for message in messages.iter().filter(|message| is_error(message)) {
+        if Key::of(message).is_some() {
+            denied_lints = true;
+        } else if !is_summary(message) {
+            return true;
+        }
+    }
+    !denied_lints
+}
+
+/// Checks whether `messages` contain denied lints.
+pub fn has_denied_lints(messages: &[Value]) -> bool {
+    messages
+        .iter()
+        .any(|message| is_error(message) && Key::of(message).is_some())
+}
+
+fn is_error(message: &Value) -> bool {
+    message["message"]["level"] == "error"
+}
+
+/// Checks whether `message` is a summary like "aborting due to 2 previous
+/// errors", which has no span.
+fn is_summary(message: &Value) -> bool {
+    let diagnostic = &message["message"];
+    diagnostic["spans"].as_array().map_or(true, Vec::is_empty)
+        && diagnostic["message"]
+            .as_str()
+            .map_or(false, |text| text.starts_with("aborting due to"))
+}
+
+/// Checks whether `code` is the code of a compiler error, like `E0308`.
+fn is_error_code(code: &str) -> bool {
+    code.len() == 5 && code.starts_with('E') && code[1..].bytes().all(|b| b.is_ascii_digit())
+}
+
+/// The 64-bit FNV-1a hash, which unlike `std`'s hashers is guaranteed to stay
+/// the same.
+pub fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+#[cfg(test)]
+fn message(level: &str, code: Option<&str>, text: &str, line: &str) -> Value {
+    let spans = if line.is_empty() {
+        json!([])
+    } else {
+        json!([{
+            "file_name": "src/lib.rs",
+            "is_primary": true,
+            "text": [{ "text": line }],
+        }])
+    };
+    json!({
+        "reason": "compiler-message",
+        "message": {
+            "level": level,
+            "code": code.map(|code| json!({ "code": code })),
+            "message": text,
+            "spans": spans,
+        },
+    })
+}
+
+#[test]
+fn test_save_load() {
+    let path = crate::temp_path("baseline-save-load.json");
+    let lint = message("warning", Some("clippy::foo"), "foo", "    let x = 1;");
+    let messages = vec![
+        lint.clone(),
+        lint.clone(),
+        message("warning", Some("clippy::bar"), "bar", "bar();"),
+        // not recorded
+        message("error", Some("E0308"), "mismatched types", "let x: u8 = \"\";"),
+        message("error", None, "aborting due to previous error", ""),
+    ];
+    assert_eq!(Baseline::save(&path, &messages).unwrap(), 3);
+    let baseline = Baseline::load(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+    assert_eq!(baseline.counts.len(), 2);
+    assert_eq!(baseline.counts[&Key::of(&lint).unwrap()], 2);
+}
+
+#[test]
+fn test_load_invalid() {
+    let path = crate::temp_path("baseline-invalid.json");
+    fs::write(&path, r#"{ "version": 0, "diagnostics": [] }"#).unwrap();
+    let version = Baseline::load(&path).map(|_| ()).unwrap_err().kind();
+    fs::write(&path, "not json").unwrap();
+    let json = Baseline::load(&path).map(|_| ()).unwrap_err().kind();
+    fs::remove_file(&path).unwrap();
+    assert_eq!(version, io::ErrorKind::InvalidData);
+    assert_eq!(json, io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn test_filter() {
+    let path = crate::temp_path("baseline-filter.json");
+    let recorded = message("error", Some("clippy::foo"), "foo", "foo();");
+    Baseline::save(&path, &[recorded.clone()]).unwrap();
+    let mut baseline = Baseline::load(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+    // moved to another line, but with the same text
+    let moved = message("error", Some("clippy::foo"), "foo", "        foo();");
+    let new = message("error", Some("clippy::foo"), "foo", "bar();");
+    let summary = message("error", None, "aborting due to 3 previous errors", "");
+    let (new_messages, recorded_messages) =
+        baseline.filter(vec![moved.clone(), moved.clone(), new.clone(), summary.clone()]);
+    // only one `foo();` is recorded
+    assert_eq!(new_messages, [moved.clone(), new, summary]);
+    assert_eq!(recorded_messages, [moved]);
+}
+
+#[test]
+fn test_aborted() {
+    let lint = message("error", Some("clippy::foo"), "foo", "foo();");
+    let summary = message("error", None, "aborting due to previous error", "");
+    let compiler_error = message("error", Some("E0425"), "cannot find value `x`", "x");
+    let warning = message("warning", Some("clippy::bar"), "bar", "bar();");
+    assert!(!aborted(&[lint.clone(), summary.clone()]));
+    assert!(aborted(&[lint.clone(), compiler_error, summary.clone()]));
+    // e.g. a build script failed
+    assert!(aborted(&[warning.clone()]));
+    assert!(aborted(&[]));
+    assert!(has_denied_lints(&[warning.clone(), lint]));
+    assert!(!has_denied_lints(&[warning, summary]));
+}
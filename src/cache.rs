@@ -0,0 +1,137 @@
+//! `cargo clippy --cache`: saves the diagnostics of each crate next to its
+//! artifact, and replays them when cargo doesn't run clippy for the crate
+//! again because it didn't change.
+
+use crate::baseline::fnv1a;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+/// The names of the configuration files, like in `clippy_lints::utils::conf`.
+const CONFIG_FILE_NAMES: [&str; 2] = [".clippy.toml", "clippy.toml"];
+
+/// Returns the key the diagnostics are cached with. It changes with the
+/// version of clippy, the arguments passed to cargo and clippy, and the
+/// configuration files of the workspace `members`, which cargo doesn't know
+/// the crates' diagnostics depend on.
+pub fn key(cargo_args: &[String], clippy_args: &str, members: &[PathBuf]) -> String {
+    let mut key = format!(
+        "{}\n{}\n{}\n",
+        env!("CARGO_PKG_VERSION"),
+        cargo_args.join(" "),
+        clippy_args
+    );
+    match env::var_os("CLIPPY_CONF_DIR") {
+        Some(dir) => push_config(&mut key, Path::new(&dir)),
+        None => {
+            for member in members {
+                push_config(&mut key, member);
+            }
+        },
+    }
+    format!("{:016x}", fnv1a(key.as_bytes()))
+}
+
+/// Appends the path and the contents of the configuration file used in `dir`
+/// to `key`.
+fn push_config(key: &mut String, dir: &Path) {
+    if let Some(file) = config_file(dir) {
+        key.push_str(&file.to_string_lossy());
+        key.push('\n');
+        // an unreadable file is reported by the driver
+        key.push_str(&fs::read_to_string(&file).unwrap_or_default());
+        key.push('\n');
+    }
+}
+
+/// Returns the configuration file the driver reads for the crates in `dir`,
+/// the first one found in `dir` or its parents.
+fn config_file(dir: &Path) -> Option<PathBuf> {
+    dir.ancestors()
+        .flat_map(|dir| CONFIG_FILE_NAMES.iter().map(move |name| dir.join(name)))
+        .find(|file| file.is_file())
+}
+
+fn cache_path(artifact: &Path) -> PathBuf {
+    artifact.with_extension("clippy")
+}
+
+fn artifact_stamp(artifact: &Path) -> Option<String> {
+    let modified = fs::metadata(artifact).and_then(|m| m.modified()).ok()?;
+    let since_epoch = modified.duration_since(UNIX_EPOCH).ok()?;
+    Some(format!("{}.{:09}", since_epoch.as_secs(), since_epoch.subsec_nanos()))
+}
+
+/// The cache starts with the `key` and the modification time of the artifact.
+/// If either changed, the cached diagnostics are stale.
+pub fn save(artifact: &Path, key: &str, diagnostics: &[String]) {
+    if let Some(stamp) = artifact_stamp(artifact) {
+        let mut cache = format!("{}\n{}\n", key, stamp);
+        for diagnostic in diagnostics {
+            cache.push_str(diagnostic);
+            cache.push('\n');
+        }
+        // without the cache, the diagnostics just aren't replayed
+        let _ = fs::write(cache_path(artifact), cache);
+    }
+}
+
+/// Returns the diagnostics cached for `artifact`, unless they are stale.
+pub fn replay(artifact: &Path, key: &str) -> Vec<serde_json::Value> {
+    let cache = match fs::read_to_string(cache_path(artifact)) {
+        Ok(cache) => cache,
+        Err(_) => return Vec::new(),
+    };
+    let mut lines = cache.lines();
+    if lines.next() != Some(key) || lines.next() != artifact_stamp(artifact).as_ref().map(String::as_str) {
+        return Vec::new();
+    }
+    lines.filter_map(|line| serde_json::from_str(line).ok()).collect()
+}
+
+#[cfg(test)]
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = crate::temp_path(&format!("cache-{}", name));
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn test_key() {
+    let dir = temp_dir("key");
+    let member = dir.join("member");
+    fs::create_dir_all(&member).unwrap();
+    let args = ["check".to_owned()];
+    let key_of = |args: &[String], clippy_args| key(args, clippy_args, &[member.clone()]);
+
+    let before = key_of(&args, "");
+    assert_eq!(key_of(&args, ""), before);
+    assert_ne!(key_of(&args, "-Dwarnings__CLIPPY_HACKERY__"), before);
+    assert_ne!(key_of(&["check".to_owned(), "--all-targets".to_owned()], ""), before);
+    // the configuration file of a parent directory applies to the member
+    fs::write(dir.join("clippy.toml"), "cognitive-complexity-threshold = 10").unwrap();
+    let configured = key_of(&args, "");
+    fs::write(dir.join("clippy.toml"), "cognitive-complexity-threshold = 20").unwrap();
+    let changed = key_of(&args, "");
+    fs::remove_dir_all(&dir).unwrap();
+    assert_ne!(configured, before);
+    assert_ne!(changed, configured);
+}
+
+#[test]
+fn test_save_replay() {
+    let dir = temp_dir("replay");
+    let artifact = dir.join("libfoo.rmeta");
+    fs::write(&artifact, "").unwrap();
+    let diagnostic = r#"{"reason":"compiler-message","message":{"level":"warning"}}"#;
+    save(&artifact, "key", &[diagnostic.to_owned()]);
+    let replayed = replay(&artifact, "key");
+    let other_key = replay(&artifact, "other key");
+    fs::remove_dir_all(&dir).unwrap();
+    assert_eq!(
+        replayed,
+        [serde_json::from_str::<serde_json::Value>(diagnostic).unwrap()]
+    );
+    assert!(other_key.is_empty());
+}
@@ -47,7 +47,7 @@ fn arg_value<'a>(
 fn test_arg_value() {
     let args: Vec<_> = ["--bar=bar", "--foobar", "123", "--foo"]
         .iter()
-        .map(|s| s.to_string())
+        .map(ToString::to_string)
         .collect();
 
     assert_eq!(arg_value(None, "--foobar", |_| true), None);
@@ -60,6 +60,40 @@ fn test_arg_value() {
     assert_eq!(arg_value(&args, "--foo", |_| true), None);
 }
 
+/// Removes the command-line option `find_arg` from `args` and returns its last value. The option is
+/// either `--arg=value` or `--arg value`.
+fn take_arg_value(args: &mut Vec<String>, find_arg: &str) -> Option<String> {
+    let mut value = None;
+    let mut i = 0;
+    while i < args.len() {
+        if args[i] == find_arg {
+            args.remove(i);
+            if i < args.len() {
+                value = Some(args.remove(i));
+            }
+        } else if args[i].starts_with(find_arg) && args[i][find_arg.len()..].starts_with('=') {
+            value = Some(args.remove(i)[find_arg.len() + 1..].to_owned());
+        } else {
+            i += 1;
+        }
+    }
+    value
+}
+
+#[test]
+fn test_take_arg_value() {
+    let mut args: Vec<_> = ["--bar=bar", "--foobar", "123", "--foo=1", "--bar", "baz", "--foo"]
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+
+    assert_eq!(take_arg_value(&mut args, "--baz"), None);
+    assert_eq!(take_arg_value(&mut args, "--bar"), Some("baz".to_owned()));
+    assert_eq!(args, ["--foobar", "123", "--foo=1", "--foo"]);
+    assert_eq!(take_arg_value(&mut args, "--foo"), Some("1".to_owned()));
+    assert_eq!(args, ["--foobar", "123"]);
+}
+
 #[allow(clippy::too_many_lines)]
 pub fn main() {
     rustc_driver::init_rustc_env_logger();
@@ -84,7 +118,7 @@ pub fn main() {
             let sys_root_arg = arg_value(&orig_args, "--sysroot", |_| true);
             let have_sys_root_arg = sys_root_arg.is_some();
             let sys_root = sys_root_arg
-                .map(|s| s.to_string())
+                .map(ToString::to_string)
                 .or_else(|| std::env::var("SYSROOT").ok())
                 .or_else(|| {
                     let home = option_env!("RUSTUP_HOME").or(option_env!("MULTIRUST_HOME"));
@@ -133,6 +167,8 @@ pub fn main() {
                 || arg_value(&orig_args, "--emit", |val| val.split(',').any(|e| e == "metadata")).is_some();
 
             if clippy_enabled {
+                // the lint levels from `clippy.toml` go first, so the ones passed on the command line win
+                args.splice(1..1, clippy_lints::group_level_args());
                 args.extend_from_slice(&["--cfg".to_owned(), r#"feature="cargo-clippy""#.to_owned()]);
                 if let Ok(extra_args) = env::var("CLIPPY_ARGS") {
                     args.extend(extra_args.split("__CLIPPY_HACKERY__").filter_map(|s| {
@@ -145,6 +181,19 @@ pub fn main() {
                 }
             }
 
+            // the caps are Clippy's own flags, so they are not passed on to rustc
+            let deps_cap = take_arg_value(&mut args, "--clippy-cap-deps-lints");
+            let generated_cap = take_arg_value(&mut args, "--clippy-cap-generated-lints");
+            if clippy_enabled {
+                if let Err(error) = clippy_lints::set_lint_caps(
+                    deps_cap.as_ref().map(String::as_str),
+                    generated_cap.as_ref().map(String::as_str),
+                ) {
+                    eprintln!("error: {}", error);
+                    exit(1);
+                }
+            }
+
             let mut controller = CompileController::basic();
             if clippy_enabled {
                 controller.after_parse.callback = Box::new(move |state| {
@@ -177,7 +226,7 @@ pub fn main() {
                     for pass in early_lint_passes {
                         ls.register_early_pass(Some(sess), true, false, pass);
                     }
-                    for pass in late_lint_passes {
+                    for pass in clippy_lints::wrap_late_passes(late_lint_passes) {
                         ls.register_late_pass(Some(sess), true, pass);
                     }
 
@@ -194,7 +243,12 @@ pub fn main() {
             controller.compilation_done.stop = Compilation::Stop;
 
             let args = args;
-            rustc_driver::run_compiler(&args, Box::new(controller), None, None)
+            let result = rustc_driver::run_compiler(&args, Box::new(controller), None, None);
+            // cargo caps the lints of dependencies from registries, their times are of no interest
+            if clippy_enabled && arg_value(&orig_args, "--cap-lints", |_| true).is_none() {
+                clippy_lints::write_pass_times();
+            }
+            result
         })
         .try_into()
         .expect("exit code too large"),
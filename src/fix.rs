@@ -0,0 +1,279 @@
+//! `cargo clippy --fix`: applies the machine applicable suggestions of the
+//! diagnostics to the source files.
+
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+/// The number of times cargo is run at most. Applying suggestions can lead to
+/// new ones, so cargo is run again until there are none left.
+pub const MAX_PASSES: usize = 10;
+
+/// A part of a suggestion, replacing the bytes `start..end` of `file`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Replacement {
+    file: PathBuf,
+    line: u64,
+    start: usize,
+    end: usize,
+    text: String,
+}
+
+impl Replacement {
+    fn overlaps(&self, other: &Self) -> bool {
+        // two insertions at the same position overlap as well, their order is unclear
+        self.file == other.file && (self.start < other.end && other.start < self.end || self.start == other.start)
+    }
+}
+
+/// The replacements of a suggestion, either all or none of them are applied.
+pub type Suggestion = Vec<Replacement>;
+
+/// Returns the machine applicable suggestions of the diagnostics in cargo's
+/// JSON `messages`. Only suggestions to the files of the workspace `members`
+/// are returned, file names are relative to the workspace `root`.
+pub fn suggestions(messages: &[serde_json::Value], root: &Path, members: &[PathBuf]) -> Vec<Suggestion> {
+    let mut suggestions = Vec::new();
+    for message in messages {
+        let children = match message["message"]["children"].as_array() {
+            Some(children) => children,
+            None => continue,
+        };
+        for child in children {
+            if let Some(suggestion) = suggestion(child, root, members) {
+                // crates built for several targets report the same suggestions
+                if !suggestions.contains(&suggestion) {
+                    suggestions.push(suggestion);
+                }
+            }
+        }
+    }
+    suggestions
+}
+
+/// Returns the replacements of the suggestion `diagnostic`. None are returned
+/// if the suggestion isn't machine applicable, or if it shows alternatives,
+/// which replace the same span.
+fn suggestion(diagnostic: &serde_json::Value, root: &Path, members: &[PathBuf]) -> Option<Suggestion> {
+    let mut replacements: Suggestion = Vec::new();
+    for span in diagnostic["spans"].as_array()? {
+        if span["suggestion_applicability"].as_str() != Some("MachineApplicable") {
+            return None;
+        }
+        let file = root.join(span["file_name"].as_str()?);
+        if !members.iter().any(|member| file.starts_with(member)) {
+            return None;
+        }
+        let replacement = Replacement {
+            file,
+            line: span["line_start"].as_u64()?,
+            start: span["byte_start"].as_u64()? as usize,
+            end: span["byte_end"].as_u64()? as usize,
+            text: span["suggested_replacement"].as_str()?.to_owned(),
+        };
+        if replacements.iter().any(|other| other.overlaps(&replacement)) {
+            return None;
+        }
+        replacements.push(replacement);
+    }
+    if replacements.is_empty() {
+        None
+    } else {
+        Some(replacements)
+    }
+}
+
+/// Applies the `suggestions` to the source files, or prints the replacements
+/// if `dry_run` is set. Suggestions overlapping an earlier one are skipped,
+/// they are reported again by the next run of cargo. Returns the number of
+/// suggestions applied.
+pub fn apply(suggestions: &[Suggestion], dry_run: bool) -> io::Result<usize> {
+    let mut sources: HashMap<&Path, String> = HashMap::new();
+    for replacement in suggestions.iter().flatten() {
+        if !sources.contains_key(&*replacement.file) {
+            sources.insert(&replacement.file, fs::read_to_string(&replacement.file)?);
+        }
+    }
+
+    let mut accepted: Vec<&Replacement> = Vec::new();
+    let mut applied = 0;
+    for suggestion in suggestions {
+        let olds = suggestion
+            .iter()
+            .map(|replacement| sources[&*replacement.file].get(replacement.start..replacement.end))
+            .collect::<Option<Vec<_>>>();
+        let changes = match olds {
+            Some(olds) => olds
+                .iter()
+                .zip(suggestion)
+                .any(|(old, replacement)| *old != replacement.text),
+            // the file changed since the diagnostic was reported
+            None => false,
+        };
+        let overlaps = suggestion
+            .iter()
+            .any(|r| accepted.iter().any(|other| r.overlaps(other)));
+        if changes && !overlaps {
+            accepted.extend(suggestion);
+            applied += 1;
+        }
+    }
+
+    accepted.sort_by(|a, b| (&a.file, a.start).cmp(&(&b.file, b.start)));
+    if dry_run {
+        for replacement in accepted {
+            println!(
+                "{}:{}: `{}` -> `{}`",
+                replacement.file.display(),
+                replacement.line,
+                &sources[&*replacement.file][replacement.start..replacement.end],
+                replacement.text
+            );
+        }
+        return Ok(applied);
+    }
+    // replace from the end of the files, so the offsets of earlier replacements stay valid
+    let mut changed = Vec::new();
+    for replacement in accepted.into_iter().rev() {
+        let source = sources.get_mut(&*replacement.file).expect("the file was read");
+        source.replace_range(replacement.start..replacement.end, &replacement.text);
+        if changed.last() != Some(&replacement.file) {
+            changed.push(replacement.file.clone());
+        }
+    }
+    for file in changed {
+        fs::write(&file, &sources[&*file])?;
+    }
+    Ok(applied)
+}
+
+/// Returns the files of the git repository at `root` with uncommitted changes,
+/// including untracked ones. `None` is returned if `root` isn't in a git
+/// repository, or git can't be run.
+pub fn uncommitted_changes(root: &Path) -> Option<Vec<String>> {
+    let output = Command::new("git")
+        .args(&["status", "--porcelain"])
+        .current_dir(root)
+        .stderr(Stdio::null())
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(changed_files(&String::from_utf8_lossy(&output.stdout)))
+}
+
+/// Returns the files in the output of `git status --porcelain`, whose lines
+/// are a two letter status and the file name, like ` M src/lib.rs`.
+fn changed_files(status: &str) -> Vec<String> {
+    status
+        .lines()
+        .filter(|line| line.len() > 3)
+        .map(|line| line[3..].to_owned())
+        .collect()
+}
+
+#[cfg(test)]
+fn temp_file(name: &str, source: &str) -> PathBuf {
+    let path = crate::temp_path(&format!("fix-{}.rs", name));
+    fs::write(&path, source).unwrap();
+    path
+}
+
+#[cfg(test)]
+fn replacement(file: &Path, start: usize, end: usize, text: &str) -> Replacement {
+    Replacement {
+        file: file.to_owned(),
+        line: 1,
+        start,
+        end,
+        text: text.to_owned(),
+    }
+}
+
+#[test]
+fn test_suggestions() {
+    let span = |file: &str, applicability: &str, start: u64| {
+        serde_json::json!({
+            "file_name": file,
+            "line_start": 1,
+            "byte_start": start,
+            "byte_end": start + 1,
+            "suggested_replacement": "x",
+            "suggestion_applicability": applicability,
+        })
+    };
+    let message = serde_json::json!({
+        "message": {
+            "children": [
+                { "spans": [span("src/lib.rs", "MachineApplicable", 0)] },
+                // the same suggestion for another target
+                { "spans": [span("src/lib.rs", "MachineApplicable", 0)] },
+                { "spans": [span("src/lib.rs", "MaybeIncorrect", 2)] },
+                // outside the workspace
+                { "spans": [span("/registry/dep/src/lib.rs", "MachineApplicable", 0)] },
+                // alternatives for the same span
+                { "spans": [span("src/lib.rs", "MachineApplicable", 4), span("src/lib.rs", "MachineApplicable", 4)] },
+                // no suggestion
+                { "spans": [] },
+            ],
+        },
+    });
+    let root = Path::new("/ws");
+    let suggestions = suggestions(&[message], root, &[root.to_owned()]);
+    assert_eq!(suggestions, [vec![replacement(&root.join("src/lib.rs"), 0, 1, "x")]]);
+}
+
+#[test]
+fn test_apply_overlapping() {
+    let file = temp_file("overlapping", "let a = b + c;");
+    let suggestions = vec![
+        vec![replacement(&file, 8, 13, "b.add(c)")],
+        // overlaps the first one, it's reported again by the next run
+        vec![replacement(&file, 12, 13, "d")],
+        // an insertion at the start of the first one, whose order is unclear
+        vec![replacement(&file, 8, 8, "&")],
+        // doesn't change anything
+        vec![replacement(&file, 0, 3, "let")],
+        vec![replacement(&file, 4, 5, "x")],
+    ];
+    let applied = apply(&suggestions, true).unwrap();
+    let unchanged = fs::read_to_string(&file).unwrap();
+    assert_eq!(apply(&suggestions, false).unwrap(), 2);
+    let source = fs::read_to_string(&file).unwrap();
+    fs::remove_file(&file).unwrap();
+    assert_eq!(applied, 2);
+    assert_eq!(unchanged, "let a = b + c;");
+    assert_eq!(source, "let x = b.add(c);");
+}
+
+#[test]
+fn test_apply_multi_file() {
+    let lib = temp_file("multi-lib", "mod a;\nfn f() { a::g(); }\n");
+    let module = temp_file("multi-module", "pub fn g() {}\n");
+    let suggestions = vec![
+        // the replacements of one suggestion are applied together
+        vec![replacement(&module, 7, 8, "h"), replacement(&lib, 19, 20, "h")],
+        // the file changed since the diagnostic was reported
+        vec![replacement(&module, 100, 101, "x")],
+    ];
+    let applied = apply(&suggestions, false).unwrap();
+    let lib_source = fs::read_to_string(&lib).unwrap();
+    let module_source = fs::read_to_string(&module).unwrap();
+    fs::remove_file(&lib).unwrap();
+    fs::remove_file(&module).unwrap();
+    assert_eq!(applied, 1);
+    assert_eq!(lib_source, "mod a;\nfn f() { a::h(); }\n");
+    assert_eq!(module_source, "pub fn h() {}\n");
+}
+
+#[test]
+fn test_changed_files() {
+    assert_eq!(
+        changed_files(" M src/lib.rs\n?? src/new.rs\nA  tests/a.rs\n"),
+        ["src/lib.rs", "src/new.rs", "tests/a.rs"]
+    );
+    assert!(changed_files("").is_empty());
+}
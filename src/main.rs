@@ -3,7 +3,19 @@
 #![feature(rustc_private)]
 #![allow(clippy::missing_docs_in_private_items)]
 
+mod baseline;
+mod cache;
+mod fix;
+mod sarif;
+
+use crate::baseline::Baseline;
+
 use rustc_tools_util::*;
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitStatus, Stdio};
+use std::thread;
 
 const CARGO_CLIPPY_HELP: &str = r#"Checks a package to catch common mistakes and improve your Rust code.
 
@@ -13,6 +25,17 @@ Usage:
 Common options:
     -h, --help               Print this message
     -V, --version            Print version info and exit
+    --fix                    Apply the machine applicable suggestions
+    --cache                  Show the diagnostics of crates which didn't
+                             change since the last run again
+    --dry-run                With `--fix`, print the replacements instead
+                             of applying them
+    --allow-dirty            With `--fix`, apply the suggestions even if the
+                             git repository has uncommitted changes
+    --message-format=sarif   Print the diagnostics as a SARIF document
+    --save-baseline PATH     Record the diagnostics in the baseline at PATH
+    --baseline PATH          Only show the diagnostics which aren't recorded
+                             in the baseline at PATH
 
 Other options are the same as `cargo check`.
 
@@ -27,6 +50,36 @@ with:
 You can use tool lints to allow or deny lints from your code, eg.:
 
     #[allow(clippy::needless_lifetimes)]
+
+Clippy's lints are silenced in crates outside the workspace and in code which
+build scripts generate into `OUT_DIR`. To cap them at another level instead,
+pass `--clippy-cap-deps-lints=LEVEL` or `--clippy-cap-generated-lints=LEVEL`
+after `--`, e.g.:
+
+    cargo clippy -- --clippy-cap-deps-lints=warn
+
+With `--cache`, the diagnostics of each crate are cached in the target
+directory, so they are shown again for crates which cargo doesn't check again
+since they didn't change. The cache is discarded when clippy, the arguments or
+`clippy.toml` change. It can't be used with `--message-format`.
+
+With `--fix`, the suggestions which are known to be correct are applied to
+the source files of the workspace, and clippy is run again until there are
+none left. Only the diagnostics of the last run are shown. `--fix` can't be
+used with `--message-format`, and it always uses the cache. To keep the fixes
+apart from your own changes, it refuses to change the files of a git
+repository with uncommitted changes, unless `--allow-dirty` is passed.
+
+With `--message-format=sarif`, the diagnostics are printed to stdout as a
+SARIF 2.1.0 document for code scanning tools, with the group of each lint and
+the suggestions. It uses the cache as well.
+
+A baseline lets you enable clippy for existing code: `--save-baseline` records
+all current diagnostics, and `--baseline` leaves them out in later runs, so
+only new ones are shown. Diagnostics are recognized by the lint, the file, the
+message and the code they point to, so moving code around keeps them hidden.
+Denied lints recorded in the baseline don't fail the build, but compiler errors
+and other failures do, and the baseline isn't saved after them.
 "#;
 
 fn show_help() {
@@ -60,12 +113,67 @@ where
     I: Iterator<Item = String>,
 {
     let mut args = vec!["check".to_owned()];
+    let mut fix = false;
+    let mut dry_run = false;
+    let mut use_cache = false;
+    let mut allow_dirty = false;
+    let mut sarif = false;
+    let mut baseline = None;
+    let mut save_baseline = None;
 
-    for arg in old_args.by_ref() {
-        if arg == "--" {
-            break;
+    while let Some(arg) = old_args.next() {
+        if let Some(path) = option_value(&arg, "--baseline", &mut old_args) {
+            baseline = Some(PathBuf::from(path));
+            continue;
+        }
+        if let Some(path) = option_value(&arg, "--save-baseline", &mut old_args) {
+            save_baseline = Some(PathBuf::from(path));
+            continue;
+        }
+        match arg.as_str() {
+            "--" => break,
+            "--fix" => fix = true,
+            "--dry-run" => dry_run = true,
+            "--cache" => use_cache = true,
+            "--allow-dirty" => allow_dirty = true,
+            "--message-format=sarif" => sarif = true,
+            "sarif" if args.last().map(String::as_str) == Some("--message-format") => {
+                args.pop();
+                sarif = true;
+            },
+            _ => args.push(arg),
         }
-        args.push(arg);
+    }
+
+    let message_format = args.iter().any(|a| a.starts_with("--message-format"));
+    let uses_baseline = baseline.is_some() || save_baseline.is_some();
+    if dry_run && !fix {
+        eprintln!("error: `--dry-run` can only be used with `--fix`");
+        return Err(1);
+    }
+    if allow_dirty && !fix {
+        eprintln!("error: `--allow-dirty` can only be used with `--fix`");
+        return Err(1);
+    }
+    if fix && (message_format || sarif) {
+        eprintln!("error: `--fix` reads cargo's JSON messages, it can't be used with `--message-format`");
+        return Err(1);
+    }
+    if fix && uses_baseline {
+        eprintln!("error: `--fix` can't be used with a baseline");
+        return Err(1);
+    }
+    if message_format && uses_baseline {
+        eprintln!("error: a baseline can only be used with `--message-format=sarif`");
+        return Err(1);
+    }
+    if use_cache && message_format {
+        eprintln!("error: `--cache` reads cargo's JSON messages, it can't be used with `--message-format`");
+        return Err(1);
+    }
+    if sarif && message_format {
+        eprintln!("error: `--message-format` can only be passed once");
+        return Err(1);
     }
 
     let clippy_args: String = old_args.map(|arg| format!("{}__CLIPPY_HACKERY__", arg)).collect();
@@ -97,15 +205,62 @@ where
         args.insert(0, "+nightly".to_string());
     }
 
-    let exit_status = std::process::Command::new("cargo")
-        .args(&args)
-        .env("RUSTC_WRAPPER", path)
-        .env("CLIPPY_ARGS", clippy_args)
-        .envs(target_dir)
-        .spawn()
-        .expect("could not run cargo")
-        .wait()
-        .expect("failed to wait for cargo?");
+    // the diagnostics are read from cargo's JSON messages to cache them, the
+    // modes reading all diagnostics need the ones of unchanged crates as well
+    let cache = use_cache || fix || sarif || uses_baseline;
+    if cache {
+        args.push("--message-format=json".to_owned());
+    }
+
+    let metadata = cargo_metadata(&args);
+    let members = metadata.as_ref().and_then(workspace_members);
+    let cache_key = cache::key(
+        &args,
+        &clippy_args,
+        members.as_ref().map_or(&[PathBuf::from(".")][..], Vec::as_slice),
+    );
+    let cargo = || {
+        let mut command = Command::new("cargo");
+        command
+            .args(&args)
+            .env("RUSTC_WRAPPER", &path)
+            .env("CLIPPY_ARGS", &clippy_args)
+            .envs(target_dir.clone());
+        // the driver caps the lints of crates outside the workspace
+        if let Some(members) = members.as_ref().and_then(|members| std::env::join_paths(members).ok()) {
+            command.env("CLIPPY_WORKSPACE_MEMBERS", members);
+        }
+        command
+    };
+    let exit_status = if fix {
+        let root = metadata
+            .as_ref()
+            .and_then(|metadata| metadata["workspace_root"].as_str())
+            .map_or_else(|| PathBuf::from("."), PathBuf::from);
+        let members = members.clone().unwrap_or_else(|| vec![root.clone()]);
+        if !dry_run && !allow_dirty {
+            check_uncommitted_changes(&root)?;
+        }
+        fix_suggestions(cargo, &cache_key, &root, &members, dry_run)?
+    } else if sarif || uses_baseline {
+        let (exit_status, messages, cargo_errors) = run_with_cargo_errors(cargo(), &cache_key);
+        return report(
+            exit_status,
+            messages,
+            cargo_errors,
+            sarif,
+            baseline.as_ref().map(PathBuf::as_path),
+            save_baseline.as_ref().map(PathBuf::as_path),
+        );
+    } else if cache {
+        run_with_messages(cargo(), &cache_key, true).0
+    } else {
+        cargo()
+            .spawn()
+            .expect("could not run cargo")
+            .wait()
+            .expect("failed to wait for cargo?")
+    };
 
     if exit_status.success() {
         Ok(())
@@ -113,3 +268,294 @@ where
         Err(exit_status.code().unwrap_or(-1))
     }
 }
+
+/// Returns the value of the option `name` if `arg` is `name=VALUE`, or if
+/// `arg` is `name` and the value is the next of the `args`.
+fn option_value(arg: &str, name: &str, args: &mut impl Iterator<Item = String>) -> Option<String> {
+    if arg == name {
+        args.next()
+    } else if arg.starts_with(name) && arg[name.len()..].starts_with('=') {
+        Some(arg[name.len() + 1..].to_owned())
+    } else {
+        None
+    }
+}
+
+/// Prints the diagnostics in cargo's JSON `messages`, as a SARIF document if
+/// `sarif` is set. The diagnostics are saved to the baseline at
+/// `save_baseline`, and the ones recorded in the `baseline` are left out.
+///
+/// If cargo failed only because of denied lints recorded in the baseline,
+/// this succeeds. If cargo aborted for another reason, i.e. `cargo_errors` is
+/// set or compiler errors were reported, it never does, and the baseline
+/// isn't saved, since the diagnostics of the crates after the failure are
+/// missing.
+fn report(
+    exit_status: ExitStatus,
+    mut messages: Vec<serde_json::Value>,
+    cargo_errors: bool,
+    sarif: bool,
+    baseline: Option<&Path>,
+    save_baseline: Option<&Path>,
+) -> Result<(), i32> {
+    let mut success = exit_status.success();
+    let aborted = !success && (cargo_errors || baseline::aborted(&messages));
+    if let Some(path) = save_baseline {
+        if aborted {
+            eprintln!(
+                "error: the baseline `{}` isn't saved, since cargo aborted before all diagnostics were reported",
+                path.display()
+            );
+        } else {
+            let saved = Baseline::save(path, &messages).map_err(|e| {
+                eprintln!("error: could not save the baseline `{}`: {}", path.display(), e);
+                1
+            })?;
+            eprintln!("saved {} diagnostics to the baseline `{}`", saved, path.display());
+        }
+    }
+    if let Some(path) = baseline {
+        let mut baseline = Baseline::load(path).map_err(|e| {
+            eprintln!("error: could not load the baseline `{}`: {}", path.display(), e);
+            1
+        })?;
+        let (new, recorded) = baseline.filter(messages);
+        messages = new;
+        // if only recorded lints are denied, the errors left are the summaries
+        // like "aborting due to 2 previous errors"
+        if !success && !aborted && !baseline::has_denied_lints(&messages) {
+            messages.retain(|message| message["message"]["level"] != "error");
+            success = true;
+        }
+        if !recorded.is_empty() {
+            eprintln!(
+                "{} diagnostics recorded in the baseline `{}` aren't shown",
+                recorded.len(),
+                path.display()
+            );
+        }
+    }
+    if sarif {
+        let document = serde_json::to_string_pretty(&sarif::document(&messages)).expect("JSON values serialize");
+        println!("{}", document);
+    } else {
+        for message in &messages {
+            print_rendered(message);
+        }
+    }
+    if success {
+        Ok(())
+    } else {
+        Err(exit_status.code().unwrap_or(-1))
+    }
+}
+
+/// Runs cargo until there are no machine applicable suggestions left, and
+/// applies them after each run. Only the diagnostics of the last run are
+/// printed.
+fn fix_suggestions(
+    cargo: impl Fn() -> Command,
+    cache_key: &str,
+    root: &Path,
+    members: &[PathBuf],
+    dry_run: bool,
+) -> Result<ExitStatus, i32> {
+    let mut applied = 0;
+    for _ in 0..fix::MAX_PASSES {
+        let (exit_status, messages) = run_with_messages(cargo(), cache_key, false);
+        let suggestions = fix::suggestions(&messages, root, members);
+        if !suggestions.is_empty() {
+            applied += fix::apply(&suggestions, dry_run).map_err(|e| {
+                eprintln!("error: could not apply the suggestions: {}", e);
+                1
+            })?;
+        }
+        // the files didn't change in a dry run, so running cargo again is pointless
+        if suggestions.is_empty() || dry_run {
+            for message in &messages {
+                print_rendered(message);
+            }
+            if dry_run {
+                eprintln!("{} suggestions would be applied", applied);
+            } else {
+                eprintln!("applied {} suggestions", applied);
+            }
+            return Ok(exit_status);
+        }
+    }
+    eprintln!(
+        "error: there are still suggestions after running clippy {} times, {} suggestions were applied",
+        fix::MAX_PASSES,
+        applied
+    );
+    Err(1)
+}
+
+/// Fails if the git repository at `root` has uncommitted changes, which the
+/// fixes would be mixed with.
+fn check_uncommitted_changes(root: &Path) -> Result<(), i32> {
+    let files = match fix::uncommitted_changes(root) {
+        Some(files) => files,
+        None => return Ok(()),
+    };
+    if files.is_empty() {
+        return Ok(());
+    }
+    eprintln!("error: the working directory has uncommitted changes:");
+    for file in &files {
+        eprintln!("    {}", file);
+    }
+    eprintln!("commit or stash them first, or pass `--allow-dirty` to apply the suggestions anyway");
+    Err(1)
+}
+
+/// Runs cargo with `--message-format=json` and returns its exit status and
+/// the messages of the diagnostics.
+fn run_with_messages(mut command: Command, cache_key: &str, print: bool) -> (ExitStatus, Vec<serde_json::Value>) {
+    let mut child = command.stdout(Stdio::piped()).spawn().expect("could not run cargo");
+    let stdout = child.stdout.take().expect("cargo's stdout is piped");
+    let messages = read_diagnostics(BufReader::new(stdout), cache_key, print);
+    (child.wait().expect("failed to wait for cargo?"), messages)
+}
+
+/// Like `run_with_messages`, but also returns whether cargo printed an error
+/// of its own, e.g. about a failed build script, besides the crates which
+/// didn't compile, whose errors are in the JSON messages. Cargo's output is
+/// forwarded to stderr.
+fn run_with_cargo_errors(mut command: Command, cache_key: &str) -> (ExitStatus, Vec<serde_json::Value>, bool) {
+    let mut child = command
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("could not run cargo");
+    let stderr = child.stderr.take().expect("cargo's stderr is piped");
+    let cargo_errors = thread::spawn(move || {
+        let mut cargo_errors = false;
+        for line in BufReader::new(stderr).lines() {
+            let line = match line {
+                Ok(line) => line,
+                Err(_) => break,
+            };
+            eprintln!("{}", line);
+            cargo_errors |= is_cargo_error(&line);
+        }
+        cargo_errors
+    });
+    let stdout = child.stdout.take().expect("cargo's stdout is piped");
+    let messages = read_diagnostics(BufReader::new(stdout), cache_key, false);
+    let exit_status = child.wait().expect("failed to wait for cargo?");
+    (exit_status, messages, cargo_errors.join().unwrap_or(true))
+}
+
+/// Checks whether `line` of cargo's output is an error other than a crate
+/// failing to compile.
+fn is_cargo_error(line: &str) -> bool {
+    let line = line.to_lowercase();
+    line.starts_with("error") && !line.starts_with("error: could not compile") && line != "error: build failed"
+}
+
+#[test]
+fn test_is_cargo_error() {
+    assert!(is_cargo_error(
+        "error: failed to run custom build command for `foo v0.1.0`"
+    ));
+    assert!(is_cargo_error("error: internal compiler error: unexpected panic"));
+    assert!(!is_cargo_error("error: Could not compile `foo`."));
+    assert!(!is_cargo_error("error: build failed"));
+    assert!(!is_cargo_error(
+        "warning: build failed, waiting for other jobs to finish..."
+    ));
+    assert!(!is_cargo_error("   Compiling foo v0.1.0"));
+}
+
+fn cargo_metadata(cargo_args: &[String]) -> Option<serde_json::Value> {
+    let mut command = Command::new("cargo");
+    command.args(&["metadata", "--no-deps", "--format-version", "1"]);
+    let mut cargo_args = cargo_args.iter();
+    while let Some(arg) = cargo_args.next() {
+        if arg == "--manifest-path" {
+            command.arg(arg).args(cargo_args.next());
+        } else if arg.starts_with("--manifest-path=") {
+            command.arg(arg);
+        }
+    }
+    let output = command.stderr(Stdio::null()).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    serde_json::from_slice(&output.stdout).ok()
+}
+
+/// Returns the directories of the workspace members.
+fn workspace_members(metadata: &serde_json::Value) -> Option<Vec<PathBuf>> {
+    let members = metadata["packages"]
+        .as_array()?
+        .iter()
+        .filter_map(|package| Some(Path::new(package["manifest_path"].as_str()?).parent()?.to_owned()))
+        .collect();
+    Some(members)
+}
+
+/// Reads the diagnostics from cargo's JSON messages, prints them if `print`
+/// is set, and caches them next to the artifact of each crate. Cargo doesn't
+/// run clippy for crates which didn't change since the last run, so their
+/// diagnostics are replayed from the cache. Returns the messages of the
+/// diagnostics, including the replayed ones.
+fn read_diagnostics(messages: impl BufRead, cache_key: &str, print: bool) -> Vec<serde_json::Value> {
+    let mut all = Vec::new();
+    // the diagnostics of each crate, until its artifact is reported
+    let mut diagnostics: HashMap<String, Vec<String>> = HashMap::new();
+    for line in messages.lines() {
+        let line = match line {
+            Ok(line) => line,
+            Err(_) => break,
+        };
+        let message: serde_json::Value = match serde_json::from_str(&line) {
+            Ok(message) => message,
+            Err(_) => {
+                println!("{}", line);
+                continue;
+            },
+        };
+        let krate = format!("{} {}", message["package_id"], message["target"]["name"]);
+        match message["reason"].as_str() {
+            Some("compiler-message") => {
+                if print {
+                    print_rendered(&message);
+                }
+                diagnostics.entry(krate).or_default().push(line);
+                all.push(message);
+            },
+            Some("compiler-artifact") => {
+                let diagnostics = diagnostics.remove(&krate).unwrap_or_default();
+                if let Some(artifact) = message["filenames"][0].as_str() {
+                    let artifact = Path::new(artifact);
+                    if message["fresh"].as_bool() == Some(true) {
+                        for message in cache::replay(artifact, cache_key) {
+                            if print {
+                                print_rendered(&message);
+                            }
+                            all.push(message);
+                        }
+                    } else {
+                        cache::save(artifact, cache_key, &diagnostics);
+                    }
+                }
+            },
+            _ => {},
+        }
+    }
+    all
+}
+
+fn print_rendered(message: &serde_json::Value) {
+    if let Some(rendered) = message["message"]["rendered"].as_str() {
+        eprint!("{}", rendered);
+    }
+}
+
+/// Returns a path in the temporary directory that is unique to this test run.
+#[cfg(test)]
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("clippy-{}-{}", std::process::id(), name))
+}
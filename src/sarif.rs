@@ -0,0 +1,690 @@
+//! `cargo clippy --message-format=sarif`: converts the diagnostics to a
+//! [SARIF 2.1.0](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html)
+//! document, which code scanning tools read.
+
+use serde_json::{json, Value};
+
+const SCHEMA: &str = "https://schemastore.azurewebsites.net/schemas/json/sarif-2.1.0.json";
+const DOCS_LINK: &str = "https://rust-lang.github.io/rust-clippy/master/index.html";
+
+/// The group of each lint, generated by `util/dev update_lints` from the
+/// lints `clippy_lints` declares. The CI checks that it's up to date.
+const LINT_GROUPS: &[(&str, &str)] = &[
+    // begin lint groups, generated by `util/dev update_lints`
+    ("absurd_extreme_comparisons", "correctness"),
+    ("adjacent_same_type_arguments", "pedantic"),
+    ("almost_swapped", "correctness"),
+    ("and_then_instead_of_map", "complexity"),
+    ("approx_constant", "correctness"),
+    ("assertions_on_constants", "style"),
+    ("assign_op_pattern", "style"),
+    ("await_holding_lock", "pedantic"),
+    ("bad_bit_mask", "correctness"),
+    ("blacklisted_name", "style"),
+    ("block_in_if_condition_expr", "style"),
+    ("block_in_if_condition_stmt", "style"),
+    ("blocking_in_async", "pedantic"),
+    ("bool_comparison", "complexity"),
+    ("borrow_interior_mutable_const", "correctness"),
+    ("borrowed_box", "complexity"),
+    ("box_vec", "perf"),
+    ("boxed_local", "perf"),
+    ("boxed_trait_object_arg", "perf"),
+    ("boxed_trait_object_return", "pedantic"),
+    ("builtin_type_shadow", "style"),
+    ("cargo_common_metadata", "cargo"),
+    ("cast_lossless", "complexity"),
+    ("cast_possible_truncation", "pedantic"),
+    ("cast_possible_wrap", "pedantic"),
+    ("cast_precision_loss", "pedantic"),
+    ("cast_ptr_alignment", "correctness"),
+    ("cast_ref_to_mut", "correctness"),
+    ("cast_sign_loss", "pedantic"),
+    ("chained_comparisons", "correctness"),
+    ("char_lit_as_u8", "complexity"),
+    ("chars_last_cmp", "style"),
+    ("chars_next_cmp", "complexity"),
+    ("clone_double_ref", "correctness"),
+    ("clone_on_copy", "complexity"),
+    ("clone_on_ref_ptr", "restriction"),
+    ("cmp_nan", "correctness"),
+    ("cmp_null", "style"),
+    ("cmp_owned", "perf"),
+    ("cognitive_complexity", "nursery"),
+    ("collapsible_if", "style"),
+    ("confusing_format_args", "pedantic"),
+    ("const_static_lifetime", "style"),
+    ("copy_iterator", "pedantic"),
+    ("crosspointer_transmute", "complexity"),
+    ("cyclomatic_complexity", "complexity"),
+    ("dbg_macro", "restriction"),
+    ("decimal_literal_representation", "restriction"),
+    ("declare_interior_mutable_const", "correctness"),
+    ("deep_glob_imports", "pedantic"),
+    ("default_only_trait", "pedantic"),
+    ("default_trait_access", "pedantic"),
+    ("defaulted_required_fields", "restriction"),
+    ("deprecated_cfg_attr", "complexity"),
+    ("deprecated_semver", "correctness"),
+    ("deref_addrof", "complexity"),
+    ("derivable_impls", "complexity"),
+    ("derive_hash_xor_eq", "correctness"),
+    ("detached_thread", "restriction"),
+    ("disallowed_methods", "style"),
+    ("disallowed_types", "style"),
+    ("diverging_sub_expression", "complexity"),
+    ("doc_markdown", "pedantic"),
+    ("double_comparisons", "complexity"),
+    ("double_neg", "style"),
+    ("double_parens", "complexity"),
+    ("drop_copy", "correctness"),
+    ("drop_ref", "correctness"),
+    ("duplicate_underscore_argument", "style"),
+    ("duplicated_method_bounds", "style"),
+    ("duration_since_epoch_unwrap", "pedantic"),
+    ("duration_subsec", "complexity"),
+    ("else_if_without_else", "restriction"),
+    ("empty_enum", "pedantic"),
+    ("empty_line_after_outer_attr", "nursery"),
+    ("empty_loop", "style"),
+    ("enum_clike_unportable_variant", "correctness"),
+    ("enum_glob_use", "pedantic"),
+    ("enum_variant_names", "style"),
+    ("eq_op", "correctness"),
+    ("erasing_op", "correctness"),
+    ("eval_order_dependence", "complexity"),
+    ("excessive_nesting", "pedantic"),
+    ("excessive_precision", "style"),
+    ("exhaustive_enums", "restriction"),
+    ("exhaustive_structs", "restriction"),
+    ("expect_fun_call", "perf"),
+    ("expensive_clone_in_vec_init", "pedantic"),
+    ("expl_impl_clone_on_copy", "pedantic"),
+    ("explicit_counter_loop", "complexity"),
+    ("explicit_into_iter_loop", "pedantic"),
+    ("explicit_iter_loop", "pedantic"),
+    ("explicit_write", "complexity"),
+    ("extra_unused_lifetimes", "complexity"),
+    ("fallible_impl_from", "nursery"),
+    ("filter_map", "pedantic"),
+    ("filter_next", "complexity"),
+    ("float_arithmetic", "restriction"),
+    ("float_cmp", "correctness"),
+    ("float_cmp_const", "restriction"),
+    ("fn_to_numeric_cast", "style"),
+    ("fn_to_numeric_cast_with_truncation", "style"),
+    ("for_kv_map", "style"),
+    ("for_loop_over_option", "correctness"),
+    ("for_loop_over_result", "correctness"),
+    ("forget_copy", "correctness"),
+    ("forget_ref", "correctness"),
+    ("formatted_instant", "pedantic"),
+    ("get_unwrap", "style"),
+    ("hex_literal_case", "style"),
+    ("identity_conversion", "complexity"),
+    ("identity_op", "complexity"),
+    ("if_let_some_result", "style"),
+    ("if_not_else", "pedantic"),
+    ("if_same_then_else", "correctness"),
+    ("ifs_same_cond", "correctness"),
+    ("immediately_dropped_lock", "correctness"),
+    ("implicit_clone", "pedantic"),
+    ("implicit_hasher", "style"),
+    ("implicit_return", "restriction"),
+    ("improper_extern_fn_types", "correctness"),
+    ("inconsistent_cmp_fields", "correctness"),
+    ("inconsistent_digit_grouping", "style"),
+    ("indexing_slicing", "restriction"),
+    ("ineffective_bit_mask", "correctness"),
+    ("infallible_destructuring_match", "style"),
+    ("infinite_iter", "correctness"),
+    ("inline_always", "pedantic"),
+    ("inline_fn_without_body", "correctness"),
+    ("int128_in_ffi", "pedantic"),
+    ("int_plus_one", "complexity"),
+    ("integer_arithmetic", "restriction"),
+    ("into_iter_on_array", "correctness"),
+    ("into_iter_on_ref", "style"),
+    ("invalid_ref", "correctness"),
+    ("invalid_regex", "correctness"),
+    ("invalid_upcast_comparisons", "pedantic"),
+    ("items_after_statements", "pedantic"),
+    ("iter_cloned_collect", "style"),
+    ("iter_next_loop", "correctness"),
+    ("iter_nth", "perf"),
+    ("iter_skip_next", "style"),
+    ("iterator_step_by_zero", "correctness"),
+    ("join_absolute_paths", "style"),
+    ("just_underscores_and_digits", "style"),
+    ("large_digit_groups", "pedantic"),
+    ("large_enum_variant", "perf"),
+    ("large_futures", "pedantic"),
+    ("len_without_is_empty", "style"),
+    ("len_zero", "style"),
+    ("let_and_return", "style"),
+    ("let_unit_value", "style"),
+    ("linkedlist", "pedantic"),
+    ("lock_unwrap", "restriction"),
+    ("logic_bug", "correctness"),
+    ("manual_digit_conversion", "pedantic"),
+    ("manual_filter", "complexity"),
+    ("manual_memcpy", "perf"),
+    ("manual_swap", "complexity"),
+    ("many_single_char_names", "style"),
+    ("map_clone", "style"),
+    ("map_entry", "perf"),
+    ("map_flatten", "pedantic"),
+    ("map_for_side_effects", "pedantic"),
+    ("match_as_ref", "complexity"),
+    ("match_bool", "style"),
+    ("match_like_matches_macro", "style"),
+    ("match_overlapping_arm", "style"),
+    ("match_ref_pats", "style"),
+    ("match_same_arms", "pedantic"),
+    ("match_wild_err_arm", "style"),
+    ("maybe_infinite_iter", "pedantic"),
+    ("mem_discriminant_non_enum", "correctness"),
+    ("mem_forget", "restriction"),
+    ("mem_replace_option_with_none", "style"),
+    ("min_max", "correctness"),
+    ("mismatched_field_init", "pedantic"),
+    ("misrefactored_assign_op", "complexity"),
+    ("missing_const_for_fn", "nursery"),
+    ("missing_docs_in_private_items", "restriction"),
+    ("missing_errors_doc", "pedantic"),
+    ("missing_inline_in_public_items", "restriction"),
+    ("missing_panics_doc", "pedantic"),
+    ("missing_safety_doc", "style"),
+    ("mistyped_literal_suffixes", "correctness"),
+    ("mixed_case_hex_literals", "style"),
+    ("mixed_impl_trait_args", "pedantic"),
+    ("module_inception", "style"),
+    ("module_name_repetitions", "pedantic"),
+    ("modulo_one", "correctness"),
+    ("monomorphization_bloat", "pedantic"),
+    ("multiple_crate_versions", "cargo"),
+    ("multiple_inherent_impl", "restriction"),
+    ("mut_from_ref", "correctness"),
+    ("mut_mut", "pedantic"),
+    ("mut_range_bound", "complexity"),
+    ("mutex_atomic", "perf"),
+    ("mutex_integer", "nursery"),
+    ("mutual_recursion", "correctness"),
+    ("naive_bytecount", "perf"),
+    ("naming_rules", "style"),
+    ("needless_bool", "complexity"),
+    ("needless_borrow", "nursery"),
+    ("needless_borrowed_reference", "complexity"),
+    ("needless_collect", "perf"),
+    ("needless_continue", "pedantic"),
+    ("needless_lifetimes", "complexity"),
+    ("needless_pass_by_value", "pedantic"),
+    ("needless_range_loop", "style"),
+    ("needless_range_loop_zip", "pedantic"),
+    ("needless_return", "style"),
+    ("needless_update", "complexity"),
+    ("neg_cmp_op_on_partial_ord", "complexity"),
+    ("neg_multiply", "style"),
+    ("never_loop", "correctness"),
+    ("new_ret_no_self", "style"),
+    ("new_without_default", "style"),
+    ("no_effect", "complexity"),
+    ("no_mangle_with_rust_abi", "pedantic"),
+    ("non_ascii_literal", "pedantic"),
+    ("nonminimal_bool", "complexity"),
+    ("nonsensical_open_options", "correctness"),
+    ("not_unsafe_ptr_arg_deref", "correctness"),
+    ("ok_expect", "style"),
+    ("op_ref", "style"),
+    ("option_expect_used", "restriction"),
+    ("option_literal_cmp", "pedantic"),
+    ("option_map_or_none", "style"),
+    ("option_map_unit_fn", "complexity"),
+    ("option_map_unwrap_or", "pedantic"),
+    ("option_map_unwrap_or_else", "pedantic"),
+    ("option_option", "complexity"),
+    ("option_unwrap_used", "restriction"),
+    ("or_else_instead_of_or", "complexity"),
+    ("or_fun_call", "perf"),
+    ("out_of_bounds_indexing", "correctness"),
+    ("overflow_check_conditional", "complexity"),
+    ("overflowing_enum_discriminant", "correctness"),
+    ("panic_in_result_fn", "restriction"),
+    ("panic_params", "style"),
+    ("panicking_unwrap", "nursery"),
+    ("partialeq_ne_impl", "complexity"),
+    ("possible_missing_comma", "correctness"),
+    ("precedence", "complexity"),
+    ("print_literal", "style"),
+    ("print_stdout", "restriction"),
+    ("print_with_newline", "style"),
+    ("println_empty_string", "style"),
+    ("ptr_arg", "style"),
+    ("ptr_eq", "style"),
+    ("ptr_offset_with_cast", "complexity"),
+    ("pub_enum_variant_names", "pedantic"),
+    ("question_mark", "style"),
+    ("range_minus_one", "complexity"),
+    ("range_plus_one", "complexity"),
+    ("range_zip_with_len", "complexity"),
+    ("rc_clone_in_vec_init", "style"),
+    ("redundant_clone", "nursery"),
+    ("redundant_closure", "style"),
+    ("redundant_closure_call", "complexity"),
+    ("redundant_closure_for_method_calls", "pedantic"),
+    ("redundant_field_names", "style"),
+    ("redundant_pattern", "style"),
+    ("redundant_pattern_matching", "style"),
+    ("redundant_phantom_data", "complexity"),
+    ("redundant_turbofish", "pedantic"),
+    ("ref_in_deref", "complexity"),
+    ("regex_macro", "style"),
+    ("replace_consts", "pedantic"),
+    ("result_expect_used", "restriction"),
+    ("result_map_unit_fn", "complexity"),
+    ("result_map_unwrap_or_else", "pedantic"),
+    ("result_unwrap_used", "restriction"),
+    ("reverse_range_loop", "correctness"),
+    ("search_is_some", "complexity"),
+    ("self_assignment", "correctness"),
+    ("serde_api_misuse", "correctness"),
+    ("serialized_int128", "pedantic"),
+    ("shadow_reuse", "restriction"),
+    ("shadow_same", "restriction"),
+    ("shadow_unrelated", "pedantic"),
+    ("shadowed_glob_imports", "pedantic"),
+    ("short_circuit_statement", "complexity"),
+    ("should_implement_trait", "style"),
+    ("similar_names", "pedantic"),
+    ("single_char_pattern", "perf"),
+    ("single_match", "style"),
+    ("single_match_else", "pedantic"),
+    ("single_value_format", "pedantic"),
+    ("slow_vector_initialization", "perf"),
+    ("string_add", "restriction"),
+    ("string_add_assign", "pedantic"),
+    ("string_extend_chars", "style"),
+    ("string_lit_as_bytes", "style"),
+    ("suspicious_arithmetic_impl", "correctness"),
+    ("suspicious_assignment_formatting", "style"),
+    ("suspicious_else_formatting", "style"),
+    ("suspicious_op_assign_impl", "correctness"),
+    ("swapped_arguments", "correctness"),
+    ("system_time_elapsed", "pedantic"),
+    ("temporary_assignment", "complexity"),
+    ("temporary_cstring_as_ptr", "correctness"),
+    ("too_many_arguments", "complexity"),
+    ("too_many_lines", "pedantic"),
+    ("too_many_lines_in_module", "pedantic"),
+    ("toplevel_ref_arg", "style"),
+    ("transmute_bytes_to_str", "complexity"),
+    ("transmute_int_to_bool", "complexity"),
+    ("transmute_int_to_char", "complexity"),
+    ("transmute_int_to_float", "complexity"),
+    ("transmute_ptr_to_ptr", "complexity"),
+    ("transmute_ptr_to_ref", "complexity"),
+    ("trivial_regex", "style"),
+    ("trivially_copy_pass_by_ref", "perf"),
+    ("type_complexity", "complexity"),
+    ("type_repetition_in_bounds", "style"),
+    ("unbounded_reads", "restriction"),
+    ("unchecked_time_subtraction", "pedantic"),
+    ("undocumented_unsafe_blocks", "restriction"),
+    ("unicode_not_nfc", "pedantic"),
+    ("unimplemented", "restriction"),
+    ("unit_arg", "complexity"),
+    ("unit_cmp", "correctness"),
+    ("unknown_clippy_lints", "style"),
+    ("unnecessary_cast", "complexity"),
+    ("unnecessary_filter_map", "complexity"),
+    ("unnecessary_fold", "style"),
+    ("unnecessary_mut_passed", "style"),
+    ("unnecessary_operation", "complexity"),
+    ("unnecessary_unwrap", "nursery"),
+    ("unnecessary_visibility", "pedantic"),
+    ("unneeded_field_pattern", "style"),
+    ("unreadable_literal", "style"),
+    ("unsafe_removed_from_name", "style"),
+    ("unseparated_literal_suffix", "pedantic"),
+    ("unused_async", "pedantic"),
+    ("unused_collect", "perf"),
+    ("unused_io_amount", "correctness"),
+    ("unused_label", "complexity"),
+    ("unused_pure_result", "pedantic"),
+    ("unused_unit", "style"),
+    ("unwrap_in_result", "restriction"),
+    ("unwrap_write_to_string", "style"),
+    ("use_debug", "restriction"),
+    ("use_self", "pedantic"),
+    ("used_underscore_binding", "pedantic"),
+    ("useless_asref", "complexity"),
+    ("useless_attribute", "correctness"),
+    ("useless_format", "complexity"),
+    ("useless_let_if_seq", "style"),
+    ("useless_transmute", "complexity"),
+    ("useless_vec", "perf"),
+    ("vec_box", "complexity"),
+    ("vec_len_mutated_in_loop", "correctness"),
+    ("verbose_bit_mask", "style"),
+    ("while_immutable_condition", "correctness"),
+    ("while_let_loop", "complexity"),
+    ("while_let_on_iterator", "style"),
+    ("wildcard_dependencies", "cargo"),
+    ("wildcard_enum_match_arm", "restriction"),
+    ("write_literal", "style"),
+    ("write_with_newline", "style"),
+    ("writeln_empty_string", "style"),
+    ("wrong_pub_self_convention", "restriction"),
+    ("wrong_self_convention", "style"),
+    ("wrong_transmute", "correctness"),
+    ("zero_divided_by_zero", "complexity"),
+    ("zero_prefixed_literal", "complexity"),
+    ("zero_ptr", "style"),
+    ("zero_width_space", "correctness"),
+    // end lint groups, generated by `util/dev update_lints`
+];
+
+/// Returns the SARIF document of the diagnostics in cargo's JSON `messages`.
+pub fn document(messages: &[Value]) -> Value {
+    let mut rules: Vec<Value> = Vec::new();
+    let mut results: Vec<Value> = Vec::new();
+    for message in messages {
+        let diagnostic = &message["message"];
+        let result = match result(diagnostic) {
+            Some(result) => result,
+            None => continue,
+        };
+        // crates built for several targets report the same diagnostics
+        if results.contains(&result) {
+            continue;
+        }
+        if let Some(code) = diagnostic["code"]["code"].as_str() {
+            if !rules.iter().any(|rule| rule["id"] == code) {
+                rules.push(rule(code));
+            }
+        }
+        results.push(result);
+    }
+    json!({
+        "$schema": SCHEMA,
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "clippy",
+                    "informationUri": "https://github.com/rust-lang/rust-clippy",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "rules": rules,
+                },
+            },
+            // rustc counts the columns in characters
+            "columnKind": "unicodeCodePoints",
+            "results": results,
+        }],
+    })
+}
+
+/// Returns the rule of the lint or error `code`, with its group if it's a
+/// clippy lint.
+fn rule(code: &str) -> Value {
+    let mut rule = json!({ "id": code });
+    if code.starts_with("clippy::") {
+        let name = &code["clippy::".len()..];
+        rule["helpUri"] = json!(format!("{}#{}", DOCS_LINK, name));
+        if let Ok(index) = LINT_GROUPS.binary_search_by_key(&name, |&(lint, _)| lint) {
+            rule["properties"] = json!({ "category": LINT_GROUPS[index].1 });
+        }
+    }
+    rule
+}
+
+/// Returns the result of `diagnostic`, if it has a location. Code scanning
+/// tools ignore results without one, like the number of warnings.
+fn result(diagnostic: &Value) -> Option<Value> {
+    let locations: Vec<Value> = spans(diagnostic)
+        .filter(|span| span["is_primary"].as_bool() == Some(true))
+        .map(location)
+        .collect();
+    if locations.is_empty() {
+        return None;
+    }
+    let mut result = json!({
+        "level": level(diagnostic["level"].as_str()),
+        "message": { "text": diagnostic["message"] },
+        "locations": locations,
+    });
+    if let Some(code) = diagnostic["code"]["code"].as_str() {
+        result["ruleId"] = json!(code);
+    }
+    let fixes: Vec<Value> = diagnostic["children"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(fix)
+        .collect();
+    if !fixes.is_empty() {
+        result["fixes"] = json!(fixes);
+    }
+    Some(result)
+}
+
+/// Returns the fix of the suggestion `diagnostic`, with the applicability of
+/// the suggestion.
+fn fix(diagnostic: &Value) -> Option<Value> {
+    let mut changes: Vec<Value> = Vec::new();
+    let mut applicability = None;
+    for span in spans(diagnostic) {
+        let text = span["suggested_replacement"].as_str()?;
+        applicability = span["suggestion_applicability"].as_str().or(applicability);
+        changes.push(json!({
+            "artifactLocation": { "uri": span["file_name"] },
+            "replacements": [{
+                "deletedRegion": region(span),
+                "insertedContent": { "text": text },
+            }],
+        }));
+    }
+    if changes.is_empty() {
+        return None;
+    }
+    Some(json!({
+        "description": { "text": diagnostic["message"] },
+        "artifactChanges": changes,
+        "properties": { "applicability": applicability.unwrap_or("Unspecified") },
+    }))
+}
+
+fn spans(diagnostic: &Value) -> impl Iterator<Item = &Value> {
+    diagnostic["spans"].as_array().into_iter().flatten()
+}
+
+fn location(span: &Value) -> Value {
+    json!({
+        "physicalLocation": {
+            "artifactLocation": { "uri": span["file_name"] },
+            "region": region(span),
+        },
+    })
+}
+
+fn region(span: &Value) -> Value {
+    json!({
+        "startLine": span["line_start"],
+        "startColumn": span["column_start"],
+        "endLine": span["line_end"],
+        "endColumn": span["column_end"],
+    })
+}
+
+/// Returns the SARIF level of the rustc diagnostic level `level`.
+fn level(level: Option<&str>) -> &'static str {
+    match level {
+        Some("error") | Some("error: internal compiler error") => "error",
+        Some("warning") => "warning",
+        _ => "note",
+    }
+}
+
+#[cfg(test)]
+fn span(line: u64, columns: (u64, u64), is_primary: bool, replacement: Option<&str>) -> Value {
+    json!({
+        "file_name": "src/lib.rs",
+        "line_start": line,
+        "column_start": columns.0,
+        "line_end": line,
+        "column_end": columns.1,
+        "is_primary": is_primary,
+        "suggested_replacement": replacement,
+        "suggestion_applicability": replacement.map(|_| "MachineApplicable"),
+    })
+}
+
+#[cfg(test)]
+fn diagnostic(level: &str, code: Option<&str>, text: &str, spans: Vec<Value>, children: Vec<Value>) -> Value {
+    json!({
+        "level": level,
+        "code": code.map(|code| json!({ "code": code })),
+        "message": text,
+        "spans": spans,
+        "children": children,
+    })
+}
+
+#[cfg(test)]
+fn message(diagnostic: Value) -> Value {
+    json!({ "reason": "compiler-message", "message": diagnostic })
+}
+
+#[test]
+fn test_lint_groups() {
+    // `rule` looks the lints up with a binary search
+    assert!(LINT_GROUPS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    assert_eq!(
+        rule("clippy::box_vec"),
+        json!({
+            "id": "clippy::box_vec",
+            "helpUri": format!("{}#box_vec", DOCS_LINK),
+            "properties": { "category": "perf" },
+        })
+    );
+    assert_eq!(rule("E0308"), json!({ "id": "E0308" }));
+}
+
+#[test]
+fn test_document() {
+    let lint = message(diagnostic(
+        "warning",
+        Some("clippy::len_zero"),
+        "length comparison to zero",
+        vec![span(3, (8, 20), true, None)],
+        vec![diagnostic(
+            "help",
+            None,
+            "using `is_empty` is clearer and more explicit",
+            vec![span(3, (8, 20), true, Some("v.is_empty()"))],
+            vec![],
+        )],
+    ));
+    let messages = [
+        lint.clone(),
+        // built for another target
+        lint,
+        message(diagnostic(
+            "error",
+            Some("E0308"),
+            "mismatched types",
+            vec![span(5, (17, 19), true, None), span(5, (12, 14), false, None)],
+            vec![],
+        )),
+        // the summary has no location
+        message(diagnostic("warning", None, "2 warnings emitted", vec![], vec![])),
+    ];
+    assert_eq!(
+        document(&messages),
+        json!({
+            "$schema": SCHEMA,
+            "version": "2.1.0",
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": "clippy",
+                        "informationUri": "https://github.com/rust-lang/rust-clippy",
+                        "version": env!("CARGO_PKG_VERSION"),
+                        "rules": [
+                            {
+                                "id": "clippy::len_zero",
+                                "helpUri": format!("{}#len_zero", DOCS_LINK),
+                                "properties": { "category": "style" },
+                            },
+                            { "id": "E0308" },
+                        ],
+                    },
+                },
+                "columnKind": "unicodeCodePoints",
+                "results": [
+                    {
+                        "level": "warning",
+                        "message": { "text": "length comparison to zero" },
+                        "locations": [{
+                            "physicalLocation": {
+                                "artifactLocation": { "uri": "src/lib.rs" },
+                                "region": { "startLine": 3, "startColumn": 8, "endLine": 3, "endColumn": 20 },
+                            },
+                        }],
+                        "ruleId": "clippy::len_zero",
+                        "fixes": [{
+                            "description": { "text": "using `is_empty` is clearer and more explicit" },
+                            "artifactChanges": [{
+                                "artifactLocation": { "uri": "src/lib.rs" },
+                                "replacements": [{
+                                    "deletedRegion": {
+                                        "startLine": 3,
+                                        "startColumn": 8,
+                                        "endLine": 3,
+                                        "endColumn": 20,
+                                    },
+                                    "insertedContent": { "text": "v.is_empty()" },
+                                }],
+                            }],
+                            "properties": { "applicability": "MachineApplicable" },
+                        }],
+                    },
+                    {
+                        "level": "error",
+                        "message": { "text": "mismatched types" },
+                        "locations": [{
+                            "physicalLocation": {
+                                "artifactLocation": { "uri": "src/lib.rs" },
+                                "region": { "startLine": 5, "startColumn": 17, "endLine": 5, "endColumn": 19 },
+                            },
+                        }],
+                        "ruleId": "E0308",
+                    },
+                ],
+            }],
+        })
+    );
+}
+
+#[test]
+fn test_fix() {
+    let help = |spans| diagnostic("help", None, "swap the arguments", spans, vec![]);
+    let swap = fix(&help(vec![
+        span(1, (5, 6), true, Some("b")),
+        span(1, (8, 9), true, Some("a")),
+    ]))
+    .unwrap();
+    assert_eq!(swap["description"], json!({ "text": "swap the arguments" }));
+    assert_eq!(swap["artifactChanges"].as_array().map(Vec::len), Some(2));
+    assert_eq!(
+        swap["artifactChanges"][1]["replacements"][0],
+        json!({
+            "deletedRegion": { "startLine": 1, "startColumn": 8, "endLine": 1, "endColumn": 9 },
+            "insertedContent": { "text": "a" },
+        })
+    );
+    assert_eq!(swap["properties"]["applicability"], "MachineApplicable");
+    // helps without a suggestion
+    assert_eq!(fix(&help(vec![span(1, (5, 6), true, None)])), None);
+    assert_eq!(fix(&help(vec![])), None);
+}
@@ -0,0 +1,33 @@
+// compile-flags: --test
+
+#![warn(
+    clippy::option_unwrap_used,
+    clippy::result_unwrap_used,
+    clippy::unimplemented,
+    clippy::indexing_slicing
+)]
+#![allow(dead_code)]
+
+fn not_a_test(x: Option<u8>, y: Result<u8, ()>, v: &[u8]) -> u8 {
+    x.unwrap() + y.unwrap() + v[0]
+}
+
+fn unfinished() {
+    unimplemented!()
+}
+
+#[test]
+fn test_unwrap() {
+    let v = vec![1u8];
+    let x = Some(v[0]).unwrap();
+    let y = Ok::<u8, ()>(1).unwrap();
+    let _ = v[..1].len() + usize::from(x + y);
+}
+
+#[cfg(test)]
+mod tests {
+    fn helper() {
+        Some(1).unwrap();
+        unimplemented!()
+    }
+}
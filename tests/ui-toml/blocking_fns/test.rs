@@ -0,0 +1,22 @@
+// compile-flags: --edition 2018
+#![feature(async_await, await_macro, futures_api)]
+#![warn(clippy::blocking_in_async)]
+
+use std::env;
+use std::sync::mpsc::Receiver;
+
+fn compute() -> u32 {
+    42
+}
+
+async fn configured(receiver: Receiver<u32>) -> u32 {
+    let home = env::var("HOME").is_ok();
+    receiver.recv().unwrap() + compute() + home as u32
+}
+
+// still linted
+async fn builtin() {
+    std::thread::sleep(std::time::Duration::from_millis(10));
+}
+
+fn main() {}
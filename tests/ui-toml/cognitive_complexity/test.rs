@@ -0,0 +1,18 @@
+#![warn(clippy::cognitive_complexity)]
+
+fn main() {
+    one(true);
+    two(true, false);
+}
+
+fn one(a: bool) {
+    if a {
+        println!("a");
+    }
+}
+
+fn two(a: bool, b: bool) {
+    if a && b {
+        println!("a and b");
+    }
+}
@@ -0,0 +1,24 @@
+#![warn(clippy::detached_thread)]
+
+use std::thread;
+
+mod daemons {
+    pub fn start() {
+        std::thread::spawn(|| println!("daemon"));
+    }
+
+    pub fn stop() {
+        std::thread::spawn(|| println!("stopping"));
+    }
+}
+
+fn spawn_logger() {
+    thread::spawn(|| println!("logging"));
+}
+
+fn main() {
+    thread::spawn(|| println!("hello"));
+    daemons::start();
+    daemons::stop();
+    spawn_logger();
+}
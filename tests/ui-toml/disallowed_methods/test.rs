@@ -0,0 +1,27 @@
+#![warn(clippy::disallowed_methods)]
+
+use std::env;
+use std::io::{self, Write};
+
+mod helpers {
+    pub fn reset() {}
+    pub fn init() {}
+}
+
+fn main() {
+    env::set_var("RUST_LOG", "debug");
+    std::env::set_var("RUST_LOG", "debug");
+    let _ = env::var("RUST_LOG");
+
+    let mut v = vec![1, 2, 3];
+    v.clear();
+    Vec::clear(&mut v);
+    v.truncate(0);
+
+    let mut out = io::stdout();
+    let _ = out.flush();
+    let _ = out.write(b"hello");
+
+    helpers::reset();
+    helpers::init();
+}
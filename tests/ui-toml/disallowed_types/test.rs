@@ -0,0 +1,29 @@
+#![warn(clippy::disallowed_types)]
+#![allow(dead_code)]
+
+use std::cell::Cell;
+use std::collections::{BTreeMap, HashMap};
+
+type Map = HashMap<u32, u32>;
+
+struct Legacy;
+
+struct Counters {
+    by_id: BTreeMap<u32, u32>,
+    current: Cell<u32>,
+}
+
+fn lookup(map: &Map, key: u32) -> Option<u32> {
+    map.get(&key).cloned()
+}
+
+fn take(_counters: Vec<Option<Counters>>) {}
+
+fn replace(_: Legacy) -> Legacy {
+    Legacy
+}
+
+fn main() {
+    let _ = HashMap::<u8, u8>::new();
+    let _: Vec<Cell<u8>> = Vec::new();
+}
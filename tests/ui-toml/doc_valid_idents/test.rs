@@ -0,0 +1,5 @@
+#![warn(clippy::doc_markdown)]
+
+/// Works with WebGPU, AppKit and WebKit, but not with FooBar or Kit_Foo.
+/// The default words are still valid: GitHub, JavaScript and OpenGL.
+fn main() {}
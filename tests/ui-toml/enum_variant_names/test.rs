@@ -0,0 +1,24 @@
+#![warn(clippy::enum_variant_names)]
+#![allow(dead_code)]
+
+enum Method {
+    HttpGet,
+    HttpPost,
+}
+
+enum Response {
+    HttpOkStatus,
+    HttpNotFoundStatus,
+}
+
+enum Food {
+    FoodGood,
+    FoodBad,
+}
+
+enum Fruit {
+    AppleFruit,
+    PearFruit,
+}
+
+fn main() {}
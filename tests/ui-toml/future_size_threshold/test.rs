@@ -0,0 +1,20 @@
+// compile-flags: --edition 2018
+#![feature(async_await, await_macro, futures_api)]
+#![warn(clippy::large_futures)]
+
+async fn ready() -> u32 {
+    1
+}
+
+async fn medium() -> u8 {
+    let buffer = [0u8; 100];
+    await!(ready());
+    buffer[0]
+}
+
+async fn tiny() -> u32 {
+    let value = 1;
+    await!(ready()) + value
+}
+
+fn main() {}
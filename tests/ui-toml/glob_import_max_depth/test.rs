@@ -0,0 +1,57 @@
+#![warn(clippy::deep_glob_imports)]
+#![allow(dead_code)]
+
+mod shapes {
+    pub mod round {
+        pub fn area() {}
+        pub fn perimeter() {}
+        pub struct Circle;
+    }
+}
+
+mod a {
+    use crate::shapes::round::*;
+
+    fn f() {
+        area();
+        let _ = Circle;
+    }
+}
+
+mod b {
+    use super::shapes::round::*;
+
+    fn f() {
+        perimeter();
+    }
+}
+
+mod c {
+    // unused globs are reported by rustc
+    #[allow(unused_imports)]
+    use crate::shapes::round::*;
+}
+
+mod d {
+    // `crate`, `self` and `super` aren't counted
+    use super::shapes::*;
+
+    fn f() {
+        round::area();
+    }
+}
+
+mod e {
+    // names used as local variables aren't suggested
+    use crate::shapes::round::*;
+    use std::collections::hash_map::*;
+
+    fn f() {
+        let area = 1;
+        let _ = area;
+        perimeter();
+        let _: HashMap<u8, u8> = HashMap::new();
+    }
+}
+
+fn main() {}
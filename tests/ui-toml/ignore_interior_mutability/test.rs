@@ -0,0 +1,25 @@
+#![allow(dead_code)]
+
+use std::cell::{Cell, RefCell};
+use std::sync::atomic::AtomicUsize;
+
+struct Counter {
+    count: Cell<usize>,
+    step: usize,
+}
+
+const CELL: Cell<u8> = Cell::new(0);
+const COUNTER: Counter = Counter {
+    count: Cell::new(0),
+    step: 1,
+};
+const CELLS: [Cell<u8>; 2] = [Cell::new(0), Cell::new(1)];
+
+const ATOMIC: AtomicUsize = AtomicUsize::new(0);
+const MIXED: (Cell<u8>, RefCell<u8>) = (Cell::new(0), RefCell::new(0));
+
+fn main() {
+    let _ = &CELL;
+    let _ = COUNTER.count.get();
+    let _ = &ATOMIC;
+}
@@ -0,0 +1,9 @@
+// compile-flags: -A clippy::doc_markdown
+
+/// Flags on the command line, like the one allowing `doc_markdown` for
+/// this FooBar, override the configured lint groups.
+fn main() {
+    let _ = 1;
+    fn inner() {}
+    inner();
+}
@@ -0,0 +1,12 @@
+#![warn(clippy::unreadable_literal, clippy::hex_literal_case)]
+
+fn main() {
+    let _ = 123456789;
+    let _ = 0xABCDEF01_u32;
+    let _ = 0xab_cd;
+
+    // ok
+    let _ = 1234567;
+    let _ = 1234_5678;
+    let _ = 0xAB_CD;
+}
@@ -0,0 +1,10 @@
+#![warn(clippy::lock_unwrap)]
+#![allow(clippy::immediately_dropped_lock)]
+
+use std::sync::Mutex;
+
+fn main() {
+    let mutex = Mutex::new(0);
+    let _ = mutex.lock().unwrap();
+    let _ = mutex.lock().expect("a thread panicked while counting");
+}
@@ -0,0 +1,36 @@
+// compile-flags: --cfg test
+#![warn(clippy::missing_docs_in_private_items)]
+
+//! Some docs for the crate
+
+struct Private;
+pub struct Unit;
+
+fn private_fn() {}
+pub(crate) fn crate_fn() {}
+pub fn pub_fn() {}
+
+pub mod module {
+    //! Some docs for the module
+
+    pub(super) fn super_fn() {}
+    pub(in crate) fn in_crate_fn() {}
+}
+
+/// Some docs
+pub struct Fields {
+    field: u32,
+    pub(crate) crate_field: u32,
+}
+
+/// Some docs
+pub(crate) enum Enum {
+    Variant,
+}
+
+#[cfg(test)]
+mod tests {
+    pub fn helper() {}
+}
+
+fn main() {}
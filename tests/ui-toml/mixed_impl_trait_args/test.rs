@@ -0,0 +1,33 @@
+#![warn(clippy::mixed_impl_trait_args)]
+
+use std::fmt::Display;
+
+fn mixed<T: Clone>(_: T, _: impl Display) {}
+
+fn mixed_where<T>(_: T, _: impl Display)
+where
+    T: Clone,
+{
+}
+
+// ok, `T` can't be replaced by `impl Clone`
+fn returned<T: Clone>(x: T, _: impl Display) -> T {
+    x
+}
+
+fn two_args<T: Clone>(_: T, _: T, _: impl Display) {}
+
+fn other_bound<T: Clone, U: Into<T>>(_: U, _: impl Display) {}
+
+fn in_body<T: Default>(_: T, _: impl Display) {
+    let _ = T::default();
+}
+
+// ok, `impl Trait` arguments are newer than the MSRV
+#[clippy::msrv = "1.25"]
+fn old<T: Clone>(_: T, _: impl Display) {}
+
+// ok
+fn only_impl_trait(_: impl Clone, _: impl Display) {}
+
+fn main() {}
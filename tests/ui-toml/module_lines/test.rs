@@ -0,0 +1,24 @@
+#![warn(clippy::too_many_lines_in_module)]
+
+mod small {
+    fn a() {}
+}
+
+mod big {
+    // comments don't count
+    fn a() {}
+
+    fn b() {
+        /* neither do
+         * block comments */
+        let _ = 1;
+    }
+
+    #[cfg(any())]
+    fn c() {
+        let _ = 2;
+    }
+    fn d() {}
+}
+
+fn main() {}
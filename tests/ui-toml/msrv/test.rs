@@ -0,0 +1,20 @@
+#![warn(clippy::range_plus_one, clippy::question_mark)]
+
+fn for_range(n: usize) {
+    for _ in 0..n + 1 {}
+}
+
+fn first(v: &[u8]) -> Option<u8> {
+    let first = v.first();
+    if first.is_none() {
+        return None;
+    }
+    first.cloned()
+}
+
+#[clippy::msrv = "1.26"]
+fn for_range_newer(n: usize) {
+    for _ in 0..n + 1 {}
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+#![warn(clippy::naming_rules)]
+#![allow(dead_code, non_upper_case_globals)]
+
+static counter: u32 = 0;
+static COUNTER: u32 = 0;
+
+fn conn_mgr() {}
+fn handle_request() {}
+fn handler() {}
+
+fn main() {
+    let str_name = "foo";
+    let name = str_name;
+    let _ = name;
+}
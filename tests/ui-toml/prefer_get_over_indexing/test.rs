@@ -0,0 +1,7 @@
+#![warn(clippy::get_unwrap)]
+
+fn main() {
+    let mut some_vec = vec![0, 1, 2, 3];
+    let _ = some_vec.get(0).unwrap();
+    *some_vec.get_mut(1).unwrap() = 5;
+}
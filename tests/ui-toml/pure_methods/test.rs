@@ -0,0 +1,23 @@
+#![warn(clippy::unused_pure_result)]
+
+struct Point(i32, i32);
+
+impl Point {
+    fn moved(&self, x: i32) -> Point {
+        Point(self.0 + x, self.1)
+    }
+
+    fn shift(&mut self, x: i32) -> i32 {
+        self.0 += x;
+        self.0
+    }
+}
+
+fn main() {
+    let mut p = Point(0, 0);
+    p.moved(1);
+    // not configured
+    p.shift(1);
+    // the built-in methods are still linted
+    p.0.checked_add(1);
+}
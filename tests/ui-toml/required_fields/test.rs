@@ -0,0 +1,39 @@
+#![warn(clippy::defaulted_required_fields)]
+
+#[derive(Default)]
+struct User {
+    id: u64,
+    name: String,
+    handle: u32,
+}
+
+#[derive(Default)]
+struct Connection {
+    handle: u32,
+    retries: u32,
+}
+
+fn main() {
+    let u = User {
+        name: "foo".to_owned(),
+        ..Default::default()
+    };
+    let _ = User {
+        id: 1,
+        name: "foo".to_owned(),
+        ..Default::default()
+    };
+    // only bases which are default values
+    let _ = User {
+        name: "bar".to_owned(),
+        ..u
+    };
+    let _ = Connection {
+        retries: 3,
+        ..Connection::default()
+    };
+    let _ = Connection {
+        handle: 7,
+        ..Default::default()
+    };
+}
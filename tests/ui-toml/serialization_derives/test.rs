@@ -0,0 +1,35 @@
+#![warn(clippy::serialized_int128)]
+#![allow(dead_code)]
+
+trait Encode {
+    fn encode(&self) -> Vec<u8>;
+}
+
+// stands in for a derive macro
+macro_rules! derive_encode {
+    ($name:ident) => {
+        impl Encode for $name {
+            fn encode(&self) -> Vec<u8> {
+                Vec::new()
+            }
+        }
+    };
+}
+
+struct Encoded {
+    amount: u128,
+}
+
+derive_encode!(Encoded);
+
+struct Manual {
+    amount: u128,
+}
+
+impl Encode for Manual {
+    fn encode(&self) -> Vec<u8> {
+        self.amount.to_le_bytes().to_vec()
+    }
+}
+
+fn main() {}
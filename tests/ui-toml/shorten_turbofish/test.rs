@@ -0,0 +1,13 @@
+#![warn(clippy::redundant_turbofish)]
+
+use std::collections::HashMap;
+
+fn main() {
+    let v: Vec<i32> = (0..10).collect::<Vec<i32>>();
+    let m: HashMap<i32, i32> = v.iter().map(|&x| (x, x)).collect::<HashMap<i32, i32>>();
+    // ok: already shortened
+    let w: Vec<i32> = (0..10).collect::<Vec<_>>();
+    // ok: nothing to shorten
+    let n: Result<u32, std::num::ParseIntError> = "12".parse::<u32>();
+    let _ = (m, n, w);
+}
@@ -0,0 +1,26 @@
+#![warn(clippy::too_many_arguments)]
+#![allow(dead_code)]
+
+fn good(_one: u32, _two: u32, _three: bool) {}
+
+fn draw(_x: u32, _y: u32, _visible: bool, _label: &str) {}
+
+// lifetimes aren't counted
+fn good_generics<'a, T, U>(_t: &'a T, _u: U) {}
+
+fn generic<A, B, C>(_a: A, _b: B, _c: C) {}
+
+struct Image;
+
+impl Image {
+    // `self` isn't counted
+    fn crop(&mut self, _x: u32, _y: u32, _keep_ratio: bool) {}
+
+    fn resize(&mut self, _width: u32, (_x, _y): (u32, u32), _keep_ratio: bool, _filter: u8) {}
+}
+
+trait Render {
+    fn render(&self, target: u32, width: u32, height: u32, scale: f32);
+}
+
+fn main() {}
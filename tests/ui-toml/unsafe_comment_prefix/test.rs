@@ -0,0 +1,12 @@
+#![warn(clippy::undocumented_unsafe_blocks)]
+
+fn main() {
+    let x = 1;
+    let p = &x as *const i32;
+
+    // Safety: `p` points to `x`
+    let _ = unsafe { *p };
+
+    // SAFETY: `p` points to `x`
+    let _ = unsafe { *p };
+}
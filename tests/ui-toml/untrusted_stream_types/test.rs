@@ -0,0 +1,22 @@
+#![warn(clippy::unbounded_reads)]
+
+use std::io::{self, Read};
+use std::net::TcpStream;
+
+struct Connection;
+
+impl Read for Connection {
+    fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+        Ok(0)
+    }
+}
+
+fn read_all(mut connection: Connection, mut stream: TcpStream) -> io::Result<Vec<u8>> {
+    let mut body = Vec::new();
+    connection.read_to_end(&mut body)?;
+    // only the configured types are linted
+    stream.read_to_end(&mut body)?;
+    Ok(body)
+}
+
+fn main() {}
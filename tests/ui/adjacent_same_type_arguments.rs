@@ -0,0 +1,26 @@
+#![warn(clippy::adjacent_same_type_arguments)]
+
+pub fn resize(width: u32, height: u32) {}
+
+pub fn open(path: &str, read: bool, write: bool, create: bool) {}
+
+pub struct Canvas;
+
+impl Canvas {
+    pub fn draw(&self, x: f32, y: f32, color: u32) {}
+}
+
+pub trait Shape {
+    fn scale(&mut self, x: f64, y: f64);
+}
+
+// not linted: not public
+fn private(width: u32, height: u32) {}
+
+// not linted: not adjacent
+pub fn mixed(width: u32, fill: bool, height: u32) {}
+
+// not linted: not primitive
+pub fn names(first: &str, last: &str) {}
+
+fn main() {}
@@ -0,0 +1,36 @@
+#![warn(clippy::and_then_instead_of_map, clippy::or_else_instead_of_or)]
+#![allow(clippy::manual_filter)]
+
+fn compute() -> i32 {
+    42
+}
+
+fn main() {
+    let opt = Some(1);
+    let res: Result<i32, ()> = Ok(1);
+    let x = 2;
+
+    let _ = opt.and_then(|x| Some(x + 1));
+    let _ = res.and_then(|x| Ok(x * 2));
+    let _ = opt.and_then(|x| {
+        let y = x + 1;
+        Some(y)
+    });
+    let _ = opt.or_else(|| Some(0));
+    let _ = res.or_else(|_| Ok(x));
+
+    // not always `Some`
+    let _ = opt.and_then(|x| if x > 0 { Some(x) } else { None });
+    let _ = opt.and_then(|x| {
+        if x > 0 {
+            return None;
+        }
+        Some(x)
+    });
+    let _ = opt.and_then(|x| Some(x.checked_add(1)?));
+
+    // not cheap, or using the error
+    let _ = opt.or_else(|| Some(compute()));
+    let err: Result<i32, i32> = Err(1);
+    let _ = err.or_else(|e| Ok(-e));
+}
@@ -0,0 +1,43 @@
+// compile-flags: --edition 2018
+#![feature(async_await, await_macro, futures_api)]
+#![warn(clippy::await_holding_lock)]
+
+use std::future::Future;
+use std::sync::{Mutex, RwLock};
+
+async fn ready() -> u32 {
+    1
+}
+
+async fn mutex(m: &Mutex<u32>) -> u32 {
+    let guard = m.lock().unwrap();
+    await!(ready()) + *guard
+}
+
+async fn rwlock(l: &RwLock<u32>) {
+    let mut guard = l.write().unwrap();
+    *guard += await!(ready());
+}
+
+fn block(m: &Mutex<u32>) -> impl Future<Output = u32> + '_ {
+    async move {
+        let guard = m.lock().unwrap();
+        await!(ready()) + *guard
+    }
+}
+
+// the guard is dropped before the `await!`
+async fn scoped(m: &Mutex<u32>) -> u32 {
+    let value = {
+        let guard = m.lock().unwrap();
+        *guard
+    };
+    await!(ready()) + value
+}
+
+async fn no_await(m: &Mutex<u32>) -> u32 {
+    let guard = m.lock().unwrap();
+    *guard
+}
+
+fn main() {}
@@ -0,0 +1,41 @@
+// compile-flags: --edition 2018
+#![feature(async_await, await_macro, futures_api)]
+#![warn(clippy::blocking_in_async)]
+
+use std::future::Future;
+use std::io;
+use std::thread;
+use std::time::Duration;
+
+async fn sleeps() {
+    thread::sleep(Duration::from_millis(10));
+}
+
+async fn reads(path: &str) -> io::Result<String> {
+    std::fs::read_to_string(path)
+}
+
+async fn reads_line() -> io::Result<String> {
+    let mut line = String::new();
+    io::stdin().read_line(&mut line)?;
+    Ok(line)
+}
+
+fn blocks() -> impl Future<Output = ()> {
+    async {
+        let handle = thread::spawn(|| ());
+        handle.join().unwrap();
+    }
+}
+
+// not linted: not async
+fn sync_sleep() {
+    thread::sleep(Duration::from_millis(10));
+}
+
+// not linted: the closure runs somewhere else
+async fn in_closure() -> thread::JoinHandle<()> {
+    thread::spawn(|| thread::sleep(Duration::from_millis(10)))
+}
+
+fn main() {}
@@ -0,0 +1,138 @@
+#![warn(clippy::boxed_trait_object_arg)]
+#![allow(clippy::needless_pass_by_value, clippy::borrowed_box)]
+
+use std::any::Any;
+use std::fmt::{Debug, Display};
+
+trait Shape {
+    fn area(&self) -> f64;
+}
+
+struct Square(f64);
+
+impl Shape for Square {
+    fn area(&self) -> f64 {
+        self.0 * self.0
+    }
+}
+
+fn show(value: Box<dyn Display>) {
+    println!("{}", *value);
+}
+
+fn area(shape: Box<dyn Shape>) -> f64 {
+    shape.area()
+}
+
+fn debug_both(a: Box<dyn Debug>, b: Box<dyn Debug + Send>) {
+    println!("{:?} {:?}", *a, *b);
+}
+
+// ok: the box is returned
+fn identity(shape: Box<dyn Shape>) -> Box<dyn Shape> {
+    shape
+}
+
+// ok: the box is stored
+struct Holder {
+    shapes: Vec<Box<dyn Shape>>,
+}
+
+impl Holder {
+    fn push(&mut self, shape: Box<dyn Shape>) {
+        self.shapes.push(shape);
+    }
+}
+
+// ok: the box is moved into another binding
+fn rebind(shape: Box<dyn Shape>) -> f64 {
+    let moved = shape;
+    moved.area()
+}
+
+// ok: consuming methods need the box
+fn downcast(value: Box<dyn Any>) -> Option<Box<u32>> {
+    value.downcast().ok()
+}
+
+// ok: not a trait object
+fn boxed_square(square: Box<Square>) -> f64 {
+    square.area()
+}
+
+trait Visitor {
+    fn visit(&self, shape: Box<dyn Shape>);
+}
+
+// ok: the signature is given by the trait
+impl Visitor for Square {
+    fn visit(&self, shape: Box<dyn Shape>) {
+        println!("{}", shape.area());
+    }
+}
+
+// ok: the box is borrowed
+fn borrowed(shape: &Box<dyn Shape>) -> f64 {
+    shape.area()
+}
+
+// ok: the box itself is formatted
+fn formatted(value: Box<dyn Debug>) {
+    println!("{:?}", value);
+}
+
+// ok: the box is moved into a closure
+fn moved_into_closure(shape: Box<dyn Shape>) -> impl Fn() -> f64 {
+    move || shape.area()
+}
+
+// ok: the box is captured by a closure
+fn captured(shape: Box<dyn Shape>) -> f64 {
+    let area = || shape.area();
+    area()
+}
+
+fn closure_args() -> f64 {
+    // ok: the arguments of closures are given by their callers
+    let area = |shape: Box<dyn Shape>| shape.area();
+    area(Box::new(Square(1.0)))
+}
+
+// ok: exported
+pub fn exported(shape: Box<dyn Shape>) -> f64 {
+    shape.area()
+}
+
+trait Describe {
+    // ok: a provided method, taking `impl Trait` would make the trait not object safe
+    fn describe(&self, shape: Box<dyn Shape>) {
+        println!("{}", shape.area());
+    }
+}
+
+impl Describe for Square {}
+
+#[clippy::msrv = "1.25"]
+fn old(shape: Box<dyn Shape>) -> f64 {
+    shape.area()
+}
+
+fn main() {
+    show(Box::new(1));
+    area(Box::new(Square(1.0)));
+    debug_both(Box::new(1), Box::new(2));
+    identity(Box::new(Square(1.0)));
+    Holder { shapes: Vec::new() }.push(Box::new(Square(1.0)));
+    rebind(Box::new(Square(1.0)));
+    downcast(Box::new(1_u32));
+    boxed_square(Box::new(Square(1.0)));
+    Square(1.0).visit(Box::new(Square(2.0)));
+    borrowed(&(Box::new(Square(1.0)) as Box<dyn Shape>));
+    formatted(Box::new(1));
+    moved_into_closure(Box::new(Square(1.0)));
+    captured(Box::new(Square(1.0)));
+    closure_args();
+    exported(Box::new(Square(1.0)));
+    Square(1.0).describe(Box::new(Square(2.0)));
+    old(Box::new(Square(1.0)));
+}
@@ -0,0 +1,79 @@
+#![warn(clippy::boxed_trait_object_return)]
+
+use std::fmt::{Debug, Display};
+
+fn numbers() -> Box<dyn Iterator<Item = u32>> {
+    Box::new(0..10)
+}
+
+fn describe(verbose: bool) -> Box<dyn Display> {
+    if verbose {
+        return Box::new(String::from("a verbose description"));
+    }
+    Box::new(String::from("short"))
+}
+
+fn pick(n: u32) -> Box<dyn Debug + 'static> {
+    match n {
+        0 => Box::new(1_u32),
+        1 => Box::new(2_u32),
+        _ => panic!("unexpected"),
+    }
+}
+
+// ok: different types
+fn either(flag: bool) -> Box<dyn Display> {
+    if flag {
+        Box::new(1)
+    } else {
+        Box::new("one")
+    }
+}
+
+// ok: already a trait object
+fn forward() -> Box<dyn Display> {
+    describe(false)
+}
+
+// ok: closures have distinct types
+fn adder(flag: bool) -> Box<dyn Fn(u32) -> u32> {
+    if flag {
+        Box::new(|x| x + 1)
+    } else {
+        Box::new(|x| x + 2)
+    }
+}
+
+// ok: not a trait object
+fn boxed() -> Box<u32> {
+    Box::new(1)
+}
+
+trait Source {
+    fn numbers(&self) -> Box<dyn Iterator<Item = u32>>;
+}
+
+// ok: the signature is given by the trait
+impl Source for u32 {
+    fn numbers(&self) -> Box<dyn Iterator<Item = u32>> {
+        Box::new(0..*self)
+    }
+}
+
+// ok: `impl Trait` is newer than the MSRV
+#[clippy::msrv = "1.25"]
+fn old_numbers() -> Box<dyn Iterator<Item = u32>> {
+    Box::new(0..10)
+}
+
+fn main() {
+    numbers();
+    describe(true);
+    pick(0);
+    either(true);
+    forward();
+    adder(true);
+    boxed();
+    1_u32.numbers();
+    old_numbers();
+}
@@ -0,0 +1,14 @@
+fn main() {
+    let (a, b, c) = (1, 2, 3);
+    let flag = true;
+
+    let _ = ((a < b) as i32) < c;
+    let _ = a <= (b <= c) as i32;
+    let _ = (a > b) > flag;
+    let _ = (a < b) < (b < c);
+
+    // ok
+    let _ = a < b && b < c;
+    let _ = (a < b) == flag;
+    let _ = ((a == b) as i32) < c;
+}
@@ -0,0 +1,106 @@
+#![allow(clippy::all)]
+#![warn(clippy::cognitive_complexity)]
+#![allow(unused)]
+
+fn main() {}
+
+#[clippy::cognitive_complexity = "0"]
+mod scores {
+    fn straight(x: u8) -> u8 {
+        let y = x + 1;
+        y * 2
+    }
+
+    // a flat `match` counts once, however many arms it has
+    fn flat_match(x: u8) -> &'static str {
+        match x {
+            0 => "zero",
+            1 => "one",
+            2 => "two",
+            3 => "three",
+            4 => "four",
+            _ => "many",
+        }
+    }
+
+    fn nested_ifs(a: bool, b: bool, c: bool) {
+        if a {
+            if b {
+                if c {
+                    println!("abc");
+                }
+            }
+        }
+    }
+
+    fn else_chain(x: u8) {
+        if x == 0 {
+            println!("zero");
+        } else if x == 1 {
+            println!("one");
+        } else {
+            println!("many");
+        }
+    }
+
+    fn if_let(x: Option<u8>) {
+        if let Some(x) = x {
+            println!("{}", x);
+        } else if let None = x {
+            println!("none");
+        }
+    }
+
+    fn logical(a: bool, b: bool, c: bool, d: bool) {
+        if a && b && c || d {
+            println!("yes");
+        }
+    }
+
+    fn loops(v: &[u8]) {
+        'outer: for i in v {
+            let mut n = *i;
+            while n > 0 {
+                if n == 5 {
+                    continue 'outer;
+                }
+                n -= 1;
+            }
+        }
+    }
+
+    fn while_let(mut v: Vec<u8>) {
+        while let Some(x) = v.pop() {
+            println!("{}", x);
+        }
+    }
+
+    fn closure(v: &[u8]) -> Vec<u8> {
+        v.iter().map(|&x| if x > 5 { x } else { 0 }).collect()
+    }
+
+    // early exits don't count
+    fn early_exits(x: Option<u8>) -> Option<u8> {
+        let y = x?;
+        if y == 0 {
+            return None;
+        }
+        Some(y)
+    }
+
+    fn macros(x: u8) {
+        assert!(x > 0);
+        assert_eq!(x, 1);
+    }
+}
+
+#[clippy::cognitive_complexity = "5"]
+fn below_limit(a: bool, b: bool) {
+    if a {
+        if b {
+            println!("ab");
+        }
+    } else {
+        println!("not a");
+    }
+}
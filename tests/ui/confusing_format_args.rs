@@ -0,0 +1,21 @@
+#![warn(clippy::confusing_format_args)]
+
+use std::io::Write;
+
+fn main() {
+    let (a, b) = (1, 2);
+
+    // lint
+    println!("{} {0}", a);
+    println!("{1} {0}", a, b);
+    let _ = format!("{} {1}", a, b);
+    let mut v = Vec::new();
+    writeln!(&mut v, "{1} {0} {1}", a, b).unwrap();
+
+    // these are fine
+    println!("{} {}", a, b);
+    println!("{0} {1} {0}", a, b);
+    println!("{a} {b}", a = a, b = b);
+    println!("{0} {b}", a, b = b);
+    let _ = format!("{0}{{}}{1}", a, b);
+}
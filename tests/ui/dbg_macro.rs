@@ -20,4 +20,6 @@ fn main() {
     dbg!(42);
     dbg!(dbg!(dbg!(42)));
     foo(3) + dbg!(factorial(4));
+    let (a, b) = (1, 2);
+    let _ = dbg!(a + b) * 2;
 }
@@ -0,0 +1,12 @@
+// run-rustfix
+
+#![warn(clippy::dbg_macro)]
+
+fn double(n: u32) -> u32 {
+    dbg!(n * 2)
+}
+
+fn main() {
+    let x = dbg!(double(3));
+    let _ = dbg!(x + 1);
+}
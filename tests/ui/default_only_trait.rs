@@ -0,0 +1,76 @@
+#![warn(clippy::default_only_trait)]
+#![allow(dead_code)]
+
+struct Person;
+struct Robot;
+
+trait Greet {
+    fn greet(&self) {
+        println!("hello");
+    }
+}
+
+impl Greet for Person {}
+
+trait Log {
+    fn log(message: &str) {
+        println!("{}", message);
+    }
+}
+
+// ok: more than one implementor
+trait Wave {
+    fn wave(&self) {}
+}
+
+impl Wave for Person {}
+impl Wave for Robot {}
+
+// ok: a required method
+trait Name {
+    fn name(&self) -> String;
+
+    fn shout(&self) -> String {
+        self.name().to_uppercase()
+    }
+}
+
+impl Name for Person {
+    fn name(&self) -> String {
+        String::from("person")
+    }
+}
+
+// ok: an associated type
+trait Output {
+    type Item;
+
+    fn nothing(&self) {}
+}
+
+// ok: blanket implementation
+trait DebugExt: std::fmt::Debug {
+    fn print(&self) {
+        println!("{:?}", self);
+    }
+}
+
+impl<T: std::fmt::Debug> DebugExt for T {}
+
+// ok: exported
+pub trait Exported {
+    fn exported(&self) {}
+}
+
+// ok: allowed on the trait
+#[allow(clippy::default_only_trait)]
+trait Allowed {
+    fn allowed(&self) {}
+}
+
+fn main() {
+    Person.greet();
+    <Person as Wave>::wave(&Person);
+    Person.shout();
+    1.print();
+}
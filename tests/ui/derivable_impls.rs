@@ -0,0 +1,115 @@
+#![allow(dead_code)]
+
+struct Config {
+    verbose: bool,
+    level: u32,
+    ratio: f64,
+    name: String,
+    label: &'static str,
+    separator: char,
+    limit: Option<usize>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            verbose: false,
+            level: 0,
+            ratio: 0.0,
+            name: Default::default(),
+            label: "",
+            separator: '\0',
+            limit: None,
+        }
+    }
+}
+
+struct Tuple(u32, Vec<u8>);
+
+impl Default for Tuple {
+    fn default() -> Self {
+        Tuple(0, Vec::default())
+    }
+}
+
+struct Unit;
+
+impl Default for Unit {
+    fn default() -> Self {
+        Unit
+    }
+}
+
+// no lint, `#[default]` is newer than the compiler, which is the MSRV if none is set
+enum Mode {
+    Fast,
+    Slow(u32),
+}
+
+impl Default for Mode {
+    fn default() -> Self {
+        Mode::Fast
+    }
+}
+
+// not the default of `level`
+struct NonZero {
+    level: u32,
+}
+
+impl Default for NonZero {
+    fn default() -> Self {
+        Self { level: 1 }
+    }
+}
+
+// the derive would require `T: Default`
+struct Generic<T> {
+    items: Vec<T>,
+}
+
+impl<T> Default for Generic<T> {
+    fn default() -> Self {
+        Self { items: Vec::new() }
+    }
+}
+
+// non-unit variants can't be `#[default]`
+enum Data {
+    Empty(u32),
+}
+
+impl Default for Data {
+    fn default() -> Self {
+        Data::Empty(0)
+    }
+}
+
+#[clippy::msrv = "1.62"]
+mod new {
+    pub enum Mode {
+        Fast,
+        Slow(u32),
+    }
+
+    impl Default for Mode {
+        fn default() -> Self {
+            Mode::Fast
+        }
+    }
+}
+
+#[clippy::msrv = "1.61"]
+mod old {
+    pub enum Mode {
+        Fast,
+    }
+
+    impl Default for Mode {
+        fn default() -> Self {
+            Mode::Fast
+        }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+#![warn(clippy::detached_thread)]
+
+use std::thread;
+
+fn spawn_logger() {
+    thread::spawn(|| println!("logging"));
+}
+
+fn main() {
+    thread::spawn(|| println!("hello"));
+    let _ = thread::spawn(|| println!("hello"));
+    thread::Builder::new().spawn(|| println!("hello")).unwrap();
+
+    let handle = thread::spawn(|| println!("hello"));
+    handle.join().unwrap();
+    let _handle = thread::spawn(|| println!("hello"));
+    spawn_logger();
+}
@@ -0,0 +1,49 @@
+#![warn(clippy::missing_errors_doc)]
+
+use std::io;
+
+/// Reads the config.
+pub fn read_config() -> Result<String, io::Error> {
+    unimplemented!();
+}
+
+/// Reads the config, with an alias of `Result`.
+pub fn read_config_alias() -> io::Result<String> {
+    unimplemented!();
+}
+
+/// Reads the config.
+///
+/// # Errors
+///
+/// Fails if there is no config.
+pub fn documented() -> Result<String, io::Error> {
+    unimplemented!();
+}
+
+/// Private functions don't need to document their errors.
+fn private() -> Result<String, io::Error> {
+    unimplemented!();
+}
+
+pub struct Config;
+
+impl Config {
+    /// Parses the config.
+    pub fn parse(_text: &str) -> Result<Self, ()> {
+        unimplemented!();
+    }
+}
+
+impl std::str::FromStr for Config {
+    type Err = ();
+
+    /// The errors of trait methods are documented by the trait.
+    fn from_str(text: &str) -> Result<Self, ()> {
+        Self::parse(text)
+    }
+}
+
+fn main() {
+    let _ = private();
+}
@@ -0,0 +1,62 @@
+#![warn(clippy::missing_panics_doc)]
+
+/// Parses the number.
+pub fn unwrap(text: &str) -> u32 {
+    text.parse().unwrap()
+}
+
+/// Returns the first byte.
+pub fn index(bytes: &[u8]) -> u8 {
+    bytes[0]
+}
+
+/// Checks the length.
+pub fn assert(bytes: &[u8]) {
+    assert!(bytes.len() > 1, "too short");
+}
+
+/// Parses the numbers.
+pub fn closure(texts: &[&str]) -> Vec<u32> {
+    texts.iter().map(|text| text.parse().unwrap()).collect()
+}
+
+pub struct Parser;
+
+impl Parser {
+    /// Always fails.
+    pub fn parse(&self) {
+        panic!("can't parse");
+    }
+}
+
+/// Returns the first byte.
+///
+/// # Panics
+///
+/// Panics if `bytes` is empty.
+pub fn documented(bytes: &[u8]) -> u8 {
+    bytes[0]
+}
+
+/// Only checks in debug builds.
+pub fn debug_assert(bytes: &[u8]) {
+    debug_assert!(!bytes.is_empty());
+}
+
+/// Never reaches the end.
+pub fn unreachable(x: bool) -> u32 {
+    if x {
+        1
+    } else {
+        unreachable!()
+    }
+}
+
+/// Private functions don't need to document their panics.
+fn private(bytes: &[u8]) -> u8 {
+    bytes[0]
+}
+
+fn main() {
+    private(&[1]);
+}
@@ -0,0 +1,33 @@
+// compile-flags: --test
+
+#![warn(clippy::missing_panics_doc)]
+
+/// Parses the number.
+pub fn unwrap(text: &str) -> u32 {
+    text.parse().unwrap()
+}
+
+/// Checks the parser.
+#[cfg(test)]
+pub fn check(text: &str) -> u32 {
+    text.parse().unwrap()
+}
+
+/// Only checks in tests.
+pub fn statement(text: &str) {
+    #[cfg(test)]
+    let _: u32 = text.parse().unwrap();
+}
+
+#[cfg(test)]
+pub mod helpers {
+    /// Returns the first byte.
+    pub fn first(bytes: &[u8]) -> u8 {
+        bytes[0]
+    }
+}
+
+#[test]
+fn test_unwrap() {
+    assert_eq!(unwrap("1"), 1);
+}
@@ -0,0 +1,66 @@
+/// This is not sufficiently documented.
+pub unsafe fn destroy_the_planet() {
+    unimplemented!();
+}
+
+/// This one is.
+///
+/// # Safety
+///
+/// This function shouldn't be called unless the horsemen are ready
+pub unsafe fn apocalypse(universe: &mut ()) {
+    unimplemented!();
+}
+
+/// This is a private function, so docs aren't necessary.
+unsafe fn you_dont_see_me() {
+    unimplemented!();
+}
+
+/// Implementors have to be careful.
+pub unsafe trait Helper {}
+
+/// Implementors have to be careful.
+///
+/// # Safety
+///
+/// The helper must not help.
+pub unsafe trait DocumentedHelper {}
+
+// Undocumented items are left to `missing_docs`.
+pub unsafe fn undocumented() {
+    unimplemented!();
+}
+
+fn main() {
+    unsafe {
+        you_dont_see_me();
+        destroy_the_planet();
+        apocalypse(&mut ());
+    }
+}
+
+pub struct Struct;
+
+impl Struct {
+    /// Not documented enough either.
+    pub unsafe fn method() {
+        unimplemented!();
+    }
+}
+
+pub trait Trait {
+    /// Calls `method`.
+    ///
+    /// # Safety
+    ///
+    /// Never.
+    unsafe fn documented();
+}
+
+impl Trait for Struct {
+    /// The safety of trait methods is documented by the trait.
+    unsafe fn documented() {
+        Self::method();
+    }
+}
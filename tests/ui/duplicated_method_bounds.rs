@@ -0,0 +1,128 @@
+#![warn(clippy::duplicated_method_bounds)]
+#![allow(dead_code)]
+
+use std::fmt::Debug;
+
+struct Foo<T>(T);
+
+impl<T> Foo<T> {
+    fn a(&self)
+    where
+        T: Clone,
+    {
+    }
+    fn b(&self)
+    where
+        T: Clone + Debug,
+    {
+    }
+    fn c(&self) -> T
+    where
+        T: Clone,
+    {
+        self.0.clone()
+    }
+}
+
+struct Bar<F>(F);
+
+impl<F> Bar<F> {
+    fn a(&self)
+    where
+        for<'a> F: Fn(&'a u8),
+    {
+    }
+    fn b(&self)
+    where
+        for<'b> F: Fn(&'b u8),
+    {
+    }
+    fn c(&self)
+    where
+        for<'c> F: Fn(&'c u8),
+    {
+    }
+}
+
+trait Tr<T> {
+    fn a(&self)
+    where
+        T: Default;
+    fn b(&self)
+    where
+        T: Default;
+    fn c(&self)
+    where
+        T: Default,
+    {
+    }
+}
+
+// ok, the bounds differ between the methods
+impl<T> Bar<T> {
+    fn d(&self)
+    where
+        T: Clone,
+    {
+    }
+    fn e(&self)
+    where
+        T: Debug,
+    {
+    }
+    fn f(&self)
+    where
+        T: Clone,
+    {
+    }
+}
+
+// ok, the bounds refer to the methods' own generics
+struct Baz;
+
+impl Baz {
+    fn a<U>(&self, _: U)
+    where
+        U: Clone,
+    {
+    }
+    fn b<U>(&self, _: U)
+    where
+        U: Clone,
+    {
+    }
+    fn c<U>(&self, _: U)
+    where
+        U: Clone,
+    {
+    }
+}
+
+// ok, `Self: Sized` keeps the trait object safe
+trait Object {
+    fn a(&self)
+    where
+        Self: Sized;
+    fn b(&self)
+    where
+        Self: Sized;
+    fn c(&self)
+    where
+        Self: Sized;
+}
+
+// ok, not enough methods
+impl<T> Foo<Vec<T>> {
+    fn d(&self)
+    where
+        T: Clone,
+    {
+    }
+    fn e(&self)
+    where
+        T: Clone,
+    {
+    }
+}
+
+fn main() {}
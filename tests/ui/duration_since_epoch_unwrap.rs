@@ -0,0 +1,19 @@
+#![warn(clippy::duration_since_epoch_unwrap)]
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+fn main() {
+    let _ = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+    let _ = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH).expect("clock");
+    let start = SystemTime::now();
+    let _ = start.duration_since(std::time::UNIX_EPOCH).unwrap().as_secs();
+
+    // ok: the error is handled
+    let _ = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+    let _ = match SystemTime::now().duration_since(UNIX_EPOCH) {
+        Ok(duration) => duration,
+        Err(_) => Duration::from_secs(0),
+    };
+    // ok: not the epoch
+    let _ = SystemTime::now().duration_since(start).unwrap();
+}
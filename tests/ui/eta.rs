@@ -14,7 +14,7 @@ fn main() {
     meta(|a| foo(a));
     let c = Some(1u8).map(|a| {1+2; foo}(a));
     let d = Some(1u8).map(|a| foo((|b| foo2(b))(a))); //is adjusted?
-    all(&[1, 2, 3], &&2, |x, y| below(x, y)); //is adjusted
+    all(&[1, 2, 3], &&2, |x, y| below(x, y)); // the arguments are reborrowed
     unsafe {
         Some(1u8).map(|a| unsafe_fn(a)); // unsafe fn
     }
@@ -26,6 +26,9 @@ fn main() {
     // See #515
     let a: Option<Box<::std::ops::Deref<Target = [i32]>>> =
         Some(vec![1i32, 2]).map(|v| -> Box<::std::ops::Deref<Target = [i32]>> { Box::new(v) });
+    // the argument is coerced to another type
+    let v = vec![1u8];
+    let f = Some(&v).map(|v| first(v));
 }
 
 fn meta<F>(f: F)
@@ -52,6 +55,10 @@ fn below(x: &u8, y: &u8) -> bool {
     x < y
 }
 
+fn first(v: &[u8]) -> u8 {
+    v[0]
+}
+
 unsafe fn unsafe_fn(_: u8) {}
 
 fn divergent(_: u8) -> ! {
@@ -0,0 +1,58 @@
+#![warn(clippy::excessive_nesting)]
+#![allow(dead_code, clippy::collapsible_if, clippy::never_loop, clippy::single_match)]
+
+fn too_deep(v: &[Option<u32>]) {
+    for x in v {
+        if let Some(x) = x {
+            match x {
+                0 => loop {
+                    for _ in 0..*x {
+                        if *x == 2 {
+                            println!("deep");
+                        }
+                    }
+                    break;
+                },
+                _ => (),
+            }
+        }
+    }
+}
+
+// ok, `else if` chains don't add to the depth
+fn else_if_chain(v: &[u32]) {
+    for &x in v {
+        if x == 0 {
+            println!("0");
+        } else if x == 1 {
+            println!("1");
+        } else if x == 2 {
+            println!("2");
+        } else if x == 3 {
+            println!("3");
+        } else if x == 4 {
+            println!("4");
+        } else {
+            println!("other");
+        }
+    }
+}
+
+// ok, nested functions are checked separately
+fn outer(v: &[u32]) {
+    for _ in v {
+        if v.is_empty() {
+            fn inner(v: &[u32]) {
+                for _ in v {
+                    if v.is_empty() {
+                        loop {
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn main() {}
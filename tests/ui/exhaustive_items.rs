@@ -0,0 +1,60 @@
+#![feature(custom_inner_attributes, non_exhaustive)]
+#![clippy::msrv = "1.40"]
+#![warn(clippy::exhaustive_enums, clippy::exhaustive_structs)]
+#![crate_type = "lib"]
+#![allow(dead_code)]
+
+pub enum Exhaustive {
+    Foo,
+    Bar,
+}
+
+pub mod inner {
+    pub enum NestedExhaustive {
+        Foo,
+        Bar,
+    }
+}
+
+#[non_exhaustive]
+pub enum NonExhaustive {
+    Foo,
+    Bar,
+}
+
+enum Private {
+    Foo,
+    Bar,
+}
+
+pub struct ExhaustiveStruct {
+    pub foo: u8,
+    pub bar: String,
+}
+
+pub struct ExhaustiveTuple(pub u8, pub u8);
+
+pub struct Unit;
+
+#[non_exhaustive]
+pub struct NonExhaustiveStruct {
+    pub foo: u8,
+    pub bar: String,
+}
+
+pub struct PrivateField {
+    pub foo: u8,
+    bar: String,
+}
+
+pub struct PrivateTuple(pub u8, u8);
+
+struct PrivateStruct {
+    pub foo: u8,
+}
+
+#[clippy::msrv = "1.39"]
+pub enum Old {
+    Foo,
+    Bar,
+}
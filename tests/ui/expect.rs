@@ -0,0 +1,21 @@
+#![warn(clippy::option_expect_used, clippy::result_expect_used)]
+
+fn option_expect() {
+    let opt = Some(0);
+    let _ = opt.expect("");
+}
+
+fn result_expect() {
+    let res: Result<u8, ()> = Ok(0);
+    let _ = res.expect("");
+}
+
+// not linted: `unwrap` has its own lints
+fn unwrap() {
+    let _ = Some(0).unwrap();
+}
+
+fn main() {
+    option_expect();
+    result_expect();
+}
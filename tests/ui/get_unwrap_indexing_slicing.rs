@@ -0,0 +1,13 @@
+#![warn(clippy::get_unwrap, clippy::indexing_slicing)]
+
+fn main() {
+    let mut some_vec = vec![0, 1, 2, 3];
+    // `[]` would be linted by `indexing_slicing`
+    let _ = some_vec.get(0).unwrap();
+    *some_vec.get_mut(1).unwrap() = 5;
+}
+
+#[allow(clippy::indexing_slicing)]
+fn indexing_allowed(some_vec: &[u32]) {
+    let _ = some_vec.get(0).unwrap();
+}
@@ -259,4 +259,17 @@ fn func() {
 
 fn f(val: &[u8]) {}
 
+// every pair of identical blocks is linted
+fn chains(x: u8) -> u8 {
+    if x == 0 {
+        1
+    } else if x == 1 {
+        1
+    } else if x == 2 {
+        2
+    } else {
+        2
+    }
+}
+
 fn main() {}
@@ -22,6 +22,15 @@ fn ifs_same_cond() {
     } else if a == 1 {
     }
 
+    // every repeated condition is linted
+    if a == 3 {
+    } else if a == 4 {
+    } else if a == 3 {
+        //~ ERROR ifs same condition
+    } else if a == 4 {
+        //~ ERROR ifs same condition
+    }
+
     // See #659
     if cfg!(feature = "feature1-659") {
         1
@@ -0,0 +1,28 @@
+#![warn(clippy::immediately_dropped_lock)]
+#![allow(unused_must_use)]
+
+use std::sync::{Mutex, RwLock};
+
+fn main() {
+    let mutex = Mutex::new(0);
+    let rwlock = RwLock::new(0);
+
+    let _ = mutex.lock().unwrap();
+    mutex.lock().unwrap();
+    let _ = rwlock.read().unwrap();
+    rwlock.write().unwrap();
+    let _ = mutex.lock();
+
+    // held until the end of the block
+    {
+        let _guard = mutex.lock().unwrap();
+    }
+    {
+        let guard = rwlock.write().unwrap();
+        drop(guard);
+    }
+
+    // the guard is used in the statement
+    *mutex.lock().unwrap() += 1;
+    let _ = *rwlock.read().unwrap();
+}
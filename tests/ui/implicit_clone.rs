@@ -0,0 +1,38 @@
+#![warn(clippy::implicit_clone)]
+#![allow(clippy::redundant_clone)]
+
+use std::borrow::Borrow;
+use std::path::PathBuf;
+
+fn return_owned_from_slice(slice: &[u32]) -> Vec<u32> {
+    slice.to_owned()
+}
+
+fn main() {
+    let vec = vec![5];
+    let _ = return_owned_from_slice(&vec);
+    let _ = vec.to_owned();
+    let _ = vec.to_vec();
+
+    let vec_ref = &vec;
+    let _ = return_owned_from_slice(&vec_ref);
+    let _ = vec_ref.to_owned();
+    let _ = vec_ref.to_vec();
+
+    let s = String::from("foo");
+    let _ = s.to_string();
+    let _ = s.to_owned();
+    let _ = s.as_str().to_string();
+    let _ = s.as_str().to_owned();
+    let s_ref: &String = s.borrow();
+    let _ = s_ref.to_string();
+
+    let pathbuf = PathBuf::new();
+    let _ = pathbuf.to_owned();
+    let _ = pathbuf.as_path().to_owned();
+
+    // `Copy` values aren't cloned
+    let x = 5u32;
+    let _ = x.to_owned();
+    let _ = x.to_string();
+}
@@ -0,0 +1,37 @@
+#![allow(dead_code)]
+
+use std::num::NonZeroU32;
+use std::ptr::NonNull;
+
+pub trait Trait {}
+
+#[no_mangle]
+pub extern "C" fn string(s: String) -> Vec<u8> {
+    s.into_bytes()
+}
+
+#[no_mangle]
+pub extern "C" fn fat_pointers(_: &str, _: *const [u8], _: Box<dyn Trait>) {}
+
+#[no_mangle]
+pub extern "C" fn options(_: Option<u32>, _: Option<&str>) {}
+
+#[no_mangle]
+pub extern "C" fn ffi_safe(
+    _: *const u8,
+    _: usize,
+    _: &String,
+    _: Option<&u8>,
+    _: Option<Box<u32>>,
+    _: Option<extern "C" fn()>,
+    _: Option<NonNull<u8>>,
+    _: Option<NonZeroU32>,
+) -> i32 {
+    0
+}
+
+extern "C" fn not_exported(_: &str) {}
+
+pub fn rust_abi(_: &str) {}
+
+fn main() {}
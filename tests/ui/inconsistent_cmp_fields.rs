@@ -0,0 +1,92 @@
+#![allow(dead_code)]
+
+use std::cmp::Ordering;
+
+// `Ord` only looks at one of the fields
+#[derive(PartialEq, Eq, PartialOrd)]
+struct Version {
+    number: u32,
+    name: String,
+}
+
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.number.cmp(&other.number)
+    }
+}
+
+// `PartialEq` ignores a field the derived `PartialOrd` compares
+#[derive(PartialOrd)]
+struct Entry {
+    key: u32,
+    value: u32,
+}
+
+impl PartialEq for Entry {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+// both by hand, each with a field of its own
+struct Pair(u32, u32, u32);
+
+impl PartialEq for Pair {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0 && self.1 == other.1
+    }
+}
+
+impl PartialOrd for Pair {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        (self.0, self.2).partial_cmp(&(other.0, other.2))
+    }
+}
+
+// ok: the same fields
+#[derive(Eq)]
+struct Key {
+    id: u32,
+    cache: u32,
+}
+
+impl PartialEq for Key {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl PartialOrd for Key {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Key {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.id.cmp(&other.id)
+    }
+}
+
+// ok: the fields `PartialEq` compares are unknown
+struct Name(String);
+
+impl Name {
+    fn normalized(&self) -> String {
+        self.0.to_lowercase()
+    }
+}
+
+impl PartialEq for Name {
+    fn eq(&self, other: &Self) -> bool {
+        self.normalized() == other.normalized()
+    }
+}
+
+impl PartialOrd for Name {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.0.partial_cmp(&other.0)
+    }
+}
+
+fn main() {}
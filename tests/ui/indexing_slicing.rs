@@ -1,7 +1,7 @@
 #![feature(plugin)]
 #![warn(clippy::indexing_slicing)]
 #![warn(clippy::out_of_bounds_indexing)]
-#![allow(clippy::no_effect, clippy::unnecessary_operation)]
+#![allow(dead_code, clippy::no_effect, clippy::unnecessary_operation)]
 
 fn main() {
     let x = [1, 2, 3, 4];
@@ -84,4 +84,23 @@ fn main() {
     let num = 1;
     &x[num..10]; // should trigger out of bounds error
     &x[10..num]; // should trigger out of bounds error
+
+    // bounds folded from casts, calls and methods
+    enum Size {
+        Small = 2,
+        Large = 8,
+    }
+    &x[0..Size::Small as usize]; // Ok, should not produce stderr.
+    &x[..Size::Large as usize]; // should trigger out of bounds error
+    &x[..8.0 as usize]; // should trigger out of bounds error
+    &x[..std::mem::size_of::<u64>()]; // should trigger out of bounds error
+    &x[..2usize.pow(3)]; // should trigger out of bounds error
+    &x[..1usize << 3]; // should trigger out of bounds error
+    &x[..2usize.checked_add(3).unwrap()]; // should trigger out of bounds error
+    &x[..250u8.checked_add(10).unwrap_or(2) as usize]; // Ok, should not produce stderr.
+}
+
+fn generic<T>() {
+    let x = [1, 2, 3, 4];
+    &x[..std::mem::size_of::<T>()]; // the size is only known after monomorphization
 }
@@ -1,4 +1,4 @@
-use std::iter::repeat;
+use std::iter::{from_fn, repeat, repeat_with, successors};
 #[allow(clippy::trivially_copy_pass_by_ref)]
 fn square_is_lower_64(x: &u32) -> bool {
     x * x < 64
@@ -22,6 +22,8 @@ fn infinite_iters() {
     (0..3_u32).flat_map(|x| x..).sum::<u32>(); // infinite iter
     (0_usize..).flat_map(|x| 0..x).product::<usize>(); // infinite iter
     (0_u64..).filter(|x| x % 2 == 0).last(); // infinite iter
+    repeat_with(|| 0_u64).sum::<u64>(); // infinite iter
+    (0_u32..).step_by(2).count(); // infinite iter
     (0..42_u64).by_ref().last(); // not an infinite, because ranges are double-ended
     (0..).next(); // iterator is not exhausted
 }
@@ -40,6 +42,8 @@ fn potential_infinite_iters() {
     (0..).position(|x| x == 24); // maybe infinite iter
     (0..).any(|x| x == 24); // maybe infinite iter
     (0..).all(|x| x == 24); // maybe infinite iter
+    from_fn(|| Some(0)).count(); // maybe infinite iter
+    successors(Some(1_u32), |x| x.checked_mul(2)).last(); // maybe infinite iter
 
     (0..).zip(0..42).take_while(|&(x, _)| x != 42).count(); // not infinite
     repeat(42).take_while(|x| *x == 42).next(); // iterator is not exhausted
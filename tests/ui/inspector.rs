@@ -0,0 +1,6 @@
+fn main() {
+    #[clippy::dump]
+    let _units = [(), ()];
+    #[clippy::dump]
+    let (_first, _second) = ((), ());
+}
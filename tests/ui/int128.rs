@@ -0,0 +1,59 @@
+#![warn(clippy::int128_in_ffi, clippy::serialized_int128)]
+#![allow(dead_code)]
+
+#[macro_use]
+extern crate serde_derive;
+
+#[repr(C)]
+struct Balance {
+    amount: u128,
+    parts: [i128; 2],
+    count: u64,
+}
+
+#[repr(C)]
+union Bits {
+    int: u128,
+    float: f64,
+}
+
+struct NotRepr {
+    amount: u128,
+}
+
+extern "C" fn add(a: u128, b: &i128) -> u128 {
+    a + *b as u128
+}
+
+extern "C" fn small(a: u64) -> u64 {
+    a
+}
+
+fn rust(a: u128) -> u128 {
+    a
+}
+
+#[derive(Serialize)]
+struct Serialized {
+    amount: u128,
+    id: Option<i128>,
+    name: String,
+}
+
+#[derive(Deserialize)]
+enum Deserialized {
+    Amount(u128),
+    Name(String),
+}
+
+#[derive(Serialize, Deserialize)]
+struct Small {
+    amount: u64,
+}
+
+#[derive(Debug, Clone)]
+struct NotSerialized {
+    amount: u128,
+}
+
+fn main() {}
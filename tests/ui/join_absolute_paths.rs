@@ -0,0 +1,20 @@
+use std::path::{Path, PathBuf};
+
+fn main() {
+    let base = Path::new("/srv/www");
+    let _ = base.join("/index.html");
+    let mut buf = PathBuf::from("C:\\www");
+    let _ = buf.join("\\index.html"); // only a separator on Windows
+    let _ = (&buf).join("/");
+    let file = "/index.html";
+    let _ = base.join(file);
+
+    // ok
+    let _ = base.join("index.html");
+    let _ = base.join(Path::new("/index.html"));
+    buf.push("/index.html");
+    let _ = ["a", "b"].join("/");
+    let mut file = "/index.html";
+    file = &file[1..];
+    let _ = base.join(file);
+}
@@ -0,0 +1,39 @@
+// compile-flags: --edition 2018
+#![feature(async_await, await_macro, futures_api)]
+#![warn(clippy::large_futures)]
+
+use std::future::Future;
+
+async fn ready() -> u32 {
+    1
+}
+
+async fn large() -> u8 {
+    let buffer = [0u8; 20_000];
+    await!(ready());
+    buffer[0]
+}
+
+fn large_block() -> impl Future<Output = u8> {
+    async {
+        let buffer = [0u8; 20_000];
+        await!(ready());
+        buffer[0]
+    }
+}
+
+// not linted: the buffer isn't alive across the `await!`
+async fn small() -> u32 {
+    let first = {
+        let buffer = [0u8; 20_000];
+        buffer[0]
+    };
+    await!(ready()) + u32::from(first)
+}
+
+// not linted: the future is boxed
+async fn boxed() -> u8 {
+    await!(Box::pin(large()))
+}
+
+fn main() {}
@@ -214,6 +214,9 @@ fn main() {
         //no error as HasWrongIsEmpty does not have .is_empty()
         println!("Or this!");
     }
+
+    // the receiver is a macro call
+    if vec![1, 2].len() == 0 {}
 }
 
 fn test_slice(b: &[u8]) {
@@ -0,0 +1,21 @@
+#![warn(clippy::lock_unwrap)]
+#![allow(clippy::immediately_dropped_lock)]
+
+use std::sync::{Arc, Mutex, RwLock};
+
+fn main() {
+    let mutex = Mutex::new(0);
+    let rwlock = Arc::new(RwLock::new(0));
+
+    let _ = mutex.lock().unwrap();
+    let _ = mutex.lock().expect("the mutex is poisoned");
+    let _ = rwlock.read().unwrap();
+    let _ = rwlock.write().expect("the lock is poisoned");
+
+    // already handled
+    let _ = mutex.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+    let _ = mutex.try_lock().unwrap();
+
+    // not a lock
+    let _ = Some(0).unwrap();
+}
@@ -0,0 +1,22 @@
+#![allow(unused)]
+#![warn(clippy::manual_digit_conversion)]
+
+const ZERO: char = (b'0' + 0) as char;
+
+const fn digit(c: char) -> u8 {
+    c as u8 - b'0'
+}
+
+fn main() {
+    let n = 7u8;
+    let c = '7';
+    let _ = (b'0' + n) as char;
+    let _ = (n + b'0') as char;
+    let _ = c as u8 - b'0';
+    let _ = c as u32 - '0' as u32;
+
+    // not digits
+    let _ = (b'a' + n) as char;
+    let _ = c as u8 - b'a';
+    let _ = n - b'0';
+}
@@ -0,0 +1,64 @@
+#![allow(dead_code)]
+
+fn is_long(s: &str) -> bool {
+    s.len() > 3
+}
+
+fn main() {
+    let opt = Some(3);
+    let name = Some(String::from("clippy"));
+
+    let _ = if let Some(x) = opt {
+        if x > 2 {
+            Some(x)
+        } else {
+            None
+        }
+    } else {
+        None
+    };
+    let _ = match opt {
+        Some(x) => {
+            if x % 2 == 0 {
+                Some(x)
+            } else {
+                None
+            }
+        },
+        None => None,
+    };
+    let _ = opt.and_then(|x| if x > 2 { Some(x) } else { None });
+    let _ = name.clone().and_then(|s| if is_long(&s) { Some(s) } else { None });
+
+    // not filtering
+    let _ = opt.and_then(|x| if x > 2 { Some(x + 1) } else { None });
+    let _ = opt.and_then(|x| if x > 2 { None } else { Some(x) });
+    let _ = match opt {
+        Some(x) if x > 1 => {
+            if x > 2 {
+                Some(x)
+            } else {
+                None
+            }
+        },
+        _ => None,
+    };
+}
+
+fn try_in_condition(opt: Option<i32>, limit: Option<i32>) -> Option<i32> {
+    match opt {
+        Some(x) => {
+            if x > limit? {
+                Some(x)
+            } else {
+                None
+            }
+        },
+        None => None,
+    }
+}
+
+#[clippy::msrv = "1.26"]
+fn old(opt: Option<i32>) -> Option<i32> {
+    opt.and_then(|x| if x > 2 { Some(x) } else { None })
+}
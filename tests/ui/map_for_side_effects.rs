@@ -0,0 +1,42 @@
+#![warn(clippy::map_for_side_effects)]
+#![allow(unused_must_use)]
+
+fn main() {
+    let v = vec![1, 2, 3];
+
+    let _: Vec<_> = v
+        .iter()
+        .map(|x| {
+            println!("{}", x);
+            x
+        })
+        .collect();
+    let _ = v.iter().map(|x| println!("{}", x)).count();
+    v.iter().map(|x| println!("{}", x));
+
+    // ok
+    let _: Vec<_> = v.iter().map(|x| x + 1).collect();
+    let _: Vec<_> = v
+        .iter()
+        .map(|x| {
+            println!("{}", x);
+            x + 1
+        })
+        .collect();
+    let _: Vec<_> = vec![1, 2]
+        .into_iter()
+        .map(|mut x| {
+            x += 1;
+            x
+        })
+        .collect();
+    let mut w = vec![1, 2];
+    let _: Vec<_> = w
+        .iter_mut()
+        .map(|x| {
+            *x += 1;
+            x
+        })
+        .collect();
+    let _ = v.iter().map(|x| println!("{}", x)).collect::<Vec<_>>();
+}
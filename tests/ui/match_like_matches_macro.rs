@@ -0,0 +1,58 @@
+#![feature(custom_inner_attributes)]
+#![clippy::msrv = "1.42"]
+#![warn(clippy::match_like_matches_macro)]
+#![allow(clippy::match_bool, clippy::redundant_pattern_matching)]
+
+enum Foo {
+    A(u32),
+    B,
+    C,
+}
+
+fn main() {
+    let c = 'x';
+    let foo = Foo::B;
+
+    // lint
+    let _ = match c {
+        '0'..='9' => true,
+        _ => false,
+    };
+    let _ = match foo {
+        Foo::A(_) | Foo::B => false,
+        _ => true,
+    };
+    let _ = match foo {
+        Foo::A(x) if x > 2 => true,
+        _ => false,
+    };
+    let _ = if let Foo::C = foo { true } else { false };
+
+    // no lint, `redundant_pattern_matching` or `match_bool` lint these
+    let _ = if let Some(_) = Some(1) { true } else { false };
+    let _ = match c == 'x' {
+        true => true,
+        _ => false,
+    };
+
+    // no lint, the arms don't return bool literals
+    let _ = match foo {
+        Foo::A(x) => x > 2,
+        _ => false,
+    };
+    let _ = match foo {
+        Foo::A(_) => true,
+        Foo::B => false,
+        _ => true,
+    };
+}
+
+fn msrv() {
+    #![clippy::msrv = "1.41"]
+
+    // no lint, `matches!` is newer than the MSRV
+    let _ = match 'x' {
+        '0'..='9' => true,
+        _ => false,
+    };
+}
@@ -0,0 +1,54 @@
+#![warn(clippy::mismatched_field_init)]
+#![allow(clippy::redundant_field_names)]
+
+struct Rect {
+    width: u32,
+    height: u32,
+}
+
+impl Rect {
+    fn new(width: u32, height: u32) -> Self {
+        Self {
+            width: height,
+            height: width,
+        }
+    }
+
+    fn with_width(width: u32, height: u32) -> Self {
+        Rect { width: height, height }
+    }
+
+    // not linted: there's no `height` parameter
+    fn square(width: u32) -> Self {
+        Self { width, height: width }
+    }
+
+    // not linted: the parameters are named like the fields they initialize
+    fn fine(width: u32, height: u32) -> Self {
+        Self {
+            width: width,
+            height: height,
+        }
+    }
+
+    // not linted: `w` and `h` aren't field names
+    fn short(w: u32, h: u32) -> Self {
+        Self { width: h, height: w }
+    }
+
+    // not linted: not a constructor
+    fn transposed(&self, width: u32, height: u32) -> (u32, Rect) {
+        (self.width, Rect { width: height, height: width })
+    }
+}
+
+enum Shape {
+    Ellipse { x: f32, y: f32 },
+}
+
+// not linted: not a constructor
+fn ellipse(x: f32, y: f32) -> Shape {
+    Shape::Ellipse { x: y, y }
+}
+
+fn main() {}
@@ -55,3 +55,19 @@ trait Foo {
         33
     }
 }
+
+impl Foo for Game {
+    // This should not be suggested to be made const either
+    // (rustc doesn't allow const fns in trait impls)
+    fn f() -> u32 {
+        1
+    }
+}
+
+#[clippy::msrv = "1.30"]
+mod old {
+    // `const fn` needs Rust 1.31
+    fn one() -> i32 {
+        1
+    }
+}
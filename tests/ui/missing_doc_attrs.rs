@@ -0,0 +1,20 @@
+#![warn(clippy::missing_docs_in_private_items)]
+#![feature(external_doc)]
+
+//! Some docs for the crate
+
+#[doc(include = "auxiliary/missing_doc_attrs.md")]
+fn included() {}
+
+#[doc(include = "auxiliary/missing_doc_attrs.md")]
+struct Included {
+    #[doc(include = "auxiliary/missing_doc_attrs.md")]
+    field: u32,
+}
+
+#[doc(hidden)]
+fn hidden() {}
+
+fn undocumented() {}
+
+fn main() {}
@@ -0,0 +1,32 @@
+#![warn(clippy::mixed_impl_trait_args)]
+#![allow(dead_code)]
+
+use std::fmt::Display;
+
+fn mixed<T: Clone>(_: T, _: impl Display) {}
+
+fn nested<T>(_: T, _: &[impl Display]) {}
+
+struct Foo;
+
+impl Foo {
+    fn method<T: Clone>(&self, _: T, _: impl Display) {}
+}
+
+trait Bar {
+    fn bar<T: Clone>(&self, _: T, _: impl Display);
+}
+
+// ok, the signature is given by the trait
+impl Bar for Foo {
+    fn bar<T: Clone>(&self, _: T, _: impl Display) {}
+}
+
+// ok
+fn only_generics<T: Clone, U: Display>(_: T, _: U) {}
+
+fn only_impl_trait(_: impl Clone, _: impl Display) {}
+
+fn lifetimes_only<'a>(_: &'a str, _: impl Display) {}
+
+fn main() {}
@@ -0,0 +1,89 @@
+#![warn(clippy::monomorphization_bloat)]
+#![allow(dead_code)]
+
+use std::path::Path;
+
+fn greet(name: impl Into<String>) -> usize {
+    let name: String = name.into();
+    let _ = name.len() + 1;
+    let _ = name.len() + 2;
+    let _ = name.len() + 3;
+    let _ = name.len() + 4;
+    let _ = name.len() + 5;
+    let _ = name.len() + 6;
+    let _ = name.len() + 7;
+    let _ = name.len() + 8;
+    let _ = name.len() + 9;
+    let _ = name.len() + 10;
+    let _ = name.len() + 11;
+    let _ = name.len() + 12;
+    let _ = name.len() + 13;
+    let _ = name.len() + 14;
+    let _ = name.len() + 15;
+    let _ = name.len() + 16;
+    let _ = name.len() + 17;
+    let _ = name.len() + 18;
+    let _ = name.len() + 19;
+    let _ = name.len() + 20;
+    name.len()
+}
+
+fn open<P: AsRef<Path>>(path: P) -> bool {
+    let path = path.as_ref();
+    let _ = path.is_file();
+    let _ = path.is_file();
+    let _ = path.is_file();
+    let _ = path.is_file();
+    let _ = path.is_file();
+    let _ = path.is_file();
+    let _ = path.is_file();
+    let _ = path.is_file();
+    let _ = path.is_file();
+    let _ = path.is_file();
+    let _ = path.is_file();
+    let _ = path.is_file();
+    let _ = path.is_file();
+    let _ = path.is_file();
+    let _ = path.is_file();
+    let _ = path.is_file();
+    let _ = path.is_file();
+    let _ = path.is_file();
+    let _ = path.is_file();
+    let _ = path.is_file();
+    path.exists()
+}
+
+// ok, not large enough
+fn small(name: impl Into<String>) -> usize {
+    let name: String = name.into();
+    name.len()
+}
+
+// ok, the generic argument is used later on
+fn later<T: Clone + Into<String>>(value: T) -> usize {
+    let name: String = value.clone().into();
+    let _ = name.len() + 1;
+    let _ = name.len() + 2;
+    let _ = name.len() + 3;
+    let _ = name.len() + 4;
+    let _ = name.len() + 5;
+    let _ = name.len() + 6;
+    let _ = name.len() + 7;
+    let _ = name.len() + 8;
+    let _ = name.len() + 9;
+    let _ = name.len() + 10;
+    let _ = name.len() + 11;
+    let _ = name.len() + 12;
+    let _ = name.len() + 13;
+    let _ = name.len() + 14;
+    let _ = name.len() + 15;
+    let _ = name.len() + 16;
+    let _ = name.len() + 17;
+    let _ = name.len() + 18;
+    let _ = name.len() + 19;
+    let _ = name.len() + 20;
+    let other: String = value.into();
+    other.len()
+}
+
+fn main() {}
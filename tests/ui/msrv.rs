@@ -0,0 +1,55 @@
+#![warn(
+    clippy::match_like_matches_macro,
+    clippy::range_plus_one,
+    clippy::redundant_field_names
+)]
+#![allow(dead_code)]
+
+struct Foo {
+    bar: u8,
+}
+
+fn new(bar: u8) -> Foo {
+    Foo { bar: bar }
+}
+
+fn for_range(n: usize) {
+    for _ in 0..n + 1 {}
+}
+
+#[clippy::msrv = "1.25"]
+mod old {
+    use super::Foo;
+
+    fn new(bar: u8) -> Foo {
+        Foo { bar: bar }
+    }
+
+    fn for_range(n: usize) {
+        for _ in 0..n + 1 {}
+    }
+
+    #[clippy::msrv = "1.26.0"]
+    fn for_range_newer(n: usize) {
+        for _ in 0..n + 1 {}
+    }
+}
+
+#[clippy::msrv = "1.16.0"]
+mod older {
+    use super::Foo;
+
+    fn new(bar: u8) -> Foo {
+        Foo { bar: bar }
+    }
+}
+
+// no lint, `matches!` is newer than the compiler, which is the MSRV if none is set
+fn is_digit(c: char) -> bool {
+    match c {
+        '0'..='9' => true,
+        _ => false,
+    }
+}
+
+fn main() {}
@@ -0,0 +1,5 @@
+#[clippy::msrv = "1.x"]
+mod foo {}
+
+#[clippy::msrv]
+fn main() {}
@@ -0,0 +1,71 @@
+#![allow(dead_code)]
+
+use std::fmt;
+
+struct Foo;
+
+impl Default for Foo {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Foo {
+    fn new() -> Self {
+        Default::default()
+    }
+}
+
+struct Name;
+
+impl fmt::Display for Name {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_string())
+    }
+}
+
+struct Meters(f64);
+struct Feet(f64);
+
+impl From<Feet> for Meters {
+    fn from(feet: Feet) -> Self {
+        feet.into()
+    }
+}
+
+// ok: no cycle
+struct Config {
+    verbose: bool,
+}
+
+impl Config {
+    fn new() -> Self {
+        Config { verbose: false }
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ok: `new` only calls `default` for some levels
+struct Level(u8);
+
+impl Level {
+    fn new(level: u8) -> Self {
+        if level > 3 {
+            return Default::default();
+        }
+        Level(level)
+    }
+}
+
+impl Default for Level {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+fn main() {}
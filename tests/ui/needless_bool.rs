@@ -154,3 +154,46 @@ fn needless_bool_in_the_suggestion_wraps_the_predicate_of_if_else_statement_in_b
         true
     };
 }
+
+fn if_then_return(x: bool) -> bool {
+    if x {
+        return true;
+    }
+    false
+}
+
+#[allow(clippy::needless_return)]
+fn if_then_return2(x: bool, y: bool) -> bool {
+    if x && y {
+        return false;
+    }
+    return true;
+}
+
+fn if_then_return_in_closure(x: bool) -> bool {
+    let f = || {
+        if x {
+            return false;
+        }
+        true
+    };
+    f()
+}
+
+fn if_then_return_in_block(x: bool) -> bool {
+    // the tail of the inner block isn't returned
+    let y = {
+        if x {
+            return true;
+        }
+        false
+    };
+    !y
+}
+
+fn if_then_return_same(x: bool) -> bool {
+    if x {
+        return true;
+    }
+    true
+}
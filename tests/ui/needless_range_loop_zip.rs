@@ -0,0 +1,46 @@
+#![warn(clippy::needless_range_loop_zip)]
+#![allow(clippy::needless_range_loop)]
+
+fn add(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut sum = Vec::new();
+    for i in 0..a.len() {
+        sum.push(a[i] + b[i]);
+    }
+    sum
+}
+
+fn main() {
+    let names = vec!["a", "b", "c"];
+    let ages = [1, 2, 3];
+    for i in 0..names.len() {
+        println!("{} is {}", names[i], ages[i]);
+    }
+
+    // the range isn't bound by either collection
+    for i in 0..3 {
+        println!("{} is {}", names[i], ages[i]);
+    }
+
+    // the loop variable is used on its own
+    for i in 0..names.len() {
+        println!("{}: {} is {}", i, names[i], ages[i]);
+    }
+
+    // one of the collections is mutated
+    let mut doubled = vec![0; 3];
+    for i in 0..doubled.len() {
+        doubled[i] = ages[i] * 2;
+    }
+
+    // one of the collections is also used without indexing
+    for i in 0..names.len() {
+        println!("{} is {} of {}", names[i], ages[i], ages.len());
+    }
+
+    // three collections
+    for i in 0..names.len() {
+        println!("{} is {} ({})", names[i], ages[i], doubled[i]);
+    }
+
+    let _ = add(&[1], &[2]);
+}
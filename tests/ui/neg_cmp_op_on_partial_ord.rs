@@ -1,6 +1,7 @@
 //! This test case utilizes `f64` an easy example for `PartialOrd` only types
 //! but the lint itself actually validates any expression where the left
 //! operand implements `PartialOrd` but not `Ord`.
+#![allow(clippy::match_like_matches_macro)]
 
 use std::cmp::Ordering;
 
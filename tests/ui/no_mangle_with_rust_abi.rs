@@ -0,0 +1,23 @@
+#![warn(clippy::no_mangle_with_rust_abi)]
+#![allow(dead_code)]
+
+#[no_mangle]
+pub fn rust_abi(x: i32) -> i32 {
+    x + 1
+}
+
+#[no_mangle]
+pub unsafe fn unsafe_rust_abi() {}
+
+#[no_mangle]
+pub extern "C" fn c_abi() {}
+
+#[no_mangle]
+pub extern "system" fn system_abi() {}
+
+#[no_mangle]
+pub extern fn default_extern_abi() {}
+
+pub fn mangled() {}
+
+fn main() {}
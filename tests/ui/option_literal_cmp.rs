@@ -0,0 +1,21 @@
+#![warn(clippy::option_literal_cmp)]
+
+fn main() {
+    let opt = Some(3);
+    let name = Some("foo");
+    let ratio = Some(0.5);
+
+    if opt == Some(3) {}
+    if Some("foo") == name {}
+    let _ = opt == Some(3);
+    let _ = opt != Some(3);
+    if ratio == Some(0.5) {}
+    let _ = opt == None;
+    let _ = None != name;
+    let _ = opt.map(|x| x + 1) == None;
+
+    // no literals
+    let three = 3;
+    let _ = opt == Some(three);
+    let _ = opt == name.map(str::len);
+}
@@ -0,0 +1,25 @@
+#![warn(clippy::overflowing_enum_discriminant)]
+
+const FLAG: u32 = 0x100;
+const NEGATIVE: i16 = -1;
+
+#[repr(u8)]
+enum Flags {
+    Low = 1,
+    High = FLAG as u8,
+    All = NEGATIVE as u8,
+    // fits
+    Mid = 0x10u32 as u8,
+    // not linted: a cast to the same width keeps the bits
+    Max = -2i8 as u8,
+}
+
+#[repr(i8)]
+enum Signed {
+    // not linted: a cast to the same width keeps the bits
+    A = 200u8 as i8,
+    B = -1i32 as i8,
+    C = 1,
+}
+
+fn main() {}
@@ -0,0 +1,59 @@
+#![warn(clippy::unwrap_in_result, clippy::panic_in_result_fn)]
+#![allow(clippy::unnecessary_operation)]
+
+use std::num::ParseIntError;
+
+fn unwraps(input: &str, fallback: Option<u32>) -> Result<u32, ParseIntError> {
+    let value = input.parse::<u32>().unwrap();
+    Ok(value + fallback.expect("there's a fallback"))
+}
+
+fn panics(input: &str) -> Result<u32, ParseIntError> {
+    if input.is_empty() {
+        panic!("empty input");
+    }
+    if input == "?" {
+        unimplemented!();
+    }
+    input.parse()
+}
+
+fn indexes(args: &[&str]) -> Result<u32, ParseIntError> {
+    args[0].parse()
+}
+
+fn indexes_array(array: [u32; 2], i: usize) -> Result<u32, ParseIntError> {
+    Ok(array[i])
+}
+
+struct Parser;
+
+impl Parser {
+    fn parse(&self, input: &str) -> Result<u32, ParseIntError> {
+        Ok(input.parse::<u32>().unwrap())
+    }
+}
+
+fn closures(inputs: &[&str]) -> Result<Vec<u32>, ParseIntError> {
+    // linted: the closure returns a `Result`
+    let parse = |input: &str| -> Result<u32, ParseIntError> { Ok(input.parse::<u32>().unwrap()) };
+    // not linted: the closure doesn't return a `Result`
+    let lengths: Vec<_> = inputs.iter().map(|input| input.parse::<u32>().unwrap()).collect();
+    inputs.iter().map(|input| parse(input)).collect()
+}
+
+// not linted
+fn allowed(input: &str) -> Result<u32, ParseIntError> {
+    #[allow(clippy::unwrap_in_result)]
+    let value = input.parse::<u32>().unwrap();
+    assert!(value > 0, "positive");
+    let array = [1, 2];
+    array[1];
+    Ok(value)
+}
+
+fn no_result(input: &str) -> u32 {
+    input.parse().unwrap()
+}
+
+fn main() {}
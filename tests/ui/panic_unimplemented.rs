@@ -56,6 +56,25 @@ fn unimplemented() {
     let b = a + 2;
 }
 
+macro_rules! todo {
+    () => {
+        panic!("not yet implemented")
+    };
+}
+
+fn todo() -> u8 {
+    todo!()
+}
+
+fn not_implemented() -> u8 {
+    panic!("not implemented")
+}
+
+#[allow(clippy::unimplemented)]
+fn stub() {
+    unimplemented!();
+}
+
 fn main() {
     missing();
     ok_single();
@@ -65,4 +84,7 @@ fn main() {
     ok_nomsg();
     ok_escaped();
     unimplemented();
+    todo();
+    not_implemented();
+    stub();
 }
@@ -0,0 +1,19 @@
+#![warn(clippy::ptr_eq)]
+
+fn main() {
+    let a = [1, 2, 3];
+    let b = [1, 2, 3];
+    let p = &a as *const [i32; 3];
+    let q = &b as *const [i32; 3];
+
+    let _ = &a as *const _ == &b as *const _;
+    let _ = &a as *const [i32; 3] != &b as *const [i32; 3];
+    let _ = p as usize == q as usize;
+    let _ = &a as *const [i32; 3] as usize == &b as *const [i32; 3] as usize;
+
+    // ok: the pointee types differ
+    let _ = &a as *const _ as *const i32 == &b[0] as *const i32;
+    // ok: no casts
+    let _ = p == q;
+    let _ = std::ptr::eq(&a, &b);
+}
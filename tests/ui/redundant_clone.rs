@@ -56,3 +56,22 @@ fn cannot_move_from_type_with_drop() -> String {
     let s = TypeWithDrop { x: String::new() };
     s.x.clone() // removing this `clone()` summons E0509
 }
+
+fn borrower_used_later() {
+    let s = String::from("foo");
+    let borrowed = &s;
+    let _ = s.clone(); // `s` can't be moved out while `borrowed` is used
+    let _ = borrowed.len();
+
+    let v = vec![String::from("foo")];
+    let first = v.first();
+    let _ = v.clone();
+    let _ = first.is_some();
+}
+
+fn borrower_not_used_later() {
+    let s = String::from("foo");
+    let borrowed = &s;
+    let _ = borrowed.len();
+    let _ = s.clone();
+}
@@ -0,0 +1,48 @@
+#![allow(unused)]
+#![warn(clippy::redundant_closure_for_method_calls)]
+
+struct Meters(u32);
+
+impl Meters {
+    fn value(&self) -> u32 {
+        self.0
+    }
+
+    fn add(&self, other: &Meters) -> u32 {
+        self.0 + other.0
+    }
+
+    fn convert<T: From<u32>>(&self) -> T {
+        T::from(self.0)
+    }
+}
+
+trait Describe {
+    fn describe(&self) -> String;
+}
+
+impl Describe for Meters {
+    fn describe(&self) -> String {
+        format!("{}m", self.0)
+    }
+}
+
+fn combine<F: Fn(&Meters, &Meters) -> u32>(f: F) -> u32 {
+    f(&Meters(1), &Meters(2))
+}
+
+fn main() {
+    let distances = vec![Meters(1), Meters(2)];
+    let _: Vec<_> = distances.iter().map(|m| m.value()).collect();
+    let _: Vec<_> = distances.iter().map(|m| m.describe()).collect();
+    combine(|a, b| a.add(b));
+    let _ = Some('a').map(|c| c.is_alphabetic());
+
+    // the receiver has to be borrowed
+    let _: Vec<_> = vec![Meters(3)].into_iter().map(|m| m.value()).collect();
+    // the receiver is captured
+    let start = Meters(4);
+    let _: Vec<_> = distances.iter().map(|m| start.add(m)).collect();
+    // the generic arguments can't be given in the path
+    let _: Vec<u64> = distances.iter().map(|m| m.convert::<u64>()).collect();
+}
@@ -0,0 +1,67 @@
+#![warn(clippy::redundant_phantom_data)]
+
+use std::marker::PhantomData;
+
+struct Wrapper<T> {
+    value: T,
+    _marker: PhantomData<T>,
+}
+
+struct Boxed<T>(Option<Box<T>>, PhantomData<T>);
+
+struct Plain {
+    value: u32,
+    _marker: PhantomData<String>,
+}
+
+// ok: the marker tells drop check that `T` is owned
+struct RawVec<T> {
+    ptr: *mut T,
+    _marker: PhantomData<T>,
+}
+
+// ok: a reference doesn't own the value
+struct Ref<'a, T> {
+    value: &'a T,
+    _marker: PhantomData<T>,
+}
+
+// ok: the parameter isn't used anywhere else
+struct Tagged<Tag> {
+    id: u32,
+    _tag: PhantomData<Tag>,
+}
+
+// ok: opts out of `Send` and `Sync`
+struct NotSend {
+    value: u32,
+    _marker: PhantomData<*const ()>,
+}
+
+fn main() {
+    let _ = Wrapper {
+        value: 1,
+        _marker: PhantomData,
+    };
+    let _ = Boxed::<u32>(None, PhantomData);
+    let _ = Plain {
+        value: 1,
+        _marker: PhantomData,
+    };
+    let _ = RawVec::<u32> {
+        ptr: std::ptr::null_mut(),
+        _marker: PhantomData,
+    };
+    let _ = Ref {
+        value: &1,
+        _marker: PhantomData,
+    };
+    let _ = Tagged::<()> {
+        id: 1,
+        _tag: PhantomData,
+    };
+    let _ = NotSend {
+        value: 1,
+        _marker: PhantomData,
+    };
+}
@@ -0,0 +1,38 @@
+#![warn(clippy::redundant_turbofish)]
+
+use std::collections::HashSet;
+
+struct Numbers {
+    values: Vec<u32>,
+}
+
+struct Wrapper<T> {
+    value: T,
+}
+
+fn evens() -> Vec<u32> {
+    (0..10).filter(|x| x % 2 == 0).collect::<Vec<u32>>()
+}
+
+fn main() {
+    let v: Vec<i32> = (0..10).collect::<Vec<i32>>();
+    let set: HashSet<i32> = v.iter().cloned().collect::<HashSet<_>>();
+    let _ = Numbers {
+        values: (0..3).collect::<Vec<u32>>(),
+    };
+
+    // ok: the binding isn't annotated
+    let w = (0..10).collect::<Vec<i32>>();
+    // ok: the annotation is incomplete
+    let x: Vec<_> = (0..10).collect::<Vec<i32>>();
+    // ok: the field type is generic
+    let _ = Wrapper {
+        value: (0..3).collect::<Vec<u32>>(),
+    };
+    // ok: the turbofish isn't on the outermost call
+    let n: u32 = "12".parse::<u32>().unwrap();
+    // ok: no turbofish
+    let y: Vec<i32> = (0..10).collect();
+    let _ = evens();
+    let _ = (set, n, w, x, y);
+}
@@ -0,0 +1,38 @@
+#![allow(unused_variables, clippy::many_single_char_names)]
+
+struct Person {
+    id: u32,
+    age: u32,
+}
+
+impl Person {
+    fn set_id(&mut self, id: u32) {
+        self.id = self.id;
+    }
+
+    fn grow(&mut self) {
+        self.age = self.age;
+    }
+}
+
+fn main() {
+    let mut a = 1;
+    a = a;
+    let mut v = [1, 2, 3];
+    let i = 0;
+    v[i] = v[i];
+    let mut p = Person { id: 1, age: 1 };
+    p.age = p.age;
+    p.set_id(2);
+    p.grow();
+
+    // ok: a different place
+    v[0] = v[1];
+    // ok: the index could have side effects
+    v[next(i)] = v[next(i)];
+    let _ = (a, v, p.age);
+}
+
+fn next(i: usize) -> usize {
+    i + 1
+}
@@ -0,0 +1,54 @@
+#![warn(clippy::shadowed_glob_imports)]
+#![allow(dead_code, unused_imports)]
+
+mod shapes {
+    pub fn area() {}
+    pub fn perimeter() {}
+    pub struct Circle;
+    fn hidden() {}
+}
+
+mod colors {
+    pub const RED: u32 = 0xff_00_00;
+    pub const GREEN: u32 = 0x00_ff_00;
+}
+
+mod a {
+    use super::shapes::*;
+
+    fn area() {}
+    struct Circle;
+}
+
+mod b {
+    use super::shapes::*;
+
+    // private items of the glob imported module aren't imported
+    fn hidden() {}
+}
+
+mod c {
+    // single imports take precedence themselves
+    use super::colors::RED;
+    use super::colors::*;
+}
+
+mod d {
+    // exported globs are API
+    pub use super::colors::*;
+
+    pub const GREEN: u32 = 0;
+}
+
+mod e {
+    #[allow(clippy::shadowed_glob_imports)]
+    use super::colors::*;
+
+    const RED: u32 = 0;
+}
+
+use std::f64::consts::*;
+
+const PI: f64 = 3.0;
+
+fn main() {}
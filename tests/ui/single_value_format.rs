@@ -0,0 +1,23 @@
+#![allow(clippy::useless_format)]
+#![warn(clippy::single_value_format)]
+
+use std::path::Path;
+
+fn main() {
+    let n = 42;
+    let _ = format!("{}", n);
+    let _ = format!("{}", n + 1);
+    let _ = format!("{}", Path::new("/tmp").display());
+
+    // the format makes a difference
+    let _ = format!("{:+}", n);
+    let _ = format!("{:5}", n);
+    let _ = format!("{:?}", n);
+    let _ = format!("{:#?}", n);
+    // no `Display`
+    let _ = format!("{:?}", [n]);
+    let _ = format!("{:?}", "foo");
+    let _ = format!("n = {}", n);
+    // linted by `useless_format`
+    let _ = format!("{}", "foo");
+}
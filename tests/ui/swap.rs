@@ -35,6 +35,7 @@ fn main() {
     array();
     slice();
     vec();
+    nested();
 
     let mut a = 42;
     let mut b = 1337;
@@ -55,3 +56,10 @@ fn main() {
     c.0 = a;
     a = t;
 }
+
+fn nested() {
+    let mut foo = vec![Foo(1), Foo(2)];
+    let temp = foo[0].0;
+    foo[0].0 = foo[1].0;
+    foo[1].0 = temp;
+}
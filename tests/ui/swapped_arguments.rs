@@ -0,0 +1,56 @@
+#![warn(clippy::swapped_arguments)]
+
+use std::cmp::Ordering;
+
+fn draw(x: u32, y: u32) {}
+
+fn copy(src: &[u8], len: usize, dst: &mut [u8]) {}
+
+struct Canvas;
+
+impl Canvas {
+    fn draw(&self, x: u32, y: u32) {}
+}
+
+#[derive(PartialEq, Eq)]
+struct Reversed(u32);
+
+impl Reversed {
+    fn is_before(&self, other: &Self) -> bool {
+        other.is_after(self)
+    }
+
+    fn is_after(&self, other: &Self) -> bool {
+        self.0 < other.0
+    }
+}
+
+impl PartialOrd for Reversed {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(other.cmp(self))
+    }
+}
+
+impl Ord for Reversed {
+    fn cmp(&self, other: &Self) -> Ordering {
+        Ord::cmp(&other.0, &self.0)
+    }
+}
+
+fn main() {
+    let (x, y) = (1, 2);
+    draw(y, x);
+    Canvas.draw(y, x);
+
+    let (src, mut dst) = ([0; 4], [0; 4]);
+    copy(&mut dst, 4, &src);
+
+    // not linted
+    draw(x, y);
+    draw(y, y);
+    draw(y, 0);
+    let (a, b) = (1, 2);
+    draw(b, a);
+    std::cmp::max(y, x);
+    Reversed(1).is_before(&Reversed(2));
+}
@@ -0,0 +1,23 @@
+#![warn(clippy::system_time_elapsed, clippy::formatted_instant)]
+
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+fn main() {
+    let start = SystemTime::now();
+    let _ = start.elapsed();
+    let _ = SystemTime::now().duration_since(start);
+    let _ = SystemTime::now().elapsed();
+
+    // ok: a wall-clock timestamp
+    let _ = SystemTime::now().duration_since(UNIX_EPOCH);
+    // ok: monotonic
+    let begin = Instant::now();
+    let _ = begin.elapsed();
+
+    println!("started at {:?}", begin);
+    println!("{} {:?}", 1, Instant::now());
+
+    // ok: durations can be formatted
+    println!("took {:?}", begin.elapsed());
+    println!("now {:?}", start);
+}
@@ -0,0 +1,44 @@
+#![warn(clippy::type_repetition_in_bounds)]
+#![allow(dead_code)]
+
+use std::fmt::Debug;
+
+fn split<T>(_: T)
+where
+    T: Clone,
+    T: Debug,
+{
+}
+
+fn inline_and_where<T: Clone>(_: T)
+where
+    T: Debug,
+{
+}
+
+fn exact_duplicate<T: Clone + Debug + Clone>(_: T) {}
+
+fn repeated_twice<T>(_: T)
+where
+    T: Clone,
+    T: Debug,
+    T: Clone,
+{
+}
+
+// ok
+fn combined<T: Clone + Debug, U: Clone>(_: T, _: U)
+where
+    Vec<U>: Debug,
+{
+}
+
+// ok, higher-ranked predicates are not merged
+fn higher_ranked<F>(_: F)
+where
+    F: Clone,
+    for<'a> F: Fn(&'a u8),
+{
+}
+
+fn main() {}
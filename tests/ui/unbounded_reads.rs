@@ -0,0 +1,32 @@
+#![warn(clippy::unbounded_reads)]
+
+use std::fs::File;
+use std::io::{self, BufReader, Read};
+use std::net::TcpStream;
+
+fn read_request(mut stream: TcpStream) -> io::Result<String> {
+    let mut request = String::new();
+    stream.read_to_string(&mut request)?;
+    Ok(request)
+}
+
+fn read_buffered(stream: &TcpStream) -> io::Result<Vec<u8>> {
+    let mut reader = BufReader::new(stream);
+    let mut body = Vec::new();
+    reader.read_to_end(&mut body)?;
+    Ok(body)
+}
+
+fn read_limited(stream: &mut TcpStream) -> io::Result<Vec<u8>> {
+    let mut body = Vec::new();
+    stream.by_ref().take(1024).read_to_end(&mut body)?;
+    Ok(body)
+}
+
+fn read_file(mut file: File) -> io::Result<String> {
+    let mut contents = String::new();
+    file.read_to_string(&mut contents)?;
+    Ok(contents)
+}
+
+fn main() {}
@@ -0,0 +1,26 @@
+#![warn(clippy::unchecked_time_subtraction)]
+
+use std::time::{Duration, Instant};
+
+fn main() {
+    let start = Instant::now();
+    let deadline = start + Duration::from_secs(5);
+    let timeout = Duration::from_secs(10);
+
+    let _ = deadline - start;
+    let _ = timeout - start.elapsed();
+    let mut remaining = timeout;
+    remaining -= Duration::from_secs(1);
+
+    // ok: `Instant::now()` is never earlier than a previous instant
+    let _ = Instant::now() - start;
+    // ok: an `Instant` minus a `Duration`
+    let _ = deadline - timeout;
+    // ok: checked
+    let _ = timeout.checked_sub(start.elapsed());
+}
+
+#[clippy::msrv = "1.39"]
+fn duration_since(start: Instant, end: Instant) {
+    let _ = end - start;
+}
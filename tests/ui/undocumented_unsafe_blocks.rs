@@ -0,0 +1,40 @@
+#![warn(clippy::undocumented_unsafe_blocks)]
+
+macro_rules! read {
+    ($p:expr) => {
+        unsafe { *$p }
+    };
+}
+
+fn main() {
+    let v = vec![1, 2, 3];
+    let p = v.as_ptr();
+
+    let _ = unsafe { *p };
+
+    // SAFETY: `v` has three elements
+    let _ = unsafe { *v.get_unchecked(2) };
+
+    // SAFETY: `p` points into `v`, which is still alive
+    //
+    // it isn't written to while it's read
+    let _ = unsafe { *p };
+
+    // SAFETY: the comment has to be right above the block
+
+    let _ = unsafe { *p };
+
+    let _ = vec![
+        // SAFETY: `p` points into `v`
+        unsafe { *p },
+        unsafe { *p.add(1) },
+    ];
+
+    // a comment without the prefix
+    unsafe {
+        let _ = *p;
+    }
+
+    // blocks expanded from macros aren't linted
+    let _ = read!(p);
+}
@@ -0,0 +1,55 @@
+#![warn(clippy::unnecessary_visibility)]
+
+mod parser {
+    pub(crate) fn skip_whitespace(s: &str) -> &str {
+        s.trim_start()
+    }
+
+    pub(super) const LIMIT: usize = 10;
+
+    pub(crate) fn parse(s: &str) -> usize {
+        skip_whitespace(s).len().min(LIMIT)
+    }
+
+    pub(crate) struct Parser;
+
+    impl Parser {
+        pub(crate) fn new() -> Self {
+            Parser
+        }
+
+        pub(crate) fn reset(&mut self) {}
+
+        pub(crate) fn run(&mut self) {
+            self.reset();
+        }
+    }
+
+    mod inner {
+        pub(in crate::parser) fn helper() {}
+
+        fn call_helper() {
+            helper();
+        }
+    }
+
+    // uses in submodules don't need the visibility either
+    pub(crate) fn only_in_tests() {}
+
+    mod tests {
+        fn test() {
+            super::only_in_tests();
+        }
+    }
+
+    // unused items are left to `dead_code`
+    pub(crate) fn unused() {}
+
+    fn private() {}
+}
+
+fn main() {
+    let _ = parser::parse(" foo");
+    let mut p = parser::Parser::new();
+    p.run();
+}
@@ -0,0 +1,32 @@
+// compile-flags: --edition 2018
+#![feature(async_await, await_macro, futures_api)]
+#![warn(clippy::unused_async)]
+
+use std::future::Future;
+
+async fn add(a: u32, b: u32) -> u32 {
+    a + b
+}
+
+async fn awaits() -> u32 {
+    await!(ready()) + 1
+}
+
+async fn ready() -> u32 {
+    await!(add(1, 2))
+}
+
+// the `await!` is in a block which is a future of its own
+async fn nested() -> impl Future<Output = u32> {
+    async { await!(ready()) }
+}
+
+struct S;
+
+impl S {
+    async fn method(&self) -> u32 {
+        0
+    }
+}
+
+fn main() {}
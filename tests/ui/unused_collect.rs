@@ -0,0 +1,20 @@
+#![warn(clippy::unused_collect)]
+
+use std::collections::HashSet;
+
+fn main() {
+    let v = vec![1, 2, 3];
+    let mut out = Vec::new();
+    let mut set = HashSet::new();
+
+    v.iter().map(|x| println!("{}", x)).collect::<Vec<()>>();
+    v.iter().map(|x| out.push(*x)).count();
+    v.iter().inspect(|x| println!("{}", x)).count();
+    v.iter().map(|x| set.insert(*x)).count();
+
+    // the results are used
+    let _units = v.iter().map(|x| println!("{}", x)).collect::<Vec<()>>();
+    let _count = v.iter().map(|x| println!("{}", x)).count();
+    // there are no side effects
+    v.iter().filter(|x| **x > 1).count();
+}
@@ -1,4 +1,4 @@
-#![allow(dead_code)]
+#![allow(dead_code, unused_must_use)]
 #![warn(clippy::unused_io_amount)]
 
 use std::io;
@@ -23,4 +23,19 @@ fn unwrap<T: io::Read + io::Write>(s: &mut T) {
     s.read(&mut buf).unwrap();
 }
 
+fn discarded<T: io::Read + io::Write>(s: &mut T) -> io::Result<()> {
+    s.write(b"test");
+    let _ = s.write(b"test");
+    let _ = s.write(b"test")?;
+    let mut buf = [0u8; 4];
+    s.read(&mut buf).ok();
+    Ok(())
+}
+
+fn handled<T: io::Read + io::Write>(s: &mut T) -> io::Result<()> {
+    let n = s.write(b"test")?;
+    s.write_all(&b"test"[n..])?;
+    Ok(())
+}
+
 fn main() {}
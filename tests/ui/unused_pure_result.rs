@@ -0,0 +1,37 @@
+#![warn(clippy::unused_pure_result)]
+#![allow(unused_variables)]
+
+struct Counter(u32);
+
+impl Counter {
+    fn len(&mut self) -> u32 {
+        self.0 += 1;
+        self.0
+    }
+}
+
+fn main() {
+    let s = String::from(" foo ");
+    s.trim();
+    s.to_uppercase();
+    let x: u32 = 5;
+    x.checked_add(1);
+    x.wrapping_mul(2);
+    let v = vec![1, 2, 3];
+    v.clone();
+    v.len();
+
+    // ok, the result is used
+    let t = s.trim();
+    let y = x.checked_add(1).unwrap_or(0);
+    println!("{}", v.clone().len());
+
+    // ok, not a std method, though it's named like one
+    let mut c = Counter(0);
+    c.len();
+
+    // ok, methods with side effects
+    let mut w = vec![1];
+    w.push(2);
+    w.pop();
+}
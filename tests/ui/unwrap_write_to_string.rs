@@ -0,0 +1,22 @@
+#![warn(clippy::unwrap_write_to_string)]
+
+use std::fmt::Write;
+
+fn main() {
+    let mut s = String::new();
+    let name = String::from("foo");
+    let greeting = "hello";
+
+    write!(s, "foo").unwrap();
+    writeln!(s, "foo").expect("can't fail");
+    write!(s, "{}", name).unwrap();
+    write!(&mut s, "{}", greeting).unwrap();
+    write!(s, "{}: {}", greeting, name).unwrap();
+    writeln!(s, "{}", name).unwrap();
+
+    // not a `String`
+    let mut out = std::io::sink();
+    std::io::Write::write_fmt(&mut out, format_args!("foo")).unwrap();
+    // the result isn't unwrapped
+    let _ = write!(s, "foo");
+}
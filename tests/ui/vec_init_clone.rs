@@ -0,0 +1,37 @@
+#![warn(clippy::rc_clone_in_vec_init, clippy::expensive_clone_in_vec_init)]
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+
+fn counter() -> Rc<RefCell<u32>> {
+    Rc::new(RefCell::new(0))
+}
+
+fn main() {
+    let len = 10;
+
+    let _ = vec![Rc::new(RefCell::new(0)); len];
+    let _ = vec![Arc::new(Mutex::new(Vec::<u8>::new())); 2];
+    let _ = vec![String::from("cell"); len];
+    let _ = vec![vec![0_u8; 8]; 100];
+
+    // sharing an existing `Rc` is intended
+    let shared = Rc::new(0);
+    let _ = vec![shared; len];
+    let _ = vec![shared.clone(); len];
+    let _ = vec![Rc::clone(&shared); len];
+    // other functions may return a shared `Rc`
+    let _ = vec![counter(); len];
+
+    // empty values are cheap to clone
+    let _: Vec<Vec<u8>> = vec![Vec::new(); len];
+    let _: Vec<Vec<u8>> = vec![vec![]; len];
+    let _ = vec![String::new(); len];
+
+    // a few clones are fine
+    let _ = vec![String::from("cell"); 4];
+
+    // `Copy` elements
+    let _ = vec![0_u8; len];
+}
@@ -0,0 +1,54 @@
+#![allow(clippy::needless_range_loop)]
+
+fn remove_zeros(v: &mut Vec<u32>) {
+    for i in 0..v.len() {
+        if v[i] == 0 {
+            v.remove(i);
+        }
+    }
+}
+
+fn main() {
+    let mut v = vec![1, 0, 2, 0];
+    for i in 0..v.len() {
+        if v[i] == 1 {
+            v.insert(i, 0);
+        }
+    }
+    remove_zeros(&mut v);
+
+    // ok: the pushed elements aren't visited
+    for i in 0..v.len() {
+        let x = v[i];
+        v.push(x);
+    }
+
+    // ok: the index is checked against the current length
+    for i in 0..v.len() {
+        if i >= v.len() {
+            break;
+        }
+        if v[i] == 0 {
+            v.swap_remove(i);
+        }
+    }
+
+    // ok: the loop is left right after the mutation
+    for i in 0..v.len() {
+        if v[i] == 2 {
+            v.insert(i, 1);
+            break;
+        }
+    }
+
+    // ok: another vector is mutated
+    let mut w = Vec::new();
+    for i in 0..v.len() {
+        w.push(v[i]);
+    }
+
+    // ok: the length is unchanged
+    for i in 0..v.len() {
+        v[i] += 1;
+    }
+}